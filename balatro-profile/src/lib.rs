@@ -13,4 +13,4 @@ mod save_snapshot;
 pub use error::ProfileError;
 pub use item_id::ItemId;
 pub use profile::{Profile, ProfileSummary};
-pub use save_snapshot::{SaveSnapshot, SaveSnapshotSummary};
+pub use save_snapshot::{BlindStatus, SaveSnapshot, SaveSnapshotSummary, SaveState, SavedCards};
@@ -137,6 +137,22 @@ impl Profile {
     pub fn summary(&self) -> ProfileSummary<'_> {
         ProfileSummary(self)
     }
+
+    /// Whether the real game's pools can offer the item named `name` on
+    /// this profile. The tags gated on a discovery (an edition, or
+    /// Blueprint for the Rare Tag) check `discovered`, everything else
+    /// checks `unlocked`.
+    pub fn has_unlocked(&self, name: &str) -> bool {
+        let requires = match name {
+            "Negative Tag" => ItemId::Edition(Edition::Negative),
+            "Foil Tag" => ItemId::Edition(Edition::Foil),
+            "Holographic Tag" => ItemId::Edition(Edition::Holographic),
+            "Polychrome Tag" => ItemId::Edition(Edition::Polychrome),
+            "Rare Tag" => ItemId::Joker(Jokers::Blueprint(Default::default())),
+            _ => return self.unlocked.iter().any(|i| i.to_string() == name),
+        };
+        self.discovered.contains(&requires)
+    }
 }
 
 impl fmt::Display for Profile {
@@ -346,11 +362,9 @@ impl fmt::Display for ProfileSummary<'_> {
             "Furthest ante: {}",
             format_number(p.high_scores.furthest_ante.amount as i64)
         )?;
-        let win_rate = if cs.wins + cs.losses > 0 {
-            cs.wins * 100 / (cs.wins + cs.losses)
-        } else {
-            0
-        };
+        let win_rate = (cs.wins * 100)
+            .checked_div(cs.wins + cs.losses)
+            .unwrap_or(0);
         writeln!(
             f,
             "Wins: {} / Rounds: {} ({win_rate}%)",
@@ -638,6 +652,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_has_unlocked_by_name() {
+        let (Some(meta), Some(profile)) = (fixture("1-meta.jkr"), fixture("1-profile.jkr")) else {
+            return;
+        };
+        let p = Profile::from_lua(&meta, &profile).expect("parses");
+        assert!(p.has_unlocked("Scary Face"));
+        assert_eq!(
+            p.has_unlocked("Negative Tag"),
+            p.discovered.contains(&ItemId::Edition(Edition::Negative))
+        );
+    }
+
     #[test]
     fn test_parses_real_2_and_3_profiles() {
         for n in [2, 3] {
@@ -1,25 +1,161 @@
 use std::fmt;
 
 use balatro_jkr::LuaValue;
-use balatro_types::{Card, Consumable, DeckVariant, Edition, Jokers, Stake, Suit, Value};
+use balatro_types::{
+    Blind, BossBlind, Card, Consumable, DeckVariant, Edition, Enhancement, HandRank, Jokers,
+    PERISHABLE_ROUNDS, Pack, Seal, Stake, Stickers, Suit, Tag, Value, Voucher,
+};
 
 use crate::error::ProfileError;
 use crate::fmt_num::format_number;
-use crate::lua_ext::{as_num, as_str, get, require};
+use crate::item_id::ItemId;
+use crate::lua_ext::{as_num, as_str, get, require, str_entries};
 
-/// A typed view of an in-progress run, parsed from `save.jkr`. First pass —
-/// no export, no stateful joker counters.
+/// Where a run was when it was saved, from the save's `STATE` (`G.STATES`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveState {
+    BlindSelect,
+    /// Mid-blind: selecting, playing or drawing a hand.
+    Playing,
+    RoundEval,
+    Shop,
+    PackOpen,
+    GameOver,
+}
+
+impl SaveState {
+    pub fn from_id(n: u8) -> Option<Self> {
+        match n {
+            1..=3 => Some(Self::Playing),
+            4 => Some(Self::GameOver),
+            5 => Some(Self::Shop),
+            7 => Some(Self::BlindSelect),
+            8 => Some(Self::RoundEval),
+            9 | 10 | 15 | 17 | 18 => Some(Self::PackOpen),
+            _ => None,
+        }
+    }
+}
+
+/// Where one of the ante's blinds stands, from
+/// `GAME.round_resets.blind_states`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlindStatus {
+    Upcoming,
+    Select,
+    Current,
+    Defeated,
+    Skipped,
+}
+
+impl BlindStatus {
+    pub fn from_id(s: &str) -> Option<Self> {
+        match s {
+            "Upcoming" => Some(Self::Upcoming),
+            "Select" => Some(Self::Select),
+            "Current" => Some(Self::Current),
+            "Defeated" => Some(Self::Defeated),
+            "Skipped" => Some(Self::Skipped),
+            _ => None,
+        }
+    }
+}
+
+/// The cards in one of the save's `cardAreas`, sorted by kind.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SavedCards {
+    pub jokers: Vec<(Jokers, Option<Edition>)>,
+    /// Perkeo's copies come back as `Some(Edition::Negative)`.
+    pub consumables: Vec<(Consumable, Option<Edition>)>,
+    pub cards: Vec<Card>,
+    pub vouchers: Vec<Voucher>,
+    /// Booster packs on sale; `contents` is empty until one is opened.
+    pub packs: Vec<Pack>,
+}
+
+impl SavedCards {
+    pub fn len(&self) -> usize {
+        self.jokers.len()
+            + self.consumables.len()
+            + self.cards.len()
+            + self.vouchers.len()
+            + self.packs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every card's name, jokers first.
+    fn names(&self) -> Vec<String> {
+        let jokers = self.jokers.iter().map(|(j, e)| joker_label(j, *e));
+        let consumables = self
+            .consumables
+            .iter()
+            .map(|(c, e)| consumable_label(c, *e));
+        let cards = self.cards.iter().map(card_label);
+        let vouchers = self.vouchers.iter().map(|v| v.name().to_string());
+        let packs = self.packs.iter().map(|p| p.name());
+        jokers
+            .chain(consumables)
+            .chain(cards)
+            .chain(vouchers)
+            .chain(packs)
+            .collect()
+    }
+}
+
+/// A typed view of an in-progress run, parsed from `save.jkr`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SaveSnapshot {
     pub stake: Stake,
     pub deck: DeckVariant,
     pub dollars: i64,
-    /// Always `None` — no confirmed field to parse it from yet.
+    /// `GAME.won`: the final ante's boss has been beaten, even if the run
+    /// carried on into endless mode.
+    pub won: bool,
+    /// `GAME.pseudorandom.seed`.
+    pub seed: Option<String>,
+    pub state: Option<SaveState>,
+    pub ante: usize,
+    pub round: usize,
+    /// The blind being played, or the next one up outside of a blind.
+    pub blind: Option<Blind>,
+    pub blind_states: Vec<(Blind, BlindStatus)>,
+    pub boss: Option<BossBlind>,
+    /// Times each boss has come up this run, from `GAME.bosses_used`. The
+    /// boss on offer is already counted.
+    pub bosses_used: Vec<(BossBlind, usize)>,
+    /// Tags on offer for skipping the Small and Big Blinds.
+    pub blind_tags: Vec<(Blind, Tag)>,
+    pub hands_left: usize,
+    pub discards_left: usize,
+    /// Chips scored so far against the current blind.
+    pub chips: f64,
+    /// `(hand, level, times played)`, for every hand in the save.
+    pub hand_levels: Vec<(HandRank, usize, usize)>,
+    /// Redeemed vouchers, from `GAME.used_vouchers`.
+    pub vouchers: Vec<Voucher>,
+    /// Tags won by skipping and not yet used.
+    pub tags: Vec<Tag>,
+    /// Stickers, `ability.perish_tally`, `ability.extra_value` and the
+    /// joker's own counter are carried on the `Jokers` itself.
     pub jokers: Vec<(Jokers, Option<Edition>)>,
     pub deck_cards: Vec<Card>,
     pub hand: Vec<Card>,
     pub discard: Vec<Card>,
-    pub consumables: Vec<Consumable>,
+    pub consumables: Vec<(Consumable, Option<Edition>)>,
+    /// This ante's voucher, from `current_round.voucher`, whether or not
+    /// it's been bought.
+    pub voucher: Option<Voucher>,
+    /// The shop's current reroll price.
+    pub reroll_cost: usize,
+    /// For sale in the shop, empty outside of it.
+    pub shop: SavedCards,
+    /// The open booster pack's choices, empty when none is open.
+    pub pack: SavedCards,
+    /// Picks left in the open pack.
+    pub pack_choices: usize,
 }
 
 impl SaveSnapshot {
@@ -37,21 +173,101 @@ impl SaveSnapshot {
             .ok_or_else(|| ProfileError::UnknownId(deck_key.to_string()))?;
 
         let dollars = as_num(require(game, "dollars")?).unwrap_or(0.0) as i64;
+        let round_resets = get(game, "round_resets");
+        let current_round = get(game, "current_round");
+        let num = |v: Option<&LuaValue>, key: &str| {
+            v.and_then(|v| get(v, key)).and_then(as_num).unwrap_or(0.0)
+        };
 
         Ok(SaveSnapshot {
             stake,
             deck,
             dollars,
-            jokers: pile_cards(card_areas, "jokers")
-                .filter_map(Jokers::from_id)
-                .map(|j| (j, None))
-                .collect(),
-            deck_cards: pile_playing_cards(card_areas, "deck"),
-            hand: pile_playing_cards(card_areas, "hand"),
-            discard: pile_playing_cards(card_areas, "discard"),
-            consumables: pile_cards(card_areas, "consumeables")
-                .filter_map(Consumable::from_id)
+            won: get(game, "won") == Some(&LuaValue::Bool(true)),
+            seed: get(game, "pseudorandom")
+                .and_then(|p| get(p, "seed"))
+                .and_then(as_str)
+                .map(str::to_string),
+            state: get(save, "STATE")
+                .and_then(as_num)
+                .and_then(|n| SaveState::from_id(n as u8)),
+            ante: num(round_resets, "ante") as usize,
+            round: num(Some(game), "round") as usize,
+            blind: get(game, "blind_on_deck")
+                .and_then(as_str)
+                .and_then(parse_blind),
+            blind_states: round_resets
+                .and_then(|r| get(r, "blind_states"))
+                .map(|states| parse_blind_map(states, BlindStatus::from_id))
+                .unwrap_or_default(),
+            boss: round_resets
+                .and_then(|r| get(r, "blind_choices"))
+                .and_then(|c| get(c, "Boss"))
+                .and_then(as_str)
+                .and_then(BossBlind::from_id),
+            bosses_used: get(game, "bosses_used")
+                .map(|used| {
+                    str_entries(used)
+                        .filter_map(|(id, n)| Some((BossBlind::from_id(id)?, as_num(n)? as usize)))
+                        .filter(|(_, n)| *n > 0)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            blind_tags: round_resets
+                .and_then(|r| get(r, "blind_tags"))
+                .map(|tags| parse_blind_map(tags, Tag::from_id))
+                .unwrap_or_default(),
+            hands_left: num(current_round, "hands_left") as usize,
+            discards_left: num(current_round, "discards_left") as usize,
+            chips: num(Some(game), "chips"),
+            hand_levels: get(game, "hands")
+                .map(parse_hand_levels)
+                .unwrap_or_default(),
+            vouchers: get(game, "used_vouchers")
+                .map(|used| {
+                    str_entries(used)
+                        .filter(|(_, redeemed)| **redeemed == LuaValue::Bool(true))
+                        .filter_map(|(id, _)| Voucher::from_id(id))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            tags: get(save, "tags")
+                .and_then(balatro_jkr_table_values)
+                .into_iter()
+                .flatten()
+                .filter_map(|tag| get(tag, "key"))
+                .filter_map(as_str)
+                .filter_map(Tag::from_id)
                 .collect(),
+            jokers: parse_area(card_areas, "jokers").jokers,
+            deck_cards: parse_area(card_areas, "deck").cards,
+            hand: parse_area(card_areas, "hand").cards,
+            discard: parse_area(card_areas, "discard").cards,
+            consumables: parse_area(card_areas, "consumeables").consumables,
+            voucher: current_round
+                .and_then(|r| get(r, "voucher"))
+                .and_then(as_str)
+                .and_then(Voucher::from_id),
+            reroll_cost: num(current_round, "reroll_cost") as usize,
+            shop: ["shop_jokers", "shop_vouchers", "shop_booster"]
+                .iter()
+                .fold(SavedCards::default(), |mut shop, area| {
+                    let SavedCards {
+                        jokers,
+                        consumables,
+                        cards,
+                        vouchers,
+                        packs,
+                    } = parse_area(card_areas, area);
+                    shop.jokers.extend(jokers);
+                    shop.consumables.extend(consumables);
+                    shop.cards.extend(cards);
+                    shop.vouchers.extend(vouchers);
+                    shop.packs.extend(packs);
+                    shop
+                }),
+            pack: parse_area(card_areas, "pack_cards"),
+            pack_choices: num(Some(game), "pack_choices") as usize,
         })
     }
 
@@ -64,29 +280,91 @@ impl SaveSnapshot {
 impl fmt::Display for SaveSnapshot {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "{}, {:?} Stake", self.deck.name(), self.stake)?;
+        if let Some(seed) = &self.seed {
+            writeln!(f, "Seed: {seed}")?;
+        }
+        writeln!(f, "Ante {}, round {}", self.ante, self.round)?;
+        if let Some(state) = self.state {
+            writeln!(f, "State: {state:?}")?;
+        }
+        writeln!(f, "{}", blind_line(self))?;
+        writeln!(
+            f,
+            "Hands left: {}, discards left: {}, chips: {}",
+            self.hands_left,
+            self.discards_left,
+            format_number(self.chips as i64)
+        )?;
         writeln!(f, "Dollars: {}", format_number(self.dollars))?;
 
+        if !self.blind_states.is_empty() {
+            writeln!(f, "\nBlinds:")?;
+            for (blind, status) in &self.blind_states {
+                let tag = self.blind_tags.iter().find(|(b, _)| b == blind);
+                match tag {
+                    Some((_, tag)) => writeln!(f, "  {blind:?}: {status:?} ({})", tag.name())?,
+                    None => writeln!(f, "  {blind:?}: {status:?}")?,
+                }
+            }
+        }
+
+        writeln!(f, "\nHand Levels:")?;
+        for (rank, level, played) in &self.hand_levels {
+            writeln!(f, "  {}: level {level}, played {played}x", rank.id())?;
+        }
+
+        writeln!(f, "\nVouchers ({}):", self.vouchers.len())?;
+        for v in &self.vouchers {
+            writeln!(f, "  {}", v.name())?;
+        }
+
+        writeln!(f, "\nTags ({}):", self.tags.len())?;
+        for t in &self.tags {
+            writeln!(f, "  {}", t.name())?;
+        }
+
         writeln!(f, "\nJokers ({}):", self.jokers.len())?;
         for (j, edition) in &self.jokers {
-            match edition {
-                Some(e) => writeln!(f, "  {} ({e:?})", j.name())?,
-                None => writeln!(f, "  {}", j.name())?,
-            }
+            writeln!(f, "  {}", joker_label(j, *edition))?;
         }
 
         writeln!(f, "\nConsumables ({}):", self.consumables.len())?;
-        for c in &self.consumables {
-            writeln!(f, "  {}", c.name())?;
+        for (c, edition) in &self.consumables {
+            writeln!(f, "  {}", consumable_label(c, *edition))?;
         }
 
         writeln!(f, "\nHand ({}):", self.hand.len())?;
         for c in &self.hand {
-            writeln!(f, "  {c}")?;
+            writeln!(f, "  {}", card_label(c))?;
         }
 
         writeln!(f, "\nDiscard ({}):", self.discard.len())?;
         for c in &self.discard {
-            writeln!(f, "  {c}")?;
+            writeln!(f, "  {}", card_label(c))?;
+        }
+
+        if !self.shop.is_empty() {
+            writeln!(
+                f,
+                "\nShop ({}, reroll ${}):",
+                self.shop.len(),
+                self.reroll_cost
+            )?;
+            for name in self.shop.names() {
+                writeln!(f, "  {name}")?;
+            }
+        }
+
+        if !self.pack.is_empty() {
+            writeln!(
+                f,
+                "\nOpen Pack ({}, {} to pick):",
+                self.pack.len(),
+                self.pack_choices
+            )?;
+            for name in self.pack.names() {
+                writeln!(f, "  {name}")?;
+            }
         }
 
         write!(f, "\nDeck ({} cards remaining)", self.deck_cards.len())?;
@@ -102,38 +380,315 @@ impl fmt::Display for SaveSnapshotSummary<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = self.0;
         writeln!(f, "{}, {:?} Stake", s.deck.name(), s.stake)?;
+        if let Some(seed) = &s.seed {
+            writeln!(f, "Seed: {seed}")?;
+        }
+        writeln!(f, "Ante {}, round {}", s.ante, s.round)?;
+        if let Some(state) = s.state {
+            writeln!(f, "State: {state:?}")?;
+        }
+        writeln!(f, "{}", blind_line(s))?;
+        writeln!(
+            f,
+            "Hands left: {}, discards left: {}, chips: {}",
+            s.hands_left,
+            s.discards_left,
+            format_number(s.chips as i64)
+        )?;
         writeln!(f, "Dollars: {}", format_number(s.dollars))?;
-        writeln!(f, "Jokers: {}", s.jokers.len())?;
-        writeln!(f, "Consumables: {}", s.consumables.len())?;
+        let levelled: Vec<String> = s
+            .hand_levels
+            .iter()
+            .filter(|(_, level, _)| *level > 1)
+            .map(|(rank, level, _)| format!("{} {level}", rank.id()))
+            .collect();
+        writeln!(f, "Hand levels: {}", list_line(levelled))?;
+        writeln!(
+            f,
+            "Vouchers: {}",
+            list_line(s.vouchers.iter().map(|v| v.name().to_string()).collect())
+        )?;
+        writeln!(
+            f,
+            "Tags: {}",
+            list_line(s.tags.iter().map(|t| t.name().to_string()).collect())
+        )?;
+        writeln!(
+            f,
+            "Jokers: {}",
+            list_line(s.jokers.iter().map(|(j, e)| joker_label(j, *e)).collect())
+        )?;
+        writeln!(
+            f,
+            "Consumables: {}",
+            list_line(
+                s.consumables
+                    .iter()
+                    .map(|(c, e)| consumable_label(c, *e))
+                    .collect()
+            )
+        )?;
         writeln!(f, "Hand: {} cards", s.hand.len())?;
         writeln!(f, "Discard pile: {} cards", s.discard.len())?;
+        if !s.shop.is_empty() {
+            writeln!(
+                f,
+                "Shop (reroll ${}): {}",
+                s.reroll_cost,
+                list_line(s.shop.names())
+            )?;
+        }
+        if !s.pack.is_empty() {
+            writeln!(
+                f,
+                "Open pack ({} to pick): {}",
+                s.pack_choices,
+                list_line(s.pack.names())
+            )?;
+        }
         write!(f, "Remaining deck: {} cards", s.deck_cards.len())?;
         Ok(())
     }
 }
 
-/// `save_fields.center` ids for each card in a pile.
-fn pile_cards<'a>(card_areas: &'a LuaValue, pile: &str) -> impl Iterator<Item = &'a str> {
-    get(card_areas, pile)
-        .and_then(|p| get(p, "cards"))
+/// `"Blind: Big, boss The Wall"`.
+fn blind_line(s: &SaveSnapshot) -> String {
+    let blind = s.blind.map_or("none".to_string(), |b| format!("{b:?}"));
+    match s.boss {
+        Some(boss) => format!("Blind: {blind}, boss {}", boss.name()),
+        None => format!("Blind: {blind}"),
+    }
+}
+
+fn list_line(items: Vec<String>) -> String {
+    if items.is_empty() {
+        "none".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
+/// A joker's name with its edition and stickers, `"Joker (Foil, Eternal)"`.
+fn joker_label(joker: &Jokers, edition: Option<Edition>) -> String {
+    let stickers = joker.stickers();
+    let mut tags: Vec<String> = edition.map(|e| format!("{e:?}")).into_iter().collect();
+    for (on, name) in [
+        (stickers.eternal, "Eternal"),
+        (stickers.perishable, "Perishable"),
+        (stickers.rental, "Rental"),
+    ] {
+        if on {
+            tags.push(name.to_string());
+        }
+    }
+    if tags.is_empty() {
+        joker.name().to_string()
+    } else {
+        format!("{} ({})", joker.name(), tags.join(", "))
+    }
+}
+
+/// A consumable's name, with its edition if it has one.
+fn consumable_label(consumable: &Consumable, edition: Option<Edition>) -> String {
+    match edition {
+        Some(e) => format!("{} ({e:?})", consumable.name()),
+        None => consumable.name().to_string(),
+    }
+}
+
+/// A playing card with whatever sets it apart from a plain one.
+fn card_label(card: &Card) -> String {
+    let mut tags = vec![];
+    if let Some(e) = card.enhancement {
+        tags.push(format!("{e:?}"));
+    }
+    if card.edition != Edition::Base {
+        tags.push(format!("{:?}", card.edition));
+    }
+    if let Some(seal) = card.seal {
+        tags.push(format!("{seal:?} Seal"));
+    }
+    if card.bonus_chips > 0 {
+        tags.push(format!("+{} Chips", card.bonus_chips));
+    }
+    if tags.is_empty() {
+        card.to_string()
+    } else {
+        format!("{card} ({})", tags.join(", "))
+    }
+}
+
+/// Sorts a card area's cards by their `save_fields.center`. Cards whose
+/// center isn't recognised are skipped.
+fn parse_area(card_areas: &LuaValue, area: &str) -> SavedCards {
+    let mut saved = SavedCards::default();
+    let cards = get(card_areas, area)
+        .and_then(|a| get(a, "cards"))
         .and_then(balatro_jkr_table_values)
         .into_iter()
-        .flatten()
-        .filter_map(|card| get(card, "save_fields"))
-        .filter_map(|sf| get(sf, "center"))
-        .filter_map(as_str)
+        .flatten();
+    for card in cards {
+        let Some(save_fields) = get(card, "save_fields") else {
+            continue;
+        };
+        let Some(center) = get(save_fields, "center").and_then(as_str) else {
+            continue;
+        };
+        let edition = parse_edition(card);
+        if center == "c_base" || Enhancement::from_id(center).is_some() {
+            let code = get(save_fields, "card").and_then(as_str);
+            let Some(mut playing) = code.and_then(parse_playing_card_code) else {
+                continue;
+            };
+            playing.enhancement = Enhancement::from_id(center);
+            playing.edition = edition.unwrap_or_default();
+            playing.seal = get(card, "seal").and_then(as_str).and_then(Seal::from_id);
+            playing.bonus_chips = get(card, "ability")
+                .and_then(|a| get(a, "perma_bonus"))
+                .and_then(as_num)
+                .unwrap_or(0.0) as usize;
+            saved.cards.push(playing);
+            continue;
+        }
+        match ItemId::from_id(center) {
+            Some(ItemId::Joker(mut joker)) => {
+                if let Some(edition) = edition {
+                    joker.set_edition(edition);
+                }
+                if let Some(ability) = get(card, "ability") {
+                    apply_joker_ability(&mut joker, ability);
+                }
+                saved.jokers.push((joker, edition));
+            }
+            Some(ItemId::Consumable(c)) => saved.consumables.push((c, edition)),
+            Some(ItemId::Voucher(v)) => saved.vouchers.push(v),
+            Some(ItemId::Pack(category, size, _)) => saved.packs.push(Pack {
+                category,
+                size,
+                contents: vec![],
+            }),
+            _ => {}
+        }
+    }
+    saved
+}
+
+/// A card's `edition` table holds a flag for the edition it has
+/// (`{ foil = true, chips = 50, ... }`).
+fn parse_edition(card: &LuaValue) -> Option<Edition> {
+    let edition = get(card, "edition")?;
+    [
+        ("foil", Edition::Foil),
+        ("holo", Edition::Holographic),
+        ("polychrome", Edition::Polychrome),
+        ("negative", Edition::Negative),
+    ]
+    .into_iter()
+    .find(|(flag, _)| get(edition, flag) == Some(&LuaValue::Bool(true)))
+    .map(|(_, edition)| edition)
+}
+
+/// Stickers, rounds survived under Perishable, bonus sell value and the
+/// joker's counter.
+fn apply_joker_ability(joker: &mut Jokers, ability: &LuaValue) {
+    let flag = |key| get(ability, key) == Some(&LuaValue::Bool(true));
+    let number = |key| get(ability, key).and_then(as_num).unwrap_or(0.0) as usize;
+    let stickers = Stickers {
+        eternal: flag("eternal"),
+        perishable: flag("perishable"),
+        rental: flag("rental"),
+    };
+    joker.set_stickers(stickers);
+    let counter = joker_counter(joker, ability);
+    let state = joker.state_mut();
+    if let Some(counter) = counter {
+        state.counter = counter;
+    }
+    if stickers.perishable {
+        state.perish_rounds = PERISHABLE_ROUNDS.saturating_sub(number("perish_tally"));
+    }
+    state.extra_value = number("extra_value");
 }
 
-fn pile_playing_cards(card_areas: &LuaValue, pile: &str) -> Vec<Card> {
-    let cards = get(card_areas, pile).and_then(|p| get(p, "cards"));
-    let Some(cards) = cards.and_then(balatro_jkr_table_values) else {
-        return vec![];
+/// A joker's `JokerState::counter`, from wherever its `ability` keeps it.
+/// `None` for jokers without one.
+fn joker_counter(joker: &Jokers, ability: &LuaValue) -> Option<f32> {
+    let field = |key| get(ability, key).and_then(as_num);
+    let extra = |key| {
+        get(ability, "extra")
+            .and_then(|e| get(e, key))
+            .and_then(as_num)
     };
-    cards
-        .filter_map(|card| get(card, "save_fields"))
-        .filter_map(|sf| get(sf, "card"))
-        .filter_map(as_str)
-        .filter_map(parse_playing_card_code)
+    let counter = match joker {
+        Jokers::SpareTrousers(_)
+        | Jokers::CeremonialDagger(_)
+        | Jokers::FlashCard(_)
+        | Jokers::RedCard(_)
+        | Jokers::RideTheBus(_) => field("mult"),
+        // X mult gained on top of X1
+        Jokers::Madness(_)
+        | Jokers::Vampire(_)
+        | Jokers::Hologram(_)
+        | Jokers::Constellation(_)
+        | Jokers::LuckyCat(_)
+        | Jokers::GlassJoker(_)
+        | Jokers::Campfire(_) => field("x_mult").map(|x| x - 1.0),
+        Jokers::Canio(_) => field("caino_xmult").map(|x| x - 1.0),
+        // X0.2 per hand in a row that wasn't the most played
+        Jokers::Obelisk(_) => field("x_mult").map(|x| ((x - 1.0) / 0.2).round()),
+        Jokers::WeeJoker(_) | Jokers::SquareJoker(_) | Jokers::Runner(_) => extra("chips"),
+        // payout gained on top of $1
+        Jokers::Rocket(_) => extra("dollars").map(|d| d - 1.0),
+        // chips lost from 100
+        Jokers::IceCream(_) => extra("chips").map(|c| 100.0 - c),
+        // hand size eaten from 5
+        Jokers::TurtleBean(_) => extra("h_size").map(|h| 5.0 - h),
+        Jokers::InvisibleJoker(_) => field("invis_rounds"),
+        // cards discarded: 23 per X1 gained, then what's counted down since
+        Jokers::Yorick(_) => {
+            let gained = extra("xmult")? - 1.0;
+            Some(gained * 23.0 + 23.0 - field("yorick_discards")?)
+        }
+        _ => None,
+    };
+    counter.map(|c| c.max(0.0) as f32)
+}
+
+/// A `{ Small = ..., Big = ..., Boss = ... }` table, in blind order.
+fn parse_blind_map<T>(map: &LuaValue, parse: impl Fn(&str) -> Option<T>) -> Vec<(Blind, T)> {
+    [Blind::Small, Blind::Big, Blind::Boss]
+        .into_iter()
+        .filter_map(|blind| {
+            let key = match blind {
+                Blind::Small => "Small",
+                Blind::Big => "Big",
+                Blind::Boss => "Boss",
+            };
+            let value = get(map, key).and_then(as_str).and_then(&parse)?;
+            Some((blind, value))
+        })
+        .collect()
+}
+
+/// `"Small"`/`"Big"`/`"Boss"`, as in `GAME.blind_on_deck`.
+fn parse_blind(s: &str) -> Option<Blind> {
+    match s {
+        "Small" => Some(Blind::Small),
+        "Big" => Some(Blind::Big),
+        "Boss" => Some(Blind::Boss),
+        _ => None,
+    }
+}
+
+/// `GAME.hands` is keyed by display name (`"Three of a Kind"`); the
+/// `HandRank` id is that name without spaces.
+fn parse_hand_levels(hands: &LuaValue) -> Vec<(HandRank, usize, usize)> {
+    str_entries(hands)
+        .filter_map(|(name, hand)| {
+            let rank = HandRank::from_id(&name.replace(' ', ""))?;
+            let field = |key| get(hand, key).and_then(as_num).unwrap_or(0.0) as usize;
+            Some((rank, field("level").max(1), field("played")))
+        })
         .collect()
 }
 
@@ -145,8 +700,8 @@ fn balatro_jkr_table_values(v: &LuaValue) -> Option<impl Iterator<Item = &LuaVal
     }
 }
 
-/// Parses a playing-card code like `"D_7"` or `"C_T"`. No enhancement/seal
-/// support yet.
+/// Parses a playing-card code like `"D_7"` or `"C_T"`. Enhancement, edition
+/// and seal live elsewhere on the card, see [`parse_area`].
 fn parse_playing_card_code(code: &str) -> Option<Card> {
     let (suit_ch, rank) = code.split_once('_')?;
     let suit = match suit_ch {
@@ -223,6 +778,166 @@ mod tests {
         assert_eq!(s.deck, DeckVariant::Red);
     }
 
+    #[test]
+    fn test_parses_run_position() {
+        let save = balatro_jkr::parse(
+            r#"return {
+                ["STATE"] = 1,
+                ["BACK"] = { ["key"] = "b_red" },
+                ["cardAreas"] = {},
+                ["GAME"] = {
+                    ["stake"] = 1,
+                    ["dollars"] = 12,
+                    ["round"] = 4,
+                    ["chips"] = 150,
+                    ["blind_on_deck"] = "Big",
+                    ["pseudorandom"] = { ["seed"] = "TEST" },
+                    ["round_resets"] = { ["ante"] = 2, ["blind_choices"] = { ["Boss"] = "bl_wall" } },
+                    ["current_round"] = { ["hands_left"] = 3, ["discards_left"] = 2 },
+                    ["hands"] = {
+                        ["Three of a Kind"] = { ["level"] = 2, ["played"] = 5 },
+                        ["Pair"] = { ["level"] = 1, ["played"] = 0 },
+                    },
+                },
+            }"#,
+        )
+        .expect("valid lua");
+        let s = SaveSnapshot::from_lua(&save).expect("parses");
+        assert_eq!(s.seed.as_deref(), Some("TEST"));
+        assert_eq!(s.state, Some(SaveState::Playing));
+        assert_eq!((s.ante, s.round), (2, 4));
+        assert_eq!(s.blind, Some(Blind::Big));
+        assert_eq!(s.boss, Some(BossBlind::Wall));
+        assert_eq!((s.hands_left, s.discards_left), (3, 2));
+        assert_eq!(s.chips, 150.0);
+        assert!(s.hand_levels.contains(&(HandRank::ThreeOfAKind, 2, 5)));
+        assert!(s.hand_levels.contains(&(HandRank::OnePair, 1, 0)));
+    }
+
+    #[test]
+    fn test_parses_run_contents() {
+        let save = balatro_jkr::parse(
+            r#"return {
+                ["STATE"] = 5,
+                ["BACK"] = { ["key"] = "b_red" },
+                ["tags"] = { [1] = { ["key"] = "tag_double", ["tally"] = 1 } },
+                ["cardAreas"] = {
+                    ["jokers"] = { ["cards"] = {
+                        [1] = {
+                            ["save_fields"] = { ["center"] = "j_green_joker" },
+                            ["edition"] = { ["holo"] = true, ["mult"] = 10, ["type"] = "holo" },
+                            ["ability"] = {
+                                ["eternal"] = false,
+                                ["perishable"] = true,
+                                ["perish_tally"] = 3,
+                                ["rental"] = true,
+                                ["extra_value"] = 2,
+                            },
+                        },
+                        [2] = {
+                            ["save_fields"] = { ["center"] = "j_hologram" },
+                            ["ability"] = { ["x_mult"] = 1.5 },
+                        },
+                        [3] = {
+                            ["save_fields"] = { ["center"] = "j_ice_cream" },
+                            ["ability"] = { ["extra"] = { ["chips"] = 85, ["chip_mod"] = 5 } },
+                        },
+                    } },
+                    ["deck"] = { ["cards"] = {
+                        [1] = {
+                            ["save_fields"] = { ["center"] = "m_glass", ["card"] = "S_K" },
+                            ["seal"] = "Red",
+                            ["edition"] = { ["foil"] = true },
+                            ["ability"] = { ["perma_bonus"] = 5 },
+                        },
+                    } },
+                    ["consumeables"] = { ["cards"] = {
+                        [1] = {
+                            ["save_fields"] = { ["center"] = "c_fool" },
+                            ["edition"] = { ["negative"] = true, ["type"] = "negative" },
+                        },
+                    } },
+                    ["shop_jokers"] = { ["cards"] = {
+                        [1] = { ["save_fields"] = { ["center"] = "j_joker" } },
+                        [2] = { ["save_fields"] = { ["center"] = "c_venus" } },
+                    } },
+                    ["shop_vouchers"] = { ["cards"] = {
+                        [1] = { ["save_fields"] = { ["center"] = "v_grabber" } },
+                    } },
+                    ["shop_booster"] = { ["cards"] = {
+                        [1] = { ["save_fields"] = { ["center"] = "p_arcana_jumbo_1" } },
+                    } },
+                },
+                ["GAME"] = {
+                    ["stake"] = 1,
+                    ["dollars"] = 4,
+                    ["used_vouchers"] = { ["v_overstock_norm"] = true },
+                    ["round_resets"] = {
+                        ["ante"] = 1,
+                        ["blind_states"] = { ["Small"] = "Skipped", ["Big"] = "Select", ["Boss"] = "Upcoming" },
+                        ["blind_tags"] = { ["Small"] = "tag_double", ["Big"] = "tag_rare" },
+                    },
+                    ["current_round"] = { ["reroll_cost"] = 6, ["voucher"] = "v_grabber" },
+                    ["bosses_used"] = { ["bl_hook"] = 1, ["bl_ox"] = 0 },
+                },
+            }"#,
+        )
+        .expect("valid lua");
+        let s = SaveSnapshot::from_lua(&save).expect("parses");
+        assert_eq!(s.vouchers, vec![Voucher::Overstock]);
+        assert_eq!(s.tags, vec![Tag::Double]);
+        assert_eq!(
+            s.blind_states,
+            vec![
+                (Blind::Small, BlindStatus::Skipped),
+                (Blind::Big, BlindStatus::Select),
+                (Blind::Boss, BlindStatus::Upcoming),
+            ]
+        );
+        assert_eq!(
+            s.blind_tags,
+            vec![(Blind::Small, Tag::Double), (Blind::Big, Tag::Rare)]
+        );
+
+        let (joker, edition) = &s.jokers[0];
+        assert_eq!(*edition, Some(Edition::Holographic));
+        assert_eq!(joker.edition(), Edition::Holographic);
+        assert!(joker.stickers().perishable && joker.stickers().rental);
+        assert!(!joker.stickers().eternal);
+        assert_eq!(joker.state().perish_rounds, 2);
+        assert_eq!(joker.state().extra_value, 2);
+        // counters, from the x_mult and extra.chips the real game tracks
+        assert_eq!(s.jokers[1].0.state().counter, 0.5);
+        assert_eq!(s.jokers[2].0.state().counter, 15.0);
+
+        let card = s.deck_cards[0];
+        assert_eq!((card.value, card.suit), (Value::King, Suit::Spade));
+        assert_eq!(card.enhancement, Some(Enhancement::Glass));
+        assert_eq!(card.edition, Edition::Foil);
+        assert_eq!(card.seal, Some(Seal::Red));
+        assert_eq!(card.bonus_chips, 5);
+
+        assert_eq!(s.reroll_cost, 6);
+        assert_eq!(s.voucher, Some(Voucher::Grabber));
+        assert_eq!(s.bosses_used, vec![(BossBlind::Hook, 1)]);
+        assert_eq!(
+            s.consumables,
+            vec![(
+                Consumable::Tarot(balatro_types::Tarot::Fool),
+                Some(Edition::Negative)
+            )]
+        );
+        assert_eq!(s.shop.jokers.len(), 1);
+        assert_eq!(s.shop.consumables.len(), 1);
+        assert_eq!(s.shop.vouchers, vec![Voucher::Grabber]);
+        assert_eq!(s.shop.packs.len(), 1);
+        assert!(s.pack.is_empty());
+
+        let summary = s.summary().to_string();
+        assert!(summary.contains("Vouchers: Overstock"));
+        assert!(summary.contains("Green Joker (Holographic, Perishable, Rental)"));
+    }
+
     #[test]
     fn test_parse_playing_card_code() {
         assert_eq!(
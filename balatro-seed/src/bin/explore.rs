@@ -2,17 +2,22 @@
 //! as `TheSoul`'s website output, for direct manual diffing against it.
 //!
 //! Usage: `explore SEED [--ante N] [--cards-per-ante 15,50,50,50,50,50,50,50]
-//! [--vouchers bought|offered] [--no-activate-vouchers] [--fresh-profile] [--ante-0]`
+//! [--vouchers bought|offered] [--no-activate-vouchers] [--fresh-profile]
+//! [--profile DIR] [--ante-0]`
 //!
 //! `--vouchers bought` (default) locks and activates each drawn voucher,
 //! matching a live run; `--no-activate-vouchers` reproduces the site's own
 //! demo (locks but never activates), needed to byte-diff against it.
+//! `--profile DIR` reads a real profile folder's `meta.jkr`/`profile.jkr`
+//! and locks whatever that account hasn't unlocked yet.
 //! `--ante-0` previews the shop reachable via Hieroglyph/Petroglyph —
 //! computed last (after the full ante loop, so lock state matches) but
 //! displayed first, reusing Ante 1's boss since boss RNG has no ante suffix.
 
+use balatro_profile::Profile;
 use balatro_seed::{Instance, ShopItem, pack_card_count, voucher_upgrade};
 use balatro_types::{BossBlind, Card, Edition, Enhancement, PackCategory, PackSize, Seal};
+use std::path::Path;
 
 fn pack_display_name(category: PackCategory, size: PackSize) -> String {
     let cat = match category {
@@ -92,7 +97,7 @@ fn render_shop_item(item: ShopItem) -> String {
     match item {
         ShopItem::Joker(j) => format!("{}{}", edition_prefix(j.edition()), j.name()),
         ShopItem::Consumable(c) => c.name().to_string(),
-        ShopItem::PlayingCard => "[playing card: not implemented]".to_string(),
+        ShopItem::PlayingCard(card) => render_card(&card),
     }
 }
 
@@ -189,6 +194,26 @@ fn render_ante(
     (out, boss)
 }
 
+const USAGE: &str = "usage: explore SEED [--ante N] [--cards-per-ante 15,50,...] \
+     [--vouchers bought|offered] [--no-activate-vouchers] \
+     [--fresh-profile] [--profile DIR] [--ante-0]";
+
+/// Prints `msg` and the usage line, then exits.
+fn usage_error(msg: &str) -> ! {
+    eprintln!("{msg}\n{USAGE}");
+    std::process::exit(1);
+}
+
+/// Reads a real profile folder (`1/`, `2/`, ... in the save directory).
+fn load_profile(dir: &str) -> Result<Profile, String> {
+    let load = |file: &str| {
+        let path = Path::new(dir).join(file);
+        let bytes = std::fs::read(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        balatro_jkr::decode(&bytes).map_err(|e| format!("{}: {e}", path.display()))
+    };
+    Profile::from_lua(&load("meta.jkr")?, &load("profile.jkr")?).map_err(|e| format!("{dir}: {e}"))
+}
+
 fn main() {
     let args: Vec<String> = std::env::args().skip(1).collect();
     let mut seed: Option<String> = None;
@@ -197,54 +222,68 @@ fn main() {
     let mut vouchers_bought = true;
     let mut activate_vouchers = true;
     let mut fresh_profile = false;
+    let mut profile: Option<Profile> = None;
     let mut ante_0 = false;
 
+    // the value following the flag at `i`
+    let value = |i: usize| -> &str {
+        args.get(i + 1)
+            .unwrap_or_else(|| usage_error(&format!("{} expects a value", args[i])))
+    };
     let mut i = 0;
     while i < args.len() {
         match args[i].as_str() {
             "--ante" => {
+                max_ante = value(i)
+                    .parse()
+                    .unwrap_or_else(|_| usage_error("--ante expects an integer"));
                 i += 1;
-                max_ante = args[i].parse().expect("--ante expects an integer");
             }
             "--cards-per-ante" => {
-                i += 1;
-                cards_per_ante = args[i]
+                cards_per_ante = value(i)
                     .split(',')
-                    .map(|n| n.trim().parse().expect("cards-per-ante expects integers"))
+                    .map(|n| {
+                        n.trim()
+                            .parse()
+                            .unwrap_or_else(|_| usage_error("--cards-per-ante expects integers"))
+                    })
                     .collect();
+                i += 1;
             }
             "--vouchers" => {
-                i += 1;
-                vouchers_bought = match args[i].as_str() {
+                vouchers_bought = match value(i) {
                     "bought" => true,
                     "offered" => false,
-                    other => panic!("--vouchers expects bought|offered, got {other}"),
+                    other => {
+                        usage_error(&format!("--vouchers expects bought|offered, got {other}"))
+                    }
                 };
+                i += 1;
             }
             "--no-activate-vouchers" => activate_vouchers = false,
             "--fresh-profile" => fresh_profile = true,
+            "--profile" => {
+                profile = Some(load_profile(value(i)).unwrap_or_else(|e| usage_error(&e)));
+                i += 1;
+            }
             "--ante-0" => ante_0 = true,
             other if seed.is_none() => seed = Some(other.to_string()),
-            other => panic!("unrecognized argument: {other}"),
+            other => usage_error(&format!("unrecognized argument: {other}")),
         }
         i += 1;
     }
 
     let seed = seed
-        .unwrap_or_else(|| {
-            eprintln!(
-                "usage: explore SEED [--ante N] [--cards-per-ante 15,50,...] \
-                 [--vouchers bought|offered] [--no-activate-vouchers] \
-                 [--fresh-profile] [--ante-0]"
-            );
-            std::process::exit(1);
-        })
+        .unwrap_or_else(|| usage_error("missing SEED"))
         .to_uppercase()
         .replace('0', "O");
 
     let mut inst = Instance::new(&seed);
     // fresh_run locks reset every run regardless of profile, so always true here.
     inst.init_locks(1, fresh_profile, true);
+    if let Some(profile) = &profile {
+        inst.init_profile_locks(|name| profile.has_unlocked(name));
+    }
 
     let mut ante_1_boss = None;
     let mut sections: Vec<String> = Vec::new();
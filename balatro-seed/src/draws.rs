@@ -10,7 +10,8 @@ use balatro_types::{
 pub enum ShopItem {
     Joker(Jokers),
     Consumable(Consumable),
-    PlayingCard,
+    /// Only once Magic Trick is redeemed.
+    PlayingCard(Card),
 }
 
 /// Given a drawn base voucher, returns its upgrade tier if one exists.
@@ -56,6 +57,72 @@ pub fn pack_card_count(category: PackCategory, size: PackSize) -> i32 {
     }
 }
 
+/// Everything a brand-new profile has yet to unlock or discover, the
+/// `fresh_profile` half of [`Instance::init_locks`].
+pub const PROFILE_LOCKS: &[&str] = &[
+    "Negative Tag",
+    "Foil Tag",
+    "Holographic Tag",
+    "Polychrome Tag",
+    "Rare Tag",
+    "Golden Ticket",
+    "Mr. Bones",
+    "Acrobat",
+    "Sock and Buskin",
+    "Swashbuckler",
+    "Troubadour",
+    "Certificate",
+    "Smeared Joker",
+    "Throwback",
+    "Hanging Chad",
+    "Rough Gem",
+    "Bloodstone",
+    "Arrowhead",
+    "Onyx Agate",
+    "Glass Joker",
+    "Showman",
+    "Flower Pot",
+    "Blueprint",
+    "Wee Joker",
+    "Merry Andy",
+    "Oops! All 6s",
+    "The Idol",
+    "Seeing Double",
+    "Matador",
+    "Hit the Road",
+    "The Duo",
+    "The Trio",
+    "The Family",
+    "The Order",
+    "The Tribe",
+    "Stuntman",
+    "Invisible Joker",
+    "Brainstorm",
+    "Satellite",
+    "Shoot the Moon",
+    "Driver's License",
+    "Cartomancer",
+    "Astronomer",
+    "Burnt Joker",
+    "Bootstraps",
+    "Overstock Plus",
+    "Liquidation",
+    "Glow Up",
+    "Reroll Glut",
+    "Omen Globe",
+    "Observatory",
+    "Nacho Tong",
+    "Recyclomancy",
+    "Tarot Tycoon",
+    "Planet Tycoon",
+    "Money Tree",
+    "Antimatter",
+    "Illusion",
+    "Petroglyph",
+    "Retcon",
+    "Palette",
+];
+
 impl Instance {
     /// `fresh_profile` gates profile-level achievement locks; `fresh_run`
     /// gates locks whose requirement is inherently in-run.
@@ -99,71 +166,7 @@ impl Instance {
             self.lock("The Ox");
         }
         if fresh_profile {
-            for name in [
-                "Negative Tag",
-                "Foil Tag",
-                "Holographic Tag",
-                "Polychrome Tag",
-                "Rare Tag",
-                "Golden Ticket",
-                "Mr. Bones",
-                "Acrobat",
-                "Sock and Buskin",
-                "Swashbuckler",
-                "Troubadour",
-                "Certificate",
-                "Smeared Joker",
-                "Throwback",
-                "Hanging Chad",
-                "Rough Gem",
-                "Bloodstone",
-                "Arrowhead",
-                "Onyx Agate",
-                "Glass Joker",
-                "Showman",
-                "Flower Pot",
-                "Blueprint",
-                "Wee Joker",
-                "Merry Andy",
-                "Oops! All 6s",
-                "The Idol",
-                "Seeing Double",
-                "Matador",
-                "Hit the Road",
-                "The Duo",
-                "The Trio",
-                "The Family",
-                "The Order",
-                "The Tribe",
-                "Stuntman",
-                "Invisible Joker",
-                "Brainstorm",
-                "Satellite",
-                "Shoot the Moon",
-                "Driver's License",
-                "Cartomancer",
-                "Astronomer",
-                "Burnt Joker",
-                "Bootstraps",
-                "Overstock Plus",
-                "Liquidation",
-                "Glow Up",
-                "Reroll Glut",
-                "Omen Globe",
-                "Observatory",
-                "Nacho Tong",
-                "Recyclomancy",
-                "Tarot Tycoon",
-                "Planet Tycoon",
-                "Money Tree",
-                "Antimatter",
-                "Illusion",
-                "Petroglyph",
-                "Retcon",
-                "Palette",
-            ] {
-                self.lock(name);
-            }
+            self.init_profile_locks(|_| false);
         }
         if fresh_run {
             for name in [
@@ -201,6 +204,18 @@ impl Instance {
         }
     }
 
+    /// Locks each [`PROFILE_LOCKS`] item `is_unlocked` rejects, so draws
+    /// match a specific account instead of a brand-new or fully unlocked
+    /// one. Stays locked through `init_unlocks`.
+    pub fn init_profile_locks(&mut self, is_unlocked: impl Fn(&str) -> bool) {
+        for &name in PROFILE_LOCKS {
+            if !is_unlocked(name) {
+                self.lock(name);
+                self.profile_locked.insert(name.to_string());
+            }
+        }
+    }
+
     /// Ante-gated unlocks (`functions.hpp::initUnlocks`).
     pub fn init_unlocks(&mut self, ante: i32, fresh_profile: bool) {
         if ante == 2 {
@@ -226,7 +241,7 @@ impl Instance {
             ] {
                 self.unlock(name);
             }
-            if !fresh_profile {
+            if !fresh_profile && !self.profile_locked.contains("Negative Tag") {
                 self.unlock("Negative Tag");
             }
         }
@@ -296,12 +311,14 @@ impl Instance {
     }
 
     /// `source` selects a forced rarity for a few callers ("sou" =
-    /// legendary, "wra"/"rta" = rare, "uta" = uncommon), else rarity is rolled.
+    /// legendary, "wra"/"rta" = rare, "uta" = uncommon, "rif"/"top" =
+    /// common), else rarity is rolled.
     pub fn next_joker(&mut self, source: &str, ante: i32) -> Jokers {
         let rarity: &str = match source {
             "sou" => "4",
             "wra" | "rta" => "3",
             "uta" => "2",
+            "rif" | "top" => "1",
             _ => {
                 let poll = self.random(NodeId::Rarity { source, ante });
                 if poll > 0.95 {
@@ -357,10 +374,11 @@ impl Instance {
         self.randchoice_typed(NodeId::Tag(ante), &pools::TAGS_POOL)
     }
 
-    /// Filters to the current ante's category (finisher on `ante % 8 == 0`),
-    /// retrying with a full unlock if that category's pool is exhausted.
+    /// Filters to the current ante's category (finisher on showdown antes,
+    /// `ante >= 2 && ante % 8 == 0`), retrying with a full unlock if that
+    /// category's pool is exhausted.
     pub fn next_boss(&mut self, ante: i32) -> BossBlind {
-        let is_finisher_ante = ante % 8 == 0;
+        let is_finisher_ante = ante >= 2 && ante % 8 == 0;
         let matches_category = |name: &str| {
             let is_t = name.starts_with('T');
             (is_finisher_ante && !is_t) || (!is_finisher_ante && is_t)
@@ -386,6 +404,19 @@ impl Instance {
         pools::BOSSES_POOL.resolve(chosen)
     }
 
+    /// `get_new_boss`'s draw, for a caller that tracks boss eligibility
+    /// itself: picks among `candidates` in the real game's key order instead
+    /// of going through the lock table.
+    pub fn next_boss_among(&mut self, candidates: &[BossBlind]) -> BossBlind {
+        let pool: Vec<&str> = pools::BOSSES
+            .iter()
+            .copied()
+            .filter(|&name| candidates.contains(&pools::BOSSES_POOL.resolve(name)))
+            .collect();
+        let chosen = self.randchoice(NodeId::Boss, &pool);
+        pools::BOSSES_POOL.resolve(chosen)
+    }
+
     /// `functions.hpp::nextPack`. The run's first pack (ante <= 2, only
     /// once) is always a Buffoon pack.
     pub fn next_pack(&mut self, ante: i32) -> (PackCategory, PackSize) {
@@ -513,8 +544,38 @@ impl Instance {
         (0..size).map(|_| self.next_standard_card(ante)).collect()
     }
 
-    /// `functions.hpp::nextShopItem` (via `getShopInstance` inlined). The
-    /// `PlayingCard` branch is a placeholder — see module docs.
+    /// Balatro's `pseudorandom_element(G.P_CARDS, key)`: a plain card off
+    /// the `key` node, like Certificate's `"cert_fr"`.
+    pub fn next_card_front(&mut self, key: &str) -> Card {
+        let name = *self.pseudorandom_element(pools::CARDS, key);
+        pools::CARDS_POOL.resolve(name)
+    }
+
+    /// `create_card_for_shop`'s playing card. Illusion may turn it into an
+    /// Enhanced card and give it an edition, all off the one `illusion`
+    /// node. Never a seal, unlike a Standard Pack card.
+    pub fn next_shop_playing_card(&mut self, ante: i32) -> Card {
+        let illusion = self.is_voucher_active("Illusion");
+        let enhanced = illusion && self.random(NodeId::Illusion) > 0.6;
+        let mut card: Card = self.randchoice_typed(NodeId::FrontShop(ante), &pools::CARDS_POOL);
+        if enhanced {
+            card.enhancement =
+                Some(self.randchoice_typed(NodeId::EnhancedShop(ante), &pools::ENHANCEMENTS_POOL));
+        }
+        if illusion && self.random(NodeId::Illusion) > 0.8 {
+            let edition_poll = self.random(NodeId::Illusion);
+            card.edition = if edition_poll > 0.85 {
+                Edition::Polychrome
+            } else if edition_poll > 0.5 {
+                Edition::Holographic
+            } else {
+                Edition::Foil
+            };
+        }
+        card
+    }
+
+    /// `functions.hpp::nextShopItem` (via `getShopInstance` inlined).
     pub fn next_shop_item(&mut self, ante: i32) -> ShopItem {
         let joker_rate = 20.0f64;
         let mut tarot_rate = 4.0;
@@ -554,7 +615,7 @@ impl Instance {
         }
         poll -= planet_rate;
         if poll < playing_card_rate {
-            return ShopItem::PlayingCard;
+            return ShopItem::PlayingCard(self.next_shop_playing_card(ante));
         }
         ShopItem::Consumable(self.next_spectral("sho", ante, false))
     }
@@ -564,6 +625,29 @@ impl Instance {
 mod tests {
     use super::*;
 
+    #[test]
+    fn profile_locks_follow_the_predicate_and_survive_init_unlocks() {
+        let mut inst = Instance::new("TESTSEED");
+        inst.init_locks(1, false, true);
+        inst.init_profile_locks(|name| name != "Negative Tag" && name != "Blueprint");
+        assert!(inst.is_locked("Blueprint"));
+        assert!(!inst.is_locked("Showman"));
+
+        inst.init_unlocks(2, false);
+        assert!(inst.is_locked("Negative Tag"));
+        assert!(!inst.is_locked("Standard Tag"));
+    }
+
+    #[test]
+    fn next_boss_only_draws_finishers_on_showdown_antes() {
+        let mut inst = Instance::new("TESTSEED");
+        inst.init_locks(1, false, true);
+        for ante in [0, 1, 8, 16] {
+            let boss = inst.next_boss(ante);
+            assert_eq!(boss.is_finisher(), ante >= 2, "ante {ante} drew {boss:?}");
+        }
+    }
+
     // Smoke test: confirms the whole draw surface is stable and non-panicking.
     #[test]
     fn ante_one_smoke_test_is_stable() {
@@ -580,7 +664,7 @@ mod tests {
                 let item = match inst.next_shop_item(1) {
                     ShopItem::Joker(j) => format!("Joker({})", j.name()),
                     ShopItem::Consumable(c) => format!("Consumable({})", c.name()),
-                    ShopItem::PlayingCard => "PlayingCard".to_string(),
+                    ShopItem::PlayingCard(c) => format!("PlayingCard({c:?})"),
                 };
                 shop_items.push(item);
             }
@@ -606,4 +690,32 @@ mod tests {
             "same seed, same fixed draw sequence must repeat exactly"
         );
     }
+
+    #[test]
+    fn shop_playing_cards_roll_extras_only_under_illusion() {
+        let mut inst = Instance::new("TESTSEED");
+        for ante in 1..=50 {
+            let card = inst.next_shop_playing_card(ante);
+            assert_eq!(card.enhancement, None);
+            assert_eq!(card.edition, Edition::Base);
+            assert_eq!(card.seal, None);
+        }
+
+        inst.activate_voucher("Illusion");
+        let cards: Vec<Card> = (1..=200)
+            .map(|ante| inst.next_shop_playing_card(ante))
+            .collect();
+        assert!(cards.iter().all(|c| c.seal.is_none()));
+        assert!(cards.iter().any(|c| c.enhancement.is_some()));
+        assert!(cards.iter().any(|c| c.edition != Edition::Base));
+    }
+
+    #[test]
+    fn top_up_jokers_are_always_common() {
+        let mut inst = Instance::new("TESTSEED");
+        for ante in 1..=50 {
+            let joker = inst.next_joker("top", ante);
+            assert_eq!(joker.rarity(), balatro_types::Rarity::Common, "{joker:?}");
+        }
+    }
 }
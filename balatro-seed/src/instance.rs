@@ -40,6 +40,9 @@ pub struct Instance {
     /// The run's first pack is always Buffoon; tracked so `next_pack`
     /// only special-cases it once.
     pub(crate) generated_first_pack: bool,
+    /// Locked by `init_profile_locks`, kept out of `init_unlocks`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) profile_locked: HashSet<String>,
     pub params: InstParams,
 }
 
@@ -51,10 +54,21 @@ impl Instance {
             nodes: HashMap::new(),
             locked: HashSet::new(),
             generated_first_pack: false,
+            profile_locked: HashSet::new(),
             params: InstParams::default(),
         }
     }
 
+    pub fn seed(&self) -> &str {
+        &self.seed
+    }
+
+    /// Every stream touched so far and its stored state, the same values
+    /// the real game keeps in `GAME.pseudorandom` under the same keys.
+    pub fn node_states(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.nodes.iter().map(|(id, &state)| (id.as_str(), state))
+    }
+
     pub fn lock(&mut self, item: &(impl Named + ?Sized)) {
         self.locked.insert(item.name().to_string());
     }
@@ -146,6 +160,35 @@ impl Instance {
         let name = self.randchoice(id, pool.names);
         pool.resolve(name)
     }
+
+    /// Balatro's `pseudorandom(key)`: a float in `[0, 1)` off the `key`
+    /// node, for one-off chance rolls like `"lucky_mult"` or `"glass"`.
+    pub fn pseudorandom(&mut self, key: &str) -> f64 {
+        let node = self.get_node(key);
+        LuaRandom::new(node).random()
+    }
+
+    /// Balatro's `pseudorandom_element`: a uniform pick seeded once off the
+    /// `key` node. Like [`shuffle`](Self::shuffle), the real game sorts by
+    /// `sort_id` (or table key) first; putting `items` in that order is the
+    /// caller's job.
+    pub fn pseudorandom_element<'a, T>(&mut self, items: &'a [T], key: &str) -> &'a T {
+        let node = self.get_node(key);
+        let mut rng = LuaRandom::new(node);
+        &items[rng.randint(1, items.len() as i32) as usize - 1]
+    }
+
+    /// Balatro's `pseudoshuffle`: Fisher-Yates from the back, seeded once
+    /// off the `key` node. The real game sorts by `sort_id` first; putting
+    /// `items` in that order is the caller's job.
+    pub fn shuffle<T>(&mut self, items: &mut [T], key: &str) {
+        let node = self.get_node(key);
+        let mut rng = LuaRandom::new(node);
+        for i in (1..items.len()).rev() {
+            let j = rng.randint(1, i as i32 + 1) as usize - 1;
+            items.swap(i, j);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -217,6 +260,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pseudorandom_reads_the_raw_key_node() {
+        let mut a = Instance::new("TESTSEED");
+        let mut b = Instance::new("TESTSEED");
+        assert_eq!(a.pseudorandom("glass"), b.random(NodeId::Custom("glass")));
+    }
+
+    #[test]
+    fn pseudorandom_element_picks_off_the_raw_key_node() {
+        let items = ["A", "B", "C", "D", "E"];
+        let mut a = Instance::new("TESTSEED");
+        let mut b = Instance::new("TESTSEED");
+        for _ in 0..20 {
+            let roll = a.pseudorandom("orbital");
+            let picked = b.pseudorandom_element(&items, "orbital");
+            assert_eq!(*picked, items[(roll * items.len() as f64) as usize]);
+        }
+    }
+
+    #[test]
+    fn shuffle_is_a_deterministic_permutation() {
+        let mut a: Vec<u32> = (0..52).collect();
+        let mut b = a.clone();
+        Instance::new("TESTSEED").shuffle(&mut a, "nr1");
+        Instance::new("TESTSEED").shuffle(&mut b, "nr1");
+        assert_eq!(a, b);
+        assert_ne!(a, (0..52).collect::<Vec<_>>());
+        let mut sorted = a.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..52).collect::<Vec<_>>());
+    }
+
     #[test]
     fn lock_unlock_roundtrip() {
         let mut inst = Instance::new("TESTSEED");
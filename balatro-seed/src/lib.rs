@@ -14,6 +14,6 @@ mod pools;
 mod resolve;
 mod rng;
 
-pub use draws::{ShopItem, pack_card_count, voucher_upgrade};
+pub use draws::{PROFILE_LOCKS, ShopItem, pack_card_count, voucher_upgrade};
 pub use instance::{InstParams, Instance};
 pub use rng::{LuaRandom, pseudohash, round13};
@@ -52,6 +52,9 @@ pub(crate) enum NodeId<'a> {
     StdSeal(i32),
     StdSealType(i32),
     Cdt(i32),
+    Illusion,
+    EnhancedShop(i32),
+    FrontShop(i32),
     /// Escape hatch for tests exercising `Instance`'s primitives directly.
     #[allow(dead_code)]
     Custom(&'a str),
@@ -85,6 +88,9 @@ impl fmt::Display for NodeId<'_> {
             NodeId::StdSeal(ante) => write!(f, "stdseal{ante}"),
             NodeId::StdSealType(ante) => write!(f, "stdsealtype{ante}"),
             NodeId::Cdt(ante) => write!(f, "cdt{ante}"),
+            NodeId::Illusion => write!(f, "illusion"),
+            NodeId::EnhancedShop(ante) => write!(f, "Enhancedsho{ante}"),
+            NodeId::FrontShop(ante) => write!(f, "frontsho{ante}"),
             NodeId::Custom(s) => write!(f, "{s}"),
         }
     }
@@ -124,6 +130,9 @@ mod tests {
             (NodeId::StdSeal(ante), "stdseal3"),
             (NodeId::StdSealType(ante), "stdsealtype3"),
             (NodeId::Cdt(ante), "cdt3"),
+            (NodeId::Illusion, "illusion"),
+            (NodeId::EnhancedShop(ante), "Enhancedsho3"),
+            (NodeId::FrontShop(ante), "frontsho3"),
             (NodeId::Custom("whatever"), "whatever"),
         ];
         for (id, expected) in cases {
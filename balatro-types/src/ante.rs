@@ -1,3 +1,5 @@
+use crate::bignum::BigNum;
+use crate::stake::Stake;
 use std::convert::TryFrom;
 
 /// All ante levels.
@@ -14,13 +16,14 @@ pub enum Ante {
     Six,
     Seven,
     Eight,
+    /// Antes past eight, holding the ante number (always 9 or more).
+    Endless(usize),
 }
 
 impl Ante {
-    // Base chip requirement.
-    // Eventually this depends on deck and stake.
-    pub fn base(&self) -> usize {
-        match self {
+    // Base chip requirement at White stake.
+    pub fn base(&self) -> BigNum {
+        BigNum::from(match self {
             Self::Zero => 100,
             Self::One => 300,
             Self::Two => 800,
@@ -30,26 +33,94 @@ impl Ante {
             Self::Six => 20000,
             Self::Seven => 35000,
             Self::Eight => 50000,
+            Self::Endless(n) => return endless_base(Self::Eight.base(), *n),
+        })
+    }
+    /// Base chip requirement under `stake`. Green Stake scales faster and
+    /// Purple Stake faster again.
+    pub fn stake_base(&self, stake: Stake) -> BigNum {
+        BigNum::from(if stake >= Stake::Purple {
+            match self {
+                Self::Zero => 100,
+                Self::One => 300,
+                Self::Two => 1000,
+                Self::Three => 3200,
+                Self::Four => 9000,
+                Self::Five => 25000,
+                Self::Six => 60000,
+                Self::Seven => 110000,
+                Self::Eight => 200000,
+                Self::Endless(n) => return endless_base(Self::Eight.stake_base(stake), *n),
+            }
+        } else if stake >= Stake::Green {
+            match self {
+                Self::Zero => 100,
+                Self::One => 300,
+                Self::Two => 900,
+                Self::Three => 2600,
+                Self::Four => 8000,
+                Self::Five => 20000,
+                Self::Six => 36000,
+                Self::Seven => 60000,
+                Self::Eight => 100000,
+                Self::Endless(n) => return endless_base(Self::Eight.stake_base(stake), *n),
+            }
+        } else {
+            return self.base();
+        })
+    }
+
+    /// Ante number, 0 through 8 and beyond in endless mode.
+    pub fn num(&self) -> usize {
+        match self {
+            Self::Zero => 0,
+            Self::One => 1,
+            Self::Two => 2,
+            Self::Three => 3,
+            Self::Four => 4,
+            Self::Five => 5,
+            Self::Six => 6,
+            Self::Seven => 7,
+            Self::Eight => 8,
+            Self::Endless(n) => *n,
         }
     }
+
     pub fn next(&self, max: Ante) -> Option<Self> {
-        if *self == max {
+        if *self >= max {
             return None;
         }
+        Some(self.next_endless())
+    }
+
+    /// Following ante with no upper bound, used once a run goes endless.
+    pub fn next_endless(&self) -> Self {
         match self {
-            Self::Zero => Some(Self::One),
-            Self::One => Some(Self::Two),
-            Self::Two => Some(Self::Three),
-            Self::Three => Some(Self::Four),
-            Self::Four => Some(Self::Five),
-            Self::Five => Some(Self::Six),
-            Self::Six => Some(Self::Seven),
-            Self::Seven => Some(Self::Eight),
-            Self::Eight => None,
+            Self::Zero => Self::One,
+            Self::One => Self::Two,
+            Self::Two => Self::Three,
+            Self::Three => Self::Four,
+            Self::Four => Self::Five,
+            Self::Five => Self::Six,
+            Self::Six => Self::Seven,
+            Self::Seven => Self::Eight,
+            Self::Eight => Self::Endless(9),
+            Self::Endless(n) => Self::Endless(n + 1),
         }
     }
 }
 
+// Balatro's post-8 scaling: a * (1.6 + (0.75 * c)^d)^c where a is the
+// ante 8 requirement, c the antes past 8 and d = 1 + 0.2c, then rounded
+// down to two significant figures. Overflows to naneinf eventually.
+fn endless_base(eight: BigNum, ante: usize) -> BigNum {
+    let c = ante.saturating_sub(8) as f64;
+    let d = 1.0 + 0.2 * c;
+    let amount = (eight.to_f64() * (1.6 + (0.75 * c).powf(d)).powf(c)).floor();
+    let unit = 10f64.powf((amount.log10() - 1.0).floor());
+    BigNum::new(amount - amount % unit)
+}
+
 impl TryFrom<usize> for Ante {
     type Error = ();
 
@@ -64,7 +135,7 @@ impl TryFrom<usize> for Ante {
             6 => Ok(Self::Six),
             7 => Ok(Self::Seven),
             8 => Ok(Self::Eight),
-            _ => Err(()),
+            n => Ok(Self::Endless(n)),
         }
     }
 }
@@ -79,9 +150,32 @@ mod tests {
         assert_eq!(Ante::Eight.base(), 50000);
     }
 
+    #[test]
+    fn test_ante_stake_base() {
+        assert_eq!(Ante::Eight.stake_base(Stake::Red), 50000);
+        assert_eq!(Ante::Eight.stake_base(Stake::Green), 100000);
+        assert_eq!(Ante::Eight.stake_base(Stake::Blue), 100000);
+        assert_eq!(Ante::Eight.stake_base(Stake::Gold), 200000);
+        assert_eq!(Ante::One.stake_base(Stake::Gold), 300);
+    }
+
     #[test]
     fn test_ante_next_stops_at_max() {
         assert_eq!(Ante::Zero.next(Ante::Eight), Some(Ante::One));
         assert_eq!(Ante::Eight.next(Ante::Eight), None);
+        assert_eq!(Ante::Eight.next(Ante::Endless(10)), Some(Ante::Endless(9)));
+        assert_eq!(Ante::Endless(9).next_endless(), Ante::Endless(10));
+        assert_eq!(Ante::try_from(12), Ok(Ante::Endless(12)));
+        assert_eq!(Ante::Endless(12).num(), 12);
+    }
+
+    #[test]
+    fn test_ante_endless_base() {
+        assert_eq!(Ante::Endless(9).base(), 110000);
+        assert_eq!(Ante::Endless(10).base(), 560000);
+        assert_eq!(Ante::Endless(11).base(), 7200000);
+        assert_eq!(Ante::Endless(12).base(), 300000000);
+        assert!(Ante::Endless(9).stake_base(Stake::Green) > Ante::Endless(9).base());
+        assert!(Ante::Endless(100).base().is_naneinf());
     }
 }
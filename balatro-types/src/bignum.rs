@@ -0,0 +1,231 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// Chips, mult and score, kept as an `f64` like the real game so x-mult
+/// stays fractional (Glass x2, Steel/Polychrome x1.5, ...).
+///
+/// Anything that overflows, or turns into NaN (e.g. `inf * 0`), collapses
+/// to positive infinity, displayed as the game's `naneinf`. That keeps
+/// comparisons total: a naneinf score beats every blind.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BigNum(f64);
+
+// Past this, the game switches to scientific notation.
+const SCIENTIFIC_AT: f64 = 1e11;
+
+impl BigNum {
+    pub const ZERO: BigNum = BigNum(0.0);
+    pub const NANEINF: BigNum = BigNum(f64::INFINITY);
+
+    pub fn new(v: f64) -> Self {
+        if v.is_finite() {
+            Self(v)
+        } else {
+            Self::NANEINF
+        }
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0
+    }
+
+    pub fn is_naneinf(&self) -> bool {
+        !self.0.is_finite()
+    }
+
+    pub fn floor(self) -> Self {
+        Self::new(self.0.floor())
+    }
+
+    /// Whole-number value, saturating at `usize::MAX` for naneinf.
+    pub fn to_usize(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<usize> for BigNum {
+    fn from(v: usize) -> Self {
+        Self(v as f64)
+    }
+}
+
+impl From<f64> for BigNum {
+    fn from(v: f64) -> Self {
+        Self::new(v)
+    }
+}
+
+impl PartialEq for BigNum {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl PartialEq<usize> for BigNum {
+    fn eq(&self, other: &usize) -> bool {
+        self.0 == *other as f64
+    }
+}
+
+impl PartialEq<f64> for BigNum {
+    fn eq(&self, other: &f64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialOrd for BigNum {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl PartialOrd<usize> for BigNum {
+    fn partial_cmp(&self, other: &usize) -> Option<Ordering> {
+        self.0.partial_cmp(&(*other as f64))
+    }
+}
+
+macro_rules! bignum_op {
+    ($op:ident, $f:ident, $op_assign:ident, $f_assign:ident) => {
+        impl $op for BigNum {
+            type Output = BigNum;
+            fn $f(self, rhs: BigNum) -> BigNum {
+                BigNum::new(self.0.$f(rhs.0))
+            }
+        }
+
+        impl $op<usize> for BigNum {
+            type Output = BigNum;
+            fn $f(self, rhs: usize) -> BigNum {
+                BigNum::new(self.0.$f(rhs as f64))
+            }
+        }
+
+        impl $op<f64> for BigNum {
+            type Output = BigNum;
+            fn $f(self, rhs: f64) -> BigNum {
+                BigNum::new(self.0.$f(rhs))
+            }
+        }
+
+        impl $op_assign for BigNum {
+            fn $f_assign(&mut self, rhs: BigNum) {
+                *self = $op::$f(*self, rhs);
+            }
+        }
+
+        impl $op_assign<usize> for BigNum {
+            fn $f_assign(&mut self, rhs: usize) {
+                *self = $op::$f(*self, rhs);
+            }
+        }
+
+        impl $op_assign<f64> for BigNum {
+            fn $f_assign(&mut self, rhs: f64) {
+                *self = $op::$f(*self, rhs);
+            }
+        }
+    };
+}
+
+bignum_op!(Add, add, AddAssign, add_assign);
+bignum_op!(Sub, sub, SubAssign, sub_assign);
+bignum_op!(Mul, mul, MulAssign, mul_assign);
+bignum_op!(Div, div, DivAssign, div_assign);
+
+impl fmt::Display for BigNum {
+    // Mirrors the game's number_format: grouped integers, a couple of
+    // decimals for small fractional values, scientific past 1e11.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let v = self.0;
+        if self.is_naneinf() {
+            return write!(f, "naneinf");
+        }
+        if v.abs() >= SCIENTIFIC_AT {
+            let exp = v.abs().log10().floor();
+            let mantissa = v / 10f64.powf(exp);
+            return write!(f, "{mantissa:.3}e{exp}");
+        }
+        if v.fract() != 0.0 && v.abs() < 100.0 {
+            let decimals = if v.abs() >= 10.0 { 1 } else { 2 };
+            return write!(f, "{v:.decimals$}");
+        }
+        let digits = format!("{:.0}", v.abs());
+        let mut grouped = String::new();
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(c);
+        }
+        if v < 0.0 {
+            write!(f, "-{grouped}")
+        } else {
+            write!(f, "{grouped}")
+        }
+    }
+}
+
+// Finite values round-trip as plain JSON numbers, naneinf as the string
+// "naneinf" since JSON has no infinity.
+#[cfg(feature = "serde")]
+impl serde::Serialize for BigNum {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.is_naneinf() {
+            serializer.serialize_str("naneinf")
+        } else {
+            serializer.serialize_f64(self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BigNum {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Num(f64),
+            Str(String),
+        }
+        match Repr::deserialize(deserializer)? {
+            Repr::Num(v) => Ok(BigNum::new(v)),
+            Repr::Str(s) if s == "naneinf" => Ok(BigNum::NANEINF),
+            Repr::Str(s) => Err(serde::de::Error::custom(format!(
+                "expected number or naneinf, got {s}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fractional_mult_not_truncated() {
+        let mut mult = BigNum::from(2);
+        mult *= 1.5;
+        mult *= 1.5;
+        assert_eq!(mult, 4.5);
+    }
+
+    #[test]
+    fn test_overflow_is_naneinf() {
+        let big = BigNum::new(1e300);
+        assert!((big * big).is_naneinf());
+        assert!((BigNum::NANEINF * BigNum::ZERO).is_naneinf());
+        assert!(BigNum::NANEINF > usize::MAX);
+        assert_eq!(BigNum::NANEINF.to_string(), "naneinf");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BigNum::from(96).to_string(), "96");
+        assert_eq!(BigNum::from(1234567).to_string(), "1,234,567");
+        assert_eq!(BigNum::new(4.5).to_string(), "4.50");
+        assert_eq!(BigNum::new(22.5).to_string(), "22.5");
+        assert_eq!(BigNum::new(2.5e12).to_string(), "2.500e12");
+    }
+}
@@ -218,6 +218,18 @@ impl BossBlind {
             _ => 0,
         }
     }
+
+    /// Whether this boss can come up at `ante`. Every 8th ante from 8 on is
+    /// a showdown that only draws finishers; every other ante (0 and 1
+    /// included) draws regular bosses whose `min_ante` it has reached.
+    pub fn is_eligible_at(&self, ante: i32) -> bool {
+        let showdown = ante >= 2 && ante % 8 == 0;
+        if self.is_finisher() {
+            showdown
+        } else {
+            !showdown && ante.max(1) >= self.min_ante()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -259,4 +271,23 @@ mod tests {
         let all_any = BossBlind::iter().filter(|b| b.min_ante() == 0).count();
         assert_eq!(all_any, 13);
     }
+
+    #[test]
+    fn test_boss_blind_eligibility() {
+        let at = |ante| -> Vec<BossBlind> {
+            BossBlind::iter()
+                .filter(|b| b.is_eligible_at(ante))
+                .collect()
+        };
+        assert_eq!(at(0), at(1));
+        assert_eq!(at(1).len(), 8);
+        assert!(!at(1).contains(&BossBlind::Wall));
+        assert!(!at(1).contains(&BossBlind::VioletVessel));
+        assert!(at(2).contains(&BossBlind::Wall));
+        assert!(!at(5).contains(&BossBlind::Ox));
+        assert!(at(6).contains(&BossBlind::Ox));
+        assert!(at(8).iter().all(|b| b.is_finisher()));
+        assert_eq!(at(8).len(), 5);
+        assert_eq!(at(9).len(), 23);
+    }
 }
@@ -147,6 +147,36 @@ pub enum Enhancement {
     Lucky,
 }
 
+impl Enhancement {
+    /// Save-file id for this enhancement, the card's center.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Bonus => "m_bonus",
+            Self::Mult => "m_mult",
+            Self::Wild => "m_wild",
+            Self::Glass => "m_glass",
+            Self::Steel => "m_steel",
+            Self::Stone => "m_stone",
+            Self::Gold => "m_gold",
+            Self::Lucky => "m_lucky",
+        }
+    }
+
+    pub fn from_id(s: &str) -> Option<Self> {
+        match s {
+            "m_bonus" => Some(Self::Bonus),
+            "m_mult" => Some(Self::Mult),
+            "m_wild" => Some(Self::Wild),
+            "m_glass" => Some(Self::Glass),
+            "m_steel" => Some(Self::Steel),
+            "m_stone" => Some(Self::Stone),
+            "m_gold" => Some(Self::Gold),
+            "m_lucky" => Some(Self::Lucky),
+            _ => None,
+        }
+    }
+}
+
 /// Enum for card seals.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass(eq))]
@@ -159,6 +189,28 @@ pub enum Seal {
     Purple,
 }
 
+impl Seal {
+    /// Save-file id for this seal, as `card.seal` holds it (`"Gold"`).
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Gold => "Gold",
+            Self::Red => "Red",
+            Self::Blue => "Blue",
+            Self::Purple => "Purple",
+        }
+    }
+
+    pub fn from_id(s: &str) -> Option<Self> {
+        match s {
+            "Gold" => Some(Self::Gold),
+            "Red" => Some(Self::Red),
+            "Blue" => Some(Self::Blue),
+            "Purple" => Some(Self::Purple),
+            _ => None,
+        }
+    }
+}
+
 /// Enum for card/joker/consumable editions.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass(eq))]
@@ -226,6 +278,10 @@ pub struct Card {
     pub enhancement: Option<Enhancement>,
     pub edition: Edition,
     pub seal: Option<Seal>,
+    /// Permanent chips scored on top of the rank's (Hiker), the save's
+    /// `perma_bonus`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bonus_chips: usize,
 }
 
 impl Card {
@@ -236,6 +292,7 @@ impl Card {
             enhancement: None,
             edition: Edition::Base,
             seal: None,
+            bonus_chips: 0,
         }
     }
 
@@ -317,6 +374,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_enhancement_seal_id_round_trip() {
+        for e in Enhancement::iter() {
+            assert_eq!(Enhancement::from_id(e.id()), Some(e));
+        }
+        for s in Seal::iter() {
+            assert_eq!(Seal::from_id(s.id()), Some(s));
+        }
+        assert_eq!(Enhancement::from_id("c_base"), None);
+    }
+
     #[test]
     fn test_edition_negative_consumable_alias() {
         assert_eq!(
@@ -1,8 +1,11 @@
+#[cfg(feature = "python")]
+use pyo3::pyclass;
 use strum::EnumIter;
 
 /// One of the 15 selectable starting decks, each with its own starting
 /// modifier.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq))]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, EnumIter)]
 pub enum DeckVariant {
     Red,
@@ -45,6 +45,9 @@ pub struct Stickers {
     pub rental: bool,
 }
 
+/// Rounds a Perishable joker lasts before it's debuffed.
+pub const PERISHABLE_ROUNDS: usize = 5;
+
 /// A rotating-selector value some jokers (MailInRebate, Castle, AncientJoker,
 /// TheIdol) lock onto for the run rather than accumulating a number.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -58,11 +61,17 @@ pub enum SelectorValue {
 /// inert for the ones that don't need it.
 /// `counter` covers accumulator-style jokers (Hologram, Ramen, LuckyCat, ...).
 /// `selector` covers rotating-selector jokers.
+/// `perish_rounds` counts rounds survived under the Perishable sticker.
+/// `extra_value` is sell value gained on top of half the cost (Egg, Gift Card).
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct JokerState {
     pub counter: f32,
     pub selector: Option<SelectorValue>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub perish_rounds: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extra_value: usize,
 }
 
 /// `Jokers` is the definitive static repr of all jokers in the game.
@@ -359,8 +368,14 @@ macro_rules! joker_data {
             pub fn desc(&self) -> &'static str {
                 match self { $(Self::$variant(_) => $desc,)* }
             }
+            /// Rental jokers only ever sell for $1.
             pub fn sell_value(&self) -> usize {
-                std::cmp::max(1, self.cost() / 2)
+                let base = if self.stickers().rental {
+                    1
+                } else {
+                    std::cmp::max(1, self.cost() / 2)
+                };
+                base + self.state().extra_value
             }
         }
     };
@@ -1,4 +1,5 @@
 pub mod ante;
+pub mod bignum;
 pub mod blind;
 pub mod card;
 pub mod consumable;
@@ -15,11 +16,12 @@ pub mod tarot;
 pub mod voucher;
 
 pub use ante::Ante;
+pub use bignum::BigNum;
 pub use blind::{Blind, BossBlind};
 pub use card::{Card, Edition, Enhancement, Seal, Suit, Value};
 pub use consumable::Consumable;
 pub use deck::DeckVariant;
-pub use joker::{Categories, Jokers, Rarity, Stickers};
+pub use joker::{Categories, Jokers, PERISHABLE_ROUNDS, Rarity, Stickers};
 pub use named::Named;
 pub use pack::{Pack, PackCategory, PackContent, PackSize};
 pub use planet::{Planetarium, Planets};
@@ -11,8 +11,9 @@ pub struct Level {
 
 /// All the different possible hand ranks.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy, EnumIter)]
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy, EnumIter)]
 pub enum HandRank {
+    #[default]
     HighCard,
     OnePair,
     TwoPair,
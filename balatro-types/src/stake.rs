@@ -1,10 +1,14 @@
+#[cfg(feature = "python")]
+use pyo3::pyclass;
 use strum::EnumIter;
 
 /// Difficulty stakes, in ascending order. Each stake's modifiers stack on
 /// top of every stake before it.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, EnumIter)]
+#[cfg_attr(feature = "python", pyclass(eq))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Default, EnumIter)]
 pub enum Stake {
+    #[default]
     White,
     Red,
     Green,
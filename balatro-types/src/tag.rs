@@ -1,5 +1,7 @@
 use strum::EnumIter;
 
+use crate::rank::HandRank;
+
 /// Tags are awarded for skipping a Blind and trigger an effect on entering
 /// the next shop.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27,7 +29,9 @@ pub enum Tag {
     D6,
     TopUp,
     Speed,
-    Orbital,
+    /// Carries the poker hand rolled when the tag was offered, the one it
+    /// shows and levels up.
+    Orbital(HandRank),
     Economy,
 }
 
@@ -56,11 +60,27 @@ impl Tag {
             Self::D6 => "D6 Tag",
             Self::TopUp => "Top-up Tag",
             Self::Speed => "Speed Tag",
-            Self::Orbital => "Orbital Tag",
+            Self::Orbital(_) => "Orbital Tag",
             Self::Economy => "Economy Tag",
         }
     }
 
+    /// Earliest ante this tag can be offered at. 0 means "any ante".
+    pub fn min_ante(&self) -> i32 {
+        match self {
+            Self::Negative
+            | Self::Standard
+            | Self::Meteor
+            | Self::Buffoon
+            | Self::Handy
+            | Self::Garbage
+            | Self::Ethereal
+            | Self::TopUp
+            | Self::Orbital(_) => 2,
+            _ => 0,
+        }
+    }
+
     /// Save-file id for this tag.
     pub fn id(&self) -> &'static str {
         match self {
@@ -86,12 +106,13 @@ impl Tag {
             Self::D6 => "tag_d_six",
             Self::TopUp => "tag_top_up",
             Self::Speed => "tag_skip",
-            Self::Orbital => "tag_orbital",
+            Self::Orbital(_) => "tag_orbital",
             Self::Economy => "tag_economy",
         }
     }
 
-    /// Parses a save-file id back into a `Tag`.
+    /// Parses a save-file id back into a `Tag`. The id doesn't carry
+    /// Orbital's hand, it comes back as High Card.
     pub fn from_id(s: &str) -> Option<Self> {
         match s {
             "tag_uncommon" => Some(Self::Uncommon),
@@ -116,7 +137,7 @@ impl Tag {
             "tag_d_six" => Some(Self::D6),
             "tag_top_up" => Some(Self::TopUp),
             "tag_skip" => Some(Self::Speed),
-            "tag_orbital" => Some(Self::Orbital),
+            "tag_orbital" => Some(Self::Orbital(HandRank::HighCard)),
             "tag_economy" => Some(Self::Economy),
             _ => None,
         }
@@ -148,7 +169,7 @@ impl Tag {
             Self::D6 => "Next shop's rerolls start at $0",
             Self::TopUp => "Spawns up to 2 Common Jokers with no stickers, filling available slots",
             Self::Speed => "Gives $5 for each Blind skipped so far, including this one",
-            Self::Orbital => "Upgrades the poker hand type shown on the tag by 3 levels",
+            Self::Orbital(_) => "Upgrades the poker hand type shown on the tag by 3 levels",
             Self::Economy => "Doubles your money, capped at +$40",
         }
     }
@@ -171,6 +192,11 @@ mod tests {
         assert_eq!(Tag::iter().count(), 24);
     }
 
+    #[test]
+    fn test_tag_min_ante_count() {
+        assert_eq!(Tag::iter().filter(|t| t.min_ante() == 2).count(), 9);
+    }
+
     #[test]
     fn test_tag_name() {
         assert_eq!(Tag::D6.name(), "D6 Tag");
@@ -26,11 +26,16 @@ struct Args {
 #[derive(Subcommand)]
 enum Command {
     Show,
+    ExportJkr {
+        file: String,
+    },
     SetMoney {
-        amount: usize,
+        #[arg(allow_negative_numbers = true)]
+        amount: i64,
     },
     AddMoney {
-        amount: usize,
+        #[arg(allow_negative_numbers = true)]
+        amount: i64,
     },
     SetRerollCost {
         amount: usize,
@@ -48,13 +53,13 @@ enum Command {
         discards: usize,
     },
     SetScore {
-        score: usize,
+        score: f64,
     },
     SetChips {
-        chips: usize,
+        chips: f64,
     },
     SetMult {
-        mult: usize,
+        mult: f64,
     },
     SetJokerSlots {
         slots: usize,
@@ -127,6 +132,10 @@ fn main() -> Result<()> {
             println!("{game}");
             return Ok(());
         }
+        Command::ExportJkr { file } => {
+            let bytes = game.to_save_jkr().context("can't export as a save")?;
+            return fs::write(&file, bytes).with_context(|| format!("failed to write {file}"));
+        }
         Command::SetMoney { amount } => game.money = amount,
         Command::AddMoney { amount } => game.money = game.money.saturating_add(amount),
         Command::SetRerollCost { amount } => game.reroll_cost = amount,
@@ -137,9 +146,9 @@ fn main() -> Result<()> {
         Command::SetRound { round } => game.round = round,
         Command::SetPlays { plays } => game.plays = plays,
         Command::SetDiscards { discards } => game.discards_remaining = discards,
-        Command::SetScore { score } => game.score = score,
-        Command::SetChips { chips } => game.chips = chips,
-        Command::SetMult { mult } => game.mult = mult,
+        Command::SetScore { score } => game.score = score.into(),
+        Command::SetChips { chips } => game.chips = chips.into(),
+        Command::SetMult { mult } => game.mult = mult.into(),
         Command::SetJokerSlots { slots } => game.config.joker_slots = slots,
         Command::AddJoker {
             name,
@@ -161,9 +170,9 @@ fn main() -> Result<()> {
             }
             game.jokers.push(joker);
         }
-        Command::AddTarot { name } => game.consumables.push(Consumable::Tarot(name)),
-        Command::AddPlanet { name } => game.consumables.push(Consumable::Planet(name)),
-        Command::AddSpectral { name } => game.consumables.push(Consumable::Spectral(name)),
+        Command::AddTarot { name } => game.consumables.push(Consumable::Tarot(name).into()),
+        Command::AddPlanet { name } => game.consumables.push(Consumable::Planet(name).into()),
+        Command::AddSpectral { name } => game.consumables.push(Consumable::Spectral(name).into()),
         Command::ClearConsumables => game.consumables.clear(),
         Command::ClearDeck => {
             game.deck = Deck::new();
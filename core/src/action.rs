@@ -3,6 +3,7 @@ use crate::consumable::Consumable;
 use crate::joker::{joker_display, Jokers};
 use crate::pack::{Pack, PackContent};
 use crate::stage::{blind_display, Blind};
+use crate::voucher::Voucher;
 #[cfg(feature = "python")]
 use pyo3::pyclass;
 use std::fmt;
@@ -45,7 +46,7 @@ pub enum Action {
     MoveCard(MoveDirection, Card),
     Play(),
     Discard(),
-    CashOut(usize),
+    CashOut(),
     BuyJoker(Jokers),
     BuyConsumable(Consumable),
     UseConsumable(Consumable),
@@ -61,6 +62,11 @@ pub enum Action {
     SkipPack(),
     SortHand(SortBy),
     Reroll(),
+    BuyVoucher(Voucher),
+    BuyPlayingCard(Card),
+    RerollBoss(),
+    MoveJoker(MoveDirection, usize),
+    MoveConsumable(MoveDirection, usize),
 }
 
 impl fmt::Display for Action {
@@ -81,8 +87,8 @@ impl fmt::Display for Action {
             Self::MoveCard(dir, card) => {
                 write!(f, "MoveCard: {} - {}", card, dir)
             }
-            Self::CashOut(reward) => {
-                write!(f, "CashOut: {}", reward)
+            Self::CashOut() => {
+                write!(f, "CashOut")
             }
             Self::BuyJoker(joker) => {
                 write!(f, "BuyJoker: {}", joker_display(joker))
@@ -111,6 +117,11 @@ impl fmt::Display for Action {
             Self::SkipPack() => write!(f, "SkipPack"),
             Self::SortHand(sort_by) => write!(f, "SortHand: {}", sort_by),
             Self::Reroll() => write!(f, "Reroll"),
+            Self::BuyVoucher(voucher) => write!(f, "BuyVoucher: {}", voucher.name()),
+            Self::BuyPlayingCard(card) => write!(f, "BuyPlayingCard: {}", card),
+            Self::RerollBoss() => write!(f, "RerollBoss"),
+            Self::MoveJoker(dir, idx) => write!(f, "MoveJoker: {} - {}", idx, dir),
+            Self::MoveConsumable(dir, idx) => write!(f, "MoveConsumable: {} - {}", idx, dir),
         }
     }
 }
@@ -110,6 +110,10 @@ impl Available {
         }
     }
 
+    pub(crate) fn mutate_all<F: FnMut(&mut Card)>(&mut self, mut f: F) {
+        self.cards.iter_mut().for_each(|(c, _)| f(c));
+    }
+
     pub(crate) fn empty(&mut self) {
         self.cards = Vec::new();
     }
@@ -10,6 +10,7 @@
 
 use balatro_rs::game::Game;
 use balatro_rs::hand::SelectHand;
+use balatro_rs::score::BigNum;
 
 fn usage() -> ! {
     eprintln!("usage: calc score STATE.json");
@@ -56,7 +57,11 @@ fn main() {
     }
     println!();
 
-    let final_chips = trace.0.last().map(|s| s.chips_after).unwrap_or(0);
-    let final_mult = trace.0.last().map(|s| s.mult_after).unwrap_or(0);
+    let final_chips = trace
+        .0
+        .last()
+        .map(|s| s.chips_after)
+        .unwrap_or(BigNum::ZERO);
+    let final_mult = trace.0.last().map(|s| s.mult_after).unwrap_or(BigNum::ZERO);
     println!("{final_chips} x {final_mult} = {score}");
 }
@@ -33,6 +33,16 @@ pub struct Card {
     pub edition: Edition,
     pub enhancement: Option<Enhancement>,
     pub seal: Option<Seal>,
+    // permanent chips scored on top of the rank's (Hiker)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bonus_chips: usize,
+    // debuffed by the active boss, kept in sync by `Game`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub debuffed: bool,
+    // drawn face down by the active boss, turned back up once it leaves
+    // the hand
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub face_down: bool,
 }
 
 impl Card {
@@ -45,6 +55,25 @@ impl Card {
             edition: Edition::Base,
             enhancement: None,
             seal: None,
+            bonus_chips: 0,
+            debuffed: false,
+            face_down: false,
+        }
+    }
+
+    /// Clears the boss-set debuff and face down flags, for a card going
+    /// back to the deck or discard pile. Bonus chips are permanent.
+    pub(crate) fn reset_round_flags(&mut self) {
+        self.debuffed = false;
+        self.face_down = false;
+    }
+
+    /// A copy of this card under a fresh id, keeping everything else,
+    /// bonus chips included (DNA, Cryptid).
+    pub(crate) fn duplicate(&self) -> Self {
+        Self {
+            id: CARD_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
+            ..*self
         }
     }
 
@@ -91,6 +120,18 @@ impl Card {
     }
 }
 
+/// `balatro_types::Card` has no `id`; converting mints a fresh one.
+impl From<balatro_types::Card> for Card {
+    fn from(c: balatro_types::Card) -> Self {
+        let mut card = Card::new(c.value, c.suit);
+        card.edition = c.edition;
+        card.enhancement = c.enhancement;
+        card.seal = c.seal;
+        card.bonus_chips = c.bonus_chips;
+        card
+    }
+}
+
 impl fmt::Debug for Card {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         #[cfg(feature = "colored")]
@@ -132,6 +173,15 @@ pub fn card_display(c: &Card) -> String {
     if let Some(seal) = c.seal {
         s += &format!("[{:?}]", seal);
     }
+    if c.bonus_chips > 0 {
+        s += &format!("[+{}]", c.bonus_chips);
+    }
+    if c.debuffed {
+        s += "[Debuffed]";
+    }
+    if c.face_down {
+        s += "[FaceDown]";
+    }
     s
 }
 
@@ -202,6 +252,17 @@ mod tests {
         assert!(!king.is_odd());
     }
 
+    #[test]
+    fn test_card_display_shows_persistent_state() {
+        let mut c = Card::new(Value::Ace, Suit::Spade);
+        c.bonus_chips = 10;
+        c.debuffed = true;
+        assert_eq!(card_display(&c), "A♠[+10][Debuffed]");
+        c.face_down = true;
+        c.reset_round_flags();
+        assert_eq!(card_display(&c), "A♠[+10]");
+    }
+
     // Card's `id` is part of its derived Eq/Ord/Hash
     // so two structurally-identical cards with different ids are NOT equal.
     #[test]
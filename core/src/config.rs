@@ -1,3 +1,5 @@
+use crate::deck::DeckVariant;
+use crate::stake::Stake;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -6,6 +8,7 @@ const DEFAULT_PLAYS: usize = 4;
 const DEFAULT_DISCARDS: usize = 4;
 const DEFAULT_MONEY_START: usize = 0;
 const DEFAULT_MONEY_MAX: usize = 500;
+const DEFAULT_DEBT_MAX: usize = 0;
 const DEFAULT_REWARD_BASE: usize = 0;
 const DEFAULT_MONEY_PER_HAND: usize = 1;
 const DEFAULT_INTEREST_RATE: f32 = 0.2;
@@ -25,12 +28,11 @@ const DEFAULT_DISCARDED_MAX: usize = 100;
 const DEFAULT_SELECTED_MAX: usize = 5;
 const DEFAULT_CONSUMABLE_SLOTS: usize = 2;
 
-/// Which RNG backend `Shop` generation uses. `Fast` is `core`'s original
+/// Which RNG backend the game draws from. `Fast` is `core`'s original
 /// `rand_chacha`-based generation (unchanged). `Real` is a byte-accurate
 /// port of the real Balatro seed algorithm (`balatro-seed`), producing the
-/// same shop/pack contents the real game would for a given seed. Only
-/// affects shop-item/pack generation — deck shuffling, probability rolls,
-/// and the skip-blind tag draw are unaffected either way.
+/// same shops, packs, tags, deck shuffles and chance rolls the real game
+/// would for a given seed.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum RngMode {
@@ -48,6 +50,10 @@ pub struct Config {
     pub discards: usize,
     pub money_start: usize,
     pub money_max: usize,
+    /// How far below $0 purchases may take money, before jokers like
+    /// Credit Card.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub debt_max: usize,
     pub reward_base: usize,
     pub money_per_hand: usize,
     pub interest_rate: f32,
@@ -69,6 +75,19 @@ pub struct Config {
     pub seed: Option<u64>,
     pub seed_str: Option<String>,
     pub rng_mode: RngMode,
+    /// Starting deck, `None` plays the plain 52 cards with no deck modifier.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub deck: Option<DeckVariant>,
+    /// Difficulty stake, modifiers stack on every stake below it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stake: Stake,
+    /// Keep playing past `ante_end` until a blind is lost.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub endless: bool,
+    /// Items the real account hasn't unlocked, kept out of `RngMode::Real`
+    /// pools. `None` plays as a fully unlocked profile.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub profile_locks: Option<Vec<String>>,
 }
 
 impl Config {
@@ -79,6 +98,7 @@ impl Config {
             discards: DEFAULT_DISCARDS,
             money_start: DEFAULT_MONEY_START,
             money_max: DEFAULT_MONEY_MAX,
+            debt_max: DEFAULT_DEBT_MAX,
             reward_base: DEFAULT_REWARD_BASE,
             money_per_hand: DEFAULT_MONEY_PER_HAND,
             interest_rate: DEFAULT_INTEREST_RATE,
@@ -100,8 +120,26 @@ impl Config {
             seed: None,
             seed_str: None,
             rng_mode: RngMode::default(),
+            deck: None,
+            stake: Stake::default(),
+            endless: false,
+            profile_locks: None,
         }
     }
+
+    /// Locks everything `profile` hasn't unlocked (or discovered, for the
+    /// gated tags), so a `RngMode::Real` seed generates what that account
+    /// would see.
+    pub fn with_profile(mut self, profile: &balatro_profile::Profile) -> Self {
+        self.profile_locks = Some(
+            balatro_seed::PROFILE_LOCKS
+                .iter()
+                .filter(|name| !profile.has_unlocked(name))
+                .map(|name| name.to_string())
+                .collect(),
+        );
+        self
+    }
 }
 
 impl Default for Config {
@@ -217,6 +255,17 @@ impl Config {
     fn set_money_max(&mut self, i: usize) {
         self.money_max = i;
     }
+
+    #[getter]
+    fn get_debt_max(&mut self) -> usize {
+        self.debt_max
+    }
+
+    #[setter]
+    fn set_debt_max(&mut self, i: usize) {
+        self.debt_max = i;
+    }
+
     #[getter]
     fn get_consumable_slots(&mut self) -> usize {
         self.consumable_slots
@@ -229,7 +278,7 @@ impl Config {
 
     #[getter]
     fn get_stage_max(&self) -> usize {
-        9
+        crate::stage::Stage::MAX_INDEX
     }
 
     #[getter]
@@ -251,4 +300,34 @@ impl Config {
     fn set_seed_str(&mut self, v: Option<String>) {
         self.seed_str = v;
     }
+
+    #[getter]
+    fn get_deck(&self) -> Option<DeckVariant> {
+        self.deck
+    }
+
+    #[setter]
+    fn set_deck(&mut self, v: Option<DeckVariant>) {
+        self.deck = v;
+    }
+
+    #[getter]
+    fn get_stake(&self) -> Stake {
+        self.stake
+    }
+
+    #[setter]
+    fn set_stake(&mut self, v: Stake) {
+        self.stake = v;
+    }
+
+    #[getter]
+    fn get_endless(&self) -> bool {
+        self.endless
+    }
+
+    #[setter]
+    fn set_endless(&mut self, v: bool) {
+        self.endless = v;
+    }
 }
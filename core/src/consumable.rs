@@ -1 +1,63 @@
+use std::ops::Deref;
+
 pub use balatro_types::Consumable;
+
+/// A consumable in the player's consumable slots, along with the state
+/// that travels with it when it's moved or sold.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeldConsumable {
+    pub consumable: Consumable,
+    // sell value gained while held (Gift Card)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extra_value: usize,
+    // Perkeo's copies, each bringing its own consumable slot
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub negative: bool,
+}
+
+impl HeldConsumable {
+    /// Sell value, including anything gained while held.
+    pub fn sell_value(&self) -> usize {
+        self.consumable.sell_value() + self.extra_value
+    }
+}
+
+impl From<Consumable> for HeldConsumable {
+    fn from(consumable: Consumable) -> Self {
+        Self {
+            consumable,
+            extra_value: 0,
+            negative: false,
+        }
+    }
+}
+
+impl Deref for HeldConsumable {
+    type Target = Consumable;
+
+    fn deref(&self) -> &Consumable {
+        &self.consumable
+    }
+}
+
+impl PartialEq<Consumable> for HeldConsumable {
+    fn eq(&self, other: &Consumable) -> bool {
+        self.consumable == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planet::Planets;
+
+    #[test]
+    fn test_held_consumable_sell_value_includes_extra() {
+        let mut held = HeldConsumable::from(Consumable::Planet(Planets::Mercury));
+        let base = held.sell_value();
+        held.extra_value = 2;
+        assert_eq!(held.sell_value(), base + 2);
+        assert_eq!(held, Consumable::Planet(Planets::Mercury));
+    }
+}
@@ -2,6 +2,8 @@ use crate::card::{Card, Suit, Value};
 use rand::{seq::SliceRandom, Rng};
 use strum::IntoEnumIterator;
 
+pub use balatro_types::DeckVariant;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
 pub struct Deck {
@@ -24,15 +26,29 @@ impl Deck {
         self.cards.shuffle(rng);
     }
 
+    /// Shuffles the way the real game does: cards put in creation order,
+    /// `shuffle` applied, then drawn from the back.
+    pub(crate) fn shuffle_by(&mut self, shuffle: impl FnOnce(&mut [Card])) {
+        self.cards.sort_by_key(|c| c.id);
+        shuffle(&mut self.cards);
+        self.cards.reverse();
+    }
+
+    // cards coming back to the deck are never debuffed or face down
     pub(crate) fn append(&mut self, other: &mut Vec<Card>) {
+        other.iter_mut().for_each(Card::reset_round_flags);
         self.cards.append(other);
     }
 
     pub(crate) fn extend(&mut self, other: Vec<Card>) {
-        self.cards.extend(other);
+        self.cards.extend(other.into_iter().map(|mut c| {
+            c.reset_round_flags();
+            c
+        }));
     }
 
-    pub fn push(&mut self, card: Card) {
+    pub fn push(&mut self, mut card: Card) {
+        card.reset_round_flags();
         self.cards.push(card);
     }
 
@@ -46,10 +62,43 @@ impl Deck {
         }
     }
 
+    pub(crate) fn mutate_all<F: FnMut(&mut Card)>(&mut self, f: F) {
+        self.cards.iter_mut().for_each(f);
+    }
+
     pub fn cards(&self) -> Vec<Card> {
         self.cards.clone()
     }
 
+    /// The starting cards for `variant`. Only Abandoned, Checkered and
+    /// Erratic change the cards themselves, every other deck starts with
+    /// the standard 52.
+    pub(crate) fn for_variant(variant: DeckVariant, rng: &mut impl Rng) -> Self {
+        let mut deck = Self::default();
+        match variant {
+            DeckVariant::Abandoned => deck.cards.retain(|c| !c.is_face_card()),
+            DeckVariant::Checkered => {
+                for c in deck.cards.iter_mut() {
+                    c.suit = match c.suit {
+                        Suit::Club => Suit::Spade,
+                        Suit::Diamond => Suit::Heart,
+                        s => s,
+                    };
+                }
+            }
+            DeckVariant::Erratic => {
+                let values: Vec<Value> = Value::iter().collect();
+                let suits: Vec<Suit> = Suit::iter().collect();
+                for c in deck.cards.iter_mut() {
+                    c.value = values[rng.gen_range(0..values.len())];
+                    c.suit = suits[rng.gen_range(0..suits.len())];
+                }
+            }
+            _ => {}
+        }
+        deck
+    }
+
     // // Loops through cards, assigning index to each equal to index in deck
     // pub(crate) fn index_cards(&mut self) {
     //     let mut i = 0;
@@ -61,12 +110,15 @@ impl Deck {
 }
 
 impl Default for Deck {
+    // Created in the real game's starting order (by suit letter, then rank
+    // key), so card ids line up with its `sort_id`s for `shuffle_by`.
     fn default() -> Self {
+        let mut values: Vec<Value> = Value::iter().collect();
+        values.sort_by_key(|v| char::from(*v));
         let mut cards: Vec<Card> = Vec::new();
-        for v in Value::iter() {
-            for s in Suit::iter() {
-                let c = Card::new(v, s);
-                cards.push(c);
+        for s in [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade] {
+            for v in &values {
+                cards.push(Card::new(*v, s));
             }
         }
         Self { cards }
@@ -78,6 +130,47 @@ mod tests {
     use super::*;
     use crate::card::Value;
 
+    #[test]
+    fn test_for_variant_abandoned_has_no_face_cards() {
+        let deck = Deck::for_variant(DeckVariant::Abandoned, &mut rand::thread_rng());
+        assert_eq!(deck.len(), 40);
+        assert!(deck.cards().iter().all(|c| !c.is_face_card()));
+    }
+
+    #[test]
+    fn test_for_variant_checkered_two_suits() {
+        let deck = Deck::for_variant(DeckVariant::Checkered, &mut rand::thread_rng());
+        assert_eq!(deck.len(), 52);
+        let spades = deck
+            .cards()
+            .iter()
+            .filter(|c| c.suit == Suit::Spade)
+            .count();
+        let hearts = deck
+            .cards()
+            .iter()
+            .filter(|c| c.suit == Suit::Heart)
+            .count();
+        assert_eq!((spades, hearts), (26, 26));
+    }
+
+    #[test]
+    fn test_returning_cards_clears_round_flags() {
+        let mut deck = Deck::default();
+        let mut drawn = deck.draw(2);
+        for c in drawn.iter_mut() {
+            c.face_down = true;
+            c.debuffed = true;
+            c.bonus_chips = 5;
+        }
+        deck.extend(drawn);
+        assert!(deck.cards().iter().all(|c| !c.face_down && !c.debuffed));
+        assert_eq!(
+            deck.cards().iter().map(|c| c.bonus_chips).sum::<usize>(),
+            10
+        );
+    }
+
     #[test]
     fn test_draw_within_size() {
         let mut deck = Deck::default();
@@ -2,6 +2,7 @@ use crate::card::Card;
 use crate::game::Game;
 use crate::hand::MadeHand;
 use crate::joker::{JokerEffects, Jokers};
+use crate::modifier::StatModifiers;
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 
@@ -9,12 +10,23 @@ type GameHandFn = Arc<Mutex<dyn Fn(&mut Game, MadeHand) + Send + 'static>>;
 type GameFn = Arc<Mutex<dyn Fn(&mut Game) + Send + 'static>>;
 type GameModifyFn = Arc<Mutex<dyn Fn(&mut Game, &mut MadeHand) + Send + 'static>>;
 type CardTriggerFn = Arc<Mutex<dyn Fn(&mut Game, Card, bool) -> usize + Send + 'static>>;
+type GameDollarFn = Arc<Mutex<dyn Fn(&mut Game) -> usize + Send + 'static>>;
+type GameCardsFn = Arc<Mutex<dyn Fn(&mut Game, Vec<Card>) + Send + 'static>>;
+type GameCardFn = Arc<Mutex<dyn Fn(&mut Game, Card) + Send + 'static>>;
 
 // A joker's mere presence flips a named rule used elsewhere in scoring/hand
 // evaluation, rather than contributing a chip/mult delta at a specific hook.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RuleFlag {
     AllCardsAreFace,
+    // Four Fingers
+    FourCardHands,
+    // Shortcut
+    GappedStraights,
+    // Smeared Joker
+    SmearedSuits,
+    // Splash
+    AllPlayedCardsScore,
 }
 
 #[derive(Debug, Clone)]
@@ -23,10 +35,24 @@ pub struct EffectRegistry {
     pub on_discard: Vec<Effects>,
     pub on_handrank: Vec<Effects>,
     pub on_modify_hand: Vec<Effects>,
+    pub on_card_scored: Vec<Effects>,
     pub on_round_end: Vec<Effects>,
     pub trigger_count_played: Vec<Effects>,
     pub trigger_count_held: Vec<Effects>,
+    pub on_blind_select: Vec<Effects>,
+    pub on_cards_destroyed: Vec<Effects>,
+    pub on_cards_added: Vec<Effects>,
+    pub on_pack_skip: Vec<Effects>,
+    pub on_reroll: Vec<Effects>,
+    pub on_planet_used: Vec<Effects>,
+    pub on_card_sold: Vec<Effects>,
+    pub on_lucky_trigger: Vec<Effects>,
+    pub on_first_hand_drawn: Vec<Effects>,
+    pub on_pack_open: Vec<Effects>,
+    pub on_shop_end: Vec<Effects>,
     pub rule_flags: HashSet<RuleFlag>,
+    // summed hand size/hands/discards changes from held jokers
+    pub modifiers: StatModifiers,
 }
 
 impl EffectRegistry {
@@ -36,10 +62,23 @@ impl EffectRegistry {
             on_discard: Vec::new(),
             on_handrank: Vec::new(),
             on_modify_hand: Vec::new(),
+            on_card_scored: Vec::new(),
             on_round_end: Vec::new(),
             trigger_count_played: Vec::new(),
             trigger_count_held: Vec::new(),
+            on_blind_select: Vec::new(),
+            on_cards_destroyed: Vec::new(),
+            on_cards_added: Vec::new(),
+            on_pack_skip: Vec::new(),
+            on_reroll: Vec::new(),
+            on_planet_used: Vec::new(),
+            on_card_sold: Vec::new(),
+            on_lucky_trigger: Vec::new(),
+            on_first_hand_drawn: Vec::new(),
+            on_pack_open: Vec::new(),
+            on_shop_end: Vec::new(),
             rule_flags: HashSet::new(),
+            modifiers: StatModifiers::default(),
         }
     }
 }
@@ -56,10 +95,23 @@ impl EffectRegistry {
         self.on_discard.clear();
         self.on_handrank.clear();
         self.on_modify_hand.clear();
+        self.on_card_scored.clear();
         self.on_round_end.clear();
         self.trigger_count_played.clear();
         self.trigger_count_held.clear();
+        self.on_blind_select.clear();
+        self.on_cards_destroyed.clear();
+        self.on_cards_added.clear();
+        self.on_pack_skip.clear();
+        self.on_reroll.clear();
+        self.on_planet_used.clear();
+        self.on_card_sold.clear();
+        self.on_lucky_trigger.clear();
+        self.on_first_hand_drawn.clear();
+        self.on_pack_open.clear();
+        self.on_shop_end.clear();
         self.rule_flags.clear();
+        self.modifiers = StatModifiers::default();
         for j in jokers {
             for e in j.effects(game) {
                 match e {
@@ -69,12 +121,26 @@ impl EffectRegistry {
                     Effects::OnSell(_) => {} // not cached, computed on the sold joker directly in sell_joker
                     Effects::OnHandRank(_) => self.on_handrank.push(e),
                     Effects::OnModifyHand(_) => self.on_modify_hand.push(e),
+                    Effects::OnCardScored(_) => self.on_card_scored.push(e),
                     Effects::OnRoundEnd(_) => self.on_round_end.push(e),
+                    Effects::OnCashOut(_) => {} // not cached, each joker gets its own cash out line
                     Effects::TriggerCountPlayed(_) => self.trigger_count_played.push(e),
                     Effects::TriggerCountHeld(_) => self.trigger_count_held.push(e),
+                    Effects::OnBlindSelect(_) => self.on_blind_select.push(e),
+                    Effects::OnCardsDestroyed(_) => self.on_cards_destroyed.push(e),
+                    Effects::OnCardsAdded(_) => self.on_cards_added.push(e),
+                    Effects::OnPackSkip(_) => self.on_pack_skip.push(e),
+                    Effects::OnReroll(_) => self.on_reroll.push(e),
+                    Effects::OnPlanetUsed(_) => self.on_planet_used.push(e),
+                    Effects::OnCardSold(_) => self.on_card_sold.push(e),
+                    Effects::OnLuckyTrigger(_) => self.on_lucky_trigger.push(e),
+                    Effects::OnFirstHandDrawn(_) => self.on_first_hand_drawn.push(e),
+                    Effects::OnPackOpen(_) => self.on_pack_open.push(e),
+                    Effects::OnShopEnd(_) => self.on_shop_end.push(e),
                     Effects::RuleFlag(flag) => {
                         self.rule_flags.insert(flag);
                     }
+                    Effects::Modify(m) => self.modifiers += m,
                 }
             }
         }
@@ -91,10 +157,35 @@ pub enum Effects {
     OnSell(GameFn),
     OnHandRank(GameFn),
     OnModifyHand(GameModifyFn),
+    // each time a played card scores, retriggers included, before any
+    // joker's OnScore
+    OnCardScored(GameCardFn),
     OnRoundEnd(GameFn),
+    // dollars added to the end-of-round cash out
+    OnCashOut(GameDollarFn),
     TriggerCountPlayed(CardTriggerFn),
     TriggerCountHeld(CardTriggerFn),
+    OnBlindSelect(GameFn),
+    // playing cards destroyed (Hanged Man, glass shatters, ...)
+    OnCardsDestroyed(GameCardsFn),
+    // playing cards added to the deck or hand
+    OnCardsAdded(GameCardsFn),
+    OnPackSkip(GameFn),
+    OnReroll(GameFn),
+    OnPlanetUsed(GameFn),
+    // any joker or consumable sold, unlike OnSell which is the joker's own
+    OnCardSold(GameFn),
+    // a Lucky card hit one of its rolls
+    OnLuckyTrigger(GameFn),
+    // the round's first hand has just been dealt
+    OnFirstHandDrawn(GameFn),
+    // any booster pack opened, bought or from a tag
+    OnPackOpen(GameFn),
+    // leaving the shop for the next round
+    OnShopEnd(GameFn),
     RuleFlag(RuleFlag),
+    // changes hand size, hands, discards or selection limit while held
+    Modify(StatModifiers),
 }
 
 impl std::fmt::Debug for Effects {
@@ -106,10 +197,127 @@ impl std::fmt::Debug for Effects {
             Self::OnSell(_) => write!(f, "OnSell"),
             Self::OnHandRank(_) => write!(f, "OnHandRank"),
             Self::OnModifyHand(_) => write!(f, "OnModifyHand"),
+            Self::OnCardScored(_) => write!(f, "OnCardScored"),
             Self::OnRoundEnd(_) => write!(f, "OnRoundEnd"),
+            Self::OnCashOut(_) => write!(f, "OnCashOut"),
             Self::TriggerCountPlayed(_) => write!(f, "TriggerCountPlayed"),
             Self::TriggerCountHeld(_) => write!(f, "TriggerCountHeld"),
+            Self::OnBlindSelect(_) => write!(f, "OnBlindSelect"),
+            Self::OnCardsDestroyed(_) => write!(f, "OnCardsDestroyed"),
+            Self::OnCardsAdded(_) => write!(f, "OnCardsAdded"),
+            Self::OnPackSkip(_) => write!(f, "OnPackSkip"),
+            Self::OnReroll(_) => write!(f, "OnReroll"),
+            Self::OnPlanetUsed(_) => write!(f, "OnPlanetUsed"),
+            Self::OnCardSold(_) => write!(f, "OnCardSold"),
+            Self::OnLuckyTrigger(_) => write!(f, "OnLuckyTrigger"),
+            Self::OnFirstHandDrawn(_) => write!(f, "OnFirstHandDrawn"),
+            Self::OnPackOpen(_) => write!(f, "OnPackOpen"),
+            Self::OnShopEnd(_) => write!(f, "OnShopEnd"),
             Self::RuleFlag(flag) => write!(f, "RuleFlag({:?})", flag),
+            Self::Modify(m) => write!(f, "Modify({:?})", m),
+        }
+    }
+}
+
+// Runs `f` with `Game::copying` set, restoring the previous value after.
+fn copying<R>(g: &mut Game, f: impl FnOnce(&mut Game) -> R) -> R {
+    let prev = std::mem::replace(&mut g.copying, true);
+    let out = f(g);
+    g.copying = prev;
+    out
+}
+
+impl Effects {
+    /// Wraps this effect as a Blueprint/Brainstorm copy: it behaves the same,
+    /// but runs with `Game::copying` set so stateful jokers don't scale twice.
+    pub fn as_copy(self) -> Effects {
+        match self {
+            Self::OnPlay(f) => Self::OnPlay(Arc::new(Mutex::new(move |g: &mut Game, h| {
+                copying(g, |g| f.lock().unwrap()(g, h))
+            }))),
+            Self::OnDiscard(f) => Self::OnDiscard(Arc::new(Mutex::new(move |g: &mut Game, h| {
+                copying(g, |g| f.lock().unwrap()(g, h))
+            }))),
+            Self::OnScore(f) => Self::OnScore(Arc::new(Mutex::new(move |g: &mut Game, h| {
+                copying(g, |g| f.lock().unwrap()(g, h))
+            }))),
+            Self::OnSell(f) => Self::OnSell(Arc::new(Mutex::new(move |g: &mut Game| {
+                copying(g, |g| f.lock().unwrap()(g))
+            }))),
+            Self::OnHandRank(f) => Self::OnHandRank(Arc::new(Mutex::new(move |g: &mut Game| {
+                copying(g, |g| f.lock().unwrap()(g))
+            }))),
+            Self::OnModifyHand(f) => Self::OnModifyHand(Arc::new(Mutex::new(
+                move |g: &mut Game, h: &mut MadeHand| copying(g, |g| f.lock().unwrap()(g, h)),
+            ))),
+            Self::OnCardScored(f) => {
+                Self::OnCardScored(Arc::new(Mutex::new(move |g: &mut Game, c| {
+                    copying(g, |g| f.lock().unwrap()(g, c))
+                })))
+            }
+            Self::OnRoundEnd(f) => Self::OnRoundEnd(Arc::new(Mutex::new(move |g: &mut Game| {
+                copying(g, |g| f.lock().unwrap()(g))
+            }))),
+            Self::OnCashOut(f) => Self::OnCashOut(Arc::new(Mutex::new(move |g: &mut Game| {
+                copying(g, |g| f.lock().unwrap()(g))
+            }))),
+            Self::TriggerCountPlayed(f) => {
+                Self::TriggerCountPlayed(Arc::new(Mutex::new(move |g: &mut Game, c, scored| {
+                    copying(g, |g| f.lock().unwrap()(g, c, scored))
+                })))
+            }
+            Self::TriggerCountHeld(f) => {
+                Self::TriggerCountHeld(Arc::new(Mutex::new(move |g: &mut Game, c, scored| {
+                    copying(g, |g| f.lock().unwrap()(g, c, scored))
+                })))
+            }
+            Self::OnBlindSelect(f) => {
+                Self::OnBlindSelect(Arc::new(Mutex::new(move |g: &mut Game| {
+                    copying(g, |g| f.lock().unwrap()(g))
+                })))
+            }
+            Self::OnCardsDestroyed(f) => {
+                Self::OnCardsDestroyed(Arc::new(Mutex::new(move |g: &mut Game, c| {
+                    copying(g, |g| f.lock().unwrap()(g, c))
+                })))
+            }
+            Self::OnCardsAdded(f) => {
+                Self::OnCardsAdded(Arc::new(Mutex::new(move |g: &mut Game, c| {
+                    copying(g, |g| f.lock().unwrap()(g, c))
+                })))
+            }
+            Self::OnPackSkip(f) => Self::OnPackSkip(Arc::new(Mutex::new(move |g: &mut Game| {
+                copying(g, |g| f.lock().unwrap()(g))
+            }))),
+            Self::OnReroll(f) => Self::OnReroll(Arc::new(Mutex::new(move |g: &mut Game| {
+                copying(g, |g| f.lock().unwrap()(g))
+            }))),
+            Self::OnPlanetUsed(f) => {
+                Self::OnPlanetUsed(Arc::new(Mutex::new(move |g: &mut Game| {
+                    copying(g, |g| f.lock().unwrap()(g))
+                })))
+            }
+            Self::OnCardSold(f) => Self::OnCardSold(Arc::new(Mutex::new(move |g: &mut Game| {
+                copying(g, |g| f.lock().unwrap()(g))
+            }))),
+            Self::OnLuckyTrigger(f) => {
+                Self::OnLuckyTrigger(Arc::new(Mutex::new(move |g: &mut Game| {
+                    copying(g, |g| f.lock().unwrap()(g))
+                })))
+            }
+            Self::OnFirstHandDrawn(f) => {
+                Self::OnFirstHandDrawn(Arc::new(Mutex::new(move |g: &mut Game| {
+                    copying(g, |g| f.lock().unwrap()(g))
+                })))
+            }
+            Self::OnPackOpen(f) => Self::OnPackOpen(Arc::new(Mutex::new(move |g: &mut Game| {
+                copying(g, |g| f.lock().unwrap()(g))
+            }))),
+            Self::OnShopEnd(f) => Self::OnShopEnd(Arc::new(Mutex::new(move |g: &mut Game| {
+                copying(g, |g| f.lock().unwrap()(g))
+            }))),
+            Self::RuleFlag(flag) => Self::RuleFlag(flag),
+            Self::Modify(m) => Self::Modify(m),
         }
     }
 }
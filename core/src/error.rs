@@ -32,8 +32,12 @@ pub enum GameError {
     NoCardMatch,
     #[error("No joker match")]
     NoJokerMatch,
+    #[error("Joker is eternal")]
+    EternalJoker,
     #[error("No consumable match")]
     NoConsumableMatch,
+    #[error("No voucher match")]
+    NoVoucherMatch,
     #[error("Invalid move direction")]
     InvalidMoveDirection,
     #[error("No available slot")]
@@ -46,6 +50,8 @@ pub enum GameError {
     InvalidSelectCard,
     #[error("Invalid action space")]
     InvalidActionSpace,
+    #[error("No deck")]
+    NoDeck,
 }
 
 impl std::convert::From<ActionSpaceError> for GameError {
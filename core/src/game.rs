@@ -3,24 +3,31 @@ use crate::ante::Ante;
 use crate::available::Available;
 use crate::card::{card_display, Card, Edition, Enhancement, Seal, Suit};
 use crate::config::{Config, RngMode};
-use crate::consumable::Consumable;
-use crate::deck::Deck;
+use crate::consumable::{Consumable, HeldConsumable};
+use crate::deck::{Deck, DeckVariant};
 use crate::effect::{EffectRegistry, Effects, RuleFlag};
-use crate::error::GameError;
-use crate::hand::{MadeHand, SelectHand};
-use crate::joker::{joker_display, JokerEffects, JokerState, Jokers};
-use crate::pack::{OpenPackState, Pack, PackCategory, PackContent};
-use crate::planet::Planetarium;
+use crate::error::{GameError, PlayHandError};
+use crate::hand::{HandRules, MadeHand, SelectHand};
+use crate::joker::{
+    joker_display, JokerEffects, JokerState, Jokers, Rarity, SelectorValue, PERISHABLE_ROUNDS,
+};
+use crate::modifier::{adjust, StatModifiers};
+use crate::pack::{OpenPackState, Pack, PackCategory, PackContent, PackSize};
+use crate::planet::{Planetarium, Planets};
 use crate::rank::HandRank;
+use crate::reward::{RewardSource, RoundReward};
 use crate::rng::{Backend, FastBackend, RealBackend, RngBackend};
-use crate::score::{ScoreSource, ScoreStep, ScoreTrace};
+use crate::score::{BigNum, ScoreSource, ScoreStep, ScoreTrace};
 use crate::shop::Shop;
 use crate::spectral::SpectralEffect;
-use crate::stage::{Blind, BlindExt, End, Stage};
-use crate::tag::Tag;
+use crate::stage::{Blind, BlindExt, BossPool, End, Stage};
+use crate::stake::Stake;
+use crate::tag::{tag_trigger, Tag, TagTrigger};
 use crate::tarot::{Tarot, TarotEffect};
+use crate::voucher::Voucher;
 
 use balatro_types::BossBlind;
+use itertools::Itertools;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use std::collections::HashSet;
@@ -32,10 +39,28 @@ fn default_backend() -> Backend {
     Backend::Fast(FastBackend::new(ChaCha8Rng::from_entropy()))
 }
 
-fn default_reroll_cost() -> usize {
+pub(crate) fn default_reroll_cost() -> usize {
     5
 }
 
+// Where the item at `idx` of a `len` long row ends up after moving one
+// step, erroring at either end (matches `Available::move_card`)
+fn move_target(direction: MoveDirection, idx: usize, len: usize) -> Result<usize, GameError> {
+    if idx >= len {
+        return Err(GameError::InvalidAction);
+    }
+    match direction {
+        MoveDirection::Left if idx > 0 => Ok(idx - 1),
+        MoveDirection::Right if idx + 1 < len => Ok(idx + 1),
+        _ => Err(GameError::InvalidMoveDirection),
+    }
+}
+
+// Extra vouchers from Voucher tags sit alongside the ante's own.
+pub(crate) const SHOP_VOUCHER_SLOTS: usize = 2;
+const BOSS_REROLL_COST: usize = 10;
+const RENTAL_COST: usize = 3;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
 pub struct Game {
@@ -51,6 +76,9 @@ pub struct Game {
     pub blind: Option<Blind>,
     #[cfg_attr(feature = "serde", serde(default))]
     pub current_boss: Option<BossBlind>,
+    // bosses drawn so far, so none repeats until the pool runs dry
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) boss_pool: BossPool,
     pub stage: Stage,
     pub ante_start: Ante,
     pub ante_end: Ante,
@@ -62,26 +90,42 @@ pub struct Game {
     pub jokers: Vec<Jokers>,
     #[cfg_attr(feature = "serde", serde(skip))]
     pub effect_registry: EffectRegistry,
+    // set while a Blueprint/Brainstorm copy runs, so scaling jokers apply
+    // their current value without scaling a second time
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) copying: bool,
 
     // held consumables (planets, tarots, etc.)
-    pub consumables: Vec<Consumable>,
+    pub consumables: Vec<HeldConsumable>,
+
+    // redeemed vouchers, in the order they were bought
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub vouchers: Vec<Voucher>,
 
     // playing
     pub plays: usize,
     #[cfg_attr(feature = "serde", serde(rename = "discards"))] // shouldn't access directly
     pub discards_remaining: usize,
-    pub reward: usize,
-    pub money: usize,
+    // itemised cash out for the blind just beaten, paid on CashOut
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub round_reward: RoundReward,
+    pub money: i64,
 
     // for scoring
-    pub chips: usize,
-    pub mult: usize,
-    pub score: usize,
+    pub chips: BigNum,
+    pub mult: BigNum,
+    pub score: BigNum,
     pub prob_mult: u32,
 
     // shared game-level history some jokers key off (RideTheBus, Obelisk, etc)
     #[cfg_attr(feature = "serde", serde(default))]
     pub(crate) hand_ranks_played_this_round: HashSet<HandRank>,
+    // Blue Seal's planet
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) last_hand_played: Option<HandRank>,
+    // DNA and Sixth Sense only act on the round's first hand
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) hands_played_this_round: usize,
     #[cfg_attr(feature = "serde", serde(default))]
     pub(crate) consecutive_hands_without_face_card: usize,
     #[cfg_attr(feature = "serde", serde(default))]
@@ -98,10 +142,53 @@ pub struct Game {
     // set when a boss's ability actually triggered (Matador's trigger condition).
     #[cfg_attr(feature = "serde", serde(default))]
     pub(crate) boss_triggered_this_hand: bool,
+    // run totals the Handy, Garbage and Speed tags pay out on, never reset.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) total_hands_played: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) total_unused_discards: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) total_blinds_skipped: usize,
+    // set once the final ante's boss is beaten, endless or not. A later
+    // loss is still a win, even after an ante rollback.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) won: bool,
+    // joker slots Negative editions actually added, short of
+    // `joker_slots_max`, so losing a Negative only gives back a slot it got
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) negative_joker_slots: usize,
+    // modifiers for the current round only (Juggle tag)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) round_modifiers: StatModifiers,
+    // Director's Cut allows one boss reroll per ante
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) boss_rerolls_this_ante: usize,
+    // ids of every card played this ante (The Pillar)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) played_this_ante: HashSet<usize>,
+    // blinds skipped this ante, for the save's blind states
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) skipped_this_ante: Vec<Blind>,
+    // showdown boss state, all reset when the blind ends
+    // jokers flipped and shuffled (Amber Acorn)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) jokers_flipped: bool,
+    // id of the card that must stay selected (Cerulean Bell)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) forced_card: Option<usize>,
+    // instance id of the joker disabled for this hand (Crimson Heart)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) disabled_joker: Option<usize>,
+    // lifts Verdant Leaf's debuff, reset when a blind is selected
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) joker_sold_this_round: bool,
 
     pub last_consumable_used: Option<Consumable>,
+    // unique planets used this run (Satellite)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) planets_used: HashSet<Planets>,
     #[cfg_attr(feature = "serde", serde(default))]
-    pub last_score: usize,
+    pub last_score: BigNum,
     #[cfg_attr(feature = "serde", serde(default = "default_reroll_cost"))]
     pub reroll_cost: usize,
 
@@ -118,6 +205,10 @@ pub struct Game {
     pub(crate) tarot_prev_stage: Option<Stage>,
     // track stage so we can come back to it after temp spectral stage
     pub(crate) spectral_prev_stage: Option<Stage>,
+    // track stage so we can come back to it after a pack opened outside the
+    // shop (pack tags open theirs right where the tag was gained)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) pack_prev_stage: Option<Stage>,
 
     // open pack state (set when a pack is purchased and being opened)
     #[cfg_attr(feature = "serde", serde(default))]
@@ -138,17 +229,18 @@ impl Game {
                 (u, None)
             }
         };
-        // We always need a fast backend (for now)
-        // TODO: until real can do everything
-        let fast = FastBackend::new(ChaCha8Rng::seed_from_u64(seed));
         let backend = match config.rng_mode {
-            RngMode::Fast => Backend::Fast(fast),
+            RngMode::Fast => Backend::Fast(FastBackend::new(ChaCha8Rng::seed_from_u64(seed))),
             RngMode::Real => {
                 // Real mode needs a Balatro-format seed *string*; fall back
                 // to the numeric seed's decimal representation if only
                 // `config.seed` (no `seed_str`) was given.
                 let seed_string = seed_str.clone().unwrap_or_else(|| seed.to_string());
-                Backend::Real(RealBackend::new(&seed_string, fast))
+                let mut real = RealBackend::new(&seed_string);
+                if let Some(locked) = &config.profile_locks {
+                    real.lock_profile(locked);
+                }
+                Backend::Real(real)
             }
         };
         let mut game = Self {
@@ -163,9 +255,12 @@ impl Game {
             action_history: Vec::new(),
             jokers: Vec::new(),
             effect_registry: EffectRegistry::new(),
+            copying: false,
             consumables: Vec::new(),
+            vouchers: Vec::new(),
             blind: None,
             current_boss: None,
+            boss_pool: BossPool::default(),
             stage: Stage::PreBlind(),
             ante_start,
             ante_end: Ante::try_from(config.ante_end).unwrap_or(Ante::Eight),
@@ -173,37 +268,105 @@ impl Game {
             round: config.round_start,
             plays: config.plays,
             discards_remaining: config.discards,
-            reward: config.reward_base,
-            money: config.money_start,
-            chips: config.base_chips,
-            mult: config.base_mult,
-            score: config.base_score,
+            round_reward: RoundReward::default(),
+            money: config.money_start as i64,
+            chips: config.base_chips.into(),
+            mult: config.base_mult.into(),
+            score: config.base_score.into(),
             prob_mult: 1,
             hand_ranks_played_this_round: HashSet::new(),
+            last_hand_played: None,
+            hands_played_this_round: 0,
             consecutive_hands_without_face_card: 0,
             consecutive_hands_not_most_played_type: 0,
             discarded_this_round: Vec::new(),
             total_cards_discarded: 0,
             boss_disabled_by_luchador: false,
             boss_triggered_this_hand: false,
+            total_hands_played: 0,
+            total_unused_discards: 0,
+            total_blinds_skipped: 0,
+            won: false,
+            negative_joker_slots: 0,
+            round_modifiers: StatModifiers::default(),
+            boss_rerolls_this_ante: 0,
+            played_this_ante: HashSet::new(),
+            skipped_this_ante: Vec::new(),
+            jokers_flipped: false,
+            forced_card: None,
+            disabled_joker: None,
+            joker_sold_this_round: false,
             last_consumable_used: None,
-            last_score: 0,
+            planets_used: HashSet::new(),
+            last_score: BigNum::ZERO,
             reroll_cost: default_reroll_cost(),
             tarot_prev_stage: None,
             spectral_prev_stage: None,
+            pack_prev_stage: None,
             open_pack: None,
             seed,
             seed_str,
             backend,
             config,
         };
-        game.draw_ante_tags();
-        game.draw_ante_boss();
+        if let Some(deck) = game.config.deck {
+            game.apply_deck(deck);
+        }
+        if game.config.stake >= Stake::Blue {
+            game.apply_permanent(StatModifiers::discards(-1));
+        }
+        game.draw_ante();
         game
     }
 
+    // Starting deck modifiers. Green, Anaglyph and Plasma are checked where
+    // they apply (reward, boss defeat, scoring).
+    fn apply_deck(&mut self, deck: DeckVariant) {
+        self.backend.set_deck(deck);
+        self.deck = Deck::for_variant(deck, &mut ChaCha8Rng::seed_from_u64(self.seed));
+        match deck {
+            DeckVariant::Red => self.apply_permanent(StatModifiers::discards(1)),
+            DeckVariant::Blue => self.apply_permanent(StatModifiers::plays(1)),
+            DeckVariant::Yellow => self.earn(10),
+            DeckVariant::Black => {
+                self.config.joker_slots += 1;
+                self.apply_permanent(StatModifiers::plays(-1));
+            }
+            DeckVariant::Magic => {
+                self.redeem_voucher(Voucher::CrystalBall);
+                self.consumables
+                    .extend([HeldConsumable::from(Consumable::Tarot(Tarot::Fool)); 2]);
+            }
+            DeckVariant::Nebula => {
+                self.redeem_voucher(Voucher::Telescope);
+                self.config.consumable_slots = self.config.consumable_slots.saturating_sub(1);
+            }
+            DeckVariant::Ghost => self
+                .consumables
+                .push(Consumable::Spectral(crate::spectral::Spectral::Hex).into()),
+            DeckVariant::Zodiac => {
+                self.redeem_voucher(Voucher::TarotMerchant);
+                self.redeem_voucher(Voucher::PlanetMerchant);
+                self.redeem_voucher(Voucher::Overstock);
+            }
+            DeckVariant::Painted => {
+                self.apply_permanent(StatModifiers::hand_size(2));
+                self.config.joker_slots = self.config.joker_slots.saturating_sub(1);
+            }
+            DeckVariant::Green
+            | DeckVariant::Abandoned
+            | DeckVariant::Checkered
+            | DeckVariant::Anaglyph
+            | DeckVariant::Plasma
+            | DeckVariant::Erratic => {}
+        }
+    }
+
     pub fn start(&mut self) {
         self.stage = Stage::PreBlind();
+        // the real game draws the first round's Castle/Mail-In Rebate
+        // targets at run start, ahead of the per-round ones
+        self.roll_discard_selectors();
     }
 
     pub fn result(&self) -> Option<End> {
@@ -220,36 +383,69 @@ impl Game {
     // Fires when the round that just ended cleared the blind, before played
     // cards are swapped out and replacements drawn in. `self.available` still
     // reflects what was actually held in hand at the moment the round ended.
-    fn handle_round_end(&mut self) {
+    pub(crate) fn handle_round_end(&mut self) {
         for e in self.effect_registry.on_round_end.clone() {
             if let Effects::OnRoundEnd(f) = e {
                 f.lock().unwrap()(self)
             }
         }
 
-        let planetarium = self.planetarium.clone();
+        // Blue Seal: the planet for the round's final hand, no roll
+        let planet = self
+            .last_hand_played
+            .and_then(|rank| Planets::iter().find(|p| p.hand_rank() == rank.scoring_rank()));
         for card in self.available.not_selected() {
             if card.seal == Some(Seal::Blue)
                 && self.consumables.len() < self.config.consumable_slots
             {
-                let planet = self.backend.roll_random_planet(&planetarium, &[]);
-                self.consumables.push(planet);
+                if let Some(planet) = planet {
+                    self.consumables.push(Consumable::Planet(planet).into());
+                }
+            }
+        }
+
+        self.tick_joker_stickers();
+    }
+
+    // End of round: Perishable jokers age, Rental jokers charge
+    fn tick_joker_stickers(&mut self) {
+        let mut perished = false;
+        let mut rentals = 0;
+        for joker in self.jokers.iter_mut() {
+            let stickers = joker.stickers();
+            if stickers.perishable && joker.state().perish_rounds < PERISHABLE_ROUNDS {
+                let state = joker.state_mut();
+                state.perish_rounds += 1;
+                perished |= state.perish_rounds == PERISHABLE_ROUNDS;
+            }
+            if stickers.rental {
+                rentals += 1;
             }
         }
+        self.pay(rentals * RENTAL_COST);
+        if perished {
+            self.register_jokers();
+        }
     }
 
     pub(crate) fn clear_blind(&mut self) {
-        self.score = self.config.base_score;
-        self.plays = self.config.plays;
-        self.discards_remaining = self.config.discards;
+        self.score = self.config.base_score.into();
         self.hand_ranks_played_this_round.clear();
+        self.hands_played_this_round = 0;
         self.discarded_this_round.clear();
+        self.jokers_flipped = false;
+        self.forced_card = None;
+        if self.disabled_joker.take().is_some() {
+            self.register_jokers();
+        }
         self.boss_disabled_by_luchador = false;
+        self.round_modifiers = StatModifiers::default();
+        self.plays = self.max_plays();
+        self.discards_remaining = self.max_discards();
         self.deck.append(&mut self.discarded);
         self.deck.extend(self.available.cards());
         self.available.empty();
-        self.backend.shuffle_deck(&mut self.deck);
-        self.roll_discard_selectors();
+        self.backend.shuffle_deck(&mut self.deck, "shuffle");
     }
 
     /// The boss currently in effect, or `None` if we're not in the middle of
@@ -277,27 +473,119 @@ impl Game {
         }
     }
 
-    /// Cards dealt per round
-    pub fn hand_size(&self) -> usize {
+    /// Everything currently stacked on top of the `Config` base: held
+    /// jokers, this round's tags and the active boss.
+    pub fn modifiers(&self) -> StatModifiers {
+        let mut m = self.effect_registry.modifiers + self.round_modifiers;
         if self.active_boss() == Some(BossBlind::Manacle) {
-            self.config.available.saturating_sub(1)
-        } else {
-            self.config.available
+            m += StatModifiers::hand_size(-1);
         }
+        m
+    }
+
+    /// Cards dealt per round, capped at `config.available_max` so the
+    /// action space can hold every card.
+    pub fn hand_size(&self) -> usize {
+        adjust(self.config.available, self.modifiers().hand_size).min(self.config.available_max)
+    }
+
+    /// Hands at the start of a round
+    pub fn max_plays(&self) -> usize {
+        adjust(self.config.plays, self.modifiers().plays)
+    }
+
+    /// Discards at the start of a round
+    pub fn max_discards(&self) -> usize {
+        adjust(self.config.discards, self.modifiers().discards)
+    }
+
+    /// Most cards that can be selected at once
+    pub fn selected_max(&self) -> usize {
+        adjust(self.config.selected_max, self.modifiers().selected_max)
+    }
+
+    /// Lowest balance spending can take money to: `config.debt_max` below
+    /// zero, deeper with Credit Card.
+    pub fn money_floor(&self) -> i64 {
+        -(adjust(self.config.debt_max, self.modifiers().debt) as i64)
+    }
+
+    /// How much can be spent before hitting `money_floor`
+    pub fn spendable(&self) -> usize {
+        (self.money - self.money_floor()).max(0) as usize
+    }
+
+    // Pays out `amount`, never past `config.money_max`
+    pub(crate) fn earn(&mut self, amount: usize) {
+        let max = (self.config.money_max as i64).max(self.money);
+        self.money = (self.money + amount as i64).min(max);
+    }
+
+    // Takes `amount`. Purchases check `spendable` first, but boss and
+    // rental charges can run money into debt on their own.
+    pub(crate) fn pay(&mut self, amount: usize) {
+        self.money -= amount as i64;
+    }
+
+    // Folds a permanent change (deck, stake, voucher, spectral) into the
+    // run's base stats, and this round's remaining hands and discards.
+    pub(crate) fn apply_permanent(&mut self, m: StatModifiers) {
+        self.config.available =
+            adjust(self.config.available, m.hand_size).min(self.config.available_max);
+        self.config.plays = adjust(self.config.plays, m.plays);
+        self.plays = adjust(self.plays, m.plays);
+        self.config.discards = adjust(self.config.discards, m.discards);
+        self.discards_remaining = adjust(self.discards_remaining, m.discards);
+        self.config.selected_max = adjust(self.config.selected_max, m.selected_max);
     }
 
     // Castle/MailInRebate lock onto a fresh random suit/rank each round
     // ("changes every round") - unconditional, overwrites whatever was
-    // already there.
+    // already there. Every Castle shares the one suit, every Mail-In
+    // Rebate the one rank.
     fn roll_discard_selectors(&mut self) {
+        let ante = self.ante_current.num() as i32;
+        let (suit, value) = self.backend.roll_discard_targets(&self.full_deck(), ante);
         for j in self.jokers.iter_mut() {
-            self.backend.roll_discard_selector(j);
+            match j {
+                Jokers::Castle(_) => j.state_mut().selector = Some(SelectorValue::Suit(suit)),
+                Jokers::MailInRebate(_) => {
+                    j.state_mut().selector = Some(SelectorValue::Value(value))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // draw from deck to available, returning the cards drawn
+    fn draw(&mut self, count: usize) -> Vec<Card> {
+        let mut drawn = self.deck.draw(count);
+        for card in drawn.iter_mut() {
+            card.face_down = match self.active_boss() {
+                Some(BossBlind::Wheel) => self.prob_roll("wheel", 1, 7),
+                Some(BossBlind::Mark) => self.is_face_card(card),
+                _ => false,
+            };
+            card.debuffed = self.is_card_debuffed(card);
+        }
+        self.available.extend(drawn.clone());
+        drawn
+    }
+
+    // turn every drawn card face down (The House, The Fish)
+    fn flip_drawn(&mut self, drawn: &[Card]) {
+        for card in drawn {
+            self.available.mutate_card(card.id, |c| c.face_down = true);
         }
     }
 
-    // draw from deck to available
-    fn draw(&mut self, count: usize) {
-        self.available.extend(self.deck.draw(count));
+    // played/discarded cards go face up and lose their debuff on the way
+    // to the discard pile
+    fn discard_cards(&mut self, cards: impl IntoIterator<Item = Card>) {
+        self.discarded.extend(cards.into_iter().map(|mut c| {
+            c.reset_round_flags();
+            c
+        }));
     }
 
     // shuffle and deal new cards to available
@@ -307,8 +595,14 @@ impl Game {
         // add available back to deck and empty
         self.deck.extend(self.available.cards());
         self.available.empty();
-        self.backend.shuffle_deck(&mut self.deck);
-        self.draw(self.hand_size());
+        let key = format!("nr{}", self.ante_current.num());
+        self.backend.shuffle_deck(&mut self.deck, &key);
+        let drawn = self.draw(self.hand_size());
+        // The House: first hand is drawn face down
+        if self.active_boss() == Some(BossBlind::House) {
+            self.flip_drawn(&drawn);
+        }
+        self.sync_card_debuffs();
     }
 
     /// Reshuffles discarded/held cards back into the deck and redraws a
@@ -318,7 +612,7 @@ impl Game {
     }
 
     pub(crate) fn select_card(&mut self, card: Card) -> Result<(), GameError> {
-        if self.available.selected().len() >= self.config.selected_max {
+        if self.available.selected().len() >= self.selected_max() {
             return Err(GameError::InvalidSelectCard);
         }
         self.available.select_card(card)
@@ -337,26 +631,49 @@ impl Game {
             return Err(GameError::NoRemainingPlays);
         }
         self.plays -= 1;
-        let selected = SelectHand::new(self.available.selected());
-        let best = selected.best_hand()?;
+        self.hands_played_this_round += 1;
+        self.total_hands_played += 1;
+        let played = self.available.selected();
+        let best = self.best_hand(played.clone())?;
         let scored = best.clone();
-        let score = self.calc_score(best);
-        let clear_blind = self.handle_score(score)?;
-        // must run before the discard/redraw below: self.available still reflects
-        // the cards actually held when the round ended, not their replacements.
-        if clear_blind {
-            self.handle_round_end();
+        let allowed = self.is_hand_allowed(&played);
+        let hooked = self.hook_discard();
+        let boss_money = self.boss_money_penalty(&best, played.len());
+        let score = if allowed {
+            self.calc_score(best)
+        } else {
+            BigNum::ZERO
+        };
+        if !allowed || hooked > 0 || boss_money {
+            self.boss_triggered_this_hand = true;
         }
-        self.discarded.extend(self.available.selected());
-        let removed = self.available.remove_selected();
+        for card in &played {
+            self.played_this_ante.insert(card.id);
+        }
+        // clearing the blind runs end of round effects, which must come before
+        // the discard/redraw below: self.available still reflects the cards
+        // actually held when the round ended, not their replacements.
+        let clear_blind = self.handle_score(score)?;
+        self.discard_cards(self.available.selected());
+        self.available.remove_selected();
+        // every played card left the hand, even one a joker destroyed
+        // while scoring (Sixth Sense)
         let n = if self.active_boss() == Some(BossBlind::Serpent) {
             3
         } else {
-            removed
+            played.len() + hooked
         };
-        self.draw(n);
+        let drawn = self.draw(n);
+        // The Fish: cards drawn after a hand are face down
+        if self.active_boss() == Some(BossBlind::Fish) {
+            self.flip_drawn(&drawn);
+        }
+        if !clear_blind {
+            self.force_select_card();
+            self.disable_random_joker();
+        }
         for card in scored.hand.cards() {
-            if card.enhancement == Some(Enhancement::Glass) && self.prob_roll(1, 4) {
+            if card.enhancement == Some(Enhancement::Glass) && self.prob_roll("glass", 1, 4) {
                 self.destroy_card(card.id);
             }
         }
@@ -373,7 +690,7 @@ impl Game {
         }
         self.discards_remaining -= 1;
         let discarded = self.available.selected();
-        self.discarded.extend(discarded.iter().copied());
+        self.discard_cards(discarded.iter().copied());
         self.discarded_this_round.extend(discarded.iter().copied());
         self.total_cards_discarded += discarded.len();
         let removed = self.available.remove_selected();
@@ -383,22 +700,12 @@ impl Game {
             removed
         };
         self.draw(n);
+        self.force_select_card();
 
         // Check for purple seals
         for card in &discarded {
-            if card.seal == Some(Seal::Purple)
-                && self.consumables.len() < self.config.consumable_slots
-            {
-                let excl: Vec<Tarot> = self
-                    .consumables
-                    .iter()
-                    .filter_map(|c| match c {
-                        Consumable::Tarot(t) => Some(*t),
-                        _ => None,
-                    })
-                    .collect();
-                let tarot = self.backend.roll_random_tarot(&excl);
-                self.consumables.push(tarot);
+            if card.seal == Some(Seal::Purple) {
+                self.create_tarot("8ba");
             }
         }
 
@@ -416,9 +723,53 @@ impl Game {
     }
 
     pub(crate) fn destroy_card(&mut self, id: usize) {
+        let Some(card) = self.full_deck().into_iter().find(|c| c.id == id) else {
+            return;
+        };
         self.available.remove_by_id(id);
         self.deck.remove_by_id(id);
         self.discarded.retain(|c| c.id != id);
+        self.run_card_effects(self.effect_registry.on_cards_destroyed.clone(), &[card]);
+    }
+
+    /// Adds a new playing card to the deck (bought, or picked from a pack).
+    pub(crate) fn add_to_deck(&mut self, card: Card) {
+        self.deck.push(card);
+        self.run_card_effects(self.effect_registry.on_cards_added.clone(), &[card]);
+    }
+
+    /// Adds new playing cards straight to the hand (Cryptid, Familiar, ...).
+    pub(crate) fn add_to_hand(&mut self, cards: Vec<Card>) {
+        self.available.extend(cards.clone());
+        self.run_card_effects(self.effect_registry.on_cards_added.clone(), &cards);
+    }
+
+    // Runs cached effects that only take the game: blind select, rerolls,
+    // pack skips and opens, planet use, sales, lucky triggers, the first
+    // hand drawn and leaving the shop.
+    pub(crate) fn run_game_effects(&mut self, effects: Vec<Effects>) {
+        for e in effects {
+            if let Effects::OnBlindSelect(f)
+            | Effects::OnPackSkip(f)
+            | Effects::OnReroll(f)
+            | Effects::OnPlanetUsed(f)
+            | Effects::OnCardSold(f)
+            | Effects::OnLuckyTrigger(f)
+            | Effects::OnFirstHandDrawn(f)
+            | Effects::OnPackOpen(f)
+            | Effects::OnShopEnd(f) = e
+            {
+                f.lock().unwrap()(self);
+            }
+        }
+    }
+
+    fn run_card_effects(&mut self, effects: Vec<Effects>, cards: &[Card]) {
+        for e in effects {
+            if let Effects::OnCardsDestroyed(f) | Effects::OnCardsAdded(f) = e {
+                f.lock().unwrap()(self, cards.to_vec());
+            }
+        }
     }
 
     pub(crate) fn mutate_card<F: Fn(&mut Card) + Copy>(&mut self, id: usize, f: F) {
@@ -441,6 +792,30 @@ impl Game {
                 .contains(&RuleFlag::AllCardsAreFace)
     }
 
+    /// Whether `card` counts as `suit`: Wild cards are every suit, and
+    /// Smeared Joker makes a card count as both suits of its colour.
+    pub(crate) fn is_suit(&self, card: &Card, suit: Suit) -> bool {
+        let rules = self.hand_rules();
+        card.matches_suit(suit) || rules.suit_group(card.suit) == rules.suit_group(suit)
+    }
+
+    /// Hand-shape rules from held jokers (Four Fingers, Shortcut, Smeared
+    /// Joker, Splash).
+    pub fn hand_rules(&self) -> HandRules {
+        let flags = &self.effect_registry.rule_flags;
+        HandRules {
+            four_fingers: flags.contains(&RuleFlag::FourCardHands),
+            shortcut: flags.contains(&RuleFlag::GappedStraights),
+            smeared: flags.contains(&RuleFlag::SmearedSuits),
+            splash: flags.contains(&RuleFlag::AllPlayedCardsScore),
+        }
+    }
+
+    /// Best hand `cards` make under the current joker rules.
+    pub fn best_hand(&self, cards: Vec<Card>) -> Result<MadeHand, PlayHandError> {
+        SelectHand::new(cards).best_hand_with(self.hand_rules())
+    }
+
     pub(crate) fn is_even(&self, card: &Card) -> bool {
         card.is_even_impl(self.is_face_card(card))
     }
@@ -449,18 +824,179 @@ impl Game {
         card.is_odd_impl(self.is_face_card(card))
     }
 
-    /// Whether the active boss (if any) currently debuffs card
+    /// Whether the active boss (if any) currently debuffs card. The
+    /// card's own `debuffed` flag mirrors this for held and deck cards, but
+    /// is only re-synced after each action.
     pub fn is_card_debuffed(&self, card: &Card) -> bool {
         match self.active_boss() {
-            Some(BossBlind::Club) => card.matches_suit(Suit::Club),
-            Some(BossBlind::Goad) => card.matches_suit(Suit::Spade),
-            Some(BossBlind::Head) => card.matches_suit(Suit::Heart),
-            Some(BossBlind::Window) => card.matches_suit(Suit::Diamond),
+            Some(BossBlind::Club) => self.is_suit(card, Suit::Club),
+            Some(BossBlind::Goad) => self.is_suit(card, Suit::Spade),
+            Some(BossBlind::Head) => self.is_suit(card, Suit::Heart),
+            Some(BossBlind::Window) => self.is_suit(card, Suit::Diamond),
             Some(BossBlind::Plant) => self.is_face_card(card),
+            Some(BossBlind::Pillar) => self.played_this_ante.contains(&card.id),
+            Some(BossBlind::VerdantLeaf) => !self.joker_sold_this_round,
+            _ => false,
+        }
+    }
+
+    /// Whether the active boss lets `cards` be played as a hand. The Eye
+    /// bans repeating a hand type this round, The Mouth locks the round to
+    /// its first hand type and The Psychic needs five cards. A hand that
+    /// isn't allowed can still be played, it just scores nothing.
+    pub fn is_hand_allowed(&self, cards: &[Card]) -> bool {
+        let rank = || self.best_hand(cards.to_vec()).map(|h| h.rank);
+        match self.active_boss() {
+            Some(BossBlind::Psychic) => cards.len() >= 5,
+            Some(BossBlind::Eye) => match rank() {
+                Ok(r) => !self.hand_ranks_played_this_round.contains(&r),
+                Err(_) => false,
+            },
+            Some(BossBlind::Mouth) => match rank() {
+                Ok(r) => self.hand_ranks_played_this_round.iter().all(|p| *p == r),
+                Err(_) => false,
+            },
+            _ => true,
+        }
+    }
+
+    // Whether some selection of held cards passes `is_hand_allowed`. The
+    // move generator only holds back a disallowed play while there's an
+    // allowed one to make instead.
+    pub(crate) fn any_hand_allowed(&self) -> bool {
+        let cards = self.available.cards();
+        match self.active_boss() {
+            Some(BossBlind::Psychic) => cards.len() >= 5,
+            Some(BossBlind::Eye | BossBlind::Mouth) => (1..=self.selected_max()).any(|k| {
+                cards
+                    .iter()
+                    .copied()
+                    .combinations(k)
+                    .any(|c| self.is_hand_allowed(&c))
+            }),
+            _ => true,
+        }
+    }
+
+    // The Hook: discards 2 random held cards before the hand scores.
+    // Returns how many were discarded.
+    fn hook_discard(&mut self) -> usize {
+        if self.active_boss() != Some(BossBlind::Hook) {
+            return 0;
+        }
+        let mut n = 0;
+        for _ in 0..2 {
+            let held = self.available.not_selected();
+            if held.is_empty() {
+                break;
+            }
+            let card = self.backend.pick_random_card(held, "hook");
+            self.available.remove_by_id(card.id);
+            self.discard_cards([card]);
+            n += 1;
+        }
+        n
+    }
+
+    // The Ox zeroes money on the most played hand type, The Tooth takes $1
+    // per card played. Returns whether either fired.
+    fn boss_money_penalty(&mut self, hand: &MadeHand, played: usize) -> bool {
+        match self.active_boss() {
+            Some(BossBlind::Ox) if hand.rank == self.most_played_hand_rank() => {
+                self.money = 0;
+                true
+            }
+            Some(BossBlind::Tooth) => {
+                self.pay(played);
+                true
+            }
             _ => false,
         }
     }
 
+    /// Whether jokers are face down (Amber Acorn). They still score.
+    pub fn jokers_face_down(&self) -> bool {
+        self.jokers_flipped && self.active_boss() == Some(BossBlind::AmberAcorn)
+    }
+
+    /// The card Cerulean Bell keeps selected, if any.
+    pub fn forced_card(&self) -> Option<usize> {
+        if self.active_boss() == Some(BossBlind::CeruleanBell) {
+            self.forced_card
+        } else {
+            None
+        }
+    }
+
+    /// Whether `joker` is the one Crimson Heart disabled for this hand.
+    pub fn is_joker_disabled(&self, joker: &Jokers) -> bool {
+        self.active_boss() == Some(BossBlind::CrimsonHeart)
+            && self.disabled_joker == Some(joker.instance_id())
+    }
+
+    /// Whether `joker` currently has no effect, either disabled by Crimson
+    /// Heart or a Perishable joker that has run out of rounds.
+    pub fn is_joker_debuffed(&self, joker: &Jokers) -> bool {
+        self.is_joker_disabled(joker)
+            || (joker.stickers().perishable && joker.state().perish_rounds >= PERISHABLE_ROUNDS)
+    }
+
+    // Rebuilds cached joker effects, leaving out debuffed jokers. Hands and
+    // discards gained or lost with the joker line-up apply straight away.
+    pub(crate) fn register_jokers(&mut self) {
+        let before = self.effect_registry.modifiers;
+        self.rebuild_effect_registry();
+        let delta = self.effect_registry.modifiers - before;
+        self.plays = adjust(self.plays, delta.plays);
+        self.discards_remaining = adjust(self.discards_remaining, delta.discards);
+    }
+
+    fn rebuild_effect_registry(&mut self) {
+        let jokers: Vec<Jokers> = self
+            .jokers
+            .iter()
+            .filter(|j| !self.is_joker_debuffed(j))
+            .cloned()
+            .collect();
+        let game = self.clone();
+        self.effect_registry.register_jokers(jokers, &game);
+    }
+
+    // Cerulean Bell: once the forced card leaves the hand, another held
+    // card is picked and selected in its place
+    fn force_select_card(&mut self) {
+        if self.active_boss() != Some(BossBlind::CeruleanBell) {
+            return;
+        }
+        let selected = self.available.selected();
+        if self
+            .forced_card
+            .is_some_and(|id| selected.iter().any(|c| c.id == id))
+        {
+            return;
+        }
+        let held = self.available.not_selected();
+        if held.is_empty() {
+            return;
+        }
+        let card = self.backend.pick_random_card(held, "cerulean_bell");
+        self.forced_card = Some(card.id);
+        // selection is empty right after a draw, so this can't hit the cap
+        let _ = self.available.select_card(card);
+    }
+
+    // Crimson Heart: a fresh random joker is disabled for every hand
+    fn disable_random_joker(&mut self) {
+        if self.active_boss() != Some(BossBlind::CrimsonHeart) || self.jokers.is_empty() {
+            return;
+        }
+        let joker = self
+            .backend
+            .pick_random_joker(self.jokers.clone(), "crimson_heart");
+        self.disabled_joker = Some(joker.instance_id());
+        self.register_jokers();
+    }
+
     // Filters debuffed cards out of a collection
     pub(crate) fn non_debuffed<'a>(&self, cards: impl IntoIterator<Item = &'a Card>) -> Vec<Card> {
         cards
@@ -488,18 +1024,20 @@ impl Game {
         all
     }
 
-    pub fn prob_roll(&mut self, numerator: u32, denominator: u32) -> bool {
+    /// A `numerator` in `denominator` chance, scaled by Oops! All 6s.
+    /// `key` names the roll's stream in `RngMode::Real`.
+    pub fn prob_roll(&mut self, key: &str, numerator: u32, denominator: u32) -> bool {
         self.backend
-            .prob_roll(numerator.saturating_mul(self.prob_mult), denominator)
+            .prob_roll(key, numerator.saturating_mul(self.prob_mult), denominator)
     }
 
-    pub fn calc_score(&mut self, hand: MadeHand) -> usize {
+    pub fn calc_score(&mut self, hand: MadeHand) -> BigNum {
         self.calc_score_inner(hand, None)
     }
 
     /// Same scoring pass as `calc_score`, plus an ordered `ScoreTrace` of
     /// every chips/mult contribution along the way.
-    pub fn calc_score_traced(&mut self, hand: MadeHand) -> (usize, ScoreTrace) {
+    pub fn calc_score_traced(&mut self, hand: MadeHand) -> (BigNum, ScoreTrace) {
         let mut trace = ScoreTrace::default();
         let score = self.calc_score_inner(hand, Some(&mut trace));
         (score, trace)
@@ -509,8 +1047,8 @@ impl Game {
         &self,
         trace: &mut Option<&mut ScoreTrace>,
         source: ScoreSource,
-        chips_before: usize,
-        mult_before: usize,
+        chips_before: BigNum,
+        mult_before: BigNum,
         retrigger: bool,
     ) {
         let Some(t) = trace else {
@@ -557,7 +1095,7 @@ impl Game {
         &mut self,
         mut hand: MadeHand,
         mut trace: Option<&mut ScoreTrace>,
-    ) -> usize {
+    ) -> BigNum {
         self.boss_triggered_this_hand = false;
 
         // compute chips and mult from hand level
@@ -585,6 +1123,14 @@ impl Game {
             false,
         );
 
+        // run hand modifiers (Midas Mask, Vampire) before any card scores, so
+        // a card they change scores as changed this hand
+        for e in self.effect_registry.on_modify_hand.clone() {
+            if let Effects::OnModifyHand(f) = e {
+                f.lock().unwrap()(self, &mut hand)
+            }
+        }
+
         // first card in original play order that's actually used in scoring:
         // either part of the made hand, or a stone kicker (which always scores).
         // ordinary kickers that never score don't count, even if played first.
@@ -606,20 +1152,27 @@ impl Game {
                 let chips_before = self.chips;
                 let mult_before = self.mult;
 
-                // stone has no rank, skip normal chip value
+                // stone has no rank, skip normal chip value (its 50 and
+                // any bonus chips are scored below)
                 if card.enhancement != Some(Enhancement::Stone) {
-                    self.chips += card.chips();
+                    self.chips += card.chips() + card.bonus_chips;
                 }
                 match card.enhancement {
                     Some(Enhancement::Bonus) => self.chips += 30,
                     Some(Enhancement::Mult) => self.mult += 4,
                     Some(Enhancement::Glass) => self.mult *= 2,
                     Some(Enhancement::Lucky) => {
-                        if self.prob_roll(1, 5) {
+                        let mut lucky = false;
+                        if self.prob_roll("lucky_mult", 1, 5) {
                             self.mult += 20;
+                            lucky = true;
                         }
-                        if self.prob_roll(1, 15) {
-                            self.money += 20;
+                        if self.prob_roll("lucky_money", 1, 15) {
+                            self.earn(20);
+                            lucky = true;
+                        }
+                        if lucky {
+                            self.run_game_effects(self.effect_registry.on_lucky_trigger.clone());
                         }
                     }
                     _ => {}
@@ -631,7 +1184,7 @@ impl Game {
                     _ => {}
                 }
                 if card.seal == Some(Seal::Gold) {
-                    self.money += 3;
+                    self.earn(3);
                 }
 
                 self.record_step(
@@ -641,6 +1194,11 @@ impl Game {
                     mult_before,
                     trig > 0,
                 );
+                for e in self.effect_registry.on_card_scored.clone() {
+                    if let Effects::OnCardScored(f) = e {
+                        f.lock().unwrap()(self, card)
+                    }
+                }
             }
         }
 
@@ -657,7 +1215,7 @@ impl Game {
                 for trig in 0..self.trigger_count_played(card, is_first) {
                     let chips_before = self.chips;
                     let mult_before = self.mult;
-                    self.chips += 50;
+                    self.chips += 50 + card.bonus_chips;
                     self.record_step(
                         &mut trace,
                         ScoreSource::StoneKicker(*card),
@@ -684,10 +1242,8 @@ impl Game {
             for trig in 0..self.trigger_count_held(&card) {
                 let chips_before = self.chips;
                 let mult_before = self.mult;
-                match card.enhancement {
-                    Some(Enhancement::Steel) => self.mult += self.mult / 2,
-                    Some(Enhancement::Gold) => self.money += 3,
-                    _ => {}
+                if card.enhancement == Some(Enhancement::Steel) {
+                    self.mult += self.mult / 2;
                 }
                 self.record_step(
                     &mut trace,
@@ -699,17 +1255,13 @@ impl Game {
             }
         }
 
-        // run hand modifiers (e.g. Pareidolia) before joker scoring effects
-        for e in self.effect_registry.on_modify_hand.clone() {
-            if let Effects::OnModifyHand(f) = e {
-                f.lock().unwrap()(self, &mut hand)
-            }
-        }
-
         // apply joker effects with per-joker edition ordering.
         // effects recomputed per joker, not read from EffectRegistry. Each joker's edition
         // bonus must sandwich its own OnScore closures in self.jokers order.
         for joker in self.jokers.clone() {
+            if self.is_joker_debuffed(&joker) {
+                continue;
+            }
             let chips_before = self.chips;
             let mult_before = self.mult;
             match joker.edition() {
@@ -754,33 +1306,83 @@ impl Game {
             }
         }
 
+        // Observatory: held planets for the played hand act like x1.5 mult jokers
+        if self.has_voucher(Voucher::Observatory) {
+            for consumable in self.held_consumables() {
+                let Consumable::Planet(planet) = consumable else {
+                    continue;
+                };
+                if planet.hand_rank() != hand.rank {
+                    continue;
+                }
+                let chips_before = self.chips;
+                let mult_before = self.mult;
+                self.mult += self.mult / 2;
+                self.record_step(
+                    &mut trace,
+                    ScoreSource::Consumable(consumable),
+                    chips_before,
+                    mult_before,
+                    false,
+                );
+            }
+        }
+
         // record after joker loop, not before (for CardSharp)
         self.hand_ranks_played_this_round.insert(hand.rank);
+        self.last_hand_played = Some(hand.rank);
+
+        // Plasma Deck: chips and mult meet in the middle
+        if self.config.deck == Some(DeckVariant::Plasma) {
+            let chips_before = self.chips;
+            let mult_before = self.mult;
+            let balanced = (self.chips + self.mult) / 2;
+            self.chips = balanced;
+            self.mult = balanced;
+            self.record_step(
+                &mut trace,
+                ScoreSource::Deck(DeckVariant::Plasma),
+                chips_before,
+                mult_before,
+                false,
+            );
+        }
 
-        // compute score
-        let score = self.chips * self.mult;
+        // compute score, floored like the game does
+        let score = (self.chips * self.mult).floor();
 
         // reset chips and mult
-        self.mult = self.config.base_mult;
-        self.chips = self.config.base_chips;
+        self.mult = self.config.base_mult.into();
+        self.chips = self.config.base_chips.into();
 
         score
     }
 
-    pub fn required_score(&self) -> usize {
-        let base = self.ante_current.base();
+    pub fn required_score(&self) -> BigNum {
+        let base = self.blind_base();
         match self.blind {
             None => base,
             Some(Blind::Small) => base,
-            Some(Blind::Big) => (base as f32 * 1.5) as usize,
+            Some(Blind::Big) => base * 1.5,
             Some(Blind::Boss) => base * Self::boss_score_multiplier(self.active_boss()),
         }
     }
 
     /// The score the upcoming Boss Blind will require, based on
     /// `current_boss`, valid before boss blind is active.
-    pub fn boss_required_score(&self) -> usize {
-        self.ante_current.base() * Self::boss_score_multiplier(self.current_boss)
+    pub fn boss_required_score(&self) -> BigNum {
+        self.blind_base() * Self::boss_score_multiplier(self.current_boss)
+    }
+
+    /// The current ante's Small Blind score, after stake scaling and the
+    /// Plasma Deck's doubling.
+    pub fn blind_base(&self) -> BigNum {
+        let base = self.ante_current.stake_base(self.config.stake);
+        if self.config.deck == Some(DeckVariant::Plasma) {
+            base * 2
+        } else {
+            base
+        }
     }
 
     fn boss_score_multiplier(boss: Option<BossBlind>) -> usize {
@@ -792,80 +1394,309 @@ impl Game {
         }
     }
 
-    fn calc_reward(&mut self, blind: Blind) -> Result<usize, GameError> {
-        let mut interest = (self.money as f32 * self.config.interest_rate).floor() as usize;
-        if interest > self.config.interest_max {
-            interest = self.config.interest_max
+    fn calc_reward(&mut self, blind: Blind) -> Result<RoundReward, GameError> {
+        let mut reward = RoundReward::default();
+        // Red Stake and above: Small Blind pays nothing
+        let base = if blind == Blind::Small && self.config.stake >= Stake::Red {
+            0
+        } else {
+            blind.reward()
+        };
+        reward.push(RewardSource::Blind(blind), base);
+
+        // Green Deck: $2 per hand and $1 per discard left, no interest
+        let green = self.config.deck == Some(DeckVariant::Green);
+        if green {
+            reward.push(RewardSource::Hands, self.plays * 2);
+            reward.push(RewardSource::Discards, self.discards());
+        } else {
+            reward.push(RewardSource::Hands, self.plays * self.config.money_per_hand);
+        }
+
+        let mut gold = 0;
+        for card in self.available.cards() {
+            if card.enhancement == Some(Enhancement::Gold) && !self.is_card_debuffed(&card) {
+                gold += 3 * self.trigger_count_held(&card);
+            }
+        }
+        reward.push(RewardSource::GoldCards, gold);
+
+        for joker in self.jokers.clone() {
+            if self.is_joker_debuffed(&joker) {
+                continue;
+            }
+            for e in joker.effects(self) {
+                if let Effects::OnCashOut(f) = e {
+                    let amount = f.lock().unwrap()(self);
+                    reward.push(RewardSource::Joker(joker.clone()), amount);
+                }
+            }
+        }
+
+        if !green {
+            // no interest on debt
+            let steps = ((self.money.max(0) as f32 * self.config.interest_rate).floor() as usize)
+                .min(self.config.interest_max);
+            // To the Moon: an extra $1 per step, each
+            let per_step = 1 + self
+                .jokers
+                .iter()
+                .filter(|j| matches!(j, Jokers::ToTheMoon(_)) && !self.is_joker_debuffed(j))
+                .count();
+            reward.push(RewardSource::Interest, steps * per_step);
         }
-        let base = blind.reward();
-        let hand_bonus = self.plays * self.config.money_per_hand;
-        let reward = base + interest + hand_bonus;
         Ok(reward)
     }
 
-    fn apply_tag(&mut self, tag: Tag) {
-        match tag {
-            Tag::Uncommon => {}
-            Tag::Rare => {}
-            Tag::Negative => {}
-            Tag::Foil => {}
-            Tag::Holographic => {}
-            Tag::Polychrome => {}
-            Tag::Investment => {}
-            Tag::Voucher => {}
-            Tag::Boss => {}
-            Tag::Standard => {}
-            Tag::Charm => {}
-            Tag::Meteor => {}
-            Tag::Buffoon => {}
-            Tag::Handy => {}
-            Tag::Garbage => {}
-            Tag::Ethereal => {}
-            Tag::Coupon => {}
-            Tag::Double => {}
-            Tag::Juggle => {}
-            Tag::D6 => {}
-            Tag::TopUp => {}
-            Tag::Speed => {}
-            Tag::Orbital => {}
-            Tag::Economy => {}
+    /// Gains a tag. Any held Double tags copy it first, then whatever
+    /// fires immediately does.
+    fn add_tag(&mut self, tag: Tag) {
+        if tag != Tag::Double {
+            let doubles = self.tags.iter().filter(|t| **t == Tag::Double).count();
+            self.tags.retain(|t| *t != Tag::Double);
+            self.tags.extend(std::iter::repeat_n(tag, doubles));
         }
+        self.tags.push(tag);
+        self.trigger_tags(TagTrigger::Immediate);
     }
 
-    fn cashout(&mut self) -> Result<(), GameError> {
+    /// Fires every held tag whose moment is `trigger`, in the order they
+    /// were gained. A tag that can't take effect yet stays held.
+    fn trigger_tags(&mut self, trigger: TagTrigger) {
+        let mut held = Vec::new();
         for tag in std::mem::take(&mut self.tags) {
-            self.apply_tag(tag);
+            if tag_trigger(tag) != trigger || !self.apply_tag(tag) {
+                held.push(tag);
+            }
+        }
+        self.tags = held;
+    }
+
+    // Returns false if the tag couldn't take effect yet and should stay held.
+    fn apply_tag(&mut self, tag: Tag) -> bool {
+        match tag {
+            Tag::Uncommon => self.add_free_shop_joker(Rarity::Uncommon),
+            Tag::Rare => self.add_free_shop_joker(Rarity::Rare),
+            Tag::Negative => self.make_free_shop_edition(Edition::Negative),
+            Tag::Foil => self.make_free_shop_edition(Edition::Foil),
+            Tag::Holographic => self.make_free_shop_edition(Edition::Holographic),
+            Tag::Polychrome => self.make_free_shop_edition(Edition::Polychrome),
+            Tag::Investment => {
+                self.round_reward.push(RewardSource::Tag(tag), 25);
+                true
+            }
+            Tag::Voucher => self.add_shop_voucher(),
+            Tag::Boss => {
+                self.draw_ante_boss();
+                true
+            }
+            Tag::Standard => self.open_free_pack(PackCategory::Standard, PackSize::Mega),
+            Tag::Charm => self.open_free_pack(PackCategory::Arcana, PackSize::Mega),
+            Tag::Meteor => self.open_free_pack(PackCategory::Celestial, PackSize::Mega),
+            Tag::Buffoon => self.open_free_pack(PackCategory::Buffoon, PackSize::Mega),
+            Tag::Ethereal => self.open_free_pack(PackCategory::Spectral, PackSize::Normal),
+            Tag::Handy => {
+                self.earn(self.total_hands_played);
+                true
+            }
+            Tag::Garbage => {
+                self.earn(self.total_unused_discards);
+                true
+            }
+            Tag::Coupon => {
+                self.shop.free_jokers = self.shop.jokers.clone();
+                self.shop.free_consumables = self.shop.consumables.clone();
+                self.shop.free_packs = self.shop.packs.clone();
+                true
+            }
+            // handled in add_tag, as the next tag is gained
+            Tag::Double => false,
+            Tag::Juggle => {
+                self.round_modifiers += StatModifiers::hand_size(3);
+                true
+            }
+            Tag::D6 => {
+                self.reroll_cost = 0;
+                true
+            }
+            Tag::TopUp => {
+                for _ in 0..2 {
+                    if self.jokers.len() >= self.config.joker_slots {
+                        break;
+                    }
+                    let mut joker = self.backend.create_joker(
+                        "top",
+                        self.ante_current.num() as i32,
+                        self.prob_mult,
+                        &self.jokers,
+                        Some(Rarity::Common),
+                    );
+                    joker.set_edition(Edition::Base);
+                    self.add_joker(joker);
+                }
+                self.register_jokers();
+                true
+            }
+            Tag::Speed => {
+                self.earn(5 * self.total_blinds_skipped);
+                true
+            }
+            Tag::Orbital(rank) => {
+                for _ in 0..3 {
+                    self.planetarium.level_up(rank);
+                }
+                true
+            }
+            Tag::Economy => {
+                self.earn(self.money.clamp(0, 40) as usize);
+                true
+            }
+        }
+    }
+
+    // Uncommon/Rare tags: a free joker of that rarity takes the shop's first
+    // card slot.
+    fn add_free_shop_joker(&mut self, rarity: Rarity) -> bool {
+        let mut exclude = self.jokers.clone();
+        exclude.extend(self.shop.jokers.clone());
+        let source = if rarity == Rarity::Rare { "rta" } else { "uta" };
+        let joker = self.backend.create_joker(
+            source,
+            self.ante_current.num() as i32,
+            self.prob_mult,
+            &exclude,
+            Some(rarity),
+        );
+        self.shop.free_jokers.push(joker.clone());
+        if !self.shop.jokers.is_empty() {
+            self.shop.jokers[0] = joker;
+        } else {
+            if !self.shop.consumables.is_empty() {
+                self.shop.consumables.remove(0);
+            }
+            self.shop.jokers.push(joker);
+        }
+        true
+    }
+
+    // Edition tags: the first base-edition shop joker takes the edition
+    // and becomes free. Held until a shop has one.
+    fn make_free_shop_edition(&mut self, edition: Edition) -> bool {
+        let Some(i) = self
+            .shop
+            .jokers
+            .iter()
+            .position(|j| j.edition() == Edition::Base)
+        else {
+            return false;
+        };
+        let old = self.shop.jokers[i].clone();
+        self.shop.free_jokers.retain(|j| j != &old);
+        self.shop.jokers[i].set_edition(edition);
+        self.shop.free_jokers.push(self.shop.jokers[i].clone());
+        true
+    }
+
+    // Pack tags: open a free pack on the spot. Held while another pack is
+    // still open, `finish_pack` fires it afterwards.
+    fn open_free_pack(&mut self, category: PackCategory, size: PackSize) -> bool {
+        if self.open_pack.is_some() {
+            return false;
+        }
+        let planetarium = self.planetarium.clone();
+        let held_jokers = self.jokers.clone();
+        let pack = self.backend.gen_pack_of(
+            self.ante_current.num() as i32,
+            &planetarium,
+            self.prob_mult,
+            category,
+            size,
+            &held_jokers,
+        );
+        self.open(pack);
+        true
+    }
+
+    // Voucher tag: one more voucher on offer alongside the ante's. Held
+    // while the shop's voucher slots are full.
+    fn add_shop_voucher(&mut self) -> bool {
+        if self.shop.vouchers.len() >= SHOP_VOUCHER_SLOTS {
+            return false;
+        }
+        let exclude = self.shop.vouchers.clone();
+        if let Some(voucher) =
+            self.backend
+                .draw_voucher(self.ante_current.num() as i32, &self.vouchers, &exclude)
+        {
+            self.shop.vouchers.push(voucher);
+        }
+        true
+    }
+
+    pub fn has_voucher(&self, voucher: Voucher) -> bool {
+        self.vouchers.contains(&voucher)
+    }
+
+    // Reroll Surplus/Glut each knock $2 off every reroll
+    pub(crate) fn base_reroll_cost(&self) -> usize {
+        let discount = [Voucher::RerollSurplus, Voucher::RerollGlut]
+            .iter()
+            .filter(|v| self.has_voucher(**v))
+            .count()
+            * 2;
+        default_reroll_cost().saturating_sub(discount)
+    }
+
+    fn cashout(&mut self) -> Result<(), GameError> {
+        self.earn(self.round_reward.total());
+        self.round_reward = RoundReward::default();
+        self.open_shop();
+        Ok(())
+    }
+
+    // Rolls a fresh shop and moves into it.
+    pub(crate) fn open_shop(&mut self) {
+        self.reroll_cost = self.base_reroll_cost();
+        self.stage = Stage::Shop();
+        let planetarium = self.planetarium.clone();
+        let held_consumables = self.held_consumables();
+        let held_jokers = self.jokers.clone();
+        self.shop.refresh(
+            &planetarium,
+            &held_consumables,
+            false,
+            self.prob_mult,
+            &held_jokers,
+            self.ante_current.num() as i32,
+            &mut self.backend,
+        );
+        self.roll_shop_stickers();
+        self.trigger_tags(TagTrigger::Shop);
+    }
+
+    // Black Stake and above: shop jokers may come with stickers. Rolled
+    // below Black Stake too, so `Real` mode's sticker stream stays in step.
+    fn roll_shop_stickers(&mut self) {
+        let ante = self.ante_current.num() as i32;
+        for joker in self.shop.jokers.iter_mut() {
+            let mut stickers = self.backend.roll_stickers(self.config.stake, ante);
+            stickers.eternal &= joker.eternal_compatible();
+            stickers.perishable &= joker.perishable_compatible();
+            joker.set_stickers(stickers);
         }
-        self.money += self.reward;
-        self.reward = 0;
-        self.reroll_cost = default_reroll_cost();
-        self.stage = Stage::Shop();
-        let planetarium = self.planetarium.clone();
-        let held_consumables = self.consumables.clone();
-        let held_jokers = self.jokers.clone();
-        self.shop.refresh(
-            &planetarium,
-            &held_consumables,
-            false,
-            self.prob_mult,
-            &held_jokers,
-            self.ante_current as i32,
-            &mut self.backend,
-        );
-        Ok(())
     }
 
     pub(crate) fn reroll(&mut self) -> Result<(), GameError> {
         if self.stage != Stage::Shop() {
             return Err(GameError::InvalidStage);
         }
-        if self.money < self.reroll_cost {
+        if self.reroll_cost > self.spendable() {
             return Err(GameError::InvalidBalance);
         }
-        self.money -= self.reroll_cost;
+        self.pay(self.reroll_cost);
         self.reroll_cost += 1;
         let planetarium = self.planetarium.clone();
-        let mut held = self.consumables.clone();
+        let mut held = self.held_consumables();
         held.extend(self.shop.consumables.clone());
         let mut held_jokers = self.jokers.clone();
         held_jokers.extend(self.shop.jokers.clone());
@@ -874,9 +1705,11 @@ impl Game {
             &held,
             self.prob_mult,
             &held_jokers,
-            self.ante_current as i32,
+            self.ante_current.num() as i32,
             &mut self.backend,
         );
+        self.roll_shop_stickers();
+        self.run_game_effects(self.effect_registry.on_reroll.clone());
         Ok(())
     }
 
@@ -887,15 +1720,30 @@ impl Game {
         if idx >= self.jokers.len() {
             return Err(GameError::InvalidAction);
         }
-        self.money += self.jokers[idx].sell_value();
-        let sold = self.jokers.remove(idx);
+        if self.jokers[idx].stickers().eternal {
+            return Err(GameError::EternalJoker);
+        }
+        self.earn(self.jokers[idx].sell_value());
+        let sold = self.remove_joker(idx);
+        self.joker_sold_this_round = true;
         for e in sold.effects(self) {
             if let Effects::OnSell(f) = e {
                 f.lock().unwrap()(self);
             }
         }
-        if sold.edition() == Edition::Negative {
-            self.config.joker_slots = self.config.joker_slots.saturating_sub(1);
+        self.register_jokers();
+        self.run_game_effects(self.effect_registry.on_card_sold.clone());
+        Ok(())
+    }
+
+    // Takes the joker at `idx` out of the joker slots, whether it was sold
+    // or destroyed: a Negative joker gives back its extra slot, and the
+    // backend is told the joker can show up in the shop again. Callers
+    // rebuild the registry once they're done removing.
+    pub(crate) fn remove_joker(&mut self, idx: usize) -> Jokers {
+        let removed = self.jokers.remove(idx);
+        if removed.edition() == Edition::Negative {
+            self.lose_negative_slot();
         }
         // Only unlock if this was the last owned copy (Showman is the only
         // way to own more than one) — a discriminant compare, matching
@@ -904,15 +1752,222 @@ impl Game {
         let still_owned = self
             .jokers
             .iter()
-            .any(|j| std::mem::discriminant(j) == std::mem::discriminant(&sold));
+            .any(|j| std::mem::discriminant(j) == std::mem::discriminant(&removed));
         if !still_owned {
-            self.backend.on_joker_sold(&sold);
+            self.backend.on_joker_sold(&removed);
+        }
+        removed
+    }
+
+    // Removes every joker matching `destroy`, through `remove_joker`.
+    pub(crate) fn remove_jokers_where(&mut self, destroy: impl Fn(&Jokers) -> bool) {
+        for idx in (0..self.jokers.len()).rev() {
+            if destroy(&self.jokers[idx]) {
+                self.remove_joker(idx);
+            }
+        }
+    }
+
+    // Swaps the joker at `idx` with its neighbour. Order decides scoring
+    // (editions and effects apply left to right) and what Blueprint and
+    // Brainstorm copy, so the registry is rebuilt.
+    pub(crate) fn move_joker(
+        &mut self,
+        direction: MoveDirection,
+        idx: usize,
+    ) -> Result<(), GameError> {
+        if matches!(self.stage, Stage::End(_)) {
+            return Err(GameError::InvalidStage);
+        }
+        let to = move_target(direction, idx, self.jokers.len())?;
+        self.jokers.swap(idx, to);
+        self.register_jokers();
+        Ok(())
+    }
+
+    pub(crate) fn move_consumable(
+        &mut self,
+        direction: MoveDirection,
+        idx: usize,
+    ) -> Result<(), GameError> {
+        if matches!(self.stage, Stage::End(_)) {
+            return Err(GameError::InvalidStage);
         }
-        self.effect_registry
-            .register_jokers(self.jokers.clone(), &self.clone());
+        let held = self.consumables.len();
+        let to = move_target(direction, idx, held)?;
+        self.consumables.swap(idx, to);
         Ok(())
     }
 
+    /// The held consumables without their per-slot state.
+    pub fn held_consumables(&self) -> Vec<Consumable> {
+        self.consumables.iter().map(|c| c.consumable).collect()
+    }
+
+    /// Sell value of the held consumable at `idx`, including any Gift Card bonus.
+    pub fn consumable_sell_value(&self, idx: usize) -> usize {
+        self.consumables[idx].sell_value()
+    }
+
+    // a Negative copy takes its slot with it
+    fn remove_consumable(&mut self, idx: usize) -> Consumable {
+        let removed = self.consumables.remove(idx);
+        if removed.negative {
+            self.config.consumable_slots = self.config.consumable_slots.saturating_sub(1);
+        }
+        removed.consumable
+    }
+
+    /// Whether the held consumable at `idx` is a Negative copy (Perkeo).
+    pub fn is_consumable_negative(&self, idx: usize) -> bool {
+        self.consumables.get(idx).is_some_and(|c| c.negative)
+    }
+
+    /// Adds a Negative copy of `consumable`, which brings its own slot.
+    pub(crate) fn add_negative_consumable(&mut self, consumable: Consumable) {
+        self.consumables.push(HeldConsumable {
+            negative: true,
+            ..consumable.into()
+        });
+        self.config.consumable_slots += 1;
+    }
+
+    /// Creates a joker from another joker's effect, if there's a free
+    /// slot. `source` keys the `Real` mode draw (see
+    /// `RngBackend::create_joker`).
+    pub(crate) fn create_joker(&mut self, source: &str, rarity: Option<Rarity>) {
+        if self.jokers.len() >= self.config.joker_slots {
+            return;
+        }
+        let ante = self.ante_current.num() as i32;
+        let exclude = self.jokers.clone();
+        let joker = self
+            .backend
+            .create_joker(source, ante, self.prob_mult, &exclude, rarity);
+        self.add_joker(joker);
+        self.register_jokers();
+    }
+
+    // Joker slots once `joker` is in: a Negative joker brings its own.
+    fn joker_slots_with(&self, joker: &Jokers) -> usize {
+        if joker.edition() == Edition::Negative {
+            (self.config.joker_slots + 1).min(self.config.joker_slots_max)
+        } else {
+            self.config.joker_slots
+        }
+    }
+
+    // A Negative joker came in: add its slot, capped like `joker_slots_with`.
+    fn gain_negative_slot(&mut self) {
+        if self.config.joker_slots < self.config.joker_slots_max {
+            self.config.joker_slots += 1;
+            self.negative_joker_slots += 1;
+        }
+    }
+
+    // A Negative joker went away: give back a slot only if one was added for
+    // it, i.e. more slots were added than Negative jokers are left.
+    fn lose_negative_slot(&mut self) {
+        let negatives = self
+            .jokers
+            .iter()
+            .filter(|j| j.edition() == Edition::Negative)
+            .count();
+        if self.negative_joker_slots > negatives {
+            self.negative_joker_slots -= 1;
+            self.config.joker_slots = self.config.joker_slots.saturating_sub(1);
+        }
+    }
+
+    // Held jokers with no edition, the only ones an edition can be put on.
+    pub(crate) fn editionless_jokers(&self) -> Vec<Jokers> {
+        self.jokers
+            .iter()
+            .filter(|j| j.edition() == Edition::Base)
+            .cloned()
+            .collect()
+    }
+
+    // Changes the edition of the held joker `id` (Ectoplasm, Hex, Wheel of
+    // Fortune), adding or giving back the slot a Negative edition brings.
+    pub(crate) fn set_joker_edition(&mut self, id: usize, edition: Edition) {
+        let Some(joker) = self.jokers.iter_mut().find(|j| j.instance_id() == id) else {
+            return;
+        };
+        let was = joker.edition();
+        joker.set_edition(edition);
+        if was == Edition::Negative && edition != Edition::Negative {
+            self.lose_negative_slot();
+        } else if was != Edition::Negative && edition == Edition::Negative {
+            self.gain_negative_slot();
+        }
+    }
+
+    /// Whether there's a free slot for `joker`.
+    pub(crate) fn has_joker_room(&self, joker: &Jokers) -> bool {
+        self.jokers.len() < self.joker_slots_with(joker)
+    }
+
+    // Puts `joker` in the joker slots, however it was gained, giving a
+    // Negative joker its extra slot. Callers check `has_joker_room` first
+    // and rebuild the registry once they're done adding.
+    pub(crate) fn add_joker(&mut self, joker: Jokers) {
+        if joker.edition() == Edition::Negative {
+            self.gain_negative_slot();
+        }
+        self.backend.on_joker_bought(&joker);
+        self.jokers.push(joker);
+    }
+
+    /// Creates a Tarot from a joker, tarot or seal effect, if there's a free slot.
+    pub(crate) fn create_tarot(&mut self, source: &str) {
+        if self.consumables.len() >= self.config.consumable_slots {
+            return;
+        }
+        let held: Vec<Tarot> = self
+            .consumables
+            .iter()
+            .filter_map(|c| match c.consumable {
+                Consumable::Tarot(t) => Some(t),
+                _ => None,
+            })
+            .collect();
+        let ante = self.ante_current.num() as i32;
+        let tarot = self.backend.create_tarot(source, ante, &held);
+        self.consumables.push(tarot.into());
+    }
+
+    /// Creates a Planet from a consumable's effect, if there's a free slot.
+    pub(crate) fn create_planet(&mut self, source: &str) {
+        if self.consumables.len() >= self.config.consumable_slots {
+            return;
+        }
+        let held: Vec<Planets> = self
+            .consumables
+            .iter()
+            .filter_map(|c| match c.consumable {
+                Consumable::Planet(p) => Some(p),
+                _ => None,
+            })
+            .collect();
+        let ante = self.ante_current.num() as i32;
+        let planetarium = self.planetarium.clone();
+        let planet = self
+            .backend
+            .create_planet(source, ante, &planetarium, &held);
+        self.consumables.push(planet.into());
+    }
+
+    /// Creates a Spectral from a joker's effect, if there's a free slot.
+    pub(crate) fn create_spectral(&mut self, source: &str) {
+        if self.consumables.len() >= self.config.consumable_slots {
+            return;
+        }
+        let ante = self.ante_current.num() as i32;
+        let spectral = self.backend.create_spectral(source, ante);
+        self.consumables.push(spectral.into());
+    }
+
     pub(crate) fn sell_consumable(&mut self, idx: usize) -> Result<(), GameError> {
         if matches!(self.stage, Stage::End(_)) {
             return Err(GameError::InvalidStage);
@@ -920,8 +1975,9 @@ impl Game {
         if idx >= self.consumables.len() {
             return Err(GameError::InvalidAction);
         }
-        self.money += self.consumables[idx].sell_value();
-        self.consumables.remove(idx);
+        self.earn(self.consumable_sell_value(idx));
+        self.remove_consumable(idx);
+        self.run_game_effects(self.effect_registry.on_card_sold.clone());
         Ok(())
     }
 
@@ -929,22 +1985,17 @@ impl Game {
         if self.stage != Stage::Shop() {
             return Err(GameError::InvalidStage);
         }
-        if joker.edition() == Edition::Negative {
-            self.config.joker_slots =
-                (self.config.joker_slots + 1).min(self.config.joker_slots_max);
-        }
-        if self.jokers.len() >= self.config.joker_slots {
+        if !self.has_joker_room(&joker) {
             return Err(GameError::NoAvailableSlot);
         }
-        if joker.cost() > self.money {
+        let cost = self.shop.joker_cost(&joker);
+        if cost > self.spendable() {
             return Err(GameError::InvalidBalance);
         }
         self.shop.buy_joker(&joker)?;
-        self.money -= joker.cost();
-        self.backend.on_joker_bought(&joker);
-        self.jokers.push(joker);
-        self.effect_registry
-            .register_jokers(self.jokers.clone(), &self.clone());
+        self.pay(cost);
+        self.add_joker(joker);
+        self.register_jokers();
         Ok(())
     }
 
@@ -955,12 +2006,128 @@ impl Game {
         if self.consumables.len() >= self.config.consumable_slots {
             return Err(GameError::NoAvailableSlot);
         }
-        if consumable.cost() > self.money {
+        let cost = self.shop.consumable_cost(&consumable);
+        if cost > self.spendable() {
             return Err(GameError::InvalidBalance);
         }
         self.shop.buy_consumable(&consumable)?;
-        self.money -= consumable.cost();
-        self.consumables.push(consumable);
+        self.pay(cost);
+        self.consumables.push(consumable.into());
+        Ok(())
+    }
+
+    pub(crate) fn buy_voucher(&mut self, voucher: Voucher) -> Result<(), GameError> {
+        if self.stage != Stage::Shop() {
+            return Err(GameError::InvalidStage);
+        }
+        let cost = self.shop.voucher_cost(&voucher);
+        if cost > self.spendable() {
+            return Err(GameError::InvalidBalance);
+        }
+        self.shop.buy_voucher(&voucher)?;
+        self.pay(cost);
+        self.redeem_voucher(voucher);
+        Ok(())
+    }
+
+    pub(crate) fn buy_playing_card(&mut self, card: Card) -> Result<(), GameError> {
+        if self.stage != Stage::Shop() {
+            return Err(GameError::InvalidStage);
+        }
+        let cost = self.shop.playing_card_cost(&card);
+        if cost > self.spendable() {
+            return Err(GameError::InvalidBalance);
+        }
+        self.shop.buy_playing_card(&card)?;
+        self.pay(cost);
+        self.add_to_deck(card);
+        Ok(())
+    }
+
+    // Redeemed vouchers last the rest of the run.
+    pub(crate) fn redeem_voucher(&mut self, voucher: Voucher) {
+        self.vouchers.push(voucher);
+        self.backend.on_voucher_redeemed(voucher);
+        match voucher {
+            Voucher::Overstock | Voucher::OverstockPlus => self.shop.slots += 1,
+            Voucher::ClearanceSale => self.shop.discount_percent = 25,
+            Voucher::Liquidation => self.shop.discount_percent = 50,
+            Voucher::RerollSurplus | Voucher::RerollGlut => {
+                self.reroll_cost = self.reroll_cost.saturating_sub(2);
+            }
+            Voucher::CrystalBall => self.config.consumable_slots += 1,
+            Voucher::Grabber | Voucher::NachoTong => self.apply_permanent(StatModifiers::plays(1)),
+            Voucher::Wasteful | Voucher::Recyclomancy => {
+                self.apply_permanent(StatModifiers::discards(1))
+            }
+            Voucher::SeedMoney => self.config.interest_max += 5,
+            Voucher::MoneyTree => self.config.interest_max += 10,
+            Voucher::Antimatter => {
+                self.config.joker_slots =
+                    (self.config.joker_slots + 1).min(self.config.joker_slots_max)
+            }
+            Voucher::Hieroglyph => {
+                self.rollback_ante();
+                self.apply_permanent(StatModifiers::plays(-1));
+            }
+            Voucher::Petroglyph => {
+                self.rollback_ante();
+                self.apply_permanent(StatModifiers::discards(-1));
+            }
+            Voucher::PaintBrush | Voucher::Palette => {
+                self.apply_permanent(StatModifiers::hand_size(1))
+            }
+            // edition rates, shop weights, Omen Globe and Magic Trick live in
+            // the backend's generators; Telescope, Observatory, Director's
+            // Cut and Retcon are checked where they apply; Blank does nothing.
+            Voucher::Hone
+            | Voucher::GlowUp
+            | Voucher::OmenGlobe
+            | Voucher::Telescope
+            | Voucher::Observatory
+            | Voucher::TarotMerchant
+            | Voucher::TarotTycoon
+            | Voucher::PlanetMerchant
+            | Voucher::PlanetTycoon
+            | Voucher::MagicTrick
+            | Voucher::Illusion
+            | Voucher::DirectorsCut
+            | Voucher::Retcon
+            | Voucher::Blank => {}
+        }
+    }
+
+    // Hieroglyph/Petroglyph: back one ante, the blinds already beaten stay
+    // beaten.
+    fn rollback_ante(&mut self) {
+        if let Ok(ante) = Ante::try_from(self.ante_current.num().saturating_sub(1)) {
+            self.ante_current = ante;
+        }
+    }
+
+    /// Whether the upcoming Boss Blind can be rerolled right now (Director's
+    /// Cut once per ante, Retcon any number of times).
+    pub fn can_reroll_boss(&self) -> bool {
+        if self.stage != Stage::PreBlind() || self.blind == Some(Blind::Boss) {
+            return false;
+        }
+        if BOSS_REROLL_COST > self.spendable() {
+            return false;
+        }
+        self.has_voucher(Voucher::Retcon)
+            || (self.has_voucher(Voucher::DirectorsCut) && self.boss_rerolls_this_ante == 0)
+    }
+
+    pub(crate) fn reroll_boss(&mut self) -> Result<(), GameError> {
+        if self.stage != Stage::PreBlind() {
+            return Err(GameError::InvalidStage);
+        }
+        if !self.can_reroll_boss() {
+            return Err(GameError::InvalidAction);
+        }
+        self.pay(BOSS_REROLL_COST);
+        self.boss_rerolls_this_ante += 1;
+        self.draw_ante_boss();
         Ok(())
     }
 
@@ -1001,10 +2168,12 @@ impl Game {
             .iter()
             .position(|c| c == &consumable)
             .ok_or(GameError::InvalidAction)?;
-        self.consumables.remove(i);
+        self.remove_consumable(i);
         match consumable {
             Consumable::Planet(planet) => {
                 self.planetarium.level_up(planet.hand_rank());
+                self.planets_used.insert(planet);
+                self.run_game_effects(self.effect_registry.on_planet_used.clone());
                 self.last_consumable_used = Some(Consumable::Planet(planet));
             }
             Consumable::Tarot(t) => {
@@ -1091,16 +2260,38 @@ impl Game {
         if self.stage != Stage::Shop() {
             return Err(GameError::InvalidStage);
         }
-        if pack.cost() > self.money {
+        let cost = self.shop.pack_cost(&pack);
+        if cost > self.spendable() {
             return Err(GameError::InvalidBalance);
         }
         self.shop.buy_pack(&pack)?;
-        self.money -= pack.cost();
+        self.pay(cost);
+        self.open(pack);
+        Ok(())
+    }
 
+    // Opens `pack`, returning to the current stage once it's finished.
+    fn open(&mut self, mut pack: Pack) {
+        self.run_game_effects(self.effect_registry.on_pack_open.clone());
         // Arcana/Spectral packs draw a hand for the player to apply targeted
         // tarots/spectrals against
         if matches!(pack.category, PackCategory::Arcana | PackCategory::Spectral) {
-            self.draw(self.config.available);
+            self.draw(self.hand_size());
+        }
+
+        // Telescope: celestial packs always hold the most played hand's planet
+        if pack.category == PackCategory::Celestial && self.has_voucher(Voucher::Telescope) {
+            let rank = self.most_played_hand_rank();
+            let has_it = pack
+                .contents
+                .iter()
+                .any(|c| matches!(c, PackContent::Planet(p) if p.hand_rank() == rank));
+            if let (false, Some(planet)) = (has_it, Planets::iter().find(|p| p.hand_rank() == rank))
+            {
+                if let Some(first) = pack.contents.first_mut() {
+                    *first = PackContent::Planet(planet);
+                }
+            }
         }
 
         self.open_pack = Some(OpenPackState {
@@ -1108,8 +2299,8 @@ impl Game {
             description: pack.description(),
             contents: pack.contents,
         });
+        self.pack_prev_stage = Some(self.stage);
         self.stage = Stage::PackOpen();
-        Ok(())
     }
 
     pub(crate) fn pick_pack_card(&mut self, content: PackContent) -> Result<(), GameError> {
@@ -1153,19 +2344,14 @@ impl Game {
                 self.planetarium.level_up(p.hand_rank());
             }
             PackContent::Joker(j) => {
-                if j.edition() == Edition::Negative {
-                    self.config.joker_slots =
-                        (self.config.joker_slots + 1).min(self.config.joker_slots_max);
-                }
-                if self.jokers.len() >= self.config.joker_slots {
+                if !self.has_joker_room(&j) {
                     return Err(GameError::NoAvailableSlot);
                 }
-                self.jokers.push(j);
-                self.effect_registry
-                    .register_jokers(self.jokers.clone(), &self.clone());
+                self.add_joker(j);
+                self.register_jokers();
             }
             PackContent::PlayingCard(c) => {
-                self.deck.push(c);
+                self.add_to_deck(c);
             }
             PackContent::Spectral(s) => {
                 s.apply(self)?;
@@ -1191,6 +2377,7 @@ impl Game {
             return Err(GameError::InvalidStage);
         }
         self.finish_pack();
+        self.run_game_effects(self.effect_registry.on_pack_skip.clone());
         Ok(())
     }
 
@@ -1200,10 +2387,14 @@ impl Game {
             let cards = self.available.cards();
             self.available.empty();
             self.deck.extend(cards);
-            self.backend.shuffle_deck(&mut self.deck);
+            self.backend.shuffle_deck(&mut self.deck, "shuffle");
         }
         self.open_pack = None;
-        self.stage = Stage::Shop();
+        self.stage = self.pack_prev_stage.take().unwrap_or(Stage::Shop());
+        // a pack tag may have been waiting on this one
+        if self.stage == Stage::PreBlind() {
+            self.trigger_tags(TagTrigger::Immediate);
+        }
     }
 
     fn select_blind(&mut self, blind: Blind) -> Result<(), GameError> {
@@ -1225,7 +2416,23 @@ impl Game {
         }
         self.blind = Some(blind);
         self.stage = Stage::Blind(blind);
+        self.joker_sold_this_round = false;
+        self.run_game_effects(self.effect_registry.on_blind_select.clone());
+        self.trigger_tags(TagTrigger::RoundStart);
+        // The Needle: a single hand
+        if self.active_boss() == Some(BossBlind::Needle) {
+            self.plays = 1;
+        }
+        // Amber Acorn: jokers flipped and shuffled
+        if self.active_boss() == Some(BossBlind::AmberAcorn) {
+            self.backend.shuffle_jokers(&mut self.jokers, "aajk");
+            self.jokers_flipped = true;
+            self.register_jokers();
+        }
         self.deal();
+        self.run_game_effects(self.effect_registry.on_first_hand_drawn.clone());
+        self.force_select_card();
+        self.disable_random_joker();
         Ok(())
     }
 
@@ -1245,21 +2452,58 @@ impl Game {
         }
         self.blind = Some(blind);
         self.stage = Stage::PreBlind();
+        self.total_blinds_skipped += 1;
+        self.skipped_this_ante.push(blind);
         let tag = self
             .skip_tag(blind)
             .expect("blind is not Boss, checked above");
-        self.tags.push(tag);
+        self.add_tag(tag);
         Ok(())
     }
 
-    fn draw_ante_tags(&mut self) {
-        let (small, big) = self.backend.draw_ante_tags();
-        self.small_blind_tag = small;
-        self.big_blind_tag = big;
+    // Everything a fresh ante rolls up front: its skip tags, boss and voucher.
+    pub(crate) fn draw_ante(&mut self) {
+        self.draw_ante_tags();
+        self.draw_ante_boss();
+        self.draw_ante_voucher();
+    }
+
+    pub(crate) fn draw_ante_tags(&mut self) {
+        let (small, big) = self.backend.draw_ante_tags(self.ante_current.num() as i32);
+        self.small_blind_tag = self.offer_tag(small);
+        self.big_blind_tag = self.offer_tag(big);
+    }
+
+    // Orbital picks its hand when it's offered, so the tag shows the hand
+    // it will level.
+    pub(crate) fn offer_tag(&mut self, tag: Tag) -> Tag {
+        match tag {
+            Tag::Orbital(_) => {
+                let planetarium = self.planetarium.clone();
+                match self.backend.roll_random_planet(&planetarium, &[]) {
+                    Consumable::Planet(p) => Tag::Orbital(p.hand_rank()),
+                    _ => tag,
+                }
+            }
+            _ => tag,
+        }
+    }
+
+    pub(crate) fn draw_ante_boss(&mut self) {
+        let candidates = self.boss_pool.candidates(self.ante_current.num() as i32);
+        let boss = self.backend.draw_boss(&candidates);
+        self.boss_pool.record(boss);
+        self.current_boss = Some(boss);
     }
 
-    fn draw_ante_boss(&mut self) {
-        self.current_boss = Some(self.backend.draw_boss(self.ante_current as i32));
+    // Fresh ante, fresh voucher: whatever went unbought last ante is gone.
+    pub(crate) fn draw_ante_voucher(&mut self) {
+        self.shop.vouchers = self
+            .backend
+            .draw_voucher(self.ante_current.num() as i32, &self.vouchers, &[])
+            .into_iter()
+            .collect();
+        self.boss_rerolls_this_ante = 0;
     }
 
     pub fn skip_tag(&self, blind: Blind) -> Option<Tag> {
@@ -1271,13 +2515,14 @@ impl Game {
     }
 
     fn next_round(&mut self) -> Result<(), GameError> {
+        self.run_game_effects(self.effect_registry.on_shop_end.clone());
         self.stage = Stage::PreBlind();
         self.round += 1;
         Ok(())
     }
 
     // Returns true if should clear blind after, false if not.
-    fn handle_score(&mut self, score: usize) -> Result<bool, GameError> {
+    fn handle_score(&mut self, score: BigNum) -> Result<bool, GameError> {
         // can only handle score if stage is blind
         if !self.stage.is_blind() {
             return Err(GameError::InvalidStage);
@@ -1290,7 +2535,11 @@ impl Game {
         if self.score < required {
             // no more hands to play -> lose
             if self.plays == 0 {
-                self.stage = Stage::End(End::Lose);
+                self.stage = Stage::End(if self.won {
+                    End::EndlessLose
+                } else {
+                    End::Lose
+                });
                 return Ok(false);
             } else {
                 // more hands to play, carry on
@@ -1300,19 +2549,33 @@ impl Game {
 
         let blind = self.blind.expect("stage is blind");
         self.last_score = self.score;
+        self.total_unused_discards += self.discards();
 
         // score exceeds blind (blind passed).
-        // handle reward then progress to next stage.
-        let reward = self.calc_reward(blind)?;
-        self.reward = reward;
+        // end of round effects, then the cash out, then progress to next stage.
+        self.handle_round_end();
+        self.round_reward = self.calc_reward(blind)?;
+        // keyed by the ante of the round just beaten, so before it moves on
+        self.roll_discard_selectors();
 
         // passed boss blind, either win or progress ante
         if blind == Blind::Boss {
-            if let Some(ante_next) = self.ante_current.next(self.ante_end) {
+            self.trigger_tags(TagTrigger::BossDefeated);
+            if self.config.deck == Some(DeckVariant::Anaglyph) {
+                self.add_tag(Tag::Double);
+            }
+            let ante_next = self.ante_current.next(self.ante_end);
+            self.won |= ante_next.is_none();
+            let ante_next = match ante_next {
+                None if self.config.endless => Some(self.ante_current.next_endless()),
+                next => next,
+            };
+            if let Some(ante_next) = ante_next {
                 self.ante_current = ante_next;
                 self.blind = None;
-                self.draw_ante_tags();
-                self.draw_ante_boss();
+                self.played_this_ante.clear();
+                self.skipped_this_ante.clear();
+                self.draw_ante();
             } else {
                 self.stage = Stage::End(End::Win);
                 return Ok(false);
@@ -1326,6 +2589,30 @@ impl Game {
 
     pub fn handle_action(&mut self, action: Action) -> Result<(), GameError> {
         self.action_history.push(action.clone());
+        let res = self.dispatch_action(action);
+        self.sync_card_debuffs();
+        res
+    }
+
+    // Boss debuffs can change mid-round (Pillar, Verdant Leaf, a boss
+    // being disabled), so every action re-syncs the flag on held and deck
+    // cards.
+    pub(crate) fn sync_card_debuffs(&mut self) {
+        let debuffed: HashSet<usize> = self
+            .available
+            .cards()
+            .iter()
+            .chain(self.deck.cards().iter())
+            .filter(|c| self.is_card_debuffed(c))
+            .map(|c| c.id)
+            .collect();
+        self.available
+            .mutate_all(|c| c.debuffed = debuffed.contains(&c.id));
+        self.deck
+            .mutate_all(|c| c.debuffed = debuffed.contains(&c.id));
+    }
+
+    fn dispatch_action(&mut self, action: Action) -> Result<(), GameError> {
         match action {
             Action::SelectCard(card) => {
                 if self.stage.is_blind() {
@@ -1348,6 +2635,9 @@ impl Game {
                 if self.stage.is_blind()
                     || matches!(self.stage, Stage::TarotHand(_) | Stage::SpectralHand(_))
                 {
+                    if self.stage.is_blind() && self.forced_card() == Some(card.id) {
+                        return Err(GameError::InvalidSelectCard);
+                    }
                     self.available.deselect_card(card)
                 } else {
                     Err(GameError::InvalidAction)
@@ -1370,7 +2660,7 @@ impl Game {
                     Err(GameError::InvalidAction)
                 }
             }
-            Action::CashOut(_reward) => match self.stage {
+            Action::CashOut() => match self.stage {
                 Stage::PostBlind() => self.cashout(),
                 _ => Err(GameError::InvalidAction),
             },
@@ -1423,6 +2713,20 @@ impl Game {
                 Stage::Shop() => self.reroll(),
                 _ => Err(GameError::InvalidAction),
             },
+            Action::BuyVoucher(voucher) => match self.stage {
+                Stage::Shop() => self.buy_voucher(voucher),
+                _ => Err(GameError::InvalidAction),
+            },
+            Action::BuyPlayingCard(card) => match self.stage {
+                Stage::Shop() => self.buy_playing_card(card),
+                _ => Err(GameError::InvalidAction),
+            },
+            Action::RerollBoss() => match self.stage {
+                Stage::PreBlind() => self.reroll_boss(),
+                _ => Err(GameError::InvalidAction),
+            },
+            Action::MoveJoker(dir, idx) => self.move_joker(dir, idx),
+            Action::MoveConsumable(dir, idx) => self.move_consumable(dir, idx),
         }
     }
 
@@ -1520,8 +2824,8 @@ impl Game {
 
     pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
         let mut game: Self = serde_json::from_str(s)?;
-        let jokers = game.jokers.clone();
-        game.effect_registry.register_jokers(jokers, &game.clone());
+        // saved hands/discards already include the jokers' modifiers
+        game.rebuild_effect_registry();
 
         // Deserializing doesn't allocate ids, so Card::new() calls after this
         // point would otherwise collide with ids already present in `game`.
@@ -1562,6 +2866,7 @@ impl Game {
 mod tests {
     use super::*;
     use crate::card::{Suit, Value};
+    use crate::joker::Stickers;
 
     #[test]
     fn test_real_rng_mode_matches_balatro_seed_reference() {
@@ -1597,9 +2902,7 @@ mod tests {
     }
 
     // `cargo run -p balatro-seed --bin explore -- TEST --ante 1` reports
-    // `Boss: The Goad` for this seed, but `RealBackend::draw_boss` doesn't
-    // match it (yet).
-    // TODO: Fix min ante for real rng boss gen
+    // `Boss: The Goad` for this seed.
     #[test]
     fn test_real_rng_mode_draw_boss_reaches_instance() {
         let config = Config {
@@ -1608,7 +2911,63 @@ mod tests {
             ..Config::default()
         };
         let g = Game::new(config);
-        assert_eq!(g.current_boss, Some(BossBlind::Flint));
+        assert_eq!(g.current_boss, Some(BossBlind::Goad));
+    }
+
+    // A brand-new account's locks: none of the gated jokers the unlocked
+    // run reaches (The Trio on the 5th refresh for this seed) show up.
+    #[test]
+    fn test_real_rng_mode_applies_profile_locks() {
+        fn gated_jokers_seen(profile_locks: Option<Vec<String>>) -> usize {
+            let config = Config {
+                rng_mode: RngMode::Real,
+                seed_str: Some("TEST".to_string()),
+                profile_locks,
+                ..Config::default()
+            };
+            let mut g = Game::new(config);
+            let planetarium = g.planetarium.clone();
+            let mut seen = 0;
+            for _ in 0..10 {
+                g.shop
+                    .refresh(&planetarium, &[], false, 1, &[], 1, &mut g.backend);
+                seen += g
+                    .shop
+                    .jokers
+                    .iter()
+                    .filter(|j| balatro_seed::PROFILE_LOCKS.contains(&j.name()))
+                    .count();
+            }
+            seen
+        }
+
+        let all: Vec<String> = balatro_seed::PROFILE_LOCKS
+            .iter()
+            .map(|n| n.to_string())
+            .collect();
+        assert!(gated_jokers_seen(None) > 0);
+        assert_eq!(gated_jokers_seen(Some(all)), 0);
+    }
+
+    #[test]
+    fn test_real_rng_mode_deals_from_new_round_shuffle() {
+        let config = Config {
+            rng_mode: RngMode::Real,
+            seed_str: Some("TEST".to_string()),
+            ..Config::default()
+        };
+        let mut g = Game::new(config);
+        let mut expected = g.deck.cards();
+        expected.sort_by_key(|c| c.id);
+        balatro_seed::Instance::new("TEST").shuffle(&mut expected, "nr1");
+        expected.reverse();
+
+        g.stage = Stage::Blind(Blind::Small);
+        g.blind = Some(Blind::Small);
+        g.deal();
+        let dealt: Vec<usize> = g.available.cards().iter().map(|c| c.id).collect();
+        let expected: Vec<usize> = expected[..g.hand_size()].iter().map(|c| c.id).collect();
+        assert_eq!(dealt, expected);
     }
 
     // Same seed through two independent `Game`s: proves `RngMode::Real`
@@ -1617,7 +2976,9 @@ mod tests {
     fn test_real_rng_mode_is_deterministic_across_antes() {
         use crate::pack::PackSize;
 
-        fn run() -> Vec<(Vec<String>, Vec<String>, Vec<(PackCategory, PackSize)>)> {
+        type AnteDraws = (Vec<String>, Vec<String>, Vec<(PackCategory, PackSize)>);
+
+        fn run() -> Vec<AnteDraws> {
             let config = Config {
                 rng_mode: RngMode::Real,
                 seed_str: Some("TEST".to_string()),
@@ -1970,12 +3331,76 @@ mod tests {
         assert_eq!(g.score, score);
 
         // Enough to pass now
-        let passed = g.handle_score(1).unwrap();
+        let passed = g.handle_score(1.into()).unwrap();
         assert!(passed);
         assert_eq!(g.score, required);
         assert_eq!(g.stage, Stage::PostBlind());
     }
 
+    #[test]
+    fn test_final_boss_wins_without_endless() {
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Boss),
+            blind: Some(Blind::Boss),
+            ante_current: Ante::Eight,
+            ..Default::default()
+        };
+        g.handle_score(BigNum::NANEINF).unwrap();
+        assert_eq!(g.result(), Some(End::Win));
+    }
+
+    #[test]
+    fn test_endless_continues_past_final_ante() {
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Boss),
+            blind: Some(Blind::Boss),
+            ante_current: Ante::Eight,
+            config: Config {
+                endless: true,
+                ..Config::default()
+            },
+            ..Default::default()
+        };
+        g.handle_score(BigNum::NANEINF).unwrap();
+        assert_eq!(g.stage, Stage::PostBlind());
+        assert_eq!(g.ante_current, Ante::Endless(9));
+
+        g.stage = Stage::Blind(Blind::Small);
+        g.blind = Some(Blind::Small);
+        g.score = BigNum::ZERO;
+        assert_eq!(g.required_score(), 110000);
+
+        g.plays = 0;
+        g.handle_score(0.into()).unwrap();
+        let end = g.result().expect("game over");
+        assert_eq!(end, End::EndlessLose);
+        assert!(end.is_win());
+    }
+
+    #[test]
+    fn test_endless_loss_after_ante_rollback_is_still_a_win() {
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Boss),
+            blind: Some(Blind::Boss),
+            ante_current: Ante::Eight,
+            config: Config {
+                endless: true,
+                ..Config::default()
+            },
+            ..Default::default()
+        };
+        g.handle_score(BigNum::NANEINF).unwrap();
+        g.redeem_voucher(Voucher::Hieroglyph);
+        assert_eq!(g.ante_current, Ante::Eight);
+
+        g.stage = Stage::Blind(Blind::Small);
+        g.blind = Some(Blind::Small);
+        g.score = BigNum::ZERO;
+        g.plays = 0;
+        g.handle_score(0.into()).unwrap();
+        assert_eq!(g.result(), Some(End::EndlessLose));
+    }
+
     #[test]
     fn test_clear_blind() {
         let mut g = Game::default();
@@ -2697,6 +4122,286 @@ mod tests {
         assert_eq!(g.available.cards().len(), 8);
     }
 
+    fn boss_round(boss: BossBlind) -> Game {
+        let mut g = Game {
+            current_boss: Some(boss),
+            blind: Some(Blind::Big),
+            stage: Stage::PreBlind(),
+            ..Default::default()
+        };
+        g.select_blind(Blind::Boss).expect("can select boss blind");
+        g
+    }
+
+    // Replaces the dealt hand with `cards`, selecting the first `n`
+    fn hold(g: &mut Game, cards: Vec<Card>, n: usize) {
+        g.available.empty();
+        g.available.extend(cards.clone());
+        for card in cards.into_iter().take(n) {
+            g.select_card(card).expect("can select card");
+        }
+    }
+
+    #[test]
+    fn test_psychic_boss_needs_five_cards() {
+        let mut g = boss_round(BossBlind::Psychic);
+        let cards: Vec<Card> = g.available.cards().into_iter().take(2).collect();
+        for card in cards {
+            g.select_card(card).expect("can select card");
+        }
+        assert!(!g.is_hand_allowed(&g.available.selected()));
+        assert!(!g.gen_actions().any(|a| matches!(a, Action::Play())));
+
+        // still playable, just scores nothing
+        g.play_selected().expect("can play");
+        assert_eq!(g.score, 0);
+        assert_eq!(g.plays, g.config.plays - 1);
+    }
+
+    #[test]
+    fn test_psychic_boss_offers_five_card_play() {
+        let mut g = boss_round(BossBlind::Psychic);
+        let cards: Vec<Card> = g.available.cards().into_iter().take(5).collect();
+        for card in cards {
+            g.select_card(card).expect("can select card");
+        }
+        assert!(g.gen_actions().any(|a| matches!(a, Action::Play())));
+    }
+
+    #[test]
+    fn test_eye_boss_bans_repeated_hand_type() {
+        let mut g = boss_round(BossBlind::Eye);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let king = Card::new(Value::King, Suit::Diamond);
+        g.hand_ranks_played_this_round.insert(HandRank::HighCard);
+        assert!(!g.is_hand_allowed(&[ace]));
+        assert!(g.is_hand_allowed(&[king, king]));
+    }
+
+    #[test]
+    fn test_mouth_boss_locks_first_hand_type() {
+        let mut g = boss_round(BossBlind::Mouth);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let king = Card::new(Value::King, Suit::Diamond);
+        assert!(g.is_hand_allowed(&[ace]));
+        g.hand_ranks_played_this_round.insert(HandRank::OnePair);
+        assert!(!g.is_hand_allowed(&[ace]));
+        assert!(g.is_hand_allowed(&[king, king]));
+    }
+
+    #[test]
+    fn test_mouth_boss_offers_play_when_nothing_allowed() {
+        let mut g = boss_round(BossBlind::Mouth);
+        g.hand_ranks_played_this_round.insert(HandRank::Flush);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let two = Card::new(Value::Two, Suit::Club);
+        hold(&mut g, vec![ace, two], 1);
+        // no flush to be made, so the losing play is still on offer
+        assert!(!g.any_hand_allowed());
+        assert!(g.gen_actions().any(|a| matches!(a, Action::Play())));
+    }
+
+    #[test]
+    fn test_hook_boss_discards_two_held_cards() {
+        let mut g = boss_round(BossBlind::Hook);
+        let card = g.available.cards()[0];
+        g.select_card(card).expect("can select card");
+        g.play_selected().expect("can play");
+        assert_eq!(g.discarded.len(), 3);
+        assert_eq!(g.available.cards().len(), g.hand_size());
+    }
+
+    #[test]
+    fn test_ox_boss_zeroes_money_on_most_played_hand() {
+        let mut g = boss_round(BossBlind::Ox);
+        g.money = 20;
+        g.planetarium.play(HandRank::OnePair);
+        g.planetarium.play(HandRank::OnePair);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let king = Card::new(Value::King, Suit::Diamond);
+        let king2 = Card::new(Value::King, Suit::Club);
+        hold(&mut g, vec![ace, king], 1);
+        g.play_selected().expect("can play");
+        assert_eq!(g.money, 20);
+
+        hold(&mut g, vec![king, king2, ace], 2);
+        g.play_selected().expect("can play");
+        assert_eq!(g.money, 0);
+    }
+
+    #[test]
+    fn test_tooth_boss_costs_a_dollar_per_card() {
+        let mut g = boss_round(BossBlind::Tooth);
+        g.money = 10;
+        let cards: Vec<Card> = g.available.cards().into_iter().take(3).collect();
+        for card in cards {
+            g.select_card(card).expect("can select card");
+        }
+        g.play_selected().expect("can play");
+        assert_eq!(g.money, 7);
+    }
+
+    #[test]
+    fn test_pillar_boss_debuffs_cards_played_this_ante() {
+        let mut g = boss_round(BossBlind::Pillar);
+        let card = g.available.cards()[0];
+        assert!(!g.is_card_debuffed(&card));
+        g.played_this_ante.insert(card.id);
+        assert!(g.is_card_debuffed(&card));
+    }
+
+    #[test]
+    fn test_boss_debuff_flag_tracks_held_and_deck_cards() {
+        let mut g = boss_round(BossBlind::Club);
+        let clubs = |c: &Card| c.suit == Suit::Club;
+        let held = g.available.cards();
+        assert!(held.iter().all(|c| c.debuffed == clubs(c)));
+        assert!(g.deck.cards().iter().all(|c| c.debuffed == clubs(c)));
+
+        // a debuffed card leaves the flag behind with the hand
+        let club = g.deck.cards().into_iter().find(|c| clubs(c)).unwrap();
+        hold(&mut g, vec![club], 1);
+        g.handle_action(Action::Discard()).expect("can discard");
+        let pile = g.discarded.iter().find(|c| c.id == club.id).unwrap();
+        assert!(!pile.debuffed);
+    }
+
+    #[test]
+    fn test_needle_boss_single_hand() {
+        let g = boss_round(BossBlind::Needle);
+        assert_eq!(g.plays, 1);
+    }
+
+    #[test]
+    fn test_house_boss_first_hand_face_down() {
+        let mut g = boss_round(BossBlind::House);
+        assert!(g.available.cards().iter().all(|c| c.face_down));
+
+        let card = g.available.cards()[0];
+        g.select_card(card).expect("can select card");
+        g.discard_selected().expect("can discard");
+        // the replacement is face up, the rest stay down
+        let up = g.available.cards().iter().filter(|c| !c.face_down).count();
+        assert_eq!(up, 1);
+    }
+
+    #[test]
+    fn test_fish_boss_draws_face_down_after_play() {
+        let mut g = boss_round(BossBlind::Fish);
+        assert!(g.available.cards().iter().all(|c| !c.face_down));
+        let card = g.available.cards()[0];
+        g.select_card(card).expect("can select card");
+        g.play_selected().expect("can play");
+        let down = g.available.cards().iter().filter(|c| c.face_down).count();
+        assert_eq!(down, 1);
+    }
+
+    #[test]
+    fn test_mark_and_wheel_boss_draw_face_down() {
+        let g = boss_round(BossBlind::Mark);
+        for c in g.available.cards() {
+            assert_eq!(c.face_down, c.is_face_card());
+        }
+
+        // Oops! All 6s style odds make Wheel's 1 in 7 certain
+        let mut g = Game {
+            current_boss: Some(BossBlind::Wheel),
+            blind: Some(Blind::Big),
+            stage: Stage::PreBlind(),
+            prob_mult: 7,
+            ..Default::default()
+        };
+        g.select_blind(Blind::Boss).expect("can select boss blind");
+        assert!(g.available.cards().iter().all(|c| c.face_down));
+    }
+
+    fn jokers_with_ids(n: usize) -> Vec<Jokers> {
+        use crate::joker::*;
+        (1..=n)
+            .map(|id| {
+                let mut j = Jokers::TheJoker(TheJoker::default());
+                j.set_instance_id(id);
+                j
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_amber_acorn_flips_and_shuffles_jokers() {
+        let mut g = Game {
+            current_boss: Some(BossBlind::AmberAcorn),
+            blind: Some(Blind::Big),
+            stage: Stage::PreBlind(),
+            jokers: jokers_with_ids(5),
+            ..Default::default()
+        };
+        assert!(!g.jokers_face_down());
+        g.select_blind(Blind::Boss).expect("can select boss blind");
+        assert!(g.jokers_face_down());
+        let mut ids: Vec<usize> = g.jokers.iter().map(|j| j.instance_id()).collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+
+        g.clear_blind();
+        assert!(!g.jokers_face_down());
+    }
+
+    #[test]
+    fn test_cerulean_bell_forces_a_selected_card() {
+        let mut g = boss_round(BossBlind::CeruleanBell);
+        let forced = g.forced_card().expect("a card is forced");
+        let selected = g.available.selected();
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].id, forced);
+        assert!(matches!(
+            g.handle_action(Action::DeselectCard(selected[0])),
+            Err(GameError::InvalidSelectCard)
+        ));
+        assert!(!g
+            .gen_actions()
+            .any(|a| matches!(a, Action::DeselectCard(c) if c.id == forced)));
+
+        // discarding the forced card picks a new one
+        g.discard_selected().expect("can discard");
+        let next = g.forced_card().expect("a card is forced");
+        assert_ne!(next, forced);
+        assert_eq!(g.available.selected().len(), 1);
+    }
+
+    #[test]
+    fn test_crimson_heart_disables_a_joker_each_hand() {
+        let mut g = Game {
+            current_boss: Some(BossBlind::CrimsonHeart),
+            blind: Some(Blind::Big),
+            stage: Stage::PreBlind(),
+            jokers: jokers_with_ids(1),
+            ..Default::default()
+        };
+        g.select_blind(Blind::Boss).expect("can select boss blind");
+        assert!(g.is_joker_disabled(&g.jokers[0]));
+
+        // Joker's +4 mult is gone: (5 + 11) * 1 = 16
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
+        assert_eq!(g.calc_score(hand), 16);
+    }
+
+    #[test]
+    fn test_verdant_leaf_debuffs_until_joker_sold() {
+        let mut g = Game {
+            current_boss: Some(BossBlind::VerdantLeaf),
+            blind: Some(Blind::Big),
+            stage: Stage::PreBlind(),
+            jokers: jokers_with_ids(1),
+            ..Default::default()
+        };
+        g.select_blind(Blind::Boss).expect("can select boss blind");
+        let card = g.available.cards()[0];
+        assert!(g.is_card_debuffed(&card));
+        g.sell_joker(0).expect("can sell joker");
+        assert!(!g.is_card_debuffed(&card));
+    }
+
     #[test]
     fn test_play_selected() {
         let mut g = Game::default();
@@ -2737,10 +4442,63 @@ mod tests {
         let j1 = crate::joker::jokers_by_rarity(crate::joker::Rarity::Common)[0].clone();
         g.shop.jokers = vec![j1.clone()];
         g.buy_joker(j1.clone()).expect("buy joker");
-        assert_eq!(g.money, 10 - j1.cost());
+        assert_eq!(g.money, (10 - j1.cost()) as i64);
         assert_eq!(g.jokers.len(), 1);
     }
 
+    #[test]
+    fn test_credit_card_allows_buying_into_debt() {
+        use balatro_types::joker::CreditCard;
+        let mut g = Game::default();
+        g.start();
+        g.stage = Stage::Shop();
+        g.money = 0;
+        let j1 = crate::joker::jokers_by_rarity(crate::joker::Rarity::Common)[0].clone();
+        g.shop.jokers = vec![j1.clone()];
+        assert_eq!(g.spendable(), 0);
+        assert!(matches!(
+            g.buy_joker(j1.clone()),
+            Err(GameError::InvalidBalance)
+        ));
+
+        g.jokers.push(Jokers::CreditCard(CreditCard::default()));
+        g.register_jokers();
+        assert_eq!(g.money_floor(), -20);
+        assert!(g.gen_actions().any(|a| a == Action::BuyJoker(j1.clone())));
+        g.buy_joker(j1.clone()).expect("buy joker on credit");
+        assert_eq!(g.money, -(j1.cost() as i64));
+        assert_eq!(g.spendable(), 20 - j1.cost());
+    }
+
+    #[test]
+    fn test_money_capped_and_no_interest_on_debt() {
+        let mut g = Game {
+            money: 498,
+            ..Default::default()
+        };
+        g.earn(5);
+        assert_eq!(g.money, g.config.money_max as i64);
+
+        g.money = -10;
+        let reward = g.calc_reward(Blind::Small).unwrap();
+        assert_eq!(reward.amount(&RewardSource::Interest), 0);
+
+        // The Tooth charges past the floor
+        g.money = 1;
+        g.current_boss = Some(BossBlind::Tooth);
+        g.blind = Some(Blind::Boss);
+        g.stage = Stage::Blind(Blind::Boss);
+        let hand = SelectHand::new(vec![
+            Card::new(Value::Two, Suit::Heart),
+            Card::new(Value::Two, Suit::Club),
+            Card::new(Value::Five, Suit::Club),
+        ])
+        .best_hand()
+        .unwrap();
+        g.boss_money_penalty(&hand, 3);
+        assert_eq!(g.money, -2);
+    }
+
     #[test]
     fn test_enhancement_bonus() {
         // High card Ace with Bonus: (5 + 11 + 30) * 1 = 46
@@ -2800,7 +4558,7 @@ mod tests {
 
     #[test]
     fn test_enhancement_gold_held() {
-        // Gold card held in unplayed hand gives $3 per hand scored
+        // Gold card held in hand at the end of the round pays $3 at cash out
         let mut g = Game::default();
         let mut gold_card = Card::new(Value::Two, Suit::Heart);
         gold_card.enhancement = Some(Enhancement::Gold);
@@ -2808,7 +4566,9 @@ mod tests {
         let ace = Card::new(Value::Ace, Suit::Spade);
         let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
         g.calc_score(hand);
-        assert_eq!(g.money, 3);
+        assert_eq!(g.money, 0);
+        let reward = g.calc_reward(Blind::Small).unwrap();
+        assert_eq!(reward.amount(&RewardSource::GoldCards), 3);
     }
 
     #[test]
@@ -2842,18 +4602,16 @@ mod tests {
         gold_red.enhancement = Some(Enhancement::Gold);
         gold_red.seal = Some(Seal::Red);
         g.available.extend(vec![gold_red]);
-        let ace = Card::new(Value::Ace, Suit::Spade);
-        let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
-        g.calc_score(hand);
-        assert_eq!(g.money, 6);
+        let reward = g.calc_reward(Blind::Small).unwrap();
+        assert_eq!(reward.amount(&RewardSource::GoldCards), 6);
     }
 
     #[test]
     fn test_seal_red_retrigger_steel_held() {
         // Pair of Kings scored; held Steel+Red King retriggers its Mult bonus twice.
         // Pair level 1: chips=10, mult=2; both Kings score: chips=30
-        // Steel triggers twice: mult = 2 -> 3 (floor(2*1.5)) -> 4 (floor(3*1.5))
-        // score = 30 * 4 = 120
+        // Steel triggers twice: mult = 2 -> 3 (2*1.5) -> 4.5 (3*1.5)
+        // score = 30 * 4.5 = 135
         let mut g = Game::default();
         let king1 = Card::new(Value::King, Suit::Heart);
         let king2 = Card::new(Value::King, Suit::Diamond);
@@ -2862,7 +4620,7 @@ mod tests {
         steel_red_king.seal = Some(Seal::Red);
         g.available.extend(vec![steel_red_king]);
         let hand = SelectHand::new(vec![king1, king2]).best_hand().unwrap();
-        assert_eq!(g.calc_score(hand), 120);
+        assert_eq!(g.calc_score(hand), 135);
     }
 
     #[test]
@@ -2993,8 +4751,8 @@ mod tests {
     #[test]
     fn test_mime_retriggers_held_steel() {
         use crate::joker::*;
-        // Same math as test_seal_red_retrigger_steel_held (120), Mime instead of Red Seal:
-        // mult 2 -> 3 (floor(2*1.5)) -> 4 (floor(3*1.5)); score = 30 * 4 = 120
+        // Same math as test_seal_red_retrigger_steel_held (135), Mime instead of Red Seal:
+        // mult 2 -> 3 (2*1.5) -> 4.5 (3*1.5); score = 30 * 4.5 = 135
         let mut g = Game::default();
         g.jokers.push(Jokers::Mime(Mime::default()));
         let jokers = g.jokers.clone();
@@ -3005,7 +4763,7 @@ mod tests {
         steel_king.enhancement = Some(Enhancement::Steel);
         g.available.extend(vec![steel_king]);
         let hand = SelectHand::new(vec![king1, king2]).best_hand().unwrap();
-        assert_eq!(g.calc_score(hand), 120);
+        assert_eq!(g.calc_score(hand), 135);
     }
 
     #[test]
@@ -3021,7 +4779,7 @@ mod tests {
         assert_eq!(g.consumables.len(), 0);
         g.discard_selected().expect("can discard");
         assert_eq!(g.consumables.len(), 1);
-        assert!(matches!(g.consumables[0], Consumable::Tarot(_)));
+        assert!(matches!(*g.consumables[0], Consumable::Tarot(_)));
     }
 
     #[test]
@@ -3059,7 +4817,8 @@ mod tests {
         g.play_selected().expect("can play selected");
 
         assert_eq!(g.consumables.len(), 1);
-        assert!(matches!(g.consumables[0], Consumable::Planet(_)));
+        let rank = g.last_hand_played.expect("hand played").scoring_rank();
+        assert!(matches!(*g.consumables[0], Consumable::Planet(p) if p.hand_rank() == rank));
     }
 
     #[test]
@@ -3180,9 +4939,9 @@ mod tests {
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
         g.stage = Stage::Blind(Blind::Small);
-        // TheJoker fires (+4 mult), then Polychrome x1.5: floor((1+4)*1.5) = 7
-        // (5+11) * 7 = 112
-        assert_eq!(g.calc_score(hand), 112);
+        // TheJoker fires (+4 mult), then Polychrome x1.5: (1+4)*1.5 = 7.5
+        // (5+11) * 7.5 = 120
+        assert_eq!(g.calc_score(hand), 120);
     }
 
     #[test]
@@ -3222,12 +4981,50 @@ mod tests {
     }
 
     #[test]
-    fn test_destroy_card() {
+    fn test_negative_joker_fits_full_slots_on_any_path() {
+        use crate::joker::Jokers;
+        use balatro_types::joker::TheJoker;
         let mut g = Game::default();
-        g.deal();
-        let card = g.available.cards()[0];
-        let available_before = g.available.cards().len();
-        g.destroy_card(card.id);
+        let slots_before = g.config.joker_slots;
+        for _ in 0..slots_before {
+            g.add_joker(Jokers::TheJoker(TheJoker::default()));
+        }
+        let plain = Jokers::TheJoker(TheJoker::default());
+        let mut negative = plain.clone();
+        negative.set_edition(Edition::Negative);
+        assert!(!g.has_joker_room(&plain));
+        assert!(g.has_joker_room(&negative));
+
+        // created jokers (Riff-raff, The Soul, Judgement) go the same way
+        g.add_joker(negative);
+        assert_eq!(g.config.joker_slots, slots_before + 1);
+        g.remove_joker(slots_before);
+        assert_eq!(g.config.joker_slots, slots_before);
+    }
+
+    #[test]
+    fn test_negative_joker_at_slot_cap_takes_no_slot_away() {
+        use crate::joker::Jokers;
+        use balatro_types::joker::TheJoker;
+        let mut g = Game::default();
+        g.config.joker_slots = g.config.joker_slots_max;
+        let mut negative = Jokers::TheJoker(TheJoker::default());
+        negative.set_edition(Edition::Negative);
+
+        // capped on the way in, so nothing to give back on the way out
+        g.add_joker(negative);
+        assert_eq!(g.config.joker_slots, g.config.joker_slots_max);
+        g.remove_joker(0);
+        assert_eq!(g.config.joker_slots, g.config.joker_slots_max);
+    }
+
+    #[test]
+    fn test_destroy_card() {
+        let mut g = Game::default();
+        g.deal();
+        let card = g.available.cards()[0];
+        let available_before = g.available.cards().len();
+        g.destroy_card(card.id);
         assert_eq!(g.available.cards().len(), available_before - 1);
         assert!(g.available.cards().iter().all(|c| c.id != card.id));
     }
@@ -3277,6 +5074,37 @@ mod tests {
         assert_eq!(g2.deck.len(), g.deck.len());
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_roundtrip_keeps_fractional_mult_and_naneinf() {
+        let g = Game {
+            mult: BigNum::new(4.5),
+            score: BigNum::NANEINF,
+            ..Default::default()
+        };
+        let json = g.to_json().expect("serialize");
+        assert!(json.contains("\"score\":\"naneinf\""));
+        let g2 = Game::from_json(&json).expect("deserialize");
+        assert_eq!(g2.mult, 4.5);
+        assert!(g2.score.is_naneinf());
+    }
+
+    #[test]
+    fn test_naneinf_score_beats_blind() {
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Small),
+            blind: Some(Blind::Small),
+            chips: BigNum::new(1e300),
+            mult: BigNum::new(1e300),
+            ..Default::default()
+        };
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
+        let score = g.calc_score(hand);
+        assert!(score.is_naneinf());
+        assert!(g.handle_score(score).unwrap());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_from_json_restores_effect_registry() {
@@ -3395,11 +5223,13 @@ mod tests {
         g.blind = Some(Blind::Small);
         g.deal();
         // no cards selected — Magician needs at least 1
-        g.consumables = vec![Consumable::Tarot(Tarot::Magician)];
+        g.consumables = vec![Consumable::Tarot(Tarot::Magician).into()];
         let res = g.use_consumable(Consumable::Tarot(Tarot::Magician));
         assert!(matches!(res, Err(GameError::InvalidAction)));
         assert_eq!(g.consumables.len(), 1);
-        assert!(g.consumables.contains(&Consumable::Tarot(Tarot::Magician)));
+        assert!(g
+            .held_consumables()
+            .contains(&Consumable::Tarot(Tarot::Magician)));
     }
 
     #[test]
@@ -3413,7 +5243,7 @@ mod tests {
         let cards = g.available.cards();
         g.available.select_card(cards[0]).unwrap();
         g.available.select_card(cards[1]).unwrap();
-        g.consumables = vec![Consumable::Tarot(Tarot::Lovers)];
+        g.consumables = vec![Consumable::Tarot(Tarot::Lovers).into()];
         let res = g.use_consumable(Consumable::Tarot(Tarot::Lovers));
         assert!(matches!(res, Err(GameError::InvalidAction)));
         assert_eq!(g.consumables.len(), 1);
@@ -3434,7 +5264,7 @@ mod tests {
 
         g.sell_joker(0).expect("sell joker");
         assert_eq!(g.jokers.len(), 0);
-        assert_eq!(g.money, sell_value);
+        assert_eq!(g.money, sell_value as i64);
     }
 
     #[test]
@@ -3473,7 +5303,7 @@ mod tests {
 
         g.sell_joker(0).expect("sell joker during blind");
         assert_eq!(g.jokers.len(), 0);
-        assert_eq!(g.money, sell_value);
+        assert_eq!(g.money, sell_value as i64);
     }
 
     #[test]
@@ -3513,6 +5343,66 @@ mod tests {
         assert_eq!(g.discards(), 4);
     }
 
+    #[test]
+    fn test_move_joker_changes_scoring_order() {
+        use crate::joker::Jokers;
+        use balatro_types::joker::{Acrobat, TheJoker};
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Small),
+            blind: Some(Blind::Small),
+            plays: 0,
+            jokers: vec![
+                Jokers::TheJoker(TheJoker::default()),
+                Jokers::Acrobat(Acrobat::default()),
+            ],
+            ..Default::default()
+        };
+        g.register_jokers();
+        let ace = || {
+            SelectHand::new(vec![Card::new(Value::Ace, Suit::Heart)])
+                .best_hand()
+                .unwrap()
+        };
+        // (5 + 11) * ((1 + 4) * 3) = 240
+        assert_eq!(g.calc_score(ace()), 240);
+
+        g.handle_action(Action::MoveJoker(MoveDirection::Left, 1))
+            .expect("move joker");
+        assert!(matches!(g.jokers[0], Jokers::Acrobat(_)));
+        // (5 + 11) * (1 * 3 + 4) = 112
+        assert_eq!(g.calc_score(ace()), 112);
+
+        let res = g.move_joker(MoveDirection::Left, 0);
+        assert!(matches!(res, Err(GameError::InvalidMoveDirection)));
+        let res = g.move_joker(MoveDirection::Right, 2);
+        assert!(matches!(res, Err(GameError::InvalidAction)));
+    }
+
+    #[test]
+    fn test_move_consumable_keeps_value_and_negative() {
+        use crate::planet::Planets;
+        let mut g = Game {
+            stage: Stage::Shop(),
+            consumables: vec![HeldConsumable {
+                consumable: Consumable::Planet(Planets::Mercury),
+                extra_value: 3,
+                negative: false,
+            }],
+            ..Default::default()
+        };
+        g.add_negative_consumable(Consumable::Tarot(Tarot::Fool));
+
+        g.handle_action(Action::MoveConsumable(MoveDirection::Right, 0))
+            .expect("move consumable");
+        assert_eq!(g.consumables[1], Consumable::Planet(Planets::Mercury));
+        assert_eq!(g.consumable_sell_value(1), 1 + 3);
+        assert!(g.is_consumable_negative(0));
+        assert!(!g.is_consumable_negative(1));
+
+        let res = g.move_consumable(MoveDirection::Right, 1);
+        assert!(matches!(res, Err(GameError::InvalidMoveDirection)));
+    }
+
     #[test]
     fn test_sell_consumable() {
         use crate::planet::Planets;
@@ -3522,12 +5412,12 @@ mod tests {
         g.money = 0;
         let c = Consumable::Planet(Planets::Mercury);
         let sell_value = c.sell_value();
-        g.consumables.push(c);
+        g.consumables.push(c.into());
         assert_eq!(g.consumables.len(), 1);
 
         g.sell_consumable(0).expect("sell consumable");
         assert_eq!(g.consumables.len(), 0);
-        assert_eq!(g.money, sell_value);
+        assert_eq!(g.money, sell_value as i64);
     }
 
     #[test]
@@ -3545,7 +5435,7 @@ mod tests {
         g.start();
         g.stage = Stage::End(crate::stage::End::Win);
         g.consumables
-            .push(Consumable::Tarot(crate::tarot::Tarot::Fool));
+            .push(Consumable::Tarot(crate::tarot::Tarot::Fool).into());
         let res = g.sell_consumable(0);
         assert!(matches!(res, Err(GameError::InvalidStage)));
     }
@@ -3564,7 +5454,7 @@ mod tests {
 
         g.sell_joker(0).expect("sell joker during tarot hand");
         assert_eq!(g.jokers.len(), 0);
-        assert_eq!(g.money, sell_value);
+        assert_eq!(g.money, sell_value as i64);
     }
 
     #[test]
@@ -3576,12 +5466,12 @@ mod tests {
         g.money = 0;
         let c = Consumable::Planet(Planets::Mercury);
         let sell_value = c.sell_value();
-        g.consumables.push(c);
+        g.consumables.push(c.into());
 
         g.sell_consumable(0)
             .expect("sell consumable during tarot hand");
         assert_eq!(g.consumables.len(), 0);
-        assert_eq!(g.money, sell_value);
+        assert_eq!(g.money, sell_value as i64);
     }
 
     #[test]
@@ -3619,7 +5509,7 @@ mod tests {
         g.shop.packs.push(pack.clone());
         g.buy_pack(pack).expect("buy pack");
         assert_eq!(g.stage, Stage::PackOpen());
-        assert_eq!(g.money, 100 - cost);
+        assert_eq!(g.money, (100 - cost) as i64);
         let state = g.open_pack.as_ref().expect("open pack state");
         assert_eq!(state.picks_remaining, picks);
     }
@@ -3644,6 +5534,7 @@ mod tests {
         let mut g = Game::default();
         g.start();
         assert!(g.tags.is_empty());
+        g.small_blind_tag = Tag::Coupon;
         g.skip_blind(Blind::Small).expect("skip small blind");
         assert_eq!(g.blind, Some(Blind::Small));
         assert_eq!(g.stage, Stage::PreBlind());
@@ -3681,6 +5572,8 @@ mod tests {
     fn test_skip_blind_draws_a_real_tag() {
         let mut g = Game::default();
         g.start();
+        // a shop tag, so it's still held after the skip
+        g.small_blind_tag = Tag::Rare;
         let expected = g.small_blind_tag;
         g.skip_blind(Blind::Small).expect("skip small blind");
         assert_eq!(g.tags[0], expected);
@@ -3702,10 +5595,11 @@ mod tests {
             ..Default::default()
         };
         let ante_before = g.ante_current;
-        g.handle_score(1_000_000).expect("handle score");
+        g.handle_score(1_000_000.into()).expect("handle score");
         assert_ne!(g.ante_current, ante_before);
-        assert!(Tag::iter().any(|t| t == g.small_blind_tag));
-        assert!(Tag::iter().any(|t| t == g.big_blind_tag));
+        // by name, an Orbital tag carries the hand it was offered with
+        assert!(Tag::iter().any(|t| t.name() == g.small_blind_tag.name()));
+        assert!(Tag::iter().any(|t| t.name() == g.big_blind_tag.name()));
     }
 
     #[test]
@@ -3722,17 +5616,19 @@ mod tests {
             blind: Some(Blind::Boss),
             ..Default::default()
         };
-        g.handle_score(1_000_000).expect("handle score");
+        g.handle_score(1_000_000.into()).expect("handle score");
         let boss = g
             .current_boss
             .expect("current_boss redrawn after ante advance");
-        assert_eq!(boss.is_finisher(), g.ante_current as i32 % 8 == 0);
+        let ante = g.ante_current.num() as i32;
+        assert_eq!(boss.is_finisher(), ante >= 2 && ante % 8 == 0);
     }
 
     #[test]
     fn test_skip_blind_banks_the_predrawn_tag() {
         let mut g = Game::default();
         g.start();
+        g.small_blind_tag = Tag::D6;
         let expected = g.small_blind_tag;
         g.skip_blind(Blind::Small).expect("skip small blind");
         assert_eq!(g.tags, vec![expected]);
@@ -3744,9 +5640,10 @@ mod tests {
             stage: Stage::PostBlind(),
             ..Default::default()
         };
-        g.tags = vec![Tag::Handy, Tag::Garbage];
+        g.tags = vec![Tag::D6, Tag::Coupon];
         g.cashout().expect("cashout");
         assert!(g.tags.is_empty());
+        assert_eq!(g.reroll_cost, 0);
     }
 
     #[test]
@@ -3764,6 +5661,328 @@ mod tests {
         assert_eq!(g.tags, vec![Tag::Handy]);
     }
 
+    #[test]
+    fn test_economy_tag_doubles_money_capped() {
+        let mut g = Game::default();
+        g.start();
+        g.small_blind_tag = Tag::Economy;
+        g.money = 10;
+        g.skip_blind(Blind::Small).expect("skip small blind");
+        assert_eq!(g.money, 20);
+        assert!(g.tags.is_empty());
+
+        g.big_blind_tag = Tag::Economy;
+        g.money = 100;
+        g.skip_blind(Blind::Big).expect("skip big blind");
+        assert_eq!(g.money, 140);
+    }
+
+    #[test]
+    fn test_run_total_tags_pay_out_on_skip() {
+        let mut g = Game::default();
+        g.start();
+        g.money = 0;
+        g.total_hands_played = 7;
+        g.total_unused_discards = 4;
+        g.small_blind_tag = Tag::Speed;
+        g.skip_blind(Blind::Small).expect("skip small blind");
+        // this skip counts too
+        assert_eq!(g.money, 5);
+
+        g.tags = vec![Tag::Handy, Tag::Garbage];
+        g.trigger_tags(TagTrigger::Immediate);
+        assert_eq!(g.money, 5 + 7 + 4);
+        assert!(g.tags.is_empty());
+    }
+
+    #[test]
+    fn test_double_tag_copies_next_tag() {
+        let mut g = Game::default();
+        g.start();
+        g.small_blind_tag = Tag::Double;
+        g.skip_blind(Blind::Small).expect("skip small blind");
+        assert_eq!(g.tags, vec![Tag::Double]);
+
+        g.big_blind_tag = Tag::Coupon;
+        g.skip_blind(Blind::Big).expect("skip big blind");
+        assert_eq!(g.tags, vec![Tag::Coupon, Tag::Coupon]);
+    }
+
+    #[test]
+    fn test_juggle_tag_raises_hand_size_next_round_only() {
+        let mut g = Game::default();
+        g.start();
+        g.small_blind_tag = Tag::Juggle;
+        g.skip_blind(Blind::Small).expect("skip small blind");
+        assert_eq!(g.tags, vec![Tag::Juggle]);
+
+        g.select_blind(Blind::Big).expect("select big blind");
+        assert!(g.tags.is_empty());
+        assert_eq!(g.available.cards().len(), g.config.available + 3);
+
+        g.clear_blind();
+        assert_eq!(g.hand_size(), g.config.available);
+    }
+
+    #[test]
+    fn test_pack_tag_opens_free_pack_and_returns_to_preblind() {
+        let mut g = Game::default();
+        g.start();
+        g.money = 0;
+        g.small_blind_tag = Tag::Buffoon;
+        g.skip_blind(Blind::Small).expect("skip small blind");
+        assert_eq!(g.stage, Stage::PackOpen());
+        assert_eq!(g.money, 0);
+        assert_eq!(g.open_pack.as_ref().unwrap().picks_remaining, 2);
+        assert!(g.tags.is_empty());
+
+        g.skip_pack().expect("skip pack");
+        assert_eq!(g.stage, Stage::PreBlind());
+        assert!(g.open_pack.is_none());
+    }
+
+    #[test]
+    fn test_second_pack_tag_waits_for_first_pack() {
+        let mut g = Game::default();
+        g.start();
+        g.small_blind_tag = Tag::Double;
+        g.skip_blind(Blind::Small).expect("skip small blind");
+        g.big_blind_tag = Tag::Ethereal;
+        g.skip_blind(Blind::Big).expect("skip big blind");
+        assert_eq!(g.stage, Stage::PackOpen());
+        assert_eq!(g.tags, vec![Tag::Ethereal]);
+        // Spectral packs draw a hand to target
+        assert_eq!(g.available.cards().len(), g.config.available);
+
+        g.skip_pack().expect("skip first pack");
+        assert_eq!(g.stage, Stage::PackOpen());
+        assert!(g.tags.is_empty());
+
+        g.skip_pack().expect("skip second pack");
+        assert_eq!(g.stage, Stage::PreBlind());
+        assert!(g.available.cards().is_empty());
+        assert_eq!(g.deck.len(), 52);
+    }
+
+    #[test]
+    fn test_rare_tag_adds_free_rare_joker_to_next_shop() {
+        let mut g = Game {
+            stage: Stage::PostBlind(),
+            ..Default::default()
+        };
+        g.money = 0;
+        g.tags = vec![Tag::Rare];
+        g.cashout().expect("cashout");
+        assert!(g.tags.is_empty());
+        let joker = g.shop.jokers[0].clone();
+        assert_eq!(joker.rarity(), Rarity::Rare);
+        assert_eq!(g.shop.joker_cost(&joker), 0);
+        g.buy_joker(joker.clone()).expect("buy free joker");
+        assert_eq!(g.jokers, vec![joker]);
+        assert_eq!(g.money, 0);
+    }
+
+    #[test]
+    fn test_edition_tag_applies_to_base_shop_joker() {
+        let mut g = Game {
+            stage: Stage::Shop(),
+            ..Default::default()
+        };
+        g.shop.jokers = vec![Jokers::TheJoker(Default::default())];
+        g.tags = vec![Tag::Polychrome];
+        g.trigger_tags(TagTrigger::Shop);
+        assert!(g.tags.is_empty());
+        let joker = g.shop.jokers[0].clone();
+        assert_eq!(joker.edition(), Edition::Polychrome);
+        assert_eq!(g.shop.joker_cost(&joker), 0);
+    }
+
+    #[test]
+    fn test_edition_tag_held_without_base_shop_joker() {
+        let mut g = Game {
+            stage: Stage::Shop(),
+            ..Default::default()
+        };
+        g.shop.jokers = vec![];
+        g.tags = vec![Tag::Foil];
+        g.trigger_tags(TagTrigger::Shop);
+        assert_eq!(g.tags, vec![Tag::Foil]);
+    }
+
+    #[test]
+    fn test_coupon_tag_makes_initial_shop_free() {
+        let mut g = Game {
+            stage: Stage::PostBlind(),
+            ..Default::default()
+        };
+        g.money = 0;
+        g.tags = vec![Tag::Coupon];
+        g.cashout().expect("cashout");
+        let pack = g.shop.packs[0].clone();
+        assert_eq!(g.shop.pack_cost(&pack), 0);
+        assert!(g
+            .gen_actions()
+            .any(|a| matches!(a, Action::BuyPack(ref p) if *p == pack)));
+        g.buy_pack(pack).expect("buy free pack");
+        assert_eq!(g.money, 0);
+    }
+
+    #[test]
+    fn test_investment_tag_pays_after_boss() {
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Boss),
+            blind: Some(Blind::Boss),
+            ..Default::default()
+        };
+        g.money = 0;
+        g.tags = vec![Tag::Investment];
+        g.handle_score(1_000_000.into()).expect("handle score");
+        assert_eq!(
+            g.round_reward.amount(&RewardSource::Tag(Tag::Investment)),
+            25
+        );
+        assert!(g.tags.is_empty());
+        let total = g.round_reward.total();
+        g.cashout().expect("cash out");
+        assert_eq!(g.money, total as i64);
+    }
+
+    #[test]
+    fn test_round_reward_itemised() {
+        let mut g = Game {
+            money: 23,
+            plays: 2,
+            ..Default::default()
+        };
+        let reward = g.calc_reward(Blind::Big).unwrap();
+        assert_eq!(reward.amount(&RewardSource::Blind(Blind::Big)), 4);
+        assert_eq!(reward.amount(&RewardSource::Hands), 2);
+        assert_eq!(reward.amount(&RewardSource::Interest), 4);
+        assert_eq!(reward.total(), 10);
+    }
+
+    #[test]
+    fn test_economy_jokers_pay_at_cash_out() {
+        use crate::joker::{mint_joker_id, GoldenJoker, Rocket, ToTheMoon};
+        let mut golden = Jokers::GoldenJoker(GoldenJoker::default());
+        golden.set_instance_id(mint_joker_id());
+        let mut rocket = Jokers::Rocket(Rocket::default());
+        rocket.set_instance_id(mint_joker_id());
+        let mut moon = Jokers::ToTheMoon(ToTheMoon::default());
+        moon.set_instance_id(mint_joker_id());
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Boss),
+            blind: Some(Blind::Boss),
+            money: 10,
+            jokers: vec![golden.clone(), rocket, moon],
+            ..Default::default()
+        };
+        g.register_jokers();
+        g.handle_score(BigNum::NANEINF).expect("handle score");
+        let reward = &g.round_reward;
+        assert_eq!(reward.amount(&RewardSource::Joker(golden)), 4);
+        // Rocket: $1, plus $2 for the boss just beaten
+        let rocket_line = reward
+            .lines
+            .iter()
+            .find(|l| matches!(l.source, RewardSource::Joker(Jokers::Rocket(_))))
+            .expect("rocket line");
+        assert_eq!(rocket_line.amount, 3);
+        // To the Moon: $2 per $5 instead of $1
+        assert_eq!(reward.amount(&RewardSource::Interest), 4);
+    }
+
+    #[test]
+    fn test_cloud9_satellite_delayed_gratification_payouts() {
+        use crate::joker::{Cloud9, DelayedGratification, Satellite};
+        let cloud9 = Jokers::Cloud9(Cloud9::default());
+        let satellite = Jokers::Satellite(Satellite::default());
+        let delayed = Jokers::DelayedGratification(DelayedGratification::default());
+        let mut g = Game {
+            jokers: vec![cloud9.clone(), satellite.clone(), delayed.clone()],
+            ..Default::default()
+        };
+        g.planets_used = HashSet::from([Planets::Mercury, Planets::Pluto]);
+        let nines = g
+            .full_deck()
+            .iter()
+            .filter(|c| c.value == crate::card::Value::Nine)
+            .count();
+        let reward = g.calc_reward(Blind::Small).unwrap();
+        assert_eq!(reward.amount(&RewardSource::Joker(cloud9.clone())), nines);
+        assert_eq!(reward.amount(&RewardSource::Joker(satellite)), 2);
+        assert_eq!(
+            reward.amount(&RewardSource::Joker(delayed.clone())),
+            g.discards() * 2
+        );
+
+        g.discarded_this_round
+            .push(Card::new(crate::card::Value::Two, Suit::Club));
+        let reward = g.calc_reward(Blind::Small).unwrap();
+        assert_eq!(reward.amount(&RewardSource::Joker(delayed)), 0);
+    }
+
+    #[test]
+    fn test_egg_and_gift_card_raise_sell_values() {
+        use crate::joker::{mint_joker_id, Egg, GiftCard};
+        let mut egg = Jokers::Egg(Egg::default());
+        egg.set_instance_id(mint_joker_id());
+        let mut gift = Jokers::GiftCard(GiftCard::default());
+        gift.set_instance_id(mint_joker_id());
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Small),
+            blind: Some(Blind::Small),
+            jokers: vec![egg.clone(), gift.clone()],
+            consumables: vec![Consumable::Tarot(Tarot::Fool).into()],
+            ..Default::default()
+        };
+        g.register_jokers();
+        g.handle_score(BigNum::NANEINF).expect("handle score");
+        assert_eq!(g.jokers[0].sell_value(), egg.sell_value() + 3 + 1);
+        assert_eq!(g.jokers[1].sell_value(), gift.sell_value() + 1);
+        assert_eq!(g.consumable_sell_value(0), 2);
+
+        let money = g.money;
+        g.sell_consumable(0).unwrap();
+        assert_eq!(g.money, money + 2);
+        assert!(g.consumables.is_empty());
+    }
+
+    #[test]
+    fn test_top_up_tag_respects_joker_slots() {
+        let mut g = Game::default();
+        g.start();
+        g.config.joker_slots = 1;
+        g.small_blind_tag = Tag::TopUp;
+        g.skip_blind(Blind::Small).expect("skip small blind");
+        assert_eq!(g.jokers.len(), 1);
+        assert_eq!(g.jokers[0].rarity(), Rarity::Common);
+    }
+
+    #[test]
+    fn test_orbital_tag_levels_the_offered_hand() {
+        let mut g = Game::default();
+        g.start();
+        let before = g.planetarium.level(HandRank::Flush).level;
+        g.small_blind_tag = Tag::Orbital(HandRank::Flush);
+        g.skip_blind(Blind::Small).expect("skip small blind");
+        assert_eq!(g.planetarium.level(HandRank::Flush).level, before + 3);
+        assert_eq!(g.planetarium.level(HandRank::OnePair).level, 1);
+    }
+
+    #[test]
+    fn test_offer_tag_rolls_orbital_hand() {
+        let mut g = Game::default();
+        g.start();
+        let offered = (0..20)
+            .map(|_| g.offer_tag(Tag::Orbital(HandRank::HighCard)))
+            .collect::<Vec<_>>();
+        assert!(offered
+            .iter()
+            .any(|t| *t != Tag::Orbital(HandRank::HighCard)));
+        assert_eq!(g.offer_tag(Tag::Rare), Tag::Rare);
+    }
+
     #[test]
     fn test_pick_pack_card_planet() {
         use crate::planet::Planets;
@@ -3975,4 +6194,548 @@ mod tests {
             .any(|j| std::mem::discriminant(j) == std::mem::discriminant(&old_joker));
         assert!(!still_present);
     }
+
+    #[test]
+    fn test_ante_voucher_offered_from_game_start() {
+        let g = Game::new(Config::default());
+        assert_eq!(g.shop.vouchers.len(), 1);
+        // nothing redeemed yet, so no upgrade can show up
+        assert!(g.shop.vouchers[0].requires().is_none());
+    }
+
+    #[test]
+    fn test_ante_voucher_redrawn_after_boss_defeated() {
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Boss),
+            blind: Some(Blind::Boss),
+            ..Default::default()
+        };
+        g.shop.vouchers.clear();
+        g.boss_rerolls_this_ante = 1;
+        g.handle_score(1_000_000.into()).expect("handle score");
+        assert_eq!(g.shop.vouchers.len(), 1);
+        assert_eq!(g.boss_rerolls_this_ante, 0);
+    }
+
+    #[test]
+    fn test_upgrade_voucher_only_drawn_after_base() {
+        let mut g = Game {
+            vouchers: Voucher::iter()
+                .filter(|v| *v != Voucher::Overstock && v.requires().is_none())
+                .collect(),
+            ..Default::default()
+        };
+        // every base but Overstock redeemed: Overstock itself, or any upgrade
+        // but Overstock Plus
+        for _ in 0..50 {
+            let v = g
+                .backend
+                .draw_voucher(1, &g.vouchers, &[])
+                .expect("pool not empty");
+            assert_ne!(v, Voucher::OverstockPlus);
+            assert!(!g.vouchers.contains(&v));
+        }
+        g.vouchers = Voucher::iter().collect();
+        assert_eq!(g.backend.draw_voucher(1, &g.vouchers, &[]), None);
+    }
+
+    #[test]
+    fn test_buy_voucher() {
+        let mut g = Game {
+            stage: Stage::Shop(),
+            ..Default::default()
+        };
+        g.shop.vouchers = vec![Voucher::Grabber];
+        g.money = 9;
+        assert!(matches!(
+            g.buy_voucher(Voucher::Grabber),
+            Err(GameError::InvalidBalance)
+        ));
+        g.money = 10;
+        g.buy_voucher(Voucher::Grabber).expect("buy voucher");
+        assert_eq!(g.money, 0);
+        assert!(g.has_voucher(Voucher::Grabber));
+        assert!(g.shop.vouchers.is_empty());
+        assert_eq!(g.config.plays, Config::default().plays + 1);
+        assert_eq!(g.plays, Config::default().plays + 1);
+        g.money = 10;
+        assert!(matches!(
+            g.buy_voucher(Voucher::Grabber),
+            Err(GameError::NoVoucherMatch)
+        ));
+    }
+
+    #[test]
+    fn test_buy_voucher_wrong_stage() {
+        let mut g = Game {
+            money: 10,
+            ..Default::default()
+        };
+        g.shop.vouchers = vec![Voucher::Blank];
+        assert!(matches!(
+            g.buy_voucher(Voucher::Blank),
+            Err(GameError::InvalidStage)
+        ));
+    }
+
+    #[test]
+    fn test_overstock_adds_shop_slot() {
+        let mut g = Game::default();
+        g.redeem_voucher(Voucher::Overstock);
+        g.redeem_voucher(Voucher::OverstockPlus);
+        g.stage = Stage::PostBlind();
+        g.cashout().expect("cashout");
+        assert_eq!(g.shop.jokers.len() + g.shop.consumables.len(), 4);
+        assert!(g.gen_action_space().size() > 0);
+    }
+
+    #[test]
+    fn test_clearance_sale_and_liquidation_discount() {
+        let mut g = Game::default();
+        let voucher = Voucher::Blank;
+        assert_eq!(g.shop.voucher_cost(&voucher), 10);
+        g.redeem_voucher(Voucher::ClearanceSale);
+        assert_eq!(g.shop.voucher_cost(&voucher), 7);
+        g.redeem_voucher(Voucher::Liquidation);
+        assert_eq!(g.shop.voucher_cost(&voucher), 5);
+    }
+
+    #[test]
+    fn test_reroll_surplus_and_glut() {
+        let mut g = Game {
+            stage: Stage::PostBlind(),
+            ..Default::default()
+        };
+        g.redeem_voucher(Voucher::RerollSurplus);
+        assert_eq!(g.reroll_cost, 3);
+        g.redeem_voucher(Voucher::RerollGlut);
+        assert_eq!(g.reroll_cost, 1);
+        g.cashout().expect("cashout");
+        assert_eq!(g.reroll_cost, 1);
+    }
+
+    #[test]
+    fn test_slot_and_round_vouchers() {
+        let mut g = Game::default();
+        g.redeem_voucher(Voucher::CrystalBall);
+        g.redeem_voucher(Voucher::Wasteful);
+        g.redeem_voucher(Voucher::PaintBrush);
+        g.redeem_voucher(Voucher::SeedMoney);
+        g.redeem_voucher(Voucher::Antimatter);
+        let d = Config::default();
+        assert_eq!(g.config.consumable_slots, d.consumable_slots + 1);
+        assert_eq!(g.config.discards, d.discards + 1);
+        assert_eq!(g.discards(), d.discards + 1);
+        assert_eq!(g.hand_size(), d.available + 1);
+        assert_eq!(g.config.interest_max, d.interest_max + 5);
+        assert_eq!(g.config.joker_slots, d.joker_slots + 1);
+    }
+
+    #[test]
+    fn test_hieroglyph_and_petroglyph_roll_back_ante() {
+        let mut g = Game {
+            ante_current: Ante::Three,
+            ..Default::default()
+        };
+        g.redeem_voucher(Voucher::Hieroglyph);
+        assert_eq!(g.ante_current, Ante::Two);
+        assert_eq!(g.config.plays, Config::default().plays - 1);
+        g.redeem_voucher(Voucher::Petroglyph);
+        assert_eq!(g.ante_current, Ante::One);
+        assert_eq!(g.config.discards, Config::default().discards - 1);
+    }
+
+    #[test]
+    fn test_directors_cut_rerolls_boss_once_per_ante() {
+        let mut g = Game::default();
+        g.start();
+        g.money = 20;
+        assert!(!g.can_reroll_boss());
+        assert!(matches!(g.reroll_boss(), Err(GameError::InvalidAction)));
+        g.redeem_voucher(Voucher::DirectorsCut);
+        assert!(g.gen_actions().any(|a| a == Action::RerollBoss()));
+        g.handle_action(Action::RerollBoss()).expect("reroll boss");
+        assert_eq!(g.money, 10);
+        assert!(g.current_boss.is_some());
+        assert!(!g.can_reroll_boss());
+    }
+
+    #[test]
+    fn test_retcon_rerolls_boss_unlimited() {
+        let mut g = Game::default();
+        g.start();
+        g.money = 30;
+        g.vouchers = vec![Voucher::DirectorsCut, Voucher::Retcon];
+        for _ in 0..3 {
+            g.reroll_boss().expect("reroll boss");
+        }
+        assert_eq!(g.money, 0);
+        // not once the boss blind is up
+        g.money = 10;
+        g.blind = Some(Blind::Boss);
+        assert!(!g.can_reroll_boss());
+    }
+
+    #[test]
+    fn test_observatory_held_planet_multiplies() {
+        let mut g = Game::default();
+        let king = Card::new(Value::King, Suit::Diamond);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        g.consumables = vec![
+            Consumable::Planet(Planets::Mercury).into(),
+            Consumable::Planet(Planets::Pluto).into(),
+        ];
+        g.vouchers = vec![Voucher::Telescope, Voucher::Observatory];
+        // (10 + 20) * (2 * 1.5) = 90, Pluto isn't for pairs
+        let hand = SelectHand::new(vec![king, king, ace]).best_hand().unwrap();
+        let (score, trace) = g.calc_score_traced(hand);
+        assert_eq!(score, 90);
+        let mercury = ScoreSource::Consumable(Consumable::Planet(Planets::Mercury));
+        assert!(trace.0.iter().any(|step| step.source == mercury));
+    }
+
+    #[test]
+    fn test_telescope_celestial_pack_has_most_played_planet() {
+        let mut g = Game {
+            stage: Stage::Shop(),
+            ..Default::default()
+        };
+        g.vouchers = vec![Voucher::Telescope];
+        g.planetarium.play(HandRank::Flush);
+        let pack = Pack {
+            category: PackCategory::Celestial,
+            size: PackSize::Normal,
+            contents: vec![
+                PackContent::Planet(Planets::Pluto),
+                PackContent::Planet(Planets::Mercury),
+                PackContent::Planet(Planets::Uranus),
+            ],
+        };
+        g.open(pack);
+        let contents = &g.open_pack.as_ref().expect("pack open").contents;
+        assert_eq!(contents[0], PackContent::Planet(Planets::Jupiter));
+    }
+
+    #[test]
+    fn test_voucher_tag_adds_second_voucher() {
+        let mut g = Game {
+            stage: Stage::PostBlind(),
+            ..Default::default()
+        };
+        let ante_voucher = g.shop.vouchers[0];
+        g.tags = vec![Tag::Voucher];
+        g.cashout().expect("cashout");
+        assert!(g.tags.is_empty());
+        assert_eq!(g.shop.vouchers.len(), 2);
+        assert_eq!(g.shop.vouchers[0], ante_voucher);
+        assert_ne!(g.shop.vouchers[1], ante_voucher);
+
+        // slots full, the next one waits
+        g.tags = vec![Tag::Voucher];
+        g.trigger_tags(TagTrigger::Shop);
+        assert_eq!(g.tags, vec![Tag::Voucher]);
+    }
+
+    #[test]
+    fn test_magic_trick_shop_playing_cards() {
+        let mut g = Game {
+            stage: Stage::Shop(),
+            ..Default::default()
+        };
+        g.redeem_voucher(Voucher::MagicTrick);
+        g.money = 1000;
+        let mut bought = false;
+        for _ in 0..30 {
+            g.reroll_cost = 0;
+            g.reroll().expect("reroll");
+            if let Some(card) = g.shop.playing_cards.first().copied() {
+                assert!(card.enhancement.is_none());
+                assert_eq!(card.seal, None);
+                let deck_before = g.deck.len();
+                g.handle_action(Action::BuyPlayingCard(card))
+                    .expect("buy playing card");
+                assert_eq!(g.deck.len(), deck_before + 1);
+                bought = true;
+                break;
+            }
+        }
+        assert!(bought);
+    }
+
+    fn deck_game(deck: DeckVariant) -> Game {
+        Game::new(Config {
+            deck: Some(deck),
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn test_deck_variants_adjust_starting_config() {
+        let base = Game::new(Config::default());
+
+        let g = deck_game(DeckVariant::Red);
+        assert_eq!(g.discards(), base.discards() + 1);
+        let g = deck_game(DeckVariant::Blue);
+        assert_eq!(g.plays, base.plays + 1);
+        let g = deck_game(DeckVariant::Yellow);
+        assert_eq!(g.money, base.money + 10);
+        let g = deck_game(DeckVariant::Black);
+        assert_eq!(g.config.joker_slots, base.config.joker_slots + 1);
+        assert_eq!(g.plays, base.plays - 1);
+        let g = deck_game(DeckVariant::Painted);
+        assert_eq!(g.config.available, base.config.available + 2);
+        assert_eq!(g.config.joker_slots, base.config.joker_slots - 1);
+        let g = deck_game(DeckVariant::Abandoned);
+        assert_eq!(g.deck.len(), 40);
+    }
+
+    #[test]
+    fn test_deck_variants_starting_vouchers_and_consumables() {
+        let g = deck_game(DeckVariant::Magic);
+        assert!(g.has_voucher(Voucher::CrystalBall));
+        assert_eq!(g.consumables, vec![Consumable::Tarot(Tarot::Fool); 2]);
+
+        let base = Game::new(Config::default());
+        let g = deck_game(DeckVariant::Nebula);
+        assert!(g.has_voucher(Voucher::Telescope));
+        assert_eq!(g.config.consumable_slots, base.config.consumable_slots - 1);
+
+        let g = deck_game(DeckVariant::Ghost);
+        assert_eq!(
+            g.consumables,
+            vec![Consumable::Spectral(crate::spectral::Spectral::Hex)]
+        );
+
+        let g = deck_game(DeckVariant::Zodiac);
+        for v in [
+            Voucher::TarotMerchant,
+            Voucher::PlanetMerchant,
+            Voucher::Overstock,
+        ] {
+            assert!(g.has_voucher(v));
+            // already redeemed, never offered again
+            assert!(!g.shop.vouchers.contains(&v));
+        }
+    }
+
+    #[test]
+    fn test_green_deck_reward() {
+        let mut g = Game {
+            plays: 2,
+            discards_remaining: 3,
+            money: 50,
+            config: Config {
+                deck: Some(DeckVariant::Green),
+                ..Config::default()
+            },
+            ..Default::default()
+        };
+        // $3 blind + $2 x 2 hands + $1 x 3 discards, no interest
+        assert_eq!(g.calc_reward(Blind::Small).unwrap().total(), 3 + 4 + 3);
+    }
+
+    #[test]
+    fn test_anaglyph_deck_double_tag_after_boss() {
+        let mut g = deck_game(DeckVariant::Anaglyph);
+        g.stage = Stage::Blind(Blind::Boss);
+        g.blind = Some(Blind::Boss);
+        g.tags.clear();
+        g.handle_score(g.required_score()).expect("score");
+        assert_eq!(g.tags, vec![Tag::Double]);
+    }
+
+    #[test]
+    fn test_plasma_deck_balances_and_doubles_blinds() {
+        let base = Game::default();
+        let mut g = Game {
+            config: Config {
+                deck: Some(DeckVariant::Plasma),
+                ..Config::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(g.required_score(), base.required_score() * 2);
+
+        // Pair (level 1) -> chips=10, mult=2, plus 2 kings -> 30 chips
+        // balanced (30 + 2) / 2 = 16 -> 16 * 16 = 256
+        let king = Card::new(Value::King, Suit::Diamond);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let hand = SelectHand::new(vec![king, king, ace]).best_hand().unwrap();
+        assert_eq!(g.calc_score(hand), 256);
+    }
+
+    fn stake_game(stake: Stake) -> Game {
+        Game::new(Config {
+            stake,
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn test_red_stake_small_blind_no_reward() {
+        let mut g = stake_game(Stake::Red);
+        g.money = 0;
+        g.plays = 0;
+        assert_eq!(g.calc_reward(Blind::Small).unwrap().total(), 0);
+        assert_eq!(
+            g.calc_reward(Blind::Big).unwrap().total(),
+            Blind::Big.reward()
+        );
+
+        let mut g = stake_game(Stake::White);
+        g.money = 0;
+        g.plays = 0;
+        assert_eq!(
+            g.calc_reward(Blind::Small).unwrap().total(),
+            Blind::Small.reward()
+        );
+    }
+
+    #[test]
+    fn test_green_and_purple_stake_scale_faster() {
+        let mut white = stake_game(Stake::White);
+        let mut green = stake_game(Stake::Green);
+        let mut purple = stake_game(Stake::Purple);
+        for g in [&mut white, &mut green, &mut purple] {
+            g.ante_current = Ante::Three;
+        }
+        assert_eq!(white.required_score(), 2000);
+        assert_eq!(green.required_score(), 2600);
+        assert_eq!(purple.required_score(), 3200);
+    }
+
+    #[test]
+    fn test_blue_stake_one_less_discard() {
+        let white = stake_game(Stake::White);
+        let blue = stake_game(Stake::Blue);
+        assert_eq!(blue.discards(), white.discards() - 1);
+        assert_eq!(blue.config.discards, white.config.discards - 1);
+    }
+
+    #[test]
+    fn test_stake_shop_joker_stickers() {
+        let roll = |stake: Stake| {
+            let mut g = Game::new(Config {
+                stake,
+                seed: Some(7),
+                ..Config::default()
+            });
+            g.stage = Stage::Shop();
+            g.money = 1000;
+            let mut stickers = Vec::new();
+            for _ in 0..100 {
+                g.reroll_cost = 0;
+                g.reroll().expect("reroll");
+                stickers.extend(g.shop.jokers.iter().map(|j| j.stickers()));
+            }
+            stickers
+        };
+
+        assert!(roll(Stake::Blue).iter().all(|s| !s.perishable && !s.rental));
+        assert!(roll(Stake::Blue).iter().any(|s| s.eternal));
+        assert!(roll(Stake::Purple)
+            .iter()
+            .all(|s| *s == Stickers::default() || s.eternal));
+
+        let gold = roll(Stake::Gold);
+        assert!(gold.iter().any(|s| s.perishable));
+        assert!(gold.iter().any(|s| s.rental));
+        assert!(gold.iter().all(|s| !(s.eternal && s.perishable)));
+
+        let white = roll(Stake::White);
+        assert!(white.iter().all(|s| *s == Stickers::default()));
+    }
+
+    fn with_stickers(mut joker: Jokers, stickers: Stickers) -> Jokers {
+        joker.set_stickers(stickers);
+        joker
+    }
+
+    #[test]
+    fn test_eternal_joker_cannot_be_sold() {
+        let eternal = Stickers {
+            eternal: true,
+            ..Default::default()
+        };
+        let mut jokers = jokers_with_ids(2);
+        jokers[0] = with_stickers(jokers[0].clone(), eternal);
+        let mut g = Game {
+            stage: Stage::Shop(),
+            jokers,
+            ..Default::default()
+        };
+        assert!(matches!(g.sell_joker(0), Err(GameError::EternalJoker)));
+        let sells: Vec<Action> = g
+            .gen_actions()
+            .filter(|a| matches!(a, Action::SellJoker(_)))
+            .collect();
+        assert_eq!(sells, vec![Action::SellJoker(1)]);
+        g.sell_joker(1).expect("can sell");
+    }
+
+    #[test]
+    fn test_perishable_joker_debuffed_after_five_rounds() {
+        let perishable = Stickers {
+            perishable: true,
+            ..Default::default()
+        };
+        let joker = with_stickers(jokers_with_ids(1)[0].clone(), perishable);
+        let mut g = Game {
+            jokers: vec![joker],
+            ..Default::default()
+        };
+        g.register_jokers();
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        for _ in 0..4 {
+            g.tick_joker_stickers();
+        }
+        assert!(!g.is_joker_debuffed(&g.jokers[0]));
+        // Joker's +4 mult: (5 + 11) * 5 = 80
+        let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
+        assert_eq!(g.calc_score(hand), 80);
+
+        g.tick_joker_stickers();
+        assert!(g.is_joker_debuffed(&g.jokers[0]));
+        let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
+        assert_eq!(g.calc_score(hand), 16);
+    }
+
+    #[test]
+    fn test_rental_joker_costs() {
+        let rental = Stickers {
+            rental: true,
+            ..Default::default()
+        };
+        let joker = with_stickers(jokers_with_ids(1)[0].clone(), rental);
+        let g = Game::default();
+        assert_eq!(g.shop.joker_cost(&joker), 1);
+        assert_eq!(joker.sell_value(), 1);
+
+        let mut g = Game {
+            jokers: vec![joker],
+            money: 10,
+            ..Default::default()
+        };
+        g.tick_joker_stickers();
+        assert_eq!(g.money, 7);
+    }
+
+    #[test]
+    fn test_shop_stickers_respect_joker_compatibility() {
+        let mut g = Game::new(Config {
+            stake: Stake::Gold,
+            seed: Some(11),
+            ..Config::default()
+        });
+        g.stage = Stage::Shop();
+        for _ in 0..100 {
+            g.reroll_cost = 0;
+            g.reroll().expect("reroll");
+            for j in &g.shop.jokers {
+                let s = j.stickers();
+                assert!(!s.eternal || j.eternal_compatible());
+                assert!(!s.perishable || j.perishable_compatible());
+            }
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use crate::action::{Action, MoveDirection, SortBy};
-use crate::card::Edition;
+use crate::card::{Card, Edition};
 use crate::consumable::Consumable;
 use crate::game::Game;
 use crate::pack::PackContent;
@@ -14,7 +14,7 @@ impl Game {
             return None;
         }
         // Cannot select more than max
-        if self.available.selected().len() >= self.config.selected_max {
+        if self.available.selected().len() >= self.selected_max() {
             return None;
         }
         let combos = self
@@ -31,7 +31,14 @@ impl Game {
         if !self.stage.is_blind() {
             return None;
         }
-        let selected = self.available.selected();
+        // Cerulean Bell's forced card stays selected
+        let forced = self.forced_card();
+        let selected: Vec<Card> = self
+            .available
+            .selected()
+            .into_iter()
+            .filter(|c| Some(c.id) != forced)
+            .collect();
         if selected.is_empty() {
             return None;
         }
@@ -52,6 +59,10 @@ impl Game {
         if self.available.selected().is_empty() {
             return None;
         }
+        // Boss won't let this hand score, and there's another that would
+        if !self.is_hand_allowed(&self.available.selected()) && self.any_hand_allowed() {
+            return None;
+        }
         let combos = vec![Action::Play()].into_iter();
         Some(combos)
     }
@@ -120,7 +131,7 @@ impl Game {
         if self.stage != Stage::PostBlind() {
             return None;
         }
-        Some(vec![Action::CashOut(self.reward)].into_iter())
+        Some(vec![Action::CashOut()].into_iter())
     }
 
     // Get next round action
@@ -167,7 +178,7 @@ impl Game {
         if self.jokers.len() >= self.config.joker_slots {
             return None;
         }
-        self.shop.gen_moves_buy_joker(self.money)
+        self.shop.gen_moves_buy_joker(self.spendable())
     }
 
     // Get buy consumable actions
@@ -176,7 +187,7 @@ impl Game {
             return None;
         }
         self.shop.gen_moves_buy_consumable(
-            self.money,
+            self.spendable(),
             self.config.consumable_slots,
             self.consumables.len(),
         )
@@ -197,6 +208,7 @@ impl Game {
         let actions: Vec<Action> = self
             .consumables
             .iter()
+            .map(|c| c.consumable)
             .filter(|c| match c {
                 Consumable::Planet(_) => true,
                 // Hand-touching tarots are only usable during Blind, with a valid
@@ -215,7 +227,6 @@ impl Game {
                             && selected_count <= s.max_targets())
                 }
             })
-            .cloned()
             .map(Action::UseConsumable)
             .collect();
         if actions.is_empty() {
@@ -232,7 +243,11 @@ impl Game {
         if self.jokers.is_empty() {
             return None;
         }
-        let actions: Vec<Action> = (0..self.jokers.len()).map(Action::SellJoker).collect();
+        // Eternal jokers can't be sold
+        let actions: Vec<Action> = (0..self.jokers.len())
+            .filter(|i| !self.jokers[*i].stickers().eternal)
+            .map(Action::SellJoker)
+            .collect();
         Some(actions.into_iter())
     }
 
@@ -249,12 +264,35 @@ impl Game {
         Some(actions.into_iter())
     }
 
+    // Every joker but the leftmost can move left, every one but the
+    // rightmost right
+    fn gen_actions_move_joker(&self) -> Option<impl Iterator<Item = Action>> {
+        if matches!(self.stage, Stage::End(_)) {
+            return None;
+        }
+        let n = self.jokers.len();
+        let left = (1..n).map(|i| Action::MoveJoker(MoveDirection::Left, i));
+        let right = (0..n.saturating_sub(1)).map(|i| Action::MoveJoker(MoveDirection::Right, i));
+        Some(left.chain(right))
+    }
+
+    fn gen_actions_move_consumable(&self) -> Option<impl Iterator<Item = Action>> {
+        if matches!(self.stage, Stage::End(_)) {
+            return None;
+        }
+        let n = self.consumables.len();
+        let left = (1..n).map(|i| Action::MoveConsumable(MoveDirection::Left, i));
+        let right =
+            (0..n.saturating_sub(1)).map(|i| Action::MoveConsumable(MoveDirection::Right, i));
+        Some(left.chain(right))
+    }
+
     // Get buy pack actions
     fn gen_actions_buy_pack(&self) -> Option<impl Iterator<Item = Action>> {
         if self.stage != Stage::Shop() {
             return None;
         }
-        self.shop.gen_moves_buy_pack(self.money)
+        self.shop.gen_moves_buy_pack(self.spendable())
     }
 
     // Get pick pack card actions (active while a pack is open)
@@ -296,12 +334,36 @@ impl Game {
         if self.stage != Stage::Shop() {
             return None;
         }
-        if self.money < self.reroll_cost {
+        if self.spendable() < self.reroll_cost {
             return None;
         }
         Some(vec![Action::Reroll()].into_iter())
     }
 
+    // Get buy voucher actions
+    fn gen_actions_buy_voucher(&self) -> Option<impl Iterator<Item = Action>> {
+        if self.stage != Stage::Shop() {
+            return None;
+        }
+        self.shop.gen_moves_buy_voucher(self.spendable())
+    }
+
+    // Get buy playing card actions (Magic Trick)
+    fn gen_actions_buy_playing_card(&self) -> Option<impl Iterator<Item = Action>> {
+        if self.stage != Stage::Shop() {
+            return None;
+        }
+        self.shop.gen_moves_buy_playing_card(self.spendable())
+    }
+
+    // Director's Cut/Retcon
+    fn gen_actions_reroll_boss(&self) -> Option<impl Iterator<Item = Action>> {
+        if !self.can_reroll_boss() {
+            return None;
+        }
+        Some(vec![Action::RerollBoss()].into_iter())
+    }
+
     // are we in the temp tarot hand stage?
     // if so, we draw a temp hand and give options for applying the tarot.
     fn gen_actions_tarot_hand(&self) -> Option<impl Iterator<Item = Action>> {
@@ -455,6 +517,11 @@ impl Game {
         let pick_pack_cards = self.gen_actions_pick_pack_card();
         let skip_packs = self.gen_actions_skip_pack();
         let rerolls = self.gen_actions_reroll();
+        let buy_vouchers = self.gen_actions_buy_voucher();
+        let buy_playing_cards = self.gen_actions_buy_playing_card();
+        let reroll_bosses = self.gen_actions_reroll_boss();
+        let move_jokers = self.gen_actions_move_joker();
+        let move_consumables = self.gen_actions_move_consumable();
 
         select_cards
             .into_iter()
@@ -479,6 +546,11 @@ impl Game {
             .chain(pick_pack_cards.into_iter().flatten())
             .chain(skip_packs.into_iter().flatten())
             .chain(rerolls.into_iter().flatten())
+            .chain(buy_vouchers.into_iter().flatten())
+            .chain(buy_playing_cards.into_iter().flatten())
+            .chain(reroll_bosses.into_iter().flatten())
+            .chain(move_jokers.into_iter().flatten())
+            .chain(move_consumables.into_iter().flatten())
     }
 
     fn unmask_action_space_select_cards(&self, space: &mut ActionSpace) {
@@ -486,7 +558,7 @@ impl Game {
             return;
         }
         // Cannot select more if max already selected
-        if self.available.selected().len() >= self.config.selected_max {
+        if self.available.selected().len() >= self.selected_max() {
             return;
         }
         self.available
@@ -510,7 +582,8 @@ impl Game {
             return;
         }
         // Can only play/discard is have remaining
-        if self.plays != 0 {
+        let selected = self.available.selected();
+        if self.plays != 0 && (self.is_hand_allowed(&selected) || !self.any_hand_allowed()) {
             space.unmask_play();
         }
         if self.discards() != 0 {
@@ -590,7 +663,7 @@ impl Game {
             .jokers
             .iter()
             .enumerate()
-            .filter(|(_i, j)| j.cost() <= self.money)
+            .filter(|(_i, j)| self.shop.joker_cost(j) <= self.spendable())
             .for_each(|(i, _j)| {
                 space
                     .unmask_buy_joker(i)
@@ -609,7 +682,7 @@ impl Game {
             .consumables
             .iter()
             .enumerate()
-            .filter(|(_i, c)| c.cost() <= self.money)
+            .filter(|(_i, c)| self.shop.consumable_cost(c) <= self.spendable())
             .for_each(|(i, _c)| {
                 space
                     .unmask_buy_consumable(i)
@@ -626,7 +699,7 @@ impl Game {
         }
         let selected_count = self.available.selected().len();
         self.consumables.iter().enumerate().for_each(|(i, c)| {
-            let valid = match c {
+            let valid = match c.consumable {
                 Consumable::Planet(_) => true,
                 Consumable::Tarot(t) => {
                     if !t.requires_targets() {
@@ -660,11 +733,15 @@ impl Game {
         if matches!(self.stage, Stage::End(_)) {
             return;
         }
-        self.jokers.iter().enumerate().for_each(|(i, _)| {
-            space
-                .unmask_sell_joker(i)
-                .expect("valid index for sell joker");
-        });
+        self.jokers
+            .iter()
+            .enumerate()
+            .filter(|(_, j)| !j.stickers().eternal)
+            .for_each(|(i, _)| {
+                space
+                    .unmask_sell_joker(i)
+                    .expect("valid index for sell joker");
+            });
     }
 
     fn unmask_action_space_sell_consumable(&self, space: &mut ActionSpace) {
@@ -678,6 +755,34 @@ impl Game {
         });
     }
 
+    fn unmask_action_space_move_jokers(&self, space: &mut ActionSpace) {
+        if matches!(self.stage, Stage::End(_)) {
+            return;
+        }
+        for i in 0..self.jokers.len().saturating_sub(1) {
+            space
+                .unmask_move_joker_left(i)
+                .expect("valid index for move joker left");
+            space
+                .unmask_move_joker_right(i)
+                .expect("valid index for move joker right");
+        }
+    }
+
+    fn unmask_action_space_move_consumables(&self, space: &mut ActionSpace) {
+        if matches!(self.stage, Stage::End(_)) {
+            return;
+        }
+        for i in 0..self.consumables.len().saturating_sub(1) {
+            space
+                .unmask_move_consumable_left(i)
+                .expect("valid index for move consumable left");
+            space
+                .unmask_move_consumable_right(i)
+                .expect("valid index for move consumable right");
+        }
+    }
+
     fn unmask_action_space_buy_pack(&self, space: &mut ActionSpace) {
         if self.stage != Stage::Shop() {
             return;
@@ -686,7 +791,7 @@ impl Game {
             .packs
             .iter()
             .enumerate()
-            .filter(|(_, p)| p.cost() <= self.money)
+            .filter(|(_, p)| self.shop.pack_cost(p) <= self.spendable())
             .for_each(|(i, _)| {
                 space.unmask_buy_pack(i).expect("valid index for buy pack");
             });
@@ -741,12 +846,51 @@ impl Game {
         if self.stage != Stage::Shop() {
             return;
         }
-        if self.money < self.reroll_cost {
+        if self.spendable() < self.reroll_cost {
             return;
         }
         space.unmask_reroll();
     }
 
+    fn unmask_action_space_buy_voucher(&self, space: &mut ActionSpace) {
+        if self.stage != Stage::Shop() {
+            return;
+        }
+        self.shop
+            .vouchers
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| self.shop.voucher_cost(v) <= self.spendable())
+            .for_each(|(i, _)| {
+                space
+                    .unmask_buy_voucher(i)
+                    .expect("valid index for buy voucher");
+            });
+    }
+
+    fn unmask_action_space_buy_playing_card(&self, space: &mut ActionSpace) {
+        if self.stage != Stage::Shop() {
+            return;
+        }
+        self.shop
+            .playing_cards
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| self.shop.playing_card_cost(c) <= self.spendable())
+            .for_each(|(i, _)| {
+                space
+                    .unmask_buy_playing_card(i)
+                    .expect("valid index for buy playing card");
+            });
+    }
+
+    fn unmask_action_space_reroll_boss(&self, space: &mut ActionSpace) {
+        if !self.can_reroll_boss() {
+            return;
+        }
+        space.unmask_reroll_boss();
+    }
+
     fn unmask_action_space_tarot_hand(&self, space: &mut ActionSpace) {
         let Stage::TarotHand(t) = self.stage else {
             return;
@@ -861,6 +1005,11 @@ impl Game {
         self.unmask_action_space_skip_pack(&mut space);
         self.unmask_action_space_sort_hand(&mut space);
         self.unmask_action_space_reroll(&mut space);
+        self.unmask_action_space_buy_voucher(&mut space);
+        self.unmask_action_space_buy_playing_card(&mut space);
+        self.unmask_action_space_reroll_boss(&mut space);
+        self.unmask_action_space_move_jokers(&mut space);
+        self.unmask_action_space_move_consumables(&mut space);
         space
     }
 }
@@ -960,7 +1109,7 @@ mod tests {
         // Make a fresh space
         space = ActionSpace::from(g.config.clone());
         // Now select 5 cards, no more selects available, regenerate action space
-        for _ in 0..g.config.selected_max {
+        for _ in 0..g.selected_max() {
             g.select_card(*g.available.not_selected().first().expect("is first card"))
                 .expect("can select");
         }
@@ -1057,7 +1206,9 @@ mod tests {
         let space = g.gen_action_space();
         assert!(space.skip_blind[0] == 1);
 
-        // Skip Small: next blind is Big, still offerable
+        // Skip Small: next blind is Big, still offerable. Pin a tag that
+        // waits for the shop, a pack tag would open its pack right away.
+        g.small_blind_tag = crate::tag::Tag::Coupon;
         g.handle_action(Action::SkipBlind(Blind::Small))
             .expect("skip small blind");
         let actions: Vec<Action> = g.gen_actions().collect();
@@ -1078,4 +1229,32 @@ mod tests {
         let space = g.gen_action_space();
         assert!(space.skip_blind[0] == 0);
     }
+
+    #[test]
+    fn test_gen_actions_move_joker_and_consumable() {
+        use crate::planet::Planets;
+        use crate::tarot::Tarot;
+        let mut g = Game {
+            stage: Stage::Shop(),
+            consumables: vec![
+                Consumable::Tarot(Tarot::Fool).into(),
+                Consumable::Planet(Planets::Mercury).into(),
+            ],
+            ..Default::default()
+        };
+        g.jokers
+            .push(crate::joker::Jokers::TheJoker(Default::default()));
+        let moves: Vec<Action> = g
+            .gen_actions()
+            .filter(|a| matches!(a, Action::MoveJoker(..) | Action::MoveConsumable(..)))
+            .collect();
+        // a lone joker has nowhere to go
+        assert_eq!(
+            moves,
+            vec![
+                Action::MoveConsumable(MoveDirection::Left, 1),
+                Action::MoveConsumable(MoveDirection::Right, 0),
+            ]
+        );
+    }
 }
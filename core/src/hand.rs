@@ -31,6 +31,39 @@ pub struct MadeHand {
     pub all: Vec<Card>,
 }
 
+/// Joker rules that change how a played hand is read, see `Game::hand_rules`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HandRules {
+    // Four Fingers: flushes and straights need only 4 cards
+    pub four_fingers: bool,
+    // Shortcut: straights can skip a single rank between cards
+    pub shortcut: bool,
+    // Smeared Joker: Hearts/Diamonds and Spades/Clubs are one suit each
+    pub smeared: bool,
+    // Splash: every played card scores, not just the made hand
+    pub splash: bool,
+}
+
+impl HandRules {
+    fn min_len(&self) -> usize {
+        if self.four_fingers {
+            4
+        } else {
+            5
+        }
+    }
+
+    /// The suit `suit` is grouped under, Smeared Joker folds the two red
+    /// and the two black suits together.
+    pub fn suit_group(&self, suit: Suit) -> Suit {
+        match suit {
+            Suit::Diamond if self.smeared => Suit::Heart,
+            Suit::Club if self.smeared => Suit::Spade,
+            _ => suit,
+        }
+    }
+}
+
 // SelectHand represents (up to 5) cards user selects from hand for action
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass)]
@@ -125,6 +158,11 @@ impl SelectHand {
     // OnePair
     // HighCard
     pub fn best_hand(&self) -> Result<MadeHand, PlayHandError> {
+        self.best_hand_with(HandRules::default())
+    }
+
+    /// Like `best_hand`, but read under joker `rules` (see `Game::best_hand`).
+    pub fn best_hand_with(&self, rules: HandRules) -> Result<MadeHand, PlayHandError> {
         if self.0.is_empty() {
             return Err(PlayHandError::NoCards);
         }
@@ -134,23 +172,23 @@ impl SelectHand {
 
         // We start trying to evaluate best hands first, so we
         // can return best hand right when we find it.
-        let (hand, rank) = if let Some(hand) = self.is_flush_five() {
+        let (hand, rank) = if let Some(hand) = self.is_flush_five(rules) {
             (hand, HandRank::FlushFive)
-        } else if let Some(hand) = self.is_flush_house() {
+        } else if let Some(hand) = self.is_flush_house(rules) {
             (hand, HandRank::FlushHouse)
         } else if let Some(hand) = self.is_five_of_kind() {
             (hand, HandRank::FiveOfAKind)
-        } else if let Some(hand) = self.is_royal_flush() {
+        } else if let Some(hand) = self.is_royal_flush(rules) {
             (hand, HandRank::RoyalFlush)
-        } else if let Some(hand) = self.is_straight_flush() {
+        } else if let Some(hand) = self.is_straight_flush(rules) {
             (hand, HandRank::StraightFlush)
         } else if let Some(hand) = self.is_four_of_kind() {
             (hand, HandRank::FourOfAKind)
         } else if let Some(hand) = self.is_fullhouse() {
             (hand, HandRank::FullHouse)
-        } else if let Some(hand) = self.is_flush() {
+        } else if let Some(hand) = self.is_flush(rules) {
             (hand, HandRank::Flush)
-        } else if let Some(hand) = self.is_straight() {
+        } else if let Some(hand) = self.is_straight(rules) {
             (hand, HandRank::Straight)
         } else if let Some(hand) = self.is_three_of_kind() {
             (hand, HandRank::ThreeOfAKind)
@@ -163,6 +201,7 @@ impl SelectHand {
         } else {
             return Err(PlayHandError::UnknownHand);
         };
+        let hand = if rules.splash { self.clone() } else { hand };
         Ok(MadeHand {
             hand,
             rank,
@@ -223,35 +262,60 @@ impl SelectHand {
         Some(SelectHand::new(self.cards_of_values(&[value])))
     }
 
-    pub(crate) fn is_straight(&self) -> Option<SelectHand> {
-        if self.len() != 5 {
+    pub(crate) fn is_straight(&self, rules: HandRules) -> Option<SelectHand> {
+        let needed = rules.min_len();
+        if self.len() < needed {
             return None;
         }
-        // Iterate our sorted values. Each value must be one more than the previous.
-        let values = self.values();
-        if values.windows(2).all(|v| (v[1] as u16 - v[0] as u16) == 1) {
-            return Some(self.clone());
+        // Distinct rank positions, Two at 1 so a low Ace can sit at 0.
+        let ace = Value::Ace as u16 + 1;
+        let mut ranks: Vec<u16> = self
+            .values()
+            .into_iter()
+            .map(|v| v as u16 + 1)
+            .dedup()
+            .collect();
+        if ranks.last() == Some(&ace) {
+            ranks.insert(0, 0);
         }
 
-        // Special case for low ace.
-        // Values are sorted with Ace as high (2, 3, 4, 5, A)
-        // Therefore, we can check that last value is ace, first value is two.
-        // Then remove the last value (ace) from vec and check for incremental values
-        // for everything else (2, 3, 4, 5).
-        if values[4] == Value::Ace && values[0] == Value::Two {
-            let skip_last: Vec<Value> = values.into_iter().rev().skip(1).rev().collect();
-            if skip_last
-                .windows(2)
-                .all(|v| (v[1] as u16 - v[0] as u16) == 1)
-            {
-                return Some(self.clone());
-            }
+        // Longest run ending at each rank, along with the rank before it.
+        // Shortcut lets neighbours in the run be two ranks apart.
+        let max_gap = if rules.shortcut { 2 } else { 1 };
+        let mut runs: Vec<(usize, Option<usize>)> = Vec::with_capacity(ranks.len());
+        for i in 0..ranks.len() {
+            let prev = (0..i)
+                .filter(|&j| ranks[i] - ranks[j] <= max_gap)
+                .max_by_key(|&j| runs[j].0);
+            runs.push(match prev {
+                Some(j) => (runs[j].0 + 1, Some(j)),
+                None => (1, None),
+            });
         }
-        None
+        let (mut i, _) = runs.iter().enumerate().max_by_key(|(_, run)| run.0)?;
+        if runs[i].0 < needed {
+            return None;
+        }
+        let mut straight = vec![ranks[i]];
+        while let Some(j) = runs[i].1 {
+            straight.push(ranks[j]);
+            i = j;
+        }
+
+        let cards: Vec<Card> = self
+            .0
+            .iter()
+            .filter(|c| {
+                let rank = c.value as u16 + 1;
+                straight.contains(&rank) || (rank == ace && straight.contains(&0))
+            })
+            .cloned()
+            .collect();
+        Some(SelectHand::new(cards))
     }
 
-    pub(crate) fn is_flush(&self) -> Option<SelectHand> {
-        if self.len() < 5 {
+    pub(crate) fn is_flush(&self, rules: HandRules) -> Option<SelectHand> {
+        if self.len() < rules.min_len() {
             return None;
         }
         // Each wild card in hand reduces the number of a suit needed to make a flush
@@ -263,7 +327,7 @@ impl SelectHand {
             .cloned()
             .collect();
         let wild_count = wilds.len();
-        let needed = 5usize.saturating_sub(wild_count);
+        let needed = rules.min_len().saturating_sub(wild_count);
 
         if needed == 0 {
             return Some(self.clone());
@@ -275,7 +339,10 @@ impl SelectHand {
             .iter()
             .filter(|c| c.enhancement != Some(Enhancement::Wild))
         {
-            suit_groups.entry(card.suit).or_default().push(*card);
+            suit_groups
+                .entry(rules.suit_group(card.suit))
+                .or_default()
+                .push(*card);
         }
 
         if let Some((_, mut cards)) = suit_groups.into_iter().find(|(_, v)| v.len() >= needed) {
@@ -314,24 +381,30 @@ impl SelectHand {
         Some(SelectHand::new(self.cards_of_values(&[value])))
     }
 
-    pub(crate) fn is_straight_flush(&self) -> Option<SelectHand> {
-        if self.is_flush().is_some() && self.is_straight().is_some() {
-            return Some(self.clone());
-        }
-        None
+    // With Four Fingers the flush and the straight can each be 4 of the 5
+    // played cards, the hand is every card in either.
+    pub(crate) fn is_straight_flush(&self, rules: HandRules) -> Option<SelectHand> {
+        let flush = self.is_flush(rules)?.cards();
+        let straight = self.is_straight(rules)?.cards();
+        let cards = self
+            .0
+            .iter()
+            .filter(|c| flush.contains(c) || straight.contains(c))
+            .cloned()
+            .collect();
+        Some(SelectHand::new(cards))
     }
 
-    pub(crate) fn is_royal_flush(&self) -> Option<SelectHand> {
-        if self.is_straight_flush().is_some()
-            && self.values().into_iter().eq(vec![
-                Value::Ten,
-                Value::Jack,
-                Value::Queen,
-                Value::King,
-                Value::Ace,
-            ])
-        {
-            return Some(self.clone());
+    pub(crate) fn is_royal_flush(&self, rules: HandRules) -> Option<SelectHand> {
+        let hand = self.is_straight_flush(rules)?;
+        if hand.values().into_iter().eq(vec![
+            Value::Ten,
+            Value::Jack,
+            Value::Queen,
+            Value::King,
+            Value::Ace,
+        ]) {
+            return Some(hand);
         }
         None
     }
@@ -347,15 +420,15 @@ impl SelectHand {
         Some(SelectHand::new(self.cards_of_values(&[value])))
     }
 
-    pub(crate) fn is_flush_house(&self) -> Option<SelectHand> {
-        if self.is_flush().is_some() && self.is_fullhouse().is_some() {
+    pub(crate) fn is_flush_house(&self, rules: HandRules) -> Option<SelectHand> {
+        if self.is_flush(rules).is_some() && self.is_fullhouse().is_some() {
             return Some(self.clone());
         }
         None
     }
 
-    pub(crate) fn is_flush_five(&self) -> Option<SelectHand> {
-        if self.is_flush().is_some() && self.is_five_of_kind().is_some() {
+    pub(crate) fn is_flush_five(&self, rules: HandRules) -> Option<SelectHand> {
+        if self.is_flush(rules).is_some() && self.is_five_of_kind().is_some() {
             return Some(self.clone());
         }
         None
@@ -761,27 +834,27 @@ mod tests {
 
         // Valid 5 (2, 3, 4 ,5 ,6)
         let hand = SelectHand::new(vec![c2, c3, c4, c5, c6]);
-        let straight = hand.is_straight();
+        let straight = hand.is_straight(HandRules::default());
         assert_eq!(straight.unwrap().len(), 5);
 
         // Valid 5 with low ace (A, 2, 3, 4 ,5)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, c5]);
-        let straight = hand.is_straight();
+        let straight = hand.is_straight(HandRules::default());
         assert_eq!(straight.unwrap().len(), 5);
 
         // Invalid 5 (2, 3, 4, 5, 7)
         let hand = SelectHand::new(vec![c2, c3, c4, c5, c7]);
-        let straight = hand.is_straight();
+        let straight = hand.is_straight(HandRules::default());
         assert_eq!(straight, None);
 
         // Invalid 5 with low ace (A, 2, 3, 4, 7)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, c7]);
-        let straight = hand.is_straight();
+        let straight = hand.is_straight(HandRules::default());
         assert_eq!(straight, None);
 
         // Invalid 4 (2, 3, 4, 5)
         let hand = SelectHand::new(vec![c2, c3, c4, c5]);
-        let straight = hand.is_straight();
+        let straight = hand.is_straight(HandRules::default());
         assert_eq!(straight, None);
     }
 
@@ -796,22 +869,22 @@ mod tests {
 
         // Valid 5 (h, h, h, h, h)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, c5]);
-        let flush = hand.is_flush();
+        let flush = hand.is_flush(HandRules::default());
         assert_eq!(flush.unwrap().len(), 5);
 
         // Valid 5 from 7 cards (h, h, h, h, h, d, d)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, c5, not, not]);
-        let flush = hand.is_flush();
+        let flush = hand.is_flush(HandRules::default());
         assert_eq!(flush.unwrap().len(), 5);
 
         // Invalid 5 (h, h, h, h, d)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, not]);
-        let flush = hand.is_flush();
+        let flush = hand.is_flush(HandRules::default());
         assert_eq!(flush, None);
 
         // Invalid 4 (h, h, h, h)
         let hand = SelectHand::new(vec![c1, c2, c3, c4]);
-        let flush = hand.is_flush();
+        let flush = hand.is_flush(HandRules::default());
         assert_eq!(flush, None);
     }
 
@@ -921,27 +994,27 @@ mod tests {
 
         // Valid 5 (2h, 3h, 4h, 5h ,6h)
         let hand = SelectHand::new(vec![c2, c3, c4, c5, c6]);
-        let sf = hand.is_straight_flush();
+        let sf = hand.is_straight_flush(HandRules::default());
         assert_eq!(sf.unwrap().len(), 5);
 
         // Valid 5 with low ace (Ah, 2h, 3h, 4h, 5h)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, c5]);
-        let sf = hand.is_straight_flush();
+        let sf = hand.is_straight_flush(HandRules::default());
         assert_eq!(sf.unwrap().len(), 5);
 
         // Invalid 5, wrong value (2h, 3h, 4h, 5h, 7h)
         let hand = SelectHand::new(vec![c2, c3, c4, c5, not1]);
-        let sf = hand.is_straight_flush();
+        let sf = hand.is_straight_flush(HandRules::default());
         assert_eq!(sf, None);
 
         // Invalid 5, wrong suit (2h, 3h, 4h, 5h, 6d)
         let hand = SelectHand::new(vec![c2, c3, c4, c5, not2]);
-        let sf = hand.is_straight_flush();
+        let sf = hand.is_straight_flush(HandRules::default());
         assert_eq!(sf, None);
 
         // Invalid 4 (2h, 3h, 4h, 5h)
         let hand = SelectHand::new(vec![c2, c3, c4, c5]);
-        let sf = hand.is_straight_flush();
+        let sf = hand.is_straight_flush(HandRules::default());
         assert_eq!(sf, None);
     }
 
@@ -957,27 +1030,27 @@ mod tests {
 
         // Valid 5 (10s, Js, Qs, Ks, As)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, c5]);
-        let rf = hand.is_royal_flush();
+        let rf = hand.is_royal_flush(HandRules::default());
         assert_eq!(rf.unwrap().len(), 5);
 
         // Valid 5, scrambled input order (Js, 10s, Ks, Qs, As)
         let hand = SelectHand::new(vec![c2, c1, c4, c3, c5]);
-        let rf = hand.is_royal_flush();
+        let rf = hand.is_royal_flush(HandRules::default());
         assert_eq!(rf.unwrap().len(), 5);
 
         // Invalid 5, wrong value (9s, Js, Qs, Ks, As)
         let hand = SelectHand::new(vec![not1, c2, c3, c4, c5]);
-        let rf = hand.is_royal_flush();
+        let rf = hand.is_royal_flush(HandRules::default());
         assert_eq!(rf, None);
 
         // Invalid 5, wrong suit (10s, Js, Qs, Ks, Ad)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, not2]);
-        let rf = hand.is_royal_flush();
+        let rf = hand.is_royal_flush(HandRules::default());
         assert_eq!(rf, None);
 
         // Invalid 4 (2h, 3h, 4h, 5h)
         let hand = SelectHand::new(vec![c2, c3, c4, c5]);
-        let rf = hand.is_royal_flush();
+        let rf = hand.is_royal_flush(HandRules::default());
         assert_eq!(rf, None);
     }
 
@@ -1037,22 +1110,22 @@ mod tests {
 
         // Valid 5 (Kh, Kh, Kh, Ah, Ah)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, c5]);
-        let fh = hand.is_flush_house();
+        let fh = hand.is_flush_house(HandRules::default());
         assert_eq!(fh.unwrap().len(), 5);
 
         // Invalid 5 (Kh, Kh, Kh, Ah, 2h)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, not1]);
-        let fh = hand.is_flush_house();
+        let fh = hand.is_flush_house(HandRules::default());
         assert_eq!(fh, None);
 
         // Invalid 5 (Kh, Kh, Kh, Ah, Ad)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, not2]);
-        let fh = hand.is_flush_house();
+        let fh = hand.is_flush_house(HandRules::default());
         assert_eq!(fh, None);
 
         // Invalid 4 (Kh, Kh, Kh, Ah)
         let hand = SelectHand::new(vec![c1, c2, c3, c4]);
-        let fh = hand.is_flush_house();
+        let fh = hand.is_flush_house(HandRules::default());
         assert_eq!(fh, None);
     }
 
@@ -1067,8 +1140,8 @@ mod tests {
         wild.enhancement = Some(Enhancement::Wild);
 
         let hand = SelectHand::new(vec![c1, c2, c3, c4, wild]);
-        assert!(hand.is_flush().is_some());
-        assert_eq!(hand.is_flush().unwrap().len(), 5);
+        assert!(hand.is_flush(HandRules::default()).is_some());
+        assert_eq!(hand.is_flush(HandRules::default()).unwrap().len(), 5);
     }
 
     #[test]
@@ -1082,7 +1155,7 @@ mod tests {
         wild.enhancement = Some(Enhancement::Wild);
 
         let hand = SelectHand::new(vec![c1, c2, c3, c4, wild]);
-        assert!(hand.is_flush().is_none());
+        assert!(hand.is_flush(HandRules::default()).is_none());
     }
 
     #[test]
@@ -1097,7 +1170,7 @@ mod tests {
         wild2.enhancement = Some(Enhancement::Wild);
 
         let hand = SelectHand::new(vec![c1, c2, c3, wild1, wild2]);
-        assert!(hand.is_flush().is_some());
+        assert!(hand.is_flush(HandRules::default()).is_some());
     }
 
     #[test]
@@ -1112,22 +1185,145 @@ mod tests {
 
         // Valid 5 (Kh, Kh, Kh, Kh, Kh)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, c5]);
-        let ff = hand.is_flush_five();
+        let ff = hand.is_flush_five(HandRules::default());
         assert_eq!(ff.unwrap().len(), 5);
 
         // Invalid 5 (Kh, Kh, Kh, Kh, 2h)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, not1]);
-        let ff = hand.is_flush_five();
+        let ff = hand.is_flush_five(HandRules::default());
         assert_eq!(ff, None);
 
         // Invalid 5 (Kh, Kh, Kh, Kh, Kd)
         let hand = SelectHand::new(vec![c1, c2, c3, c4, not2]);
-        let ff = hand.is_flush_five();
+        let ff = hand.is_flush_five(HandRules::default());
         assert_eq!(ff, None);
 
         // Invalid 4 (Kh, Kh, Kh, Kh)
         let hand = SelectHand::new(vec![c1, c2, c3, c4]);
-        let ff = hand.is_flush_five();
+        let ff = hand.is_flush_five(HandRules::default());
         assert_eq!(ff, None);
     }
+
+    #[test]
+    fn test_four_fingers() {
+        let rules = HandRules {
+            four_fingers: true,
+            ..Default::default()
+        };
+        let h1 = Card::new(Value::King, Suit::Heart);
+        let h2 = Card::new(Value::Nine, Suit::Heart);
+        let h3 = Card::new(Value::Seven, Suit::Heart);
+        let h4 = Card::new(Value::Two, Suit::Heart);
+        let s1 = Card::new(Value::Ace, Suit::Spade);
+
+        // 4 hearts + a spade: flush of the 4 hearts
+        let hand = SelectHand::new(vec![h1, h2, h3, h4, s1]);
+        assert_eq!(hand.is_flush(HandRules::default()), None);
+        assert_eq!(hand.is_flush(rules).unwrap().len(), 4);
+        assert_eq!(hand.best_hand_with(rules).unwrap().rank, HandRank::Flush);
+
+        // 2, 3, 4, 5 + 9: straight of the first four
+        let c2 = Card::new(Value::Two, Suit::Club);
+        let c3 = Card::new(Value::Three, Suit::Diamond);
+        let c4 = Card::new(Value::Four, Suit::Club);
+        let c5 = Card::new(Value::Five, Suit::Spade);
+        let c9 = Card::new(Value::Nine, Suit::Club);
+        let hand = SelectHand::new(vec![c2, c3, c4, c5, c9]);
+        let straight = hand.is_straight(rules).unwrap();
+        assert_eq!(straight.cards(), vec![c2, c3, c4, c5]);
+        assert_eq!(hand.best_hand_with(rules).unwrap().rank, HandRank::Straight);
+
+        // 4 card straight flush
+        let hand = SelectHand::new(vec![
+            Card::new(Value::Six, Suit::Heart),
+            Card::new(Value::Seven, Suit::Heart),
+            Card::new(Value::Eight, Suit::Heart),
+            Card::new(Value::Nine, Suit::Heart),
+        ]);
+        assert_eq!(
+            hand.best_hand_with(rules).unwrap().rank,
+            HandRank::StraightFlush
+        );
+        assert_eq!(hand.best_hand().unwrap().rank, HandRank::HighCard);
+    }
+
+    #[test]
+    fn test_shortcut() {
+        let rules = HandRules {
+            shortcut: true,
+            ..Default::default()
+        };
+        // 10, 8, 6, 5, 3
+        let hand = SelectHand::new(vec![
+            Card::new(Value::Ten, Suit::Heart),
+            Card::new(Value::Eight, Suit::Club),
+            Card::new(Value::Six, Suit::Spade),
+            Card::new(Value::Five, Suit::Diamond),
+            Card::new(Value::Three, Suit::Heart),
+        ]);
+        assert_eq!(hand.is_straight(HandRules::default()), None);
+        assert_eq!(hand.is_straight(rules).unwrap().len(), 5);
+
+        // A, 3, 5, 7, 9 using a low ace
+        let hand = SelectHand::new(vec![
+            Card::new(Value::Ace, Suit::Heart),
+            Card::new(Value::Three, Suit::Club),
+            Card::new(Value::Five, Suit::Spade),
+            Card::new(Value::Seven, Suit::Diamond),
+            Card::new(Value::Nine, Suit::Heart),
+        ]);
+        assert_eq!(hand.is_straight(rules).unwrap().len(), 5);
+
+        // a gap of two ranks is still too wide: 2, 4, 6, 9, J
+        let hand = SelectHand::new(vec![
+            Card::new(Value::Two, Suit::Heart),
+            Card::new(Value::Four, Suit::Club),
+            Card::new(Value::Six, Suit::Spade),
+            Card::new(Value::Nine, Suit::Diamond),
+            Card::new(Value::Jack, Suit::Heart),
+        ]);
+        assert_eq!(hand.is_straight(rules), None);
+    }
+
+    #[test]
+    fn test_smeared_flush() {
+        let rules = HandRules {
+            smeared: true,
+            ..Default::default()
+        };
+        let hand = SelectHand::new(vec![
+            Card::new(Value::Two, Suit::Heart),
+            Card::new(Value::Five, Suit::Diamond),
+            Card::new(Value::Seven, Suit::Heart),
+            Card::new(Value::Nine, Suit::Diamond),
+            Card::new(Value::Jack, Suit::Heart),
+        ]);
+        assert_eq!(hand.is_flush(HandRules::default()), None);
+        assert_eq!(hand.best_hand_with(rules).unwrap().rank, HandRank::Flush);
+
+        // red and black never mix
+        let hand = SelectHand::new(vec![
+            Card::new(Value::Two, Suit::Heart),
+            Card::new(Value::Five, Suit::Diamond),
+            Card::new(Value::Seven, Suit::Heart),
+            Card::new(Value::Nine, Suit::Diamond),
+            Card::new(Value::Jack, Suit::Club),
+        ]);
+        assert_eq!(hand.is_flush(rules), None);
+    }
+
+    #[test]
+    fn test_splash_scores_every_card() {
+        let rules = HandRules {
+            splash: true,
+            ..Default::default()
+        };
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let king = Card::new(Value::King, Suit::Club);
+        let hand = SelectHand::new(vec![king, ace, ace]);
+        assert_eq!(hand.best_hand().unwrap().hand.len(), 2);
+        let made = hand.best_hand_with(rules).unwrap();
+        assert_eq!(made.rank, HandRank::OnePair);
+        assert_eq!(made.hand.cards(), vec![king, ace, ace]);
+    }
 }
@@ -2,7 +2,11 @@ use crate::card::{Card, Edition, Enhancement, Suit, Value};
 use crate::effect::{Effects, RuleFlag};
 use crate::game::Game;
 use crate::hand::{MadeHand, SelectHand};
+use crate::modifier::StatModifiers;
 use crate::rank::HandRank;
+use crate::rng::RngBackend;
+use crate::score::BigNum;
+use crate::stage::Blind;
 use rand::Rng;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
@@ -51,6 +55,50 @@ pub(crate) fn roll_discard_selector<R: Rng + ?Sized>(rng: &mut R, j: &mut Jokers
     }
 }
 
+// Adds `by` to the joker's counter, unless this is a Blueprint/Brainstorm
+// copy running.
+fn grow_counter(g: &mut Game, id: usize, by: f32) {
+    if g.copying {
+        return;
+    }
+    if let Some(state) = g.joker_state_mut(id) {
+        state.counter += by;
+    }
+}
+
+// OnScore for jokers whose counter is X mult gained on top of X1.
+fn x_mult_counter(id: usize) -> impl Fn(&mut Game, MadeHand) + Send + 'static {
+    move |g: &mut Game, _hand: MadeHand| {
+        let counter = g.joker_state_mut(id).map_or(0.0, |s| s.counter);
+        g.mult *= 1.0 + counter as f64;
+    }
+}
+
+/// The joker whose effects `copier` (Blueprint or Brainstorm) takes on:
+/// Blueprint copies the joker to its right, Brainstorm the leftmost joker.
+/// Chains of copiers are followed; a cycle, a missing, debuffed or
+/// incompatible target copies nothing.
+pub(crate) fn copy_target(copier: &Jokers, game: &Game) -> Option<Jokers> {
+    let mut current = copier.clone();
+    for _ in 0..=game.jokers.len() {
+        let idx = game
+            .jokers
+            .iter()
+            .position(|j| j.instance_id() == current.instance_id())?;
+        let target = match current {
+            Jokers::Blueprint(_) => game.jokers.get(idx + 1)?,
+            Jokers::Brainstorm(_) => game.jokers.first()?,
+            _ => break,
+        };
+        if !matches!(target, Jokers::Blueprint(_) | Jokers::Brainstorm(_)) {
+            return (target.blueprint_compatible() && !game.is_joker_debuffed(target))
+                .then(|| target.clone());
+        }
+        current = target.clone();
+    }
+    None
+}
+
 /// `balatro_types::Jokers` already supplies all static joker data
 /// (name/rarity/cost/desc/category/etc.) as inherent methods.
 /// The one thing that can't live there is game behavior.
@@ -77,7 +125,7 @@ impl JokerEffects for Jokers {
                     let diamonds = g
                         .non_debuffed(hand.hand.cards().iter())
                         .iter()
-                        .filter(|c| c.matches_suit(Suit::Diamond))
+                        .filter(|c| g.is_suit(c, Suit::Diamond))
                         .count();
                     g.mult += diamonds * 3
                 }
@@ -88,7 +136,7 @@ impl JokerEffects for Jokers {
                     let hearts = g
                         .non_debuffed(hand.hand.cards().iter())
                         .iter()
-                        .filter(|c| c.matches_suit(Suit::Heart))
+                        .filter(|c| g.is_suit(c, Suit::Heart))
                         .count();
                     g.mult += hearts * 3
                 }
@@ -99,7 +147,7 @@ impl JokerEffects for Jokers {
                     let spades = g
                         .non_debuffed(hand.hand.cards().iter())
                         .iter()
-                        .filter(|c| c.matches_suit(Suit::Spade))
+                        .filter(|c| g.is_suit(c, Suit::Spade))
                         .count();
                     g.mult += spades * 3
                 }
@@ -110,7 +158,7 @@ impl JokerEffects for Jokers {
                     let clubs = g
                         .non_debuffed(hand.hand.cards().iter())
                         .iter()
-                        .filter(|c| c.matches_suit(Suit::Club))
+                        .filter(|c| g.is_suit(c, Suit::Club))
                         .count();
                     g.mult += clubs * 3
                 }
@@ -142,7 +190,7 @@ impl JokerEffects for Jokers {
             }
             Self::CrazyJoker(_) => {
                 fn apply(g: &mut Game, hand: MadeHand) {
-                    if hand.hand.is_straight().is_some() {
+                    if hand.hand.is_straight(g.hand_rules()).is_some() {
                         g.mult += 12
                     }
                 }
@@ -150,7 +198,7 @@ impl JokerEffects for Jokers {
             }
             Self::DrollJoker(_) => {
                 fn apply(g: &mut Game, hand: MadeHand) {
-                    if hand.hand.is_flush().is_some() {
+                    if hand.hand.is_flush(g.hand_rules()).is_some() {
                         g.mult += 10
                     }
                 }
@@ -182,7 +230,7 @@ impl JokerEffects for Jokers {
             }
             Self::DeviousJoker(_) => {
                 fn apply(g: &mut Game, hand: MadeHand) {
-                    if hand.hand.is_straight().is_some() {
+                    if hand.hand.is_straight(g.hand_rules()).is_some() {
                         g.chips += 100
                     }
                 }
@@ -190,7 +238,7 @@ impl JokerEffects for Jokers {
             }
             Self::CraftyJoker(_) => {
                 fn apply(g: &mut Game, hand: MadeHand) {
-                    if hand.hand.is_flush().is_some() {
+                    if hand.hand.is_flush(g.hand_rules()).is_some() {
                         g.chips += 80
                     }
                 }
@@ -239,7 +287,11 @@ impl JokerEffects for Jokers {
                         .non_debuffed(hand.hand.cards().iter())
                         .iter()
                         .any(|c| g.is_face_card(c));
-                    if has_face {
+                    if g.copying {
+                        if !has_face {
+                            g.mult += g.consecutive_hands_without_face_card;
+                        }
+                    } else if has_face {
                         g.consecutive_hands_without_face_card = 0;
                     } else {
                         g.consecutive_hands_without_face_card += 1;
@@ -265,44 +317,46 @@ impl JokerEffects for Jokers {
                     } else {
                         hand.rank
                     };
-                    if played_rank == g.most_played_hand_rank() {
-                        g.consecutive_hands_not_most_played_type = 0;
-                    } else {
-                        g.consecutive_hands_not_most_played_type += 1;
-                        g.mult +=
-                            (g.mult as f64 * 0.2 * g.consecutive_hands_not_most_played_type as f64)
-                                as usize;
+                    let most_played = played_rank == g.most_played_hand_rank();
+                    if !g.copying {
+                        if most_played {
+                            g.consecutive_hands_not_most_played_type = 0;
+                        } else {
+                            g.consecutive_hands_not_most_played_type += 1;
+                        }
+                    }
+                    if !most_played {
+                        g.mult += g.mult * (0.2 * g.consecutive_hands_not_most_played_type as f64);
                     }
                 }
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
             }
             Self::WeeJoker(wee) => {
+                // grows as each 2 scores, so by the time jokers score, Wee
+                // and any copy of it add the grown value
                 let id = wee.instance_id;
-                let apply = move |g: &mut Game, hand: MadeHand| {
-                    let twos = hand
-                        .hand
-                        .cards()
-                        .iter()
-                        .filter(|c| c.value == Value::Two)
-                        .count();
-                    let counter = match g.joker_state_mut(id) {
-                        Some(state) => {
-                            state.counter += 8.0 * twos as f32;
-                            state.counter
-                        }
-                        None => return,
-                    };
+                let grow = move |g: &mut Game, card: Card| {
+                    if card.value == Value::Two {
+                        grow_counter(g, id, 8.0);
+                    }
+                };
+                let apply = move |g: &mut Game, _hand: MadeHand| {
+                    let counter = g.joker_state_mut(id).map_or(0.0, |s| s.counter);
                     g.chips += counter as usize;
                 };
-                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+                vec![
+                    Effects::OnCardScored(Arc::new(Mutex::new(grow))),
+                    Effects::OnScore(Arc::new(Mutex::new(apply))),
+                ]
             }
             Self::SpareTrousers(st) => {
                 let id = st.instance_id;
                 let apply = move |g: &mut Game, hand: MadeHand| {
                     let has_two_pair = hand.hand.is_two_pair().is_some();
+                    let copying = g.copying;
                     let counter = match g.joker_state_mut(id) {
                         Some(state) => {
-                            if has_two_pair {
+                            if has_two_pair && !copying {
                                 state.counter += 2.0;
                             }
                             state.counter
@@ -345,6 +399,10 @@ impl JokerEffects for Jokers {
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
             }
             Self::Pareidolia(_) => vec![Effects::RuleFlag(RuleFlag::AllCardsAreFace)],
+            Self::FourFingers(_) => vec![Effects::RuleFlag(RuleFlag::FourCardHands)],
+            Self::Shortcut(_) => vec![Effects::RuleFlag(RuleFlag::GappedStraights)],
+            Self::SmearedJoker(_) => vec![Effects::RuleFlag(RuleFlag::SmearedSuits)],
+            Self::Splash(_) => vec![Effects::RuleFlag(RuleFlag::AllPlayedCardsScore)],
             Self::EvenSteven(_) => {
                 fn apply(g: &mut Game, _hand: MadeHand) {
                     for card in _hand.hand.cards() {
@@ -379,8 +437,8 @@ impl JokerEffects for Jokers {
             Self::BusinessCard(_) => {
                 fn apply(g: &mut Game, _hand: MadeHand) {
                     for card in g.non_debuffed(_hand.hand.cards().iter()) {
-                        if g.is_face_card(&card) && g.prob_roll(1, 2) {
-                            g.money += 2;
+                        if g.is_face_card(&card) && g.prob_roll("business", 1, 2) {
+                            g.earn(2);
                         }
                     }
                 }
@@ -389,7 +447,7 @@ impl JokerEffects for Jokers {
             Self::FacelessJoker(_) => {
                 fn apply(g: &mut Game, _hand: MadeHand) {
                     if _hand.all.len() >= 5 {
-                        g.money += 5;
+                        g.earn(5);
                     }
                 }
                 vec![Effects::OnDiscard(Arc::new(Mutex::new(apply)))]
@@ -402,7 +460,7 @@ impl JokerEffects for Jokers {
                         .filter(|c| c.value == Value::King)
                         .count();
                     for _ in 0..kings {
-                        g.mult = (g.mult as f64 * 1.5) as usize;
+                        g.mult *= 1.5;
                     }
                 }
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
@@ -441,8 +499,8 @@ impl JokerEffects for Jokers {
             Self::ReservedParking(_) => {
                 fn apply(g: &mut Game, _hand: MadeHand) {
                     for card in g.non_debuffed(g.available.not_selected().iter()) {
-                        if g.is_face_card(&card) && g.prob_roll(1, 2) {
-                            g.money += 1;
+                        if g.is_face_card(&card) && g.prob_roll("parking", 1, 2) {
+                            g.earn(1);
                         }
                     }
                 }
@@ -456,14 +514,15 @@ impl JokerEffects for Jokers {
                         .filter(|j| j.rarity() == Rarity::Uncommon)
                         .count();
                     for _ in 0..uncommon {
-                        g.mult = (g.mult as f64 * 1.5) as usize;
+                        g.mult *= 1.5;
                     }
                 }
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
             }
             Self::Bull(_) => {
                 fn apply(g: &mut Game, _hand: MadeHand) {
-                    g.chips += g.money * 2;
+                    // nothing while in debt
+                    g.chips += g.money.max(0) as usize * 2;
                 }
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
             }
@@ -492,7 +551,7 @@ impl JokerEffects for Jokers {
                 fn apply(g: &mut Game, _hand: MadeHand) {
                     for card in _hand.hand.cards() {
                         if card.enhancement == Some(Enhancement::Gold) {
-                            g.money += 4;
+                            g.earn(4);
                         }
                     }
                 }
@@ -509,8 +568,8 @@ impl JokerEffects for Jokers {
             Self::RoughGem(_) => {
                 fn apply(g: &mut Game, _hand: MadeHand) {
                     for card in g.non_debuffed(_hand.hand.cards().iter()) {
-                        if card.matches_suit(Suit::Diamond) {
-                            g.money += 1;
+                        if g.is_suit(&card, Suit::Diamond) {
+                            g.earn(1);
                         }
                     }
                 }
@@ -519,7 +578,7 @@ impl JokerEffects for Jokers {
             Self::Bloodstone(_) => {
                 fn apply(g: &mut Game, _hand: MadeHand) {
                     for card in g.non_debuffed(_hand.hand.cards().iter()) {
-                        if card.matches_suit(Suit::Heart) && g.prob_roll(1, 2) {
+                        if g.is_suit(&card, Suit::Heart) && g.prob_roll("bloodstone", 1, 2) {
                             g.mult += g.mult / 2;
                         }
                     }
@@ -529,7 +588,7 @@ impl JokerEffects for Jokers {
             Self::Arrowhead(_) => {
                 fn apply(g: &mut Game, hand: MadeHand) {
                     for card in g.non_debuffed(hand.hand.cards().iter()) {
-                        if card.matches_suit(Suit::Spade) {
+                        if g.is_suit(&card, Suit::Spade) {
                             g.chips += 50;
                         }
                     }
@@ -539,7 +598,7 @@ impl JokerEffects for Jokers {
             Self::OnyxAgate(_) => {
                 fn apply(g: &mut Game, hand: MadeHand) {
                     for card in g.non_debuffed(hand.hand.cards().iter()) {
-                        if card.matches_suit(Suit::Club) {
+                        if g.is_suit(&card, Suit::Club) {
                             g.mult += 7;
                         }
                     }
@@ -594,7 +653,7 @@ impl JokerEffects for Jokers {
             }
             Self::TheOrder(_) => {
                 fn apply(g: &mut Game, hand: MadeHand) {
-                    if hand.hand.is_straight().is_some() {
+                    if hand.hand.is_straight(g.hand_rules()).is_some() {
                         g.mult *= 3;
                     }
                 }
@@ -602,7 +661,7 @@ impl JokerEffects for Jokers {
             }
             Self::TheTribe(_) => {
                 fn apply(g: &mut Game, hand: MadeHand) {
-                    if hand.hand.is_flush().is_some() {
+                    if hand.hand.is_flush(g.hand_rules()).is_some() {
                         g.mult *= 2;
                     }
                 }
@@ -626,7 +685,7 @@ impl JokerEffects for Jokers {
                         .iter()
                         .filter(|c| c.enhancement == Some(Enhancement::Steel))
                         .count();
-                    g.mult += (g.mult as f64 * 0.2 * count as f64) as usize;
+                    g.mult += g.mult * (0.2 * count as f64);
                 }
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
             }
@@ -707,26 +766,26 @@ impl JokerEffects for Jokers {
             }
             Self::TradingCard(_) => {
                 // "First discard of round" == self.discards already
-                // decremented to config.discards - 1 by the time this fires
+                // decremented to max_discards() - 1 by the time this fires
                 // (discard_selected decrements before invoking OnDiscard).
                 fn apply(g: &mut Game, hand: MadeHand) {
                     if hand.all.len() == 1
-                        && g.discards_remaining == g.config.discards.saturating_sub(1)
+                        && g.discards_remaining == g.max_discards().saturating_sub(1)
                     {
                         g.destroy_card(hand.all[0].id);
-                        g.money += 3;
+                        g.earn(3);
                     }
                 }
                 vec![Effects::OnDiscard(Arc::new(Mutex::new(apply)))]
             }
             Self::GreenJoker(_) => {
-                // config.plays/config.discards - remaining == used this
-                // round, both already reset to config values in clear_blind.
+                // max_plays()/max_discards() - remaining == used this
+                // round, both already reset to those values in clear_blind.
                 fn apply(g: &mut Game, _hand: MadeHand) {
-                    let hands_played = g.config.plays - g.plays;
-                    let discards_used = g.config.discards - g.discards_remaining;
+                    let hands_played = g.max_plays().saturating_sub(g.plays);
+                    let discards_used = g.max_discards().saturating_sub(g.discards_remaining);
                     let delta = hands_played as i64 - discards_used as i64;
-                    g.mult = (g.mult as i64 + delta).max(0) as usize;
+                    g.mult = BigNum::new((g.mult.to_f64() + delta as f64).max(0.0));
                 }
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
             }
@@ -737,14 +796,14 @@ impl JokerEffects for Jokers {
                         .iter()
                         .filter(|c| c.value == Value::Jack)
                         .count();
-                    g.mult += (g.mult as f64 * 0.5 * jacks as f64) as usize;
+                    g.mult += g.mult * (0.5 * jacks as f64);
                 }
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
             }
             Self::Yorick(_) => {
                 fn apply(g: &mut Game, _hand: MadeHand) {
                     let levels = g.total_cards_discarded / 23;
-                    g.mult += (g.mult as f64 * levels as f64) as usize;
+                    g.mult += g.mult * levels;
                 }
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
             }
@@ -762,7 +821,7 @@ impl JokerEffects for Jokers {
                     let count = g
                         .non_debuffed(g.discarded_this_round.iter())
                         .iter()
-                        .filter(|c| c.matches_suit(suit))
+                        .filter(|c| g.is_suit(c, suit))
                         .count();
                     g.chips += count * 3;
                 };
@@ -781,7 +840,7 @@ impl JokerEffects for Jokers {
                         return;
                     };
                     let count = hand.all.iter().filter(|c| c.value == value).count();
-                    g.money += count * 5;
+                    g.earn(count * 5);
                 };
                 vec![Effects::OnDiscard(Arc::new(Mutex::new(apply)))]
             }
@@ -794,11 +853,527 @@ impl JokerEffects for Jokers {
             Self::Matador(_) => {
                 fn apply(g: &mut Game, _hand: MadeHand) {
                     if g.boss_triggered_this_hand {
-                        g.money += 8;
+                        g.earn(8);
+                    }
+                }
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::GoldenJoker(_) => {
+                fn payout(_g: &mut Game) -> usize {
+                    4
+                }
+                vec![Effects::OnCashOut(Arc::new(Mutex::new(payout)))]
+            }
+            Self::Rocket(rocket) => {
+                // counter holds the payout gained from Boss Blinds so far
+                let id = rocket.instance_id;
+                let grow = move |g: &mut Game| {
+                    if g.blind != Some(Blind::Boss) {
+                        return;
+                    }
+                    if let Some(state) = g.joker_state_mut(id) {
+                        state.counter += 2.0;
+                    }
+                };
+                let payout = move |g: &mut Game| {
+                    let extra = g.joker_state_mut(id).map_or(0.0, |s| s.counter);
+                    1 + extra as usize
+                };
+                vec![
+                    Effects::OnRoundEnd(Arc::new(Mutex::new(grow))),
+                    Effects::OnCashOut(Arc::new(Mutex::new(payout))),
+                ]
+            }
+            Self::Cloud9(_) => {
+                fn payout(g: &mut Game) -> usize {
+                    g.full_deck()
+                        .iter()
+                        .filter(|c| c.value == Value::Nine)
+                        .count()
+                }
+                vec![Effects::OnCashOut(Arc::new(Mutex::new(payout)))]
+            }
+            // raises interest, applied directly in Game::calc_reward
+            Self::ToTheMoon(_) => vec![],
+            Self::DelayedGratification(_) => {
+                fn payout(g: &mut Game) -> usize {
+                    if g.discarded_this_round.is_empty() {
+                        g.discards() * 2
+                    } else {
+                        0
+                    }
+                }
+                vec![Effects::OnCashOut(Arc::new(Mutex::new(payout)))]
+            }
+            Self::Egg(egg) => {
+                let id = egg.instance_id;
+                let apply = move |g: &mut Game| {
+                    if let Some(state) = g.joker_state_mut(id) {
+                        state.extra_value += 3;
+                    }
+                };
+                vec![Effects::OnRoundEnd(Arc::new(Mutex::new(apply)))]
+            }
+            Self::GiftCard(_) => {
+                fn apply(g: &mut Game) {
+                    for joker in g.jokers.iter_mut() {
+                        joker.state_mut().extra_value += 1;
+                    }
+                    for held in g.consumables.iter_mut() {
+                        held.extra_value += 1;
+                    }
+                }
+                vec![Effects::OnRoundEnd(Arc::new(Mutex::new(apply)))]
+            }
+            Self::Satellite(_) => {
+                fn payout(g: &mut Game) -> usize {
+                    g.planets_used.len()
+                }
+                vec![Effects::OnCashOut(Arc::new(Mutex::new(payout)))]
+            }
+            Self::CeremonialDagger(dagger) => {
+                let id = dagger.instance_id;
+                let select = move |g: &mut Game| {
+                    if g.copying {
+                        return;
+                    }
+                    let Some(idx) = g.jokers.iter().position(|j| j.instance_id() == id) else {
+                        return;
+                    };
+                    let Some(right) = g.jokers.get(idx + 1) else {
+                        return;
+                    };
+                    if right.stickers().eternal {
+                        return;
+                    }
+                    let gain = 2 * right.sell_value();
+                    g.remove_joker(idx + 1);
+                    if let Some(state) = g.joker_state_mut(id) {
+                        state.counter += gain as f32;
+                    }
+                    g.register_jokers();
+                };
+                let apply = move |g: &mut Game, _hand: MadeHand| {
+                    let counter = g.joker_state_mut(id).map_or(0.0, |s| s.counter);
+                    g.mult += counter as usize;
+                };
+                vec![
+                    Effects::OnBlindSelect(Arc::new(Mutex::new(select))),
+                    Effects::OnScore(Arc::new(Mutex::new(apply))),
+                ]
+            }
+            Self::Madness(madness) => {
+                let id = madness.instance_id;
+                let select = move |g: &mut Game| {
+                    if g.copying || g.blind == Some(Blind::Boss) {
+                        return;
+                    }
+                    match g.joker_state_mut(id) {
+                        Some(state) => state.counter += 0.5,
+                        None => return,
+                    }
+                    let targets: Vec<Jokers> = g
+                        .jokers
+                        .iter()
+                        .filter(|j| j.instance_id() != id && !j.stickers().eternal)
+                        .cloned()
+                        .collect();
+                    if targets.is_empty() {
+                        return;
+                    }
+                    let target = g
+                        .backend
+                        .pick_random_joker(targets, "madness")
+                        .instance_id();
+                    g.remove_jokers_where(|j| j.instance_id() == target);
+                    g.register_jokers();
+                };
+                vec![
+                    Effects::OnBlindSelect(Arc::new(Mutex::new(select))),
+                    Effects::OnScore(Arc::new(Mutex::new(x_mult_counter(id)))),
+                ]
+            }
+            Self::Vampire(vampire) => {
+                // Scored enhanced cards feed the counter and lose their
+                // enhancement for good, before they score, so this hand
+                // doesn't get their bonus.
+                let id = vampire.instance_id;
+                let strip = move |g: &mut Game, hand: &mut MadeHand| {
+                    if g.copying {
+                        return;
+                    }
+                    let enhanced: Vec<usize> = g
+                        .non_debuffed(hand.hand.cards().iter())
+                        .into_iter()
+                        .filter(|c| c.enhancement.is_some())
+                        .map(|c| c.id)
+                        .collect();
+                    if enhanced.is_empty() {
+                        return;
+                    }
+                    for &card in &enhanced {
+                        g.mutate_card(card, |c| c.enhancement = None);
+                    }
+                    let cards = hand
+                        .hand
+                        .cards()
+                        .into_iter()
+                        .map(|mut c| {
+                            if enhanced.contains(&c.id) {
+                                c.enhancement = None;
+                            }
+                            c
+                        })
+                        .collect();
+                    hand.hand = SelectHand::new(cards);
+                    if let Some(state) = g.joker_state_mut(id) {
+                        state.counter += 0.1 * enhanced.len() as f32;
+                    }
+                };
+                vec![
+                    Effects::OnModifyHand(Arc::new(Mutex::new(strip))),
+                    Effects::OnScore(Arc::new(Mutex::new(x_mult_counter(id)))),
+                ]
+            }
+            Self::Hologram(hologram) => {
+                let id = hologram.instance_id;
+                let added = move |g: &mut Game, cards: Vec<Card>| {
+                    grow_counter(g, id, 0.25 * cards.len() as f32);
+                };
+                vec![
+                    Effects::OnCardsAdded(Arc::new(Mutex::new(added))),
+                    Effects::OnScore(Arc::new(Mutex::new(x_mult_counter(id)))),
+                ]
+            }
+            Self::Constellation(constellation) => {
+                let id = constellation.instance_id;
+                let used = move |g: &mut Game| grow_counter(g, id, 0.1);
+                vec![
+                    Effects::OnPlanetUsed(Arc::new(Mutex::new(used))),
+                    Effects::OnScore(Arc::new(Mutex::new(x_mult_counter(id)))),
+                ]
+            }
+            Self::LuckyCat(cat) => {
+                let id = cat.instance_id;
+                let lucky = move |g: &mut Game| grow_counter(g, id, 0.25);
+                vec![
+                    Effects::OnLuckyTrigger(Arc::new(Mutex::new(lucky))),
+                    Effects::OnScore(Arc::new(Mutex::new(x_mult_counter(id)))),
+                ]
+            }
+            Self::GlassJoker(glass) => {
+                let id = glass.instance_id;
+                let destroyed = move |g: &mut Game, cards: Vec<Card>| {
+                    let shattered = cards
+                        .iter()
+                        .filter(|c| c.enhancement == Some(Enhancement::Glass))
+                        .count();
+                    grow_counter(g, id, 0.75 * shattered as f32);
+                };
+                vec![
+                    Effects::OnCardsDestroyed(Arc::new(Mutex::new(destroyed))),
+                    Effects::OnScore(Arc::new(Mutex::new(x_mult_counter(id)))),
+                ]
+            }
+            Self::Campfire(campfire) => {
+                let id = campfire.instance_id;
+                let sold = move |g: &mut Game| grow_counter(g, id, 0.25);
+                let reset = move |g: &mut Game| {
+                    if g.copying || g.blind != Some(Blind::Boss) {
+                        return;
+                    }
+                    if let Some(state) = g.joker_state_mut(id) {
+                        state.counter = 0.0;
+                    }
+                };
+                vec![
+                    Effects::OnCardSold(Arc::new(Mutex::new(sold))),
+                    Effects::OnRoundEnd(Arc::new(Mutex::new(reset))),
+                    Effects::OnScore(Arc::new(Mutex::new(x_mult_counter(id)))),
+                ]
+            }
+            Self::Canio(canio) => {
+                let id = canio.instance_id;
+                let destroyed = move |g: &mut Game, cards: Vec<Card>| {
+                    let faces = cards.iter().filter(|c| g.is_face_card(c)).count();
+                    grow_counter(g, id, faces as f32);
+                };
+                vec![
+                    Effects::OnCardsDestroyed(Arc::new(Mutex::new(destroyed))),
+                    Effects::OnScore(Arc::new(Mutex::new(x_mult_counter(id)))),
+                ]
+            }
+            Self::FlashCard(flash) => {
+                let id = flash.instance_id;
+                let reroll = move |g: &mut Game| grow_counter(g, id, 2.0);
+                let apply = move |g: &mut Game, _hand: MadeHand| {
+                    let counter = g.joker_state_mut(id).map_or(0.0, |s| s.counter);
+                    g.mult += counter as usize;
+                };
+                vec![
+                    Effects::OnReroll(Arc::new(Mutex::new(reroll))),
+                    Effects::OnScore(Arc::new(Mutex::new(apply))),
+                ]
+            }
+            Self::RedCard(red) => {
+                let id = red.instance_id;
+                let skip = move |g: &mut Game| grow_counter(g, id, 3.0);
+                let apply = move |g: &mut Game, _hand: MadeHand| {
+                    let counter = g.joker_state_mut(id).map_or(0.0, |s| s.counter);
+                    g.mult += counter as usize;
+                };
+                vec![
+                    Effects::OnPackSkip(Arc::new(Mutex::new(skip))),
+                    Effects::OnScore(Arc::new(Mutex::new(apply))),
+                ]
+            }
+            Self::SquareJoker(square) => {
+                let id = square.instance_id;
+                let apply = move |g: &mut Game, hand: MadeHand| {
+                    if hand.all.len() == 4 {
+                        grow_counter(g, id, 4.0);
+                    }
+                    let counter = g.joker_state_mut(id).map_or(0.0, |s| s.counter);
+                    g.chips += counter as usize;
+                };
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::Runner(runner) => {
+                let id = runner.instance_id;
+                let apply = move |g: &mut Game, hand: MadeHand| {
+                    if hand.hand.is_straight(g.hand_rules()).is_some() {
+                        grow_counter(g, id, 15.0);
+                    }
+                    let counter = g.joker_state_mut(id).map_or(0.0, |s| s.counter);
+                    g.chips += counter as usize;
+                };
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::IceCream(ice) => {
+                // counter holds the chips lost so far, melts at 0
+                let id = ice.instance_id;
+                let apply = move |g: &mut Game, _hand: MadeHand| {
+                    let Some(lost) = g.joker_state_mut(id).map(|s| s.counter) else {
+                        return;
+                    };
+                    g.chips += (100.0 - lost).max(0.0) as usize;
+                    if g.copying {
+                        return;
+                    }
+                    grow_counter(g, id, 5.0);
+                    if lost + 5.0 >= 100.0 {
+                        g.remove_jokers_where(|j| j.instance_id() == id);
+                        g.register_jokers();
+                    }
+                };
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::Juggler(_) => vec![Effects::Modify(StatModifiers::hand_size(1))],
+            Self::CreditCard(_) => vec![Effects::Modify(StatModifiers::debt(20))],
+            Self::Drunkard(_) => vec![Effects::Modify(StatModifiers::discards(1))],
+            Self::Troubadour(_) => vec![Effects::Modify(
+                StatModifiers::hand_size(2) + StatModifiers::plays(-1),
+            )],
+            Self::MerryAndy(_) => vec![Effects::Modify(
+                StatModifiers::discards(3) + StatModifiers::hand_size(-1),
+            )],
+            Self::TurtleBean(bean) => {
+                // counter holds the hand size eaten so far, gone at 5
+                let id = bean.instance_id;
+                let left = 5 - (bean.state.counter as i32).min(5);
+                let eat = move |g: &mut Game| {
+                    if g.copying {
+                        return;
+                    }
+                    let Some(state) = g.joker_state_mut(id) else {
+                        return;
+                    };
+                    state.counter += 1.0;
+                    if state.counter >= 5.0 {
+                        g.remove_jokers_where(|j| j.instance_id() == id);
+                    }
+                    g.register_jokers();
+                };
+                vec![
+                    Effects::Modify(StatModifiers::hand_size(left)),
+                    Effects::OnRoundEnd(Arc::new(Mutex::new(eat))),
+                ]
+            }
+            Self::Burglar(_) => {
+                fn select(g: &mut Game) {
+                    g.plays += 3;
+                    g.discards_remaining = 0;
+                }
+                vec![Effects::OnBlindSelect(Arc::new(Mutex::new(select)))]
+            }
+            Self::Stuntman(_) => {
+                fn apply(g: &mut Game, _hand: MadeHand) {
+                    g.chips += 250;
+                }
+                vec![
+                    Effects::OnScore(Arc::new(Mutex::new(apply))),
+                    Effects::Modify(StatModifiers::hand_size(-2)),
+                ]
+            }
+            Self::RiffRaff(_) => {
+                fn select(g: &mut Game) {
+                    for _ in 0..2 {
+                        g.create_joker("rif", Some(Rarity::Common));
+                    }
+                }
+                vec![Effects::OnBlindSelect(Arc::new(Mutex::new(select)))]
+            }
+            Self::Cartomancer(_) => {
+                fn select(g: &mut Game) {
+                    g.create_tarot("car");
+                }
+                vec![Effects::OnBlindSelect(Arc::new(Mutex::new(select)))]
+            }
+            Self::Certificate(_) => {
+                fn drawn(g: &mut Game) {
+                    let mut card = g.backend.gen_card_front("cert_fr");
+                    card.seal = Some(g.backend.roll_random_seal("certsl"));
+                    g.add_to_hand(vec![card]);
+                }
+                vec![Effects::OnFirstHandDrawn(Arc::new(Mutex::new(drawn)))]
+            }
+            Self::MarbleJoker(_) => {
+                fn select(g: &mut Game) {
+                    let mut card = g.backend.gen_card_front("marb_fr");
+                    card.enhancement = Some(Enhancement::Stone);
+                    g.add_to_deck(card);
+                }
+                vec![Effects::OnBlindSelect(Arc::new(Mutex::new(select)))]
+            }
+            Self::SixthSense(_) => {
+                fn apply(g: &mut Game, hand: MadeHand) {
+                    if g.hands_played_this_round == 1
+                        && hand.all.len() == 1
+                        && hand.all[0].value == Value::Six
+                    {
+                        g.destroy_card(hand.all[0].id);
+                        g.create_spectral("sixth");
+                    }
+                }
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::Seance(_) => {
+                fn apply(g: &mut Game, hand: MadeHand) {
+                    if hand.hand.is_straight_flush(g.hand_rules()).is_some() {
+                        g.create_spectral("sea");
+                    }
+                }
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::Superposition(_) => {
+                fn apply(g: &mut Game, hand: MadeHand) {
+                    let ace = hand.hand.cards().iter().any(|c| c.value == Value::Ace);
+                    if ace && hand.hand.is_straight(g.hand_rules()).is_some() {
+                        g.create_tarot("sup");
+                    }
+                }
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::Vagabond(_) => {
+                fn apply(g: &mut Game, _hand: MadeHand) {
+                    if g.money <= 4 {
+                        g.create_tarot("vag");
+                    }
+                }
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::EightBall(_) => {
+                fn apply(g: &mut Game, hand: MadeHand) {
+                    for card in g.non_debuffed(hand.hand.cards().iter()) {
+                        if card.value == Value::Eight && g.prob_roll("8ball", 1, 4) {
+                            g.create_tarot("8ba");
+                        }
+                    }
+                }
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::Hallucination(_) => {
+                fn open(g: &mut Game) {
+                    let key = format!("halu{}", g.ante_current.num());
+                    if g.prob_roll(&key, 1, 2) {
+                        g.create_tarot("hal");
+                    }
+                }
+                vec![Effects::OnPackOpen(Arc::new(Mutex::new(open)))]
+            }
+            Self::Dna(_) => {
+                fn apply(g: &mut Game, hand: MadeHand) {
+                    if g.hands_played_this_round == 1 && hand.all.len() == 1 {
+                        g.add_to_hand(vec![hand.all[0].duplicate()]);
                     }
                 }
                 vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
             }
+            Self::InvisibleJoker(invisible) => {
+                // counter counts rounds held, ready to sell from 2
+                let id = invisible.instance_id;
+                let ready = invisible.state.counter >= 2.0;
+                let age = move |g: &mut Game| grow_counter(g, id, 1.0);
+                let sell = move |g: &mut Game| {
+                    if !ready || g.jokers.is_empty() || g.jokers.len() >= g.config.joker_slots {
+                        return;
+                    }
+                    let original = g.backend.pick_random_joker(g.jokers.clone(), "invisible");
+                    let mut clone = g.backend.clone_joker(original);
+                    if clone.edition() == Edition::Negative {
+                        clone.set_edition(Edition::Base);
+                    }
+                    g.add_joker(clone);
+                    g.register_jokers();
+                };
+                vec![
+                    Effects::OnRoundEnd(Arc::new(Mutex::new(age))),
+                    Effects::OnSell(Arc::new(Mutex::new(sell))),
+                ]
+            }
+            Self::Triboulet(_) => {
+                fn apply(g: &mut Game, hand: MadeHand) {
+                    for card in g.non_debuffed(hand.hand.cards().iter()) {
+                        if matches!(card.value, Value::King | Value::Queen) {
+                            g.mult *= 2;
+                        }
+                    }
+                }
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::Hiker(_) => {
+                // the bonus lands on the card itself, so it scores from the
+                // next time the card is played
+                fn apply(g: &mut Game, hand: MadeHand) {
+                    for card in g.non_debuffed(hand.hand.cards().iter()) {
+                        g.mutate_card(card.id, |c| c.bonus_chips += 5);
+                    }
+                }
+                vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+            }
+            Self::Perkeo(_) => {
+                fn shop_end(g: &mut Game) {
+                    if g.consumables.is_empty() {
+                        return;
+                    }
+                    let picked = g
+                        .backend
+                        .pick_random_consumable(g.held_consumables(), "perkeo");
+                    g.add_negative_consumable(picked);
+                }
+                vec![Effects::OnShopEnd(Arc::new(Mutex::new(shop_end)))]
+            }
+            Self::Blueprint(_) | Self::Brainstorm(_) => match copy_target(self, game) {
+                // a copy scores like its target, but doesn't change hand
+                // size, hands or discards a second time
+                Some(target) => target
+                    .effects(game)
+                    .into_iter()
+                    .filter(|e| !matches!(e, Effects::Modify(_)))
+                    .map(Effects::as_copy)
+                    .collect(),
+                None => vec![],
+            },
             _ => vec![],
         }
     }
@@ -880,6 +1455,57 @@ impl JokerEffects for Jokers {
                 | Self::Luchador(_)
                 | Self::Chicot(_)
                 | Self::Matador(_)
+                | Self::GoldenJoker(_)
+                | Self::Rocket(_)
+                | Self::Cloud9(_)
+                | Self::ToTheMoon(_)
+                | Self::DelayedGratification(_)
+                | Self::Egg(_)
+                | Self::GiftCard(_)
+                | Self::Satellite(_)
+                | Self::Blueprint(_)
+                | Self::Brainstorm(_)
+                | Self::FourFingers(_)
+                | Self::Shortcut(_)
+                | Self::SmearedJoker(_)
+                | Self::Splash(_)
+                | Self::CeremonialDagger(_)
+                | Self::Madness(_)
+                | Self::Vampire(_)
+                | Self::Hologram(_)
+                | Self::Constellation(_)
+                | Self::LuckyCat(_)
+                | Self::GlassJoker(_)
+                | Self::Campfire(_)
+                | Self::Canio(_)
+                | Self::FlashCard(_)
+                | Self::RedCard(_)
+                | Self::SquareJoker(_)
+                | Self::Runner(_)
+                | Self::IceCream(_)
+                | Self::Juggler(_)
+                | Self::CreditCard(_)
+                | Self::Drunkard(_)
+                | Self::Troubadour(_)
+                | Self::MerryAndy(_)
+                | Self::TurtleBean(_)
+                | Self::Burglar(_)
+                | Self::Stuntman(_)
+                | Self::RiffRaff(_)
+                | Self::Cartomancer(_)
+                | Self::Certificate(_)
+                | Self::MarbleJoker(_)
+                | Self::SixthSense(_)
+                | Self::Seance(_)
+                | Self::Superposition(_)
+                | Self::Vagabond(_)
+                | Self::EightBall(_)
+                | Self::Hallucination(_)
+                | Self::Dna(_)
+                | Self::InvisibleJoker(_)
+                | Self::Perkeo(_)
+                | Self::Triboulet(_)
+                | Self::Hiker(_)
         )
     }
 }
@@ -933,7 +1559,9 @@ pub fn joker_display(j: &Jokers) -> String {
 #[cfg(test)]
 mod tests {
     use crate::card::{Card, Enhancement, Suit, Value};
+    use crate::consumable::Consumable;
     use crate::hand::SelectHand;
+    use crate::planet::Planets;
     use crate::stage::{Blind, Stage};
     use balatro_types::BossBlind;
 
@@ -943,9 +1571,9 @@ mod tests {
     // `effects()` behavior implemented. Shop/pack generation
     // must never offer joker that silently does nothing.
     #[test]
-    fn test_exactly_74_jokers_implemented() {
+    fn test_exactly_125_jokers_implemented() {
         let count = Jokers::iter().filter(|j| j.is_implemented()).count();
-        assert_eq!(count, 74);
+        assert_eq!(count, 125);
     }
 
     #[test]
@@ -992,7 +1620,7 @@ mod tests {
         assert_eq!(score, before);
 
         // Buy (and apply) the joker
-        g.money += 1000; // Give adequate money to buy
+        g.money += 100; // Give adequate money to buy
         g.stage = Stage::Shop();
         g.shop.jokers.push(joker.clone());
         g.buy_joker(joker).unwrap();
@@ -1396,7 +2024,7 @@ mod tests {
 
         assert_eq!(g.calc_score(hand.best_hand().unwrap()), 16);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -1443,15 +2071,15 @@ mod tests {
         // (60 + 44) * 7 = 728
         assert_eq!(g.calc_score(hand.best_hand().unwrap()), 728);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
         g.stage = Stage::Blind(Blind::Small);
 
-        // 2 Steel cards total -> mult += floor(7 * 0.2 * 2) = 7 + 2 = 9
-        // (60 + 44) * 9 = 936
-        assert_eq!(g.calc_score(hand.best_hand().unwrap()), 936);
+        // 2 Steel cards total -> mult += 7 * 0.2 * 2 = 7 + 2.8 = 9.8
+        // (60 + 44) * 9.8 = 1019.2, floored to 1019
+        assert_eq!(g.calc_score(hand.best_hand().unwrap()), 1019);
     }
 
     #[test]
@@ -1481,7 +2109,7 @@ mod tests {
         g.available.extend(drawn);
         assert_eq!(g.deck.len(), 42);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -1524,7 +2152,7 @@ mod tests {
         g.destroy_card(victim.id);
         assert_eq!(g.full_deck().len(), 51);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -1580,7 +2208,7 @@ mod tests {
             16
         );
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -1755,7 +2383,7 @@ mod tests {
         // Stencil alone in 5 slots = 4 empty -> X4
         // (5 + 11) * (1 * 4) = 64
         let j = Jokers::JokerStencil(JokerStencil::default());
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -1764,7 +2392,7 @@ mod tests {
 
         // Add another joker -> 3 empty -> X3
         let j2 = Jokers::Banner(Banner::default());
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j2.clone());
         g.buy_joker(j2).unwrap();
@@ -1789,7 +2417,7 @@ mod tests {
         };
         assert_eq!(g.calc_score(best.clone()), 16);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -1821,7 +2449,7 @@ mod tests {
         };
         assert_eq!(g.calc_score(best.clone()), 16);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -1896,7 +2524,7 @@ mod tests {
 
         // Buy Abstract Joker -> 1 joker, +3 mult
         // (5 + 11) * (1 + 3) = 64
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let aj = Jokers::AbstractJoker(AbstractJoker::default());
         g.shop.jokers.push(aj.clone());
@@ -1906,7 +2534,7 @@ mod tests {
 
         // Buy Scary Face -> 2 jokers, +6 mult
         // (5 + 11) * (1 + 6) = 112
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let sf = Jokers::ScaryFace(ScaryFace::default());
         g.shop.jokers.push(sf.clone());
@@ -1936,7 +2564,7 @@ mod tests {
         assert_eq!(g.calc_score(best.clone()), 220);
 
         // Add Scary Face: still no face cards, so still 220
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let sf = Jokers::ScaryFace(ScaryFace::default());
         g.shop.jokers.push(sf.clone());
@@ -1947,7 +2575,7 @@ mod tests {
         // Add Pareidolia: now all cards are face cards
         // Scary Face gives +30 chips × 5 = +150
         // (30 + 25 + 150) * 4 = 820
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let p = Jokers::Pareidolia(Pareidolia::default());
         g.shop.jokers.push(p.clone());
@@ -2053,7 +2681,7 @@ mod tests {
         let best = hand.best_hand().unwrap();
         assert_eq!(g.calc_score(best.clone()), 7);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let es = Jokers::EvenSteven(EvenSteven::default());
         g.shop.jokers.push(es.clone());
@@ -2061,7 +2689,7 @@ mod tests {
         g.stage = Stage::Blind(Blind::Small);
         assert_eq!(g.calc_score(best.clone()), 35);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let p = Jokers::Pareidolia(Pareidolia::default());
         g.shop.jokers.push(p.clone());
@@ -2085,7 +2713,7 @@ mod tests {
         let best = hand.best_hand().unwrap();
         assert_eq!(g.calc_score(best.clone()), 8);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let ot = Jokers::OddTodd(OddTodd::default());
         g.shop.jokers.push(ot.clone());
@@ -2093,7 +2721,7 @@ mod tests {
         g.stage = Stage::Blind(Blind::Small);
         assert_eq!(g.calc_score(best.clone()), 39);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let p = Jokers::Pareidolia(Pareidolia::default());
         g.shop.jokers.push(p.clone());
@@ -2148,7 +2776,7 @@ mod tests {
         let score = g.calc_score(hand.best_hand().unwrap());
         assert_eq!(score, 64);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2173,7 +2801,7 @@ mod tests {
         };
         g.calc_score(hand.best_hand().unwrap());
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2181,9 +2809,9 @@ mod tests {
 
         let mut saw_increase = false;
         for _ in 0..100 {
-            g.money = 1000;
+            g.money = 100;
             g.calc_score(hand.best_hand().unwrap());
-            if g.money > 1000 {
+            if g.money > 100 {
                 saw_increase = true;
                 break;
             }
@@ -2201,7 +2829,7 @@ mod tests {
         g.blind = Some(Blind::Small);
         g.deal();
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2214,7 +2842,7 @@ mod tests {
         assert_eq!(g.available.selected().len(), 5);
 
         g.discard_selected().expect("can discard");
-        assert_eq!(g.money, 1001);
+        assert_eq!(g.money, 101);
     }
 
     #[test]
@@ -2227,7 +2855,7 @@ mod tests {
         g.blind = Some(Blind::Small);
         g.deal();
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2240,7 +2868,7 @@ mod tests {
         assert_eq!(g.available.selected().len(), 3);
 
         g.discard_selected().expect("can discard");
-        assert_eq!(g.money, 996);
+        assert_eq!(g.money, 96);
     }
 
     #[test]
@@ -2264,15 +2892,15 @@ mod tests {
         // (10 + 22) * 2 = 64
         assert_eq!(g.calc_score(best.clone()), 64);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
         g.stage = Stage::Blind(Blind::Small);
 
-        // Baron: 2 kings -> 2 * 1.5 = 3, then 3 * 1.5 = 4 (truncated)
-        // (10 + 22) * (2 * 1.5 * 1.5) = 32 * 4 = 128
-        assert_eq!(g.calc_score(best.clone()), 128);
+        // Baron: 2 kings -> 2 * 1.5 = 3, then 3 * 1.5 = 4.5
+        // (10 + 22) * (2 * 1.5 * 1.5) = 32 * 4.5 = 144
+        assert_eq!(g.calc_score(best.clone()), 144);
     }
 
     #[test]
@@ -2296,7 +2924,7 @@ mod tests {
         // (10 + 22) * 2 = 64
         assert_eq!(g.calc_score(best.clone()), 64);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2332,7 +2960,7 @@ mod tests {
         };
         g.available.extend(vec![king]);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let j = Jokers::MidasMask(MidasMask::default());
         g.shop.jokers.push(j.clone());
@@ -2358,7 +2986,7 @@ mod tests {
         };
         g.available.extend(vec![two]);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mm = Jokers::MidasMask(MidasMask::default());
         g.shop.jokers.push(mm.clone());
@@ -2387,7 +3015,7 @@ mod tests {
         };
         g.available.extend(vec![king1, king2, queen]);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let j = Jokers::MidasMask(MidasMask::default());
         g.shop.jokers.push(j.clone());
@@ -2457,7 +3085,7 @@ mod tests {
         let best = hand.best_hand().unwrap();
         g.calc_score(best.clone());
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2465,9 +3093,9 @@ mod tests {
 
         let mut saw_increase = false;
         for _ in 0..100 {
-            g.money = 994;
+            g.money = 94;
             g.calc_score(best.clone());
-            if g.money > 994 {
+            if g.money > 94 {
                 saw_increase = true;
                 break;
             }
@@ -2492,7 +3120,7 @@ mod tests {
         let best = hand.best_hand().unwrap();
         g.calc_score(best.clone());
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2521,7 +3149,7 @@ mod tests {
         let best = hand.best_hand().unwrap();
         g.calc_score(best.clone());
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(rp.clone());
         g.buy_joker(rp).unwrap();
@@ -2531,9 +3159,9 @@ mod tests {
 
         let mut saw_increase = false;
         for _ in 0..100 {
-            g.money = 994;
+            g.money = 94;
             g.calc_score(best.clone());
-            if g.money > 994 {
+            if g.money > 94 {
                 saw_increase = true;
                 break;
             }
@@ -2562,7 +3190,7 @@ mod tests {
 
         // Buy 2 uncommon jokers (MidasMask, Pareidolia) and BaseballCard
         let midas = Jokers::MidasMask(MidasMask::default());
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(midas.clone());
         g.buy_joker(midas).unwrap();
@@ -2570,7 +3198,7 @@ mod tests {
         g.calc_score(best.clone());
 
         let pareidolia = Jokers::Pareidolia(Pareidolia::default());
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(pareidolia.clone());
         g.buy_joker(pareidolia).unwrap();
@@ -2578,15 +3206,15 @@ mod tests {
         g.calc_score(best.clone());
 
         let bb = Jokers::BaseballCard(BaseballCard::default());
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(bb.clone());
         g.buy_joker(bb).unwrap();
         g.stage = Stage::Blind(Blind::Small);
 
         // BaseballCard: 2 uncommon * X1.5
-        // (10 + 20) * (2 * 1.5 * 1.5) = 30 * 4 = 120
-        assert_eq!(g.calc_score(best.clone()), 120);
+        // (10 + 20) * (2 * 1.5 * 1.5) = 30 * 4.5 = 135
+        assert_eq!(g.calc_score(best.clone()), 135);
     }
 
     #[test]
@@ -2602,7 +3230,7 @@ mod tests {
 
         // Buy BaseballCard with no uncommon jokers
         let bb = Jokers::BaseballCard(BaseballCard::default());
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(bb.clone());
         g.buy_joker(bb).unwrap();
@@ -2632,15 +3260,19 @@ mod tests {
         // (5 + 11) * 1 = 16
         assert_eq!(g.calc_score(best.clone()), 16);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
         g.stage = Stage::Blind(Blind::Small);
 
-        // Bull: (100 + 1000 - 6) * 2 = 1094 * 2 = 2188 chips
-        // (5 + 11 + 2188) * 1 = 2204
-        assert_eq!(g.calc_score(best.clone()), 2204);
+        // Bull: (100 + 100 - 6) * 2 = 194 * 2 = 388 chips
+        // (5 + 11 + 388) * 1 = 404
+        assert_eq!(g.calc_score(best.clone()), 404);
+
+        // nothing while in debt
+        g.money = -5;
+        assert_eq!(g.calc_score(best.clone()), 16);
     }
 
     #[test]
@@ -2660,7 +3292,7 @@ mod tests {
         // (5 + 11) * 1 = 16
         assert_eq!(g.calc_score(best.clone()), 16);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2752,7 +3384,7 @@ mod tests {
         let score = g.calc_score(hand.best_hand().unwrap());
         assert_eq!(score, 64);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2771,7 +3403,7 @@ mod tests {
         let j = Jokers::GoldenTicket(GoldenTicket::default());
 
         let mut g = Game::default();
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2794,7 +3426,7 @@ mod tests {
         };
         g.calc_score(hand.best_hand().unwrap());
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2814,7 +3446,7 @@ mod tests {
         let j = Jokers::Acrobat(Acrobat::default());
 
         let mut g = Game::default();
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2839,7 +3471,7 @@ mod tests {
         };
         g.calc_score(hand.best_hand().unwrap());
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2858,7 +3490,7 @@ mod tests {
         let j = Jokers::RoughGem(RoughGem::default());
 
         let mut g = Game::default();
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2881,7 +3513,7 @@ mod tests {
         };
         g.calc_score(hand.best_hand().unwrap());
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2899,7 +3531,7 @@ mod tests {
         let j = Jokers::Bloodstone(Bloodstone::default());
 
         let mut g = Game::default();
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -2991,7 +3623,7 @@ mod tests {
         g.available
             .extend(vec![Card::new(Value::Queen, Suit::Spade)]);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -3015,7 +3647,7 @@ mod tests {
             ..Default::default()
         };
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -3040,7 +3672,7 @@ mod tests {
             Card::new(Value::King, Suit::Spade),
         ]);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -3064,7 +3696,7 @@ mod tests {
             ..Default::default()
         };
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -3105,7 +3737,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         g.shop.jokers.push(j.clone());
         g.buy_joker(j).unwrap();
@@ -3184,7 +3816,7 @@ mod tests {
         let best = hand.best_hand().unwrap();
         assert_eq!(g.calc_score(best.clone()), 7);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let sb = Jokers::SockAndBuskin(SockAndBuskin::default());
         g.shop.jokers.push(sb.clone());
@@ -3192,7 +3824,7 @@ mod tests {
         g.stage = Stage::Blind(Blind::Small);
         assert_eq!(g.calc_score(best.clone()), 7);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let p = Jokers::Pareidolia(Pareidolia::default());
         g.shop.jokers.push(p.clone());
@@ -3219,7 +3851,7 @@ mod tests {
         // no jokers: (5 + 3) * 1 = 8
         assert_eq!(g.calc_score(best.clone()), 8);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let hack = Jokers::Hack(Hack::default());
         g.shop.jokers.push(hack.clone());
@@ -3228,7 +3860,7 @@ mod tests {
         // + Hack: 2 triggers (base 1 + Hack's 1) -> (5 + 3*2) * 1 = 11
         assert_eq!(g.calc_score(best.clone()), 11);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let sb = Jokers::SockAndBuskin(SockAndBuskin::default());
         g.shop.jokers.push(sb.clone());
@@ -3237,7 +3869,7 @@ mod tests {
         // + SockAndBuskin, no Pareidolia yet: Three isn't a face card -> unchanged
         assert_eq!(g.calc_score(best.clone()), 11);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let p = Jokers::Pareidolia(Pareidolia::default());
         g.shop.jokers.push(p.clone());
@@ -3247,7 +3879,7 @@ mod tests {
         // (base 1 + Hack 1 + SockAndBuskin 1) -> (5 + 3*3) * 1 = 14
         assert_eq!(g.calc_score(best.clone()), 14);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let hc = Jokers::HangingChad(HangingChad::default());
         g.shop.jokers.push(hc.clone());
@@ -3276,7 +3908,7 @@ mod tests {
         let score = g.calc_score(hand.clone());
         assert_eq!(score, 90);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let j = Jokers::Mime(Mime::default());
         g.shop.jokers.push(j.clone());
@@ -3284,7 +3916,7 @@ mod tests {
         g.stage = Stage::Blind(Blind::Small);
 
         let score = g.calc_score(hand);
-        assert_eq!(score, 120);
+        assert_eq!(score, 135);
     }
 
     #[test]
@@ -3300,7 +3932,7 @@ mod tests {
         let score = g.calc_score(hand.clone());
         assert_eq!(score, 16);
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let j = Jokers::Dusk(Dusk::default());
         g.shop.jokers.push(j.clone());
@@ -3320,7 +3952,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let j = Jokers::Supernova(Supernova::default());
         g.shop.jokers.push(j.clone());
@@ -3347,7 +3979,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let j = Jokers::RideTheBus(RideTheBus::default());
         g.shop.jokers.push(j.clone());
@@ -3372,7 +4004,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let j = Jokers::CardSharp(CardSharp::default());
         g.shop.jokers.push(j.clone());
@@ -3411,7 +4043,7 @@ mod tests {
             g.calc_score(high_card_hand.clone());
         }
 
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let j = Jokers::Obelisk(Obelisk::default());
         g.shop.jokers.push(j.clone());
@@ -3419,11 +4051,11 @@ mod tests {
         g.stage = Stage::Blind(Blind::Small);
 
         // FourOfAKind != most-played (HighCard, 3 plays) -> streak 0 -> 1
-        // (60 + 44) * (7 + (7 * 0.2 * 1) as usize) = 104 * (7 + 1) = 832
-        assert_eq!(g.calc_score(four_of_kind_hand.clone()), 832);
+        // (60 + 44) * (7 + 7 * 0.2 * 1) = 104 * 8.4 = 873.6, floored to 873
+        assert_eq!(g.calc_score(four_of_kind_hand.clone()), 873);
         // still != most-played (HighCard now leads 3 to 2) -> streak 1 -> 2
-        // (60 + 44) * (7 + (7 * 0.2 * 2) as usize) = 104 * (7 + 2) = 936
-        assert_eq!(g.calc_score(four_of_kind_hand), 936);
+        // (60 + 44) * (7 + 7 * 0.2 * 2) = 104 * 9.8 = 1019.2, floored to 1019
+        assert_eq!(g.calc_score(four_of_kind_hand), 1019);
         // HighCard == most-played (now 4 plays, still highest) -> streak resets
         // (5 + 11) * 1 = 16
         assert_eq!(g.calc_score(high_card_hand), 16);
@@ -3441,7 +4073,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mut j = Jokers::WeeJoker(WeeJoker::default());
         j.set_instance_id(42);
@@ -3470,7 +4102,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mut j = Jokers::SpareTrousers(SpareTrousers::default());
         j.set_instance_id(99);
@@ -3494,7 +4126,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mut j = Jokers::TradingCard(TradingCard::default());
         j.set_instance_id(1);
@@ -3531,7 +4163,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mut j = Jokers::GreenJoker(GreenJoker::default());
         j.set_instance_id(1);
@@ -3548,11 +4180,11 @@ mod tests {
         assert_eq!(g.calc_score(ace_hand.clone()), 32);
 
         g.discards_remaining -= 1; // 1 discard used
-                         // mult = 1 + (1 - 1) = 1; score = 16
+                                   // mult = 1 + (1 - 1) = 1; score = 16
         assert_eq!(g.calc_score(ace_hand.clone()), 16);
 
         g.discards_remaining -= 1; // 2 discards used
-                         // mult = 1 + (1 - 2) = 0 (floored, not negative); score = 0
+                                   // mult = 1 + (1 - 2) = 0 (floored, not negative); score = 0
         assert_eq!(g.calc_score(ace_hand), 0);
     }
 
@@ -3566,7 +4198,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mut j = Jokers::HitTheRoad(HitTheRoad::default());
         j.set_instance_id(1);
@@ -3590,8 +4222,8 @@ mod tests {
         g.select_card(jack1).unwrap();
         g.discard_selected().unwrap();
         // 1 jack discarded (the played jack above doesn't count):
-        // bonus = mult(1) * 0.5 * 1 = 0.5, truncates to 0
-        assert_eq!(g.calc_score(ace_hand.clone()), 16);
+        // bonus = mult(1) * 0.5 * 1 = 0.5 -> (5 + 11) * 1.5 = 24
+        assert_eq!(g.calc_score(ace_hand.clone()), 24);
 
         let jack2 = Card::new(Value::Jack, Suit::Spade);
         g.available.extend(vec![jack2]);
@@ -3612,7 +4244,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mut j = Jokers::Yorick(Yorick::default());
         j.set_instance_id(1);
@@ -3643,7 +4275,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mut j = Jokers::Castle(Castle::default());
         j.set_instance_id(1);
@@ -3684,7 +4316,7 @@ mod tests {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mut j = Jokers::MailInRebate(MailInRebate::default());
         j.set_instance_id(1);
@@ -3716,12 +4348,12 @@ mod tests {
     }
 
     #[test]
-    fn test_discard_selectors_reroll_on_clear_blind() {
+    fn test_discard_selectors_reroll_at_round_end() {
         let mut g = Game {
             stage: Stage::Blind(Blind::Small),
             ..Default::default()
         };
-        g.money += 1000;
+        g.money += 100;
         g.stage = Stage::Shop();
         let mut castle = Jokers::Castle(Castle::default());
         castle.set_instance_id(1);
@@ -3731,15 +4363,27 @@ mod tests {
         mail.set_instance_id(2);
         g.shop.jokers.push(mail.clone());
         g.buy_joker(mail).unwrap();
-        g.stage = Stage::Blind(Blind::Small);
+        let mut second = Jokers::Castle(Castle::default());
+        second.set_instance_id(3);
+        g.shop.jokers.push(second.clone());
+        g.buy_joker(second).unwrap();
 
         assert_eq!(g.joker_state_mut(1).unwrap().selector, None);
         assert_eq!(g.joker_state_mut(2).unwrap().selector, None);
 
-        g.clear_blind();
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .unwrap();
+        g.score += g.required_score();
+        let card = g.available.cards()[0];
+        g.select_card(card).unwrap();
+        g.play_selected().unwrap();
 
         assert!(g.joker_state_mut(1).unwrap().selector.is_some());
         assert!(g.joker_state_mut(2).unwrap().selector.is_some());
+        // every Castle watches the round's one suit
+        let suit = g.joker_state_mut(1).unwrap().selector;
+        assert_eq!(g.joker_state_mut(3).unwrap().selector, suit);
     }
 
     #[test]
@@ -4088,4 +4732,691 @@ mod tests {
         g.calc_score(hand);
         assert_eq!(g.money, money_before);
     }
+
+    // Blueprint/Brainstorm tests: distinct instance ids so copy targets
+    // resolve by position.
+    fn copy_game(jokers: Vec<Jokers>) -> Game {
+        let mut g = Game {
+            stage: Stage::Blind(Blind::Small),
+            ..Default::default()
+        };
+        for (i, mut j) in jokers.into_iter().enumerate() {
+            j.set_instance_id(i + 1);
+            g.jokers.push(j);
+        }
+        g.register_jokers();
+        g
+    }
+
+    #[test]
+    fn test_blueprint_copies_joker_to_its_right() {
+        use crate::score::ScoreSource;
+        let mut g = copy_game(vec![
+            Jokers::Blueprint(Blueprint::default()),
+            Jokers::TheJoker(TheJoker::default()),
+        ]);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
+        // (5 + 11) * (1 + 4 + 4) = 144
+        let (score, trace) = g.calc_score_traced(hand);
+        assert_eq!(score, 144);
+        let copied = trace
+            .0
+            .iter()
+            .find(|s| matches!(s.source, ScoreSource::Joker(Jokers::Blueprint(_))))
+            .expect("blueprint step");
+        assert_eq!(copied.delta_mult(), 4);
+    }
+
+    #[test]
+    fn test_brainstorm_copies_leftmost_joker() {
+        let mut g = copy_game(vec![
+            Jokers::TheJoker(TheJoker::default()),
+            Jokers::GreedyJoker(GreedyJoker::default()),
+            Jokers::Brainstorm(Brainstorm::default()),
+        ]);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
+        // no diamonds, so Greedy adds nothing: (5 + 11) * (1 + 4 + 4) = 144
+        assert_eq!(g.calc_score(hand), 144);
+    }
+
+    #[test]
+    fn test_blueprint_copies_retrigger_joker() {
+        let mut g = copy_game(vec![
+            Jokers::Blueprint(Blueprint::default()),
+            Jokers::HangingChad(HangingChad::default()),
+        ]);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
+        // ace triggers 1 + 2 + 2 times: (5 + 11*5) * 1 = 60
+        assert_eq!(g.calc_score(hand), 60);
+    }
+
+    #[test]
+    fn test_blueprint_skips_incompatible_and_missing_targets() {
+        let g = copy_game(vec![
+            Jokers::Blueprint(Blueprint::default()),
+            Jokers::Pareidolia(Pareidolia::default()),
+            Jokers::Blueprint(Blueprint::default()),
+        ]);
+        // Pareidolia isn't blueprint compatible, rightmost has nothing to copy
+        assert!(g.jokers[0].effects(&g).is_empty());
+        assert!(g.jokers[2].effects(&g).is_empty());
+        assert_eq!(g.effect_registry.rule_flags.len(), 1);
+    }
+
+    #[test]
+    fn test_copy_chain_follows_and_cycle_copies_nothing() {
+        let g = copy_game(vec![
+            Jokers::Blueprint(Blueprint::default()),
+            Jokers::Blueprint(Blueprint::default()),
+            Jokers::TheJoker(TheJoker::default()),
+        ]);
+        assert_eq!(g.jokers[0].effects(&g).len(), 1);
+
+        // Brainstorm leftmost copies itself
+        let g = copy_game(vec![
+            Jokers::Brainstorm(Brainstorm::default()),
+            Jokers::Blueprint(Blueprint::default()),
+        ]);
+        assert!(g.jokers[0].effects(&g).is_empty());
+        assert!(g.jokers[1].effects(&g).is_empty());
+    }
+
+    #[test]
+    fn test_blueprint_copying_wee_joker_scales_once() {
+        let mut g = copy_game(vec![
+            Jokers::Blueprint(Blueprint::default()),
+            Jokers::WeeJoker(WeeJoker::default()),
+        ]);
+        let two = Card::new(Value::Two, Suit::Heart);
+        let hand = SelectHand::new(vec![two]).best_hand().unwrap();
+        // Wee grows to 8 as the 2 scores, before any joker does, so the
+        // copy and Wee both add 8: chips = 5 + 2 + 8 + 8 = 23
+        assert_eq!(g.calc_score(hand.clone()), 23);
+        // grows to 16 the same way: 5 + 2 + 16 + 16 = 39
+        assert_eq!(g.calc_score(hand), 39);
+        assert_eq!(g.jokers[1].state().counter, 16.0);
+    }
+
+    #[test]
+    fn test_splash_scores_kickers() {
+        // Pair of Aces with a King kicker.
+        // before: (10 + 22) * 2 = 64; after the King scores too: (10 + 32) * 2 = 84
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let king = Card::new(Value::King, Suit::Club);
+        let mut g = copy_game(vec![]);
+        let hand = g.best_hand(vec![ace, ace, king]).unwrap();
+        assert_eq!(g.calc_score(hand), 64);
+
+        let mut g = copy_game(vec![Jokers::Splash(Splash::default())]);
+        let hand = g.best_hand(vec![ace, ace, king]).unwrap();
+        assert_eq!(g.calc_score(hand), 84);
+    }
+
+    #[test]
+    fn test_smeared_joker_suits_count_for_suit_jokers() {
+        let mut g = copy_game(vec![
+            Jokers::SmearedJoker(SmearedJoker::default()),
+            Jokers::GreedyJoker(GreedyJoker::default()),
+        ]);
+        let heart = Card::new(Value::Ace, Suit::Heart);
+        assert!(g.is_suit(&heart, Suit::Diamond));
+        assert!(!g.is_suit(&heart, Suit::Club));
+        // the heart counts as a diamond: (5 + 11) * (1 + 3) = 64
+        let hand = SelectHand::new(vec![heart]).best_hand().unwrap();
+        assert_eq!(g.calc_score(hand), 64);
+    }
+
+    #[test]
+    fn test_four_fingers_and_shortcut_change_played_hand() {
+        let cards = vec![
+            Card::new(Value::Ten, Suit::Heart),
+            Card::new(Value::Eight, Suit::Club),
+            Card::new(Value::Six, Suit::Heart),
+            Card::new(Value::Five, Suit::Heart),
+        ];
+        let g = copy_game(vec![]);
+        assert_eq!(g.best_hand(cards.clone()).unwrap().rank, HandRank::HighCard);
+
+        let g = copy_game(vec![Jokers::FourFingers(FourFingers::default())]);
+        assert_eq!(g.best_hand(cards.clone()).unwrap().rank, HandRank::HighCard);
+
+        let g = copy_game(vec![
+            Jokers::FourFingers(FourFingers::default()),
+            Jokers::Shortcut(Shortcut::default()),
+        ]);
+        let made = g.best_hand(cards).unwrap();
+        assert_eq!(made.rank, HandRank::Straight);
+        // Crazy Joker sees the 4 card straight too
+        let mut g = copy_game(vec![
+            Jokers::FourFingers(FourFingers::default()),
+            Jokers::Shortcut(Shortcut::default()),
+            Jokers::CrazyJoker(CrazyJoker::default()),
+        ]);
+        // Straight (level 1): 30 chips, 4 mult; cards 10 + 8 + 6 + 5 = 29
+        // (30 + 29) * (4 + 12) = 944
+        assert_eq!(g.calc_score(made), 944);
+    }
+
+    #[test]
+    fn test_ceremonial_dagger_eats_joker_to_its_right() {
+        let mut g = copy_game(vec![
+            Jokers::CeremonialDagger(CeremonialDagger::default()),
+            Jokers::TheJoker(TheJoker::default()),
+        ]);
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .unwrap();
+        // The Joker sells for 1, dagger gains double that
+        assert_eq!(g.jokers.len(), 1);
+        assert_eq!(g.jokers[0].state().counter, 2.0);
+    }
+
+    #[test]
+    fn test_ceremonial_dagger_gives_back_negative_slot() {
+        let mut negative = Jokers::TheJoker(TheJoker::default());
+        negative.set_edition(Edition::Negative);
+        let mut g = copy_game(vec![
+            Jokers::CeremonialDagger(CeremonialDagger::default()),
+            negative,
+        ]);
+        g.config.joker_slots += 1;
+        g.negative_joker_slots += 1;
+        let slots = g.config.joker_slots;
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .unwrap();
+        assert_eq!(g.jokers.len(), 1);
+        assert_eq!(g.config.joker_slots, slots - 1);
+    }
+
+    #[test]
+    fn test_madness_destroys_non_eternal_joker() {
+        let mut eternal = Jokers::GreedyJoker(GreedyJoker::default());
+        eternal.set_stickers(Stickers {
+            eternal: true,
+            ..Default::default()
+        });
+        let mut g = copy_game(vec![
+            Jokers::Madness(Madness::default()),
+            eternal,
+            Jokers::TheJoker(TheJoker::default()),
+        ]);
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .unwrap();
+        assert_eq!(g.jokers.len(), 2);
+        assert!(matches!(g.jokers[1], Jokers::GreedyJoker(_)));
+        assert_eq!(g.jokers[0].state().counter, 0.5);
+        // X1.5 on (5 + 11) * 1
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let hand = SelectHand::new(vec![ace]).best_hand().unwrap();
+        assert_eq!(g.calc_score(hand), 24);
+    }
+
+    #[test]
+    fn test_event_counters_grow() {
+        let mut g = copy_game(vec![
+            Jokers::Hologram(Hologram::default()),
+            Jokers::Constellation(Constellation::default()),
+            Jokers::FlashCard(FlashCard::default()),
+            Jokers::RedCard(RedCard::default()),
+            Jokers::Campfire(Campfire::default()),
+        ]);
+        g.add_to_deck(Card::new(Value::Ace, Suit::Heart));
+        assert_eq!(g.jokers[0].state().counter, 0.25);
+
+        g.consumables
+            .push(Consumable::Planet(Planets::Pluto).into());
+        g.use_consumable(Consumable::Planet(Planets::Pluto))
+            .unwrap();
+        assert_eq!(g.jokers[1].state().counter, 0.1);
+
+        g.stage = Stage::Shop();
+        g.money = 100;
+        g.reroll().unwrap();
+        assert_eq!(g.jokers[2].state().counter, 2.0);
+
+        g.stage = Stage::PackOpen();
+        g.skip_pack().unwrap();
+        assert_eq!(g.jokers[3].state().counter, 3.0);
+
+        g.consumables
+            .push(Consumable::Planet(Planets::Pluto).into());
+        g.sell_consumable(0).unwrap();
+        assert_eq!(g.jokers[4].state().counter, 0.25);
+        // resets once a Boss Blind is beaten
+        g.blind = Some(Blind::Boss);
+        g.handle_round_end();
+        assert_eq!(g.jokers[4].state().counter, 0.0);
+    }
+
+    #[test]
+    fn test_glass_joker_and_canio_count_destroyed_cards() {
+        let mut g = copy_game(vec![
+            Jokers::GlassJoker(GlassJoker::default()),
+            Jokers::Canio(Canio::default()),
+        ]);
+        let mut glass_king = Card::new(Value::King, Suit::Heart);
+        glass_king.enhancement = Some(Enhancement::Glass);
+        let two = Card::new(Value::Two, Suit::Heart);
+        g.deck.extend(vec![glass_king, two]);
+        g.destroy_card(glass_king.id);
+        g.destroy_card(two.id);
+        assert_eq!(g.jokers[0].state().counter, 0.75);
+        assert_eq!(g.jokers[1].state().counter, 1.0);
+    }
+
+    #[test]
+    fn test_vampire_strips_enhancements() {
+        let mut g = copy_game(vec![Jokers::Vampire(Vampire::default())]);
+        let mut bonus = Card::new(Value::Ace, Suit::Heart);
+        bonus.enhancement = Some(Enhancement::Bonus);
+        g.deck.extend(vec![bonus]);
+        let hand = SelectHand::new(vec![bonus]).best_hand().unwrap();
+        // stripped before it scores: (5 + 11) * 1 * X1.1
+        assert_eq!(g.calc_score(hand), 17);
+        assert!((g.jokers[0].state().counter - 0.1).abs() < 1e-6);
+        assert_eq!(g.deck.cards()[0].enhancement, None);
+    }
+
+    #[test]
+    fn test_lucky_cat_grows_on_lucky_trigger() {
+        let mut g = copy_game(vec![Jokers::LuckyCat(LuckyCat::default())]);
+        // every roll succeeds
+        g.prob_mult = 100;
+        let mut lucky = Card::new(Value::Ace, Suit::Heart);
+        lucky.enhancement = Some(Enhancement::Lucky);
+        let hand = SelectHand::new(vec![lucky]).best_hand().unwrap();
+        // (5 + 11) * (1 + 20) * X1.25 = 420
+        assert_eq!(g.calc_score(hand), 420);
+        assert_eq!(g.jokers[0].state().counter, 0.25);
+    }
+
+    #[test]
+    fn test_square_runner_and_ice_cream() {
+        let mut g = copy_game(vec![
+            Jokers::SquareJoker(SquareJoker::default()),
+            Jokers::Runner(Runner::default()),
+            Jokers::IceCream(IceCream::default()),
+        ]);
+        let four = SelectHand::new(vec![
+            Card::new(Value::Two, Suit::Heart),
+            Card::new(Value::Two, Suit::Club),
+            Card::new(Value::Nine, Suit::Heart),
+            Card::new(Value::Nine, Suit::Club),
+        ])
+        .best_hand()
+        .unwrap();
+        // Two Pair: (20 + 22 + square 4 + ice cream 100) * 2 = 292
+        assert_eq!(g.calc_score(four), 292);
+        assert_eq!(g.jokers[0].state().counter, 4.0);
+        assert_eq!(g.jokers[1].state().counter, 0.0);
+        assert_eq!(g.jokers[2].state().counter, 5.0);
+
+        let straight = SelectHand::new(vec![
+            Card::new(Value::Two, Suit::Heart),
+            Card::new(Value::Three, Suit::Club),
+            Card::new(Value::Four, Suit::Heart),
+            Card::new(Value::Five, Suit::Club),
+            Card::new(Value::Six, Suit::Club),
+        ])
+        .best_hand()
+        .unwrap();
+        // Straight: (30 + 20 + square 4 + runner 15 + ice cream 95) * 4 = 656
+        assert_eq!(g.calc_score(straight), 656);
+
+        // melts after its last 5 chips
+        g.jokers[2].state_mut().counter = 95.0;
+        let ace = SelectHand::new(vec![Card::new(Value::Ace, Suit::Heart)])
+            .best_hand()
+            .unwrap();
+        g.calc_score(ace);
+        assert_eq!(g.jokers.len(), 2);
+    }
+
+    #[test]
+    fn test_joker_counters_survive_json() {
+        let mut g = copy_game(vec![Jokers::FlashCard(FlashCard::default())]);
+        g.stage = Stage::Shop();
+        g.money = 100;
+        g.reroll().unwrap();
+        let g2 = Game::from_json(&g.to_json().unwrap()).unwrap();
+        assert_eq!(g2.jokers[0].state().counter, 2.0);
+    }
+
+    #[test]
+    fn test_stat_jokers_modify_hand_size_plays_and_discards() {
+        let base = Game::default();
+        let (size, plays, discards) = (base.hand_size(), base.max_plays(), base.max_discards());
+        let mut g = copy_game(vec![
+            Jokers::Juggler(Juggler::default()),
+            Jokers::Drunkard(Drunkard::default()),
+            Jokers::Troubadour(Troubadour::default()),
+            Jokers::MerryAndy(MerryAndy::default()),
+        ]);
+        // +1 +2 -1 hand size, -1 hand, +1 +3 discards
+        assert_eq!(g.hand_size(), size + 2);
+        assert_eq!(g.max_plays(), plays - 1);
+        assert_eq!(g.max_discards(), discards + 4);
+        assert_eq!(g.plays, plays - 1);
+        assert_eq!(g.discards_remaining, discards + 4);
+
+        // selling hands the live counters back at once
+        g.sell_joker(3).unwrap();
+        g.sell_joker(2).unwrap();
+        assert_eq!(g.hand_size(), size + 1);
+        assert_eq!(g.plays, plays);
+        assert_eq!(g.discards_remaining, discards + 1);
+    }
+
+    #[test]
+    fn test_blueprint_does_not_copy_stat_modifiers() {
+        let base = Game::default().hand_size();
+        let g = copy_game(vec![
+            Jokers::Blueprint(Blueprint::default()),
+            Jokers::Stuntman(Stuntman::default()),
+        ]);
+        assert_eq!(g.hand_size(), base - 2);
+        let ace = SelectHand::new(vec![Card::new(Value::Ace, Suit::Heart)])
+            .best_hand()
+            .unwrap();
+        // (5 + 11 + 250 + 250) * 1
+        assert_eq!(g.clone().calc_score(ace), 516);
+    }
+
+    #[test]
+    fn test_turtle_bean_shrinks_each_round() {
+        let base = Game::default().hand_size();
+        let mut g = copy_game(vec![Jokers::TurtleBean(TurtleBean::default())]);
+        assert_eq!(g.hand_size(), base + 5);
+        g.handle_round_end();
+        assert_eq!(g.hand_size(), base + 4);
+        g.jokers[0].state_mut().counter = 4.0;
+        g.register_jokers();
+        g.handle_round_end();
+        assert!(g.jokers.is_empty());
+        assert_eq!(g.hand_size(), base);
+    }
+
+    #[test]
+    fn test_burglar_trades_discards_for_hands() {
+        let mut g = copy_game(vec![Jokers::Burglar(Burglar::default())]);
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .unwrap();
+        assert_eq!(g.plays, g.max_plays() + 3);
+        assert_eq!(g.discards_remaining, 0);
+        assert!(g
+            .gen_actions()
+            .all(|a| !matches!(a, crate::action::Action::Discard())));
+    }
+
+    #[test]
+    fn test_riff_raff_and_cartomancer_respect_slots() {
+        let mut g = copy_game(vec![
+            Jokers::RiffRaff(RiffRaff::default()),
+            Jokers::Cartomancer(Cartomancer::default()),
+        ]);
+        g.config.joker_slots = 3;
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .unwrap();
+        // room for only one of Riff-raff's two
+        assert_eq!(g.jokers.len(), 3);
+        assert_eq!(g.jokers[2].rarity(), Rarity::Common);
+        assert_eq!(g.consumables.len(), 1);
+        assert!(matches!(*g.consumables[0], Consumable::Tarot(_)));
+
+        g.consumables = vec![Consumable::Planet(Planets::Pluto).into(); 2];
+        g.clear_blind();
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Big))
+            .unwrap();
+        assert_eq!(g.jokers.len(), 3);
+        assert_eq!(g.consumables, vec![Consumable::Planet(Planets::Pluto); 2]);
+    }
+
+    #[test]
+    fn test_certificate_and_marble_add_cards() {
+        let mut g = copy_game(vec![
+            Jokers::Certificate(Certificate::default()),
+            Jokers::MarbleJoker(MarbleJoker::default()),
+        ]);
+        let deck_before = g.full_deck().len();
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .unwrap();
+        assert_eq!(g.full_deck().len(), deck_before + 2);
+        let hand = g.available.cards();
+        assert_eq!(hand.len(), g.hand_size() + 1);
+        assert!(hand.last().unwrap().seal.is_some());
+        assert!(g
+            .full_deck()
+            .iter()
+            .any(|c| c.enhancement == Some(Enhancement::Stone)));
+    }
+
+    // plays `card` alone as the round's first hand, swapped in for the
+    // last card dealt
+    fn play_single(g: &mut Game, card: Card) {
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .unwrap();
+        let last = *g.available.cards().last().unwrap();
+        g.available.remove_by_id(last.id);
+        g.deck.extend(vec![last]);
+        g.available.extend(vec![card]);
+        g.select_card(card).unwrap();
+        g.play_selected().unwrap();
+    }
+
+    #[test]
+    fn test_sixth_sense_destroys_first_hand_six() {
+        let mut g = copy_game(vec![Jokers::SixthSense(SixthSense::default())]);
+        let six = Card::new(Value::Six, Suit::Club);
+        play_single(&mut g, six);
+        assert!(g.full_deck().iter().all(|c| c.id != six.id));
+        assert!(matches!(
+            g.held_consumables()[..],
+            [Consumable::Spectral(_)]
+        ));
+        // the hand is refilled despite the destroyed card
+        assert_eq!(g.available.cards().len(), g.hand_size());
+    }
+
+    #[test]
+    fn test_dna_copies_first_hand_single_card() {
+        let mut g = copy_game(vec![Jokers::Dna(Dna::default())]);
+        let mut king = Card::new(Value::King, Suit::Heart);
+        king.enhancement = Some(Enhancement::Steel);
+        king.bonus_chips = 5;
+        play_single(&mut g, king);
+        let copies: Vec<Card> = g
+            .available
+            .cards()
+            .into_iter()
+            .filter(|c| c.value == Value::King && c.enhancement == Some(Enhancement::Steel))
+            .collect();
+        assert_eq!(copies.len(), 1);
+        assert_ne!(copies[0].id, king.id);
+        assert_eq!(copies[0].bonus_chips, 5);
+        assert_eq!(g.available.cards().len(), g.hand_size() + 1);
+
+        // not on the second hand
+        let mut queen = Card::new(Value::Queen, Suit::Heart);
+        queen.enhancement = Some(Enhancement::Mult);
+        g.available.extend(vec![queen]);
+        g.select_card(queen).unwrap();
+        g.play_selected().unwrap();
+        let mult_cards = g
+            .full_deck()
+            .into_iter()
+            .filter(|c| c.enhancement == Some(Enhancement::Mult))
+            .count();
+        assert_eq!(mult_cards, 1);
+    }
+
+    #[test]
+    fn test_hand_conditioned_consumable_jokers() {
+        let mut g = copy_game(vec![
+            Jokers::Seance(Seance::default()),
+            Jokers::Superposition(Superposition::default()),
+        ]);
+        g.config.consumable_slots = 5;
+        let royal = SelectHand::new(vec![
+            Card::new(Value::Ten, Suit::Spade),
+            Card::new(Value::Jack, Suit::Spade),
+            Card::new(Value::Queen, Suit::Spade),
+            Card::new(Value::King, Suit::Spade),
+            Card::new(Value::Ace, Suit::Spade),
+        ])
+        .best_hand()
+        .unwrap();
+        g.calc_score(royal);
+        assert_eq!(g.consumables.len(), 2);
+        assert!(matches!(*g.consumables[0], Consumable::Spectral(_)));
+        assert!(matches!(*g.consumables[1], Consumable::Tarot(_)));
+
+        // Vagabond: only when broke
+        let mut g = copy_game(vec![Jokers::Vagabond(Vagabond::default())]);
+        let ace = SelectHand::new(vec![Card::new(Value::Ace, Suit::Heart)])
+            .best_hand()
+            .unwrap();
+        g.money = 5;
+        g.calc_score(ace.clone());
+        assert!(g.consumables.is_empty());
+        g.money = 4;
+        g.calc_score(ace);
+        assert_eq!(g.consumables.len(), 1);
+    }
+
+    #[test]
+    fn test_eight_ball_rolls_per_scored_eight() {
+        let mut g = copy_game(vec![Jokers::EightBall(EightBall::default())]);
+        g.prob_mult = 100;
+        g.config.consumable_slots = 5;
+        let pair = SelectHand::new(vec![
+            Card::new(Value::Eight, Suit::Heart),
+            Card::new(Value::Eight, Suit::Club),
+        ])
+        .best_hand()
+        .unwrap();
+        g.calc_score(pair);
+        assert_eq!(g.consumables.len(), 2);
+    }
+
+    #[test]
+    fn test_hallucination_on_pack_open() {
+        use crate::pack::{Pack, PackCategory, PackSize};
+        let mut g = copy_game(vec![Jokers::Hallucination(Hallucination::default())]);
+        g.prob_mult = 100;
+        g.stage = Stage::Shop();
+        g.money = 100;
+        let pack = Pack {
+            category: PackCategory::Buffoon,
+            size: PackSize::Normal,
+            contents: vec![],
+        };
+        g.shop.packs = vec![pack.clone()];
+        g.buy_pack(pack).unwrap();
+        assert!(matches!(g.held_consumables()[..], [Consumable::Tarot(_)]));
+    }
+
+    #[test]
+    fn test_invisible_joker_duplicates_after_two_rounds() {
+        let mut g = copy_game(vec![
+            Jokers::InvisibleJoker(InvisibleJoker::default()),
+            Jokers::TheJoker(TheJoker::default()),
+        ]);
+        g.handle_round_end();
+        g.sell_joker(0).unwrap();
+        // too early, nothing copied
+        assert_eq!(g.jokers.len(), 1);
+
+        let mut g = copy_game(vec![
+            Jokers::InvisibleJoker(InvisibleJoker::default()),
+            Jokers::TheJoker(TheJoker::default()),
+        ]);
+        g.jokers[1].set_edition(Edition::Negative);
+        g.handle_round_end();
+        g.handle_round_end();
+        g.sell_joker(0).unwrap();
+        assert_eq!(g.jokers.len(), 2);
+        assert!(matches!(g.jokers[1], Jokers::TheJoker(_)));
+        assert_eq!(g.jokers[1].edition(), Edition::Base);
+        assert_ne!(g.jokers[0].instance_id(), g.jokers[1].instance_id());
+    }
+
+    #[test]
+    fn test_perkeo_adds_negative_copy_leaving_shop() {
+        let mut g = copy_game(vec![Jokers::Perkeo(Perkeo::default())]);
+        let slots = g.config.consumable_slots;
+        g.consumables = vec![Consumable::Planet(Planets::Pluto).into(); slots];
+        g.stage = Stage::Shop();
+        g.handle_action(crate::action::Action::NextRound()).unwrap();
+        assert_eq!(g.consumables.len(), slots + 1);
+        assert_eq!(g.config.consumable_slots, slots + 1);
+        assert!(g.is_consumable_negative(slots));
+        g.sell_consumable(slots).unwrap();
+        assert_eq!(g.config.consumable_slots, slots);
+    }
+
+    #[test]
+    fn test_created_jokers_follow_real_rng_source() {
+        use crate::config::{Config, RngMode};
+        let config = Config {
+            rng_mode: RngMode::Real,
+            seed_str: Some("TEST".to_string()),
+            ..Config::default()
+        };
+        let mut g = Game::new(config);
+        let mut riff = Jokers::RiffRaff(RiffRaff::default());
+        riff.set_instance_id(mint_joker_id());
+        g.jokers.push(riff);
+        g.register_jokers();
+        g.stage = Stage::PreBlind();
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .unwrap();
+
+        let mut reference = balatro_seed::Instance::new("TEST");
+        for created in &g.jokers[1..] {
+            let expected = reference.next_joker("rif", 1);
+            assert_eq!(created.name(), expected.name());
+            assert_eq!(created.edition(), expected.edition());
+            assert_eq!(created.rarity(), Rarity::Common);
+        }
+        assert_eq!(g.jokers.len(), 3);
+    }
+
+    #[test]
+    fn test_triboulet_doubles_per_king_and_queen() {
+        let mut g = copy_game(vec![Jokers::Triboulet(Triboulet::default())]);
+        let pair = SelectHand::new(vec![
+            Card::new(Value::King, Suit::Heart),
+            Card::new(Value::King, Suit::Club),
+        ])
+        .best_hand()
+        .unwrap();
+        // (10 + 10 + 10) * 2 * X2 * X2 = 240
+        assert_eq!(g.calc_score(pair), 240);
+    }
+
+    #[test]
+    fn test_hiker_adds_permanent_chips_to_scored_cards() {
+        let mut g = copy_game(vec![Jokers::Hiker(Hiker::default())]);
+        let mut card = Card::new(Value::Two, Suit::Heart);
+        card.id = 9999;
+        play_single(&mut g, card);
+        let played = g.discarded.iter().find(|c| c.id == 9999).copied();
+        assert_eq!(played.map(|c| c.bonus_chips), Some(5));
+
+        // the bonus scores on top of the rank from then on
+        let high = SelectHand::new(vec![played.unwrap()]).best_hand().unwrap();
+        let mut plain = copy_game(vec![]);
+        // (5 + 2 + 5) * 1
+        assert_eq!(plain.calc_score(high), 12);
+    }
 }
@@ -20,17 +20,22 @@ pub mod game;
 pub mod generator;
 pub mod hand;
 pub mod joker;
+pub mod modifier;
 pub mod pack;
 pub mod planet;
 pub mod rank;
+pub mod reward;
 mod rng;
+pub mod save;
 pub mod score;
 pub mod shop;
 pub mod space;
 pub mod spectral;
 pub mod stage;
+pub mod stake;
 pub mod tag;
 pub mod tarot;
+pub mod voucher;
 
 #[cfg(test)]
 mod tests {
@@ -74,7 +79,7 @@ mod tests {
         let mut g = Game::default();
         g.start();
         g.stage = Stage::Shop();
-        g.consumables = vec![Consumable::Planet(Planets::Mercury)];
+        g.consumables = vec![Consumable::Planet(Planets::Mercury).into()];
         let before = g.planetarium.level(HandRank::OnePair);
         g.handle_action(Action::UseConsumable(Consumable::Planet(Planets::Mercury)))
             .unwrap();
@@ -89,7 +94,7 @@ mod tests {
         let mut g = Game::default();
         g.start();
         g.stage = Stage::PostBlind();
-        g.consumables = vec![Consumable::Planet(Planets::Jupiter)];
+        g.consumables = vec![Consumable::Planet(Planets::Jupiter).into()];
         let before = g.planetarium.level(HandRank::Flush);
         g.handle_action(Action::UseConsumable(Consumable::Planet(Planets::Jupiter)))
             .unwrap();
@@ -106,8 +111,8 @@ mod tests {
         g.money = 100;
         g.stage = Stage::Shop();
         g.consumables = vec![
-            Consumable::Planet(Planets::Pluto),
-            Consumable::Planet(Planets::Mercury),
+            Consumable::Planet(Planets::Pluto).into(),
+            Consumable::Planet(Planets::Mercury).into(),
         ];
         g.shop.consumables = vec![Consumable::Planet(Planets::Venus)];
         let res = g.handle_action(Action::BuyConsumable(Consumable::Planet(Planets::Venus)));
@@ -130,7 +135,7 @@ mod tests {
         let mut g = Game::default();
         g.start();
         g.stage = Stage::Blind(crate::stage::Blind::Small);
-        g.consumables = vec![Consumable::Planet(Planets::Mercury)];
+        g.consumables = vec![Consumable::Planet(Planets::Mercury).into()];
         let before = g.planetarium.level(HandRank::OnePair);
         let res = g.handle_action(Action::UseConsumable(Consumable::Planet(Planets::Mercury)));
         assert!(res.is_ok());
@@ -144,7 +149,7 @@ mod tests {
     fn test_use_consumable_valid_in_preblind() {
         let mut g = Game::default();
         g.start();
-        g.consumables = vec![Consumable::Planet(Planets::Saturn)];
+        g.consumables = vec![Consumable::Planet(Planets::Saturn).into()];
         let before = g.planetarium.level(HandRank::Straight);
         let res = g.handle_action(Action::UseConsumable(Consumable::Planet(Planets::Saturn)));
         assert!(res.is_ok());
@@ -159,7 +164,7 @@ mod tests {
         let mut g = Game::default();
         g.start();
         g.stage = Stage::End(crate::stage::End::Win);
-        g.consumables = vec![Consumable::Planet(Planets::Mercury)];
+        g.consumables = vec![Consumable::Planet(Planets::Mercury).into()];
         let res = g.handle_action(Action::UseConsumable(Consumable::Planet(Planets::Mercury)));
         assert!(matches!(res, Err(GameError::InvalidAction)));
     }
@@ -180,7 +185,7 @@ mod tests {
         let mut g = Game::default();
         g.start();
         g.stage = Stage::Shop();
-        g.consumables = vec![Consumable::Planet(Planets::Earth)];
+        g.consumables = vec![Consumable::Planet(Planets::Earth).into()];
         let actions: Vec<Action> = g.gen_actions().collect();
         assert!(actions.contains(&Action::UseConsumable(Consumable::Planet(Planets::Earth))));
     }
@@ -190,7 +195,7 @@ mod tests {
         let mut g = Game::default();
         g.start();
         g.stage = Stage::Blind(crate::stage::Blind::Small);
-        g.consumables = vec![Consumable::Planet(Planets::Mercury)];
+        g.consumables = vec![Consumable::Planet(Planets::Mercury).into()];
         let actions: Vec<Action> = g.gen_actions().collect();
         assert!(actions
             .iter()
@@ -239,7 +244,7 @@ mod tests {
         // at Shop, available is empty (clear_blind returns cards to deck without re-dealing)
         g.stage = Stage::Shop();
         assert_eq!(g.available.cards().len(), 0);
-        g.consumables = vec![Consumable::Tarot(Tarot::Magician)];
+        g.consumables = vec![Consumable::Tarot(Tarot::Magician).into()];
         let res = g.handle_action(Action::UseConsumable(Consumable::Tarot(Tarot::Magician)));
         assert!(matches!(res, Err(GameError::InvalidAction)));
         // Consumable is not consumed on a failed use, and no substitute hand is dealt.
@@ -255,12 +260,15 @@ mod tests {
         g.start();
         g.stage = Stage::Shop();
         assert_eq!(g.available.cards().len(), 0);
-        g.consumables = vec![Consumable::Spectral(Spectral::Talisman)];
+        g.consumables = vec![Consumable::Spectral(Spectral::Talisman).into()];
         let res = g.handle_action(Action::UseConsumable(Consumable::Spectral(
             Spectral::Talisman,
         )));
         assert!(matches!(res, Err(GameError::InvalidAction)));
-        assert_eq!(g.consumables, vec![Consumable::Spectral(Spectral::Talisman)]);
+        assert_eq!(
+            g.consumables,
+            vec![Consumable::Spectral(Spectral::Talisman)]
+        );
         assert_eq!(g.stage, Stage::Shop());
         assert_eq!(g.available.cards().len(), 0);
     }
@@ -278,7 +286,7 @@ mod tests {
         g.stage = Stage::Shop();
         g.config.consumable_slots = 3;
 
-        g.consumables = vec![Consumable::Planet(Planets::Mercury)];
+        g.consumables = vec![Consumable::Planet(Planets::Mercury).into()];
         g.handle_action(Action::UseConsumable(Consumable::Planet(Planets::Mercury)))
             .unwrap();
         assert_eq!(
@@ -288,7 +296,8 @@ mod tests {
 
         // Wraith is a 0-target Spectral that doesn't touch the hand, so it's
         // legal from Shop.
-        g.consumables.push(Consumable::Spectral(Spectral::Wraith));
+        g.consumables
+            .push(Consumable::Spectral(Spectral::Wraith).into());
         g.handle_action(Action::UseConsumable(Consumable::Spectral(
             Spectral::Wraith,
         )))
@@ -299,14 +308,16 @@ mod tests {
             Some(Consumable::Planet(Planets::Mercury))
         );
 
-        g.consumables.push(Consumable::Tarot(Tarot::Fool));
+        g.consumables.push(Consumable::Tarot(Tarot::Fool).into());
         g.handle_action(Action::UseConsumable(Consumable::Tarot(Tarot::Fool)))
             .unwrap();
-        assert!(g.consumables.contains(&Consumable::Planet(Planets::Mercury)));
+        assert!(g
+            .held_consumables()
+            .contains(&Consumable::Planet(Planets::Mercury)));
         assert!(
             !g.consumables
                 .iter()
-                .any(|c| matches!(c, Consumable::Spectral(_))),
+                .any(|c| matches!(c.consumable, Consumable::Spectral(_))),
             "Fool must never duplicate a Spectral"
         );
     }
@@ -321,17 +332,22 @@ mod tests {
         g.start();
         g.stage = Stage::Shop();
         assert_eq!(g.available.cards().len(), 0);
-        g.consumables = vec![Consumable::Spectral(Spectral::Familiar)];
+        g.consumables = vec![Consumable::Spectral(Spectral::Familiar).into()];
         let res = g.handle_action(Action::UseConsumable(Consumable::Spectral(
             Spectral::Familiar,
         )));
         assert!(matches!(res, Err(GameError::InvalidAction)));
-        assert_eq!(g.consumables, vec![Consumable::Spectral(Spectral::Familiar)]);
+        assert_eq!(
+            g.consumables,
+            vec![Consumable::Spectral(Spectral::Familiar)]
+        );
 
         let actions: Vec<Action> = g.gen_actions().collect();
-        assert!(!actions.contains(&Action::UseConsumable(Consumable::Spectral(
-            Spectral::Familiar
-        ))));
+        assert!(
+            !actions.contains(&Action::UseConsumable(Consumable::Spectral(
+                Spectral::Familiar
+            )))
+        );
     }
 
     #[test]
@@ -340,10 +356,14 @@ mod tests {
         let mut g = Game::default();
         g.start();
         g.stage = Stage::Shop();
-        g.consumables = vec![Consumable::Spectral(Spectral::Sigil)];
+        g.consumables = vec![Consumable::Spectral(Spectral::Sigil).into()];
         let actions: Vec<Action> = g.gen_actions().collect();
         // Sigil is 0-target but still touches the hand, so it's not offered from Shop.
-        assert!(!actions.contains(&Action::UseConsumable(Consumable::Spectral(Spectral::Sigil))));
+        assert!(
+            !actions.contains(&Action::UseConsumable(Consumable::Spectral(
+                Spectral::Sigil
+            )))
+        );
     }
 
     #[test]
@@ -0,0 +1,108 @@
+use std::ops::{Add, AddAssign, Sub};
+
+/// Signed adjustments to hand size, hands, discards, the selection
+/// limit and how far money may go into debt. Jokers (Juggler, Troubadour, Stuntman, ...), tags (Juggle) and
+/// bosses (The Manacle) stack them on top of the run's `Config`, while
+/// decks, stakes and vouchers fold theirs into `Config` for good.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatModifiers {
+    pub hand_size: i32,
+    pub plays: i32,
+    pub discards: i32,
+    pub selected_max: i32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub debt: i32,
+}
+
+impl StatModifiers {
+    pub fn hand_size(n: i32) -> Self {
+        Self {
+            hand_size: n,
+            ..Default::default()
+        }
+    }
+
+    pub fn plays(n: i32) -> Self {
+        Self {
+            plays: n,
+            ..Default::default()
+        }
+    }
+
+    pub fn discards(n: i32) -> Self {
+        Self {
+            discards: n,
+            ..Default::default()
+        }
+    }
+
+    pub fn debt(n: i32) -> Self {
+        Self {
+            debt: n,
+            ..Default::default()
+        }
+    }
+}
+
+/// `base` moved by `delta`, never below zero.
+pub fn adjust(base: usize, delta: i32) -> usize {
+    if delta >= 0 {
+        base + delta as usize
+    } else {
+        base.saturating_sub(delta.unsigned_abs() as usize)
+    }
+}
+
+impl Add for StatModifiers {
+    type Output = StatModifiers;
+    fn add(self, rhs: StatModifiers) -> StatModifiers {
+        StatModifiers {
+            hand_size: self.hand_size + rhs.hand_size,
+            plays: self.plays + rhs.plays,
+            discards: self.discards + rhs.discards,
+            selected_max: self.selected_max + rhs.selected_max,
+            debt: self.debt + rhs.debt,
+        }
+    }
+}
+
+impl AddAssign for StatModifiers {
+    fn add_assign(&mut self, rhs: StatModifiers) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for StatModifiers {
+    type Output = StatModifiers;
+    fn sub(self, rhs: StatModifiers) -> StatModifiers {
+        StatModifiers {
+            hand_size: self.hand_size - rhs.hand_size,
+            plays: self.plays - rhs.plays,
+            discards: self.discards - rhs.discards,
+            selected_max: self.selected_max - rhs.selected_max,
+            debt: self.debt - rhs.debt,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjust_saturates_at_zero() {
+        assert_eq!(adjust(8, 2), 10);
+        assert_eq!(adjust(8, -3), 5);
+        assert_eq!(adjust(1, -3), 0);
+    }
+
+    #[test]
+    fn test_stack() {
+        let mut m = StatModifiers::hand_size(2) + StatModifiers::plays(-1);
+        m += StatModifiers::hand_size(-1);
+        assert_eq!(m.hand_size, 1);
+        assert_eq!(m.plays, -1);
+        assert_eq!(m - m, StatModifiers::default());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::joker::Jokers;
+use crate::stage::Blind;
+use crate::tag::Tag;
+
+/// Where a line of the end-of-round cash out came from.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RewardSource {
+    Blind(Blind),
+    // $ per hand left unplayed
+    Hands,
+    // $ per discard left unused (Green Deck)
+    Discards,
+    // $3 per Gold Card held in hand at the end of the round
+    GoldCards,
+    Interest,
+    // end-of-round payout jokers (Golden Joker, Rocket, ...)
+    Joker(Jokers),
+    // tags that pay at cash out (Investment)
+    Tag(Tag),
+}
+
+/// One line of a `RoundReward`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RewardLine {
+    pub source: RewardSource,
+    pub amount: usize,
+}
+
+impl RewardLine {
+    /// Human-readable label, e.g. "Interest" or "Golden Joker".
+    pub fn describe(&self) -> String {
+        match &self.source {
+            RewardSource::Blind(blind) => crate::stage::blind_display(blind).to_string(),
+            RewardSource::Hands => "Remaining hands".to_string(),
+            RewardSource::Discards => "Remaining discards".to_string(),
+            RewardSource::GoldCards => "Gold Cards".to_string(),
+            RewardSource::Interest => "Interest".to_string(),
+            RewardSource::Joker(j) => j.name().to_string(),
+            RewardSource::Tag(t) => format!("{t:?} Tag"),
+        }
+    }
+}
+
+/// Itemised cash out for the blind just beaten, paid out on `CashOut`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RoundReward {
+    pub lines: Vec<RewardLine>,
+}
+
+impl RoundReward {
+    /// Adds a line, skipping zero payouts.
+    pub fn push(&mut self, source: RewardSource, amount: usize) {
+        if amount > 0 {
+            self.lines.push(RewardLine { source, amount });
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.lines.iter().map(|l| l.amount).sum()
+    }
+
+    /// Sum of every line from `source`.
+    pub fn amount(&self, source: &RewardSource) -> usize {
+        self.lines
+            .iter()
+            .filter(|l| &l.source == source)
+            .map(|l| l.amount)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_skips_zero_and_totals() {
+        let mut r = RoundReward::default();
+        r.push(RewardSource::Blind(Blind::Big), 4);
+        r.push(RewardSource::Hands, 0);
+        r.push(RewardSource::Interest, 3);
+        r.push(RewardSource::Tag(Tag::Investment), 25);
+        assert_eq!(r.lines.len(), 3);
+        assert_eq!(r.total(), 32);
+        assert_eq!(r.amount(&RewardSource::Interest), 3);
+        assert_eq!(r.amount(&RewardSource::Hands), 0);
+    }
+
+    #[test]
+    fn test_describe() {
+        let line = RewardLine {
+            source: RewardSource::Blind(Blind::Boss),
+            amount: 5,
+        };
+        assert_eq!(line.describe(), "Boss Blind");
+        let line = RewardLine {
+            source: RewardSource::Tag(Tag::Investment),
+            amount: 25,
+        };
+        assert_eq!(line.describe(), "Investment Tag");
+    }
+}
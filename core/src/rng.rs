@@ -1,14 +1,16 @@
 use crate::card::Card;
 use crate::consumable::Consumable;
-use crate::deck::Deck;
-use crate::joker::Jokers;
+use crate::deck::{Deck, DeckVariant};
+use crate::joker::{Jokers, Stickers};
 use crate::pack::{Pack, PackCategory, PackContent, PackSize};
 use crate::planet::{Planetarium, Planets};
 use crate::shop::{gen_random_playing_card, ConsumableGenerator, JokerGenerator, PackGenerator};
+use crate::stake::Stake;
 use crate::tag::Tag;
 use crate::tarot::Tarot;
+use crate::voucher::Voucher;
 use balatro_seed::Instance;
-use balatro_types::{BossBlind, Edition, Rarity, Suit, Value};
+use balatro_types::{BossBlind, Edition, Enhancement, Rarity, Seal, Suit, Value};
 use rand::distributions::WeightedIndex;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
@@ -20,6 +22,8 @@ use strum::IntoEnumIterator;
 pub(crate) enum GeneratedItem {
     Joker(Jokers),
     Consumable(Consumable),
+    // only once Magic Trick is redeemed
+    PlayingCard(Card),
 }
 
 pub(crate) trait RngBackend {
@@ -41,58 +45,138 @@ pub(crate) trait RngBackend {
         exclude: Option<(&PackCategory, &PackSize)>,
         held_jokers: &[Jokers],
     ) -> Pack;
+    /// A pack of a fixed category and size, outside the shop's own pack
+    /// roll — currently only the pack tags (Charm, Meteor, Ethereal, etc).
+    fn gen_pack_of(
+        &mut self,
+        ante: i32,
+        planetarium: &Planetarium,
+        prob_mult: u32,
+        category: PackCategory,
+        size: PackSize,
+        held_jokers: &[Jokers],
+    ) -> Pack;
+
+    /// The voucher on offer for a fresh ante (or an extra one from the
+    /// Voucher tag). Never one already redeemed, one whose base voucher
+    /// hasn't been, or one in `exclude`. `None` once the pool runs dry.
+    fn draw_voucher(
+        &mut self,
+        ante: i32,
+        redeemed: &[Voucher],
+        exclude: &[Voucher],
+    ) -> Option<Voucher>;
+    /// Lets the backend's generation follow the redeemed voucher (edition
+    /// rates, shop weights, Omen Globe, Magic Trick, upgrade unlocks).
+    fn on_voucher_redeemed(&mut self, voucher: Voucher);
 
     /// Owned-joker dedup hook: called on buy/sell so `Real` mode's lock
     /// table stays accurate. No-op for `Fast` mode.
     fn on_joker_bought(&mut self, joker: &Jokers);
     fn on_joker_sold(&mut self, joker: &Jokers);
+    /// The run's starting deck, for the decks that change shop generation
+    /// (Ghost's shop spectrals).
+    fn set_deck(&mut self, deck: DeckVariant);
     /// Jokers::Showman's real effect. No call site yet.
     #[allow(dead_code)]
     fn set_showman(&mut self, owned: bool);
 
-    /// A single freshly generated joker, outside the shop/pack context —
-    /// currently only Judgement. Distinct from `gen_shop_item`'s joker arm
-    /// since a shop slot's category (joker vs. consumable) is its own roll.
-    fn gen_joker(&mut self, ante: i32, prob_mult: u32, exclude: &[Jokers]) -> Jokers;
-    fn gen_joker_of_rarity(
+    /// `key` is the real game's seed for the pick (`"madness"`, `"hex"`, ...).
+    fn pick_random_joker(&mut self, available: Vec<Jokers>, key: &str) -> Jokers;
+    fn clone_joker(&mut self, j: Jokers) -> Jokers;
+    /// A joker created mid-run by a joker, consumable or tag (Riff-raff,
+    /// Judgement, Top-up). `source` is the real game's `key_append` for the
+    /// creator, so `Real` mode draws from the same
+    /// `Instance::next_joker(source, ante)` stream; `rarity` forces the tier
+    /// in `Fast` mode and must agree with `source`.
+    fn create_joker(
         &mut self,
-        _ante: i32,
+        source: &str,
+        ante: i32,
         prob_mult: u32,
         exclude: &[Jokers],
-        rarity: Rarity,
+        rarity: Option<Rarity>,
     ) -> Jokers;
-    fn pick_random_joker(&mut self, available: Vec<Jokers>) -> Jokers;
-    fn clone_joker(&mut self, j: Jokers) -> Jokers;
-    fn shuffle_deck(&mut self, deck: &mut Deck);
-    fn prob_roll(&mut self, numerator: u32, denominator: u32) -> bool;
-    /// Returns `(small_blind_tag, big_blind_tag)` for a fresh ante.
-    fn draw_ante_tags(&mut self) -> (Tag, Tag);
-    /// Returns the Boss Blind for a fresh ante.
-    fn draw_boss(&mut self, ante: i32) -> BossBlind;
-    /// A single random Tarot consumable, respecting `exclude` — used by
-    /// the purple-seal discard trigger and the Emperor/High Priestess
-    /// tarot effects.
-    fn roll_random_tarot(&mut self, exclude: &[Tarot]) -> Consumable;
-    /// A single random Planet consumable, respecting `exclude` — used by
-    /// the blue-seal round-end trigger and the High Priestess tarot effect.
+    /// A Tarot created by a joker (Cartomancer, 8 Ball, Vagabond, ...),
+    /// keyed by the creator's `source` like `create_joker`. Never The Soul.
+    fn create_tarot(&mut self, source: &str, ante: i32, exclude: &[Tarot]) -> Consumable;
+    /// A Spectral created by a joker (Sixth Sense, Seance). Never The Soul
+    /// or Black Hole.
+    fn create_spectral(&mut self, source: &str, ante: i32) -> Consumable;
+    /// A Planet created by a consumable (High Priestess), keyed by `source`
+    /// like `create_tarot`. Never Black Hole.
+    fn create_planet(
+        &mut self,
+        source: &str,
+        ante: i32,
+        planetarium: &Planetarium,
+        exclude: &[Planets],
+    ) -> Consumable;
+    /// `key` is the real game's shuffle seed, `nr{ante}` for a new round.
+    fn shuffle_deck(&mut self, deck: &mut Deck, key: &str);
+    /// Amber Acorn's joker shuffle, `key` `"aajk"`.
+    fn shuffle_jokers(&mut self, jokers: &mut [Jokers], key: &str);
+    /// Immolate's pick of the cards it destroys.
+    fn shuffle_cards(&mut self, cards: &mut [Card], key: &str);
+    /// A `numerator` in `denominator` chance. `key` is the real game's
+    /// `pseudorandom` seed for the roll (`"lucky_mult"`, `"glass"`, ...).
+    fn prob_roll(&mut self, key: &str, numerator: u32, denominator: u32) -> bool;
+    /// Returns `(small_blind_tag, big_blind_tag)` for a fresh ante, never
+    /// one whose `min_ante` is above `ante`.
+    fn draw_ante_tags(&mut self, ante: i32) -> (Tag, Tag);
+    /// Picks the Boss Blind out of `candidates`, which `BossPool` has
+    /// already narrowed to what the ante allows.
+    fn draw_boss(&mut self, candidates: &[BossBlind]) -> BossBlind;
+    /// A single random Planet consumable, respecting `exclude` — the
+    /// Orbital tag's hand.
     fn roll_random_planet(&mut self, planetarium: &Planetarium, exclude: &[Planets]) -> Consumable;
-    /// Castle/MailInRebate's per-round reroll (`Game::clear_blind`). Mint-time
-    /// rolling for freshly generated jokers already happens inside
-    /// `gen_joker`/`gen_shop_item`/`gen_pack`, each already using the right
-    /// stream per backend — this is only for rerolling an *existing* joker.
+    /// Castle's suit and Mail-In Rebate's rank for the coming round, picked
+    /// off `cards` (every card the player owns). Drawn once per round, held
+    /// or not, the way the real game keys them by the round's `ante`.
+    fn roll_discard_targets(&mut self, cards: &[Card], ante: i32) -> (Suit, Value);
+    /// A selector for a joker loaded from a save. The real game keeps the
+    /// round's targets in the save instead, so there's no stream to follow.
     fn roll_discard_selector(&mut self, j: &mut Jokers);
-    fn roll_random_edition(&mut self) -> Edition;
-    fn roll_random_suit(&mut self) -> Suit;
-    fn roll_random_value(&mut self) -> Value;
-    fn pick_random_card(&mut self, available: Vec<Card>) -> Card;
-    fn gen_random_card(
-        &mut self,
-        prob_mult: u32,
-        force_enhance: bool,
-        force_values: Option<&[Value]>,
-    ) -> Card;
+    /// Aura and Wheel of Fortune's edition, never Negative.
+    fn roll_random_edition(&mut self, key: &str) -> Edition;
+    /// Stickers for a fresh shop joker under `stake`, keyed by the `ante`
+    /// it's created in. Eternal and Perishable share one roll so a joker
+    /// never gets both.
+    fn roll_stickers(&mut self, stake: Stake, ante: i32) -> Stickers;
+    /// Sigil's suit.
+    fn roll_random_suit(&mut self, key: &str) -> Suit;
+    /// Ouija's rank.
+    fn roll_random_value(&mut self, key: &str) -> Value;
+    /// Certificate's seal for the card it creates.
+    fn roll_random_seal(&mut self, key: &str) -> Seal;
+    /// A plain card of any rank and suit (Certificate, Marble Joker).
+    fn gen_card_front(&mut self, key: &str) -> Card;
+    fn pick_random_card(&mut self, available: Vec<Card>, key: &str) -> Card;
+    /// Perkeo's pick among the held consumables.
+    fn pick_random_consumable(&mut self, available: Vec<Consumable>, key: &str) -> Consumable;
+    /// A Familiar, Grim or Incantation card: an enhanced card of one of
+    /// `values`, its rank and suit off `key` (`"familiar_create"`, ...).
+    fn create_enhanced_card(&mut self, key: &str, prob_mult: u32, values: &[Value]) -> Card;
 }
 
+// The real game's literal suit and rank lists, in the order it picks from.
+const SUITS: [Suit; 4] = [Suit::Spade, Suit::Heart, Suit::Diamond, Suit::Club];
+const VALUES: [Value; 13] = [
+    Value::Two,
+    Value::Three,
+    Value::Four,
+    Value::Five,
+    Value::Six,
+    Value::Seven,
+    Value::Eight,
+    Value::Nine,
+    Value::Ten,
+    Value::Jack,
+    Value::Queen,
+    Value::King,
+    Value::Ace,
+];
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
 pub(crate) struct FastBackend {
@@ -100,6 +184,11 @@ pub(crate) struct FastBackend {
     joker_gen: JokerGenerator,
     consumable_gen: ConsumableGenerator,
     pack_gen: PackGenerator,
+    // redeemed vouchers that shape shop generation
+    #[cfg_attr(feature = "serde", serde(default))]
+    vouchers: Vec<Voucher>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    deck: Option<DeckVariant>,
 }
 
 impl FastBackend {
@@ -108,9 +197,33 @@ impl FastBackend {
             rng,
             joker_gen: JokerGenerator::new(),
             consumable_gen: ConsumableGenerator::new(),
-            pack_gen: PackGenerator {},
+            pack_gen: PackGenerator::new(),
+            vouchers: Vec::new(),
+            deck: None,
         }
     }
+
+    // Magic Trick sells plain cards, Illusion lets them roll an
+    // enhancement, edition and seal too.
+    fn gen_shop_playing_card(&mut self) -> Card {
+        let mut card =
+            gen_random_playing_card(1, self.joker_gen.edition_rate, &mut self.rng, false, None);
+        if !self.vouchers.contains(&Voucher::Illusion) {
+            card.enhancement = None;
+            card.edition = Edition::Base;
+            card.seal = None;
+        }
+        card
+    }
+}
+
+/// Vouchers a shop may offer given what's been redeemed: not already
+/// redeemed, base voucher redeemed for upgrades, and not in `exclude`.
+fn eligible_vouchers(redeemed: &[Voucher], exclude: &[Voucher]) -> Vec<Voucher> {
+    Voucher::iter()
+        .filter(|v| !redeemed.contains(v) && !exclude.contains(v))
+        .filter(|v| v.requires().is_none_or(|base| redeemed.contains(&base)))
+        .collect()
 }
 
 impl RngBackend for FastBackend {
@@ -123,8 +236,31 @@ impl RngBackend for FastBackend {
         exclude_tarots: &[Tarot],
         exclude_planets: &[Planets],
     ) -> GeneratedItem {
-        // Joker=20, Tarot=4, Planet=4.
-        let weights = [20u32, 4, 4];
+        // Joker=20, Tarot=4, Planet=4, PlayingCard=0, Spectral=0 (x10 so the
+        // merchant rates are integers). Tarot/Planet Merchant 9.6, Tycoon 32,
+        // Magic Trick playing cards 4, Ghost Deck spectrals 2.
+        let rate = |merchant: Voucher, tycoon: Voucher| {
+            if self.vouchers.contains(&tycoon) {
+                320u32
+            } else if self.vouchers.contains(&merchant) {
+                96
+            } else {
+                40
+            }
+        };
+        let tarot = rate(Voucher::TarotMerchant, Voucher::TarotTycoon);
+        let planet = rate(Voucher::PlanetMerchant, Voucher::PlanetTycoon);
+        let card = if self.vouchers.contains(&Voucher::MagicTrick) {
+            40
+        } else {
+            0
+        };
+        let spectral = if self.deck == Some(DeckVariant::Ghost) {
+            20
+        } else {
+            0
+        };
+        let weights = [200u32, tarot, planet, card, spectral];
         let dist = WeightedIndex::new(weights).unwrap();
         match dist.sample(&mut self.rng) {
             0 => GeneratedItem::Joker(self.joker_gen.gen_joker(
@@ -136,11 +272,15 @@ impl RngBackend for FastBackend {
                 self.consumable_gen
                     .gen_tarot_consumable(exclude_tarots, &mut self.rng),
             ),
-            _ => GeneratedItem::Consumable(self.consumable_gen.gen_planet_consumable(
+            2 => GeneratedItem::Consumable(self.consumable_gen.gen_planet_consumable(
                 planetarium,
                 exclude_planets,
                 &mut self.rng,
             )),
+            3 => GeneratedItem::PlayingCard(self.gen_shop_playing_card()),
+            _ => GeneratedItem::Consumable(Consumable::Spectral(crate::spectral::random_spectral(
+                &mut self.rng,
+            ))),
         }
     }
 
@@ -156,60 +296,128 @@ impl RngBackend for FastBackend {
             .gen_pack(planetarium, prob_mult, exclude, held_jokers, &mut self.rng)
     }
 
+    fn gen_pack_of(
+        &mut self,
+        _ante: i32,
+        planetarium: &Planetarium,
+        prob_mult: u32,
+        category: PackCategory,
+        size: PackSize,
+        held_jokers: &[Jokers],
+    ) -> Pack {
+        self.pack_gen.gen_pack_of(
+            category,
+            size,
+            planetarium,
+            prob_mult,
+            held_jokers,
+            &mut self.rng,
+        )
+    }
+
+    fn draw_voucher(
+        &mut self,
+        _ante: i32,
+        redeemed: &[Voucher],
+        exclude: &[Voucher],
+    ) -> Option<Voucher> {
+        eligible_vouchers(redeemed, exclude)
+            .choose(&mut self.rng)
+            .copied()
+    }
+
+    fn on_voucher_redeemed(&mut self, voucher: Voucher) {
+        self.vouchers.push(voucher);
+        let edition_rate = if self.vouchers.contains(&Voucher::GlowUp) {
+            4
+        } else if self.vouchers.contains(&Voucher::Hone) {
+            2
+        } else {
+            1
+        };
+        self.joker_gen.edition_rate = edition_rate;
+        self.pack_gen.edition_rate = edition_rate;
+        self.pack_gen.omen_globe = self.vouchers.contains(&Voucher::OmenGlobe);
+    }
+
     fn on_joker_bought(&mut self, _joker: &Jokers) {}
     fn on_joker_sold(&mut self, _joker: &Jokers) {}
+    fn set_deck(&mut self, deck: DeckVariant) {
+        self.deck = Some(deck);
+    }
+
     fn set_showman(&mut self, _owned: bool) {}
 
-    fn gen_joker(&mut self, _ante: i32, prob_mult: u32, exclude: &[Jokers]) -> Jokers {
-        self.joker_gen.gen_joker(prob_mult, exclude, &mut self.rng)
+    fn pick_random_joker(&mut self, available: Vec<Jokers>, _key: &str) -> Jokers {
+        let idx = self.rng.gen_range(0..available.len());
+        available[idx].clone()
+    }
+
+    fn clone_joker(&mut self, j: Jokers) -> Jokers {
+        self.joker_gen.clone_joker(j, &mut self.rng)
     }
 
-    fn gen_joker_of_rarity(
+    fn create_joker(
         &mut self,
+        _source: &str,
         _ante: i32,
         prob_mult: u32,
         exclude: &[Jokers],
-        rarity: Rarity,
+        rarity: Option<Rarity>,
     ) -> Jokers {
-        self.joker_gen
-            .gen_joker_of_rarity(prob_mult, exclude, rarity, &mut self.rng)
+        match rarity {
+            Some(rarity) => {
+                self.joker_gen
+                    .gen_joker_of_rarity(prob_mult, exclude, rarity, &mut self.rng)
+            }
+            None => self.joker_gen.gen_joker(prob_mult, exclude, &mut self.rng),
+        }
     }
 
-    fn pick_random_joker(&mut self, available: Vec<Jokers>) -> Jokers {
-        let idx = self.rng.gen_range(0..available.len());
-        available[idx].clone()
+    fn create_tarot(&mut self, _source: &str, _ante: i32, exclude: &[Tarot]) -> Consumable {
+        self.consumable_gen
+            .gen_tarot_consumable(exclude, &mut self.rng)
     }
 
-    fn clone_joker(&mut self, j: Jokers) -> Jokers {
-        self.joker_gen.clone_joker(j, &mut self.rng)
+    fn create_spectral(&mut self, _source: &str, _ante: i32) -> Consumable {
+        Consumable::Spectral(crate::spectral::random_spectral(&mut self.rng))
     }
 
-    fn shuffle_deck(&mut self, deck: &mut Deck) {
+    fn create_planet(
+        &mut self,
+        _source: &str,
+        _ante: i32,
+        planetarium: &Planetarium,
+        exclude: &[Planets],
+    ) -> Consumable {
+        self.roll_random_planet(planetarium, exclude)
+    }
+
+    fn shuffle_deck(&mut self, deck: &mut Deck, _key: &str) {
         deck.shuffle(&mut self.rng);
     }
 
-    fn prob_roll(&mut self, numerator: u32, denominator: u32) -> bool {
+    fn shuffle_jokers(&mut self, jokers: &mut [Jokers], _key: &str) {
+        jokers.shuffle(&mut self.rng);
+    }
+
+    fn shuffle_cards(&mut self, cards: &mut [Card], _key: &str) {
+        cards.shuffle(&mut self.rng);
+    }
+
+    fn prob_roll(&mut self, _key: &str, numerator: u32, denominator: u32) -> bool {
         self.rng.gen_ratio(numerator.min(denominator), denominator)
     }
 
-    fn draw_ante_tags(&mut self) -> (Tag, Tag) {
-        let tags: Vec<Tag> = Tag::iter().collect();
+    fn draw_ante_tags(&mut self, ante: i32) -> (Tag, Tag) {
+        let tags: Vec<Tag> = Tag::iter().filter(|t| ante >= t.min_ante()).collect();
         let small = *tags.choose(&mut self.rng).unwrap();
         let big = *tags.choose(&mut self.rng).unwrap();
         (small, big)
     }
 
-    fn draw_boss(&mut self, ante: i32) -> BossBlind {
-        let pool: Vec<BossBlind> = BossBlind::iter()
-            .filter(|b| b.is_finisher() == (ante % 8 == 0))
-            .filter(|b| ante >= b.min_ante())
-            .collect();
-        *pool.choose(&mut self.rng).unwrap()
-    }
-
-    fn roll_random_tarot(&mut self, exclude: &[Tarot]) -> Consumable {
-        self.consumable_gen
-            .gen_tarot_consumable(exclude, &mut self.rng)
+    fn draw_boss(&mut self, candidates: &[BossBlind]) -> BossBlind {
+        *candidates.choose(&mut self.rng).unwrap()
     }
 
     fn roll_random_planet(&mut self, planetarium: &Planetarium, exclude: &[Planets]) -> Consumable {
@@ -217,36 +425,72 @@ impl RngBackend for FastBackend {
             .gen_planet_consumable(planetarium, exclude, &mut self.rng)
     }
 
+    fn roll_discard_targets(&mut self, _cards: &[Card], _ante: i32) -> (Suit, Value) {
+        let suit = SUITS[self.rng.gen_range(0..SUITS.len())];
+        let value = VALUES[self.rng.gen_range(0..VALUES.len())];
+        (suit, value)
+    }
+
     fn roll_discard_selector(&mut self, j: &mut Jokers) {
         crate::joker::roll_discard_selector(&mut self.rng, j);
     }
 
-    fn roll_random_edition(&mut self) -> Edition {
+    fn roll_random_edition(&mut self, _key: &str) -> Edition {
         const EDITIONS: [Edition; 3] = [Edition::Foil, Edition::Holographic, Edition::Polychrome];
         EDITIONS[self.rng.gen_range(0..EDITIONS.len())]
     }
 
-    fn roll_random_suit(&mut self) -> Suit {
+    fn roll_stickers(&mut self, stake: Stake, _ante: i32) -> Stickers {
+        let mut stickers = Stickers::default();
+        if stake >= Stake::Black {
+            let poll: f64 = self.rng.gen();
+            if poll > 0.7 {
+                stickers.eternal = true;
+            } else if stake >= Stake::Orange && poll > 0.4 {
+                stickers.perishable = true;
+            }
+        }
+        if stake >= Stake::Gold && self.rng.gen::<f64>() > 0.7 {
+            stickers.rental = true;
+        }
+        stickers
+    }
+
+    fn roll_random_suit(&mut self, _key: &str) -> Suit {
         let suits: Vec<Suit> = Suit::iter().collect();
         suits[self.rng.gen_range(0..suits.len())]
     }
 
-    fn roll_random_value(&mut self) -> Value {
+    fn roll_random_value(&mut self, _key: &str) -> Value {
         let values: Vec<Value> = Value::iter().collect();
         values[self.rng.gen_range(0..values.len())]
     }
 
-    fn pick_random_card(&mut self, available: Vec<Card>) -> Card {
+    fn roll_random_seal(&mut self, _key: &str) -> Seal {
+        let seals: Vec<Seal> = Seal::iter().collect();
+        seals[self.rng.gen_range(0..seals.len())]
+    }
+
+    fn gen_card_front(&mut self, key: &str) -> Card {
+        Card::new(self.roll_random_value(key), self.roll_random_suit(key))
+    }
+
+    fn pick_random_card(&mut self, available: Vec<Card>, _key: &str) -> Card {
         available[self.rng.gen_range(0..available.len())]
     }
 
-    fn gen_random_card(
-        &mut self,
-        prob_mult: u32,
-        force_enhance: bool,
-        force_values: Option<&[Value]>,
-    ) -> Card {
-        gen_random_playing_card(prob_mult, &mut self.rng, force_enhance, force_values)
+    fn pick_random_consumable(&mut self, available: Vec<Consumable>, _key: &str) -> Consumable {
+        available[self.rng.gen_range(0..available.len())]
+    }
+
+    fn create_enhanced_card(&mut self, _key: &str, prob_mult: u32, values: &[Value]) -> Card {
+        gen_random_playing_card(
+            prob_mult,
+            self.joker_gen.edition_rate,
+            &mut self.rng,
+            true,
+            Some(values),
+        )
     }
 }
 
@@ -254,22 +498,31 @@ impl RngBackend for FastBackend {
 #[derive(Debug, Clone)]
 pub(crate) struct RealBackend {
     instance: Instance,
-    // `Instance` only replays real Balatro's actual seed algorithm,
-    // need a separate dedicated fast rng for some other things atm.
+    // Engine-only rolls the real game has no stream for: a fresh Castle's
+    // selector, or a voucher when `next_voucher` lands on one not on offer.
     extra_rng: ChaCha8Rng,
-    // Need a fast backend for gen that real doesn't yet impl
-    fast: FastBackend,
 }
 
 impl RealBackend {
-    pub(crate) fn new(seed: &str, fast: FastBackend) -> Self {
+    pub(crate) fn new(seed: &str) -> Self {
+        let mut instance = Instance::new(seed);
+        // Upgrade vouchers stay out of the pool until their base is redeemed
+        for v in Voucher::iter().filter(|v| v.requires().is_some()) {
+            instance.lock(&v);
+        }
         RealBackend {
-            instance: Instance::new(seed),
+            instance,
             extra_rng: ChaCha8Rng::seed_from_u64(crate::seed_from_str(seed).wrapping_add(1)),
-            fast,
         }
     }
 
+    /// Locks `locked` out of every pool, for a specific real account (see
+    /// `Config::with_profile`).
+    pub(crate) fn lock_profile(&mut self, locked: &[String]) {
+        self.instance
+            .init_profile_locks(|name| !locked.iter().any(|l| l == name));
+    }
+
     fn gen_pack_contents(
         &mut self,
         ante: i32,
@@ -306,22 +559,12 @@ impl RealBackend {
                 .instance
                 .next_standard_pack(count, ante)
                 .into_iter()
-                .map(|c| PackContent::PlayingCard(seed_card_to_core_card(c)))
+                .map(|c| PackContent::PlayingCard(c.into()))
                 .collect(),
         }
     }
 }
 
-/// `balatro_types::Card` has no `id`; `core::Card` adds one, so only
-/// `value`/`suit` go through the id-assigning constructor.
-fn seed_card_to_core_card(c: balatro_types::Card) -> Card {
-    let mut card = Card::new(c.value, c.suit);
-    card.edition = c.edition;
-    card.enhancement = c.enhancement;
-    card.seal = c.seal;
-    card
-}
-
 /// Joker generation needs some engine only init at create time
 fn seed_joker_with_id(mut j: Jokers, rng: &mut impl Rng) -> Jokers {
     j.set_instance_id(crate::joker::mint_joker_id());
@@ -356,14 +599,8 @@ impl RngBackend for RealBackend {
                 GeneratedItem::Joker(seed_joker_with_id(j, &mut self.extra_rng))
             }
             balatro_seed::ShopItem::Consumable(c) => GeneratedItem::Consumable(c),
-            balatro_seed::ShopItem::PlayingCard => {
-                // Unreachable: needs Magic Trick active, and core has no
-                // voucher-shop mechanic to ever activate it.
-                panic!(
-                    "balatro-seed produced a shop playing card; core has no \
-                     voucher mechanic to ever enable Magic Trick's nonzero rate"
-                )
-            }
+            // only once Magic Trick is redeemed
+            balatro_seed::ShopItem::PlayingCard(c) => GeneratedItem::PlayingCard(c.into()),
         }
     }
 
@@ -385,6 +622,59 @@ impl RngBackend for RealBackend {
         }
     }
 
+    fn gen_pack_of(
+        &mut self,
+        ante: i32,
+        _planetarium: &Planetarium,
+        _prob_mult: u32,
+        category: PackCategory,
+        size: PackSize,
+        _held_jokers: &[Jokers],
+    ) -> Pack {
+        let count = balatro_seed::pack_card_count(category, size);
+        let contents = self.gen_pack_contents(ante, category, count);
+        Pack {
+            category,
+            size,
+            contents,
+        }
+    }
+
+    // Shown vouchers are locked for the draw so the Voucher tag's extra
+    // offer can't repeat one. Anything `next_voucher` turns up that still
+    // isn't eligible falls back to a fast pick.
+    fn draw_voucher(
+        &mut self,
+        ante: i32,
+        redeemed: &[Voucher],
+        exclude: &[Voucher],
+    ) -> Option<Voucher> {
+        let eligible = eligible_vouchers(redeemed, exclude);
+        if eligible.is_empty() {
+            return None;
+        }
+        for v in exclude {
+            self.instance.lock(v);
+        }
+        let drawn = self.instance.next_voucher(ante);
+        for v in exclude {
+            self.instance.unlock(v);
+        }
+        if eligible.contains(&drawn) {
+            Some(drawn)
+        } else {
+            eligible.choose(&mut self.extra_rng).copied()
+        }
+    }
+
+    fn on_voucher_redeemed(&mut self, voucher: Voucher) {
+        self.instance.activate_voucher(&voucher);
+        self.instance.lock(&voucher);
+        if let Some(upgrade) = balatro_seed::voucher_upgrade(voucher) {
+            self.instance.unlock(&upgrade);
+        }
+    }
+
     fn on_joker_bought(&mut self, joker: &Jokers) {
         self.instance.lock(joker);
     }
@@ -393,112 +683,247 @@ impl RngBackend for RealBackend {
         self.instance.unlock(joker);
     }
 
+    fn set_deck(&mut self, deck: DeckVariant) {
+        self.instance.params.deck = deck.name().to_string();
+    }
+
     fn set_showman(&mut self, owned: bool) {
         self.instance.params.showman = owned;
     }
 
-    // `Instance::next_joker(source, ante)` exists and isn't wired up yet,
-    // real accuracy here is a follow-up. Reuses the same Fast-style
-    // generator `FastBackend` uses, seeded off `extra_rng` instead.
-    fn gen_joker(&mut self, ante: i32, prob_mult: u32, exclude: &[Jokers]) -> Jokers {
-        self.fast.gen_joker(ante, prob_mult, exclude)
+    // Sorted by `instance_id`, which stands in for the real game's
+    // `sort_id` like `Card.id` does in `shuffle_deck`.
+    fn pick_random_joker(&mut self, mut available: Vec<Jokers>, key: &str) -> Jokers {
+        available.sort_by_key(|j| j.instance_id());
+        self.instance.pseudorandom_element(&available, key).clone()
     }
 
-    fn gen_joker_of_rarity(
+    // The real game's `copy_card` draws nothing, only the engine's own
+    // mint-time state is rolled.
+    fn clone_joker(&mut self, j: Jokers) -> Jokers {
+        seed_joker_with_id(j, &mut self.extra_rng)
+    }
+
+    // The rarity is implied by `source` ("rif" is always common), same
+    // as the real game's `create_card` call.
+    fn create_joker(
         &mut self,
+        source: &str,
         ante: i32,
-        prob_mult: u32,
-        exclude: &[Jokers],
-        rarity: Rarity,
+        _prob_mult: u32,
+        _exclude: &[Jokers],
+        _rarity: Option<Rarity>,
     ) -> Jokers {
-        self.fast
-            .gen_joker_of_rarity(ante, prob_mult, exclude, rarity)
+        let joker = self.instance.next_joker(source, ante);
+        seed_joker_with_id(joker, &mut self.extra_rng)
     }
 
-    fn pick_random_joker(&mut self, available: Vec<Jokers>) -> Jokers {
-        self.fast.pick_random_joker(available)
+    fn create_tarot(&mut self, source: &str, ante: i32, _exclude: &[Tarot]) -> Consumable {
+        self.instance.next_tarot(source, ante, false)
     }
 
-    fn clone_joker(&mut self, j: Jokers) -> Jokers {
-        self.fast.clone_joker(j)
+    fn create_spectral(&mut self, source: &str, ante: i32) -> Consumable {
+        self.instance.next_spectral(source, ante, false)
+    }
+
+    fn create_planet(
+        &mut self,
+        source: &str,
+        ante: i32,
+        _planetarium: &Planetarium,
+        _exclude: &[Planets],
+    ) -> Consumable {
+        self.instance.next_planet(source, ante, false)
     }
 
-    // No real Instance equivalent for deck draw order, yet
-    fn shuffle_deck(&mut self, deck: &mut Deck) {
-        self.fast.shuffle_deck(deck);
+    fn shuffle_deck(&mut self, deck: &mut Deck, key: &str) {
+        let instance = &mut self.instance;
+        deck.shuffle_by(|cards| instance.shuffle(cards, key));
     }
 
-    // No real Instance equivalent for scoring-time probability procs
-    // (Lucky/Misprint/SpaceJoker/OopsAllSixes-class effects).
-    fn prob_roll(&mut self, numerator: u32, denominator: u32) -> bool {
-        self.fast.prob_roll(numerator, denominator)
+    fn shuffle_jokers(&mut self, jokers: &mut [Jokers], key: &str) {
+        jokers.sort_by_key(|j| j.instance_id());
+        self.instance.shuffle(jokers, key);
     }
 
-    // `Instance::next_tag(ante)` exists and isn't wired up yet - real
-    // accuracy here is a follow-up.
-    fn draw_ante_tags(&mut self) -> (Tag, Tag) {
-        self.fast.draw_ante_tags()
+    fn shuffle_cards(&mut self, cards: &mut [Card], key: &str) {
+        cards.sort_by_key(|c| c.id);
+        self.instance.shuffle(cards, key);
     }
 
-    // Correct category (regular vs. finisher) and correct pool-cycling, both
-    // handled internally by `next_boss`. Minimum-ante is NOT: real Balatro
-    // enforces that by pre-locking each not-yet-eligible boss name via
-    // `Instance::init_locks`/`init_unlocks`, which nothing in `core` calls.
-    // TODO: Fix min ante for real rng boss gen
-    fn draw_boss(&mut self, ante: i32) -> BossBlind {
-        self.instance.next_boss(ante)
+    // The real game's `pseudorandom(key) < normal / odds`, with Oops! All
+    // 6s already folded into `numerator`.
+    fn prob_roll(&mut self, key: &str, numerator: u32, denominator: u32) -> bool {
+        self.instance.pseudorandom(key) < numerator as f64 / denominator as f64
     }
 
-    // `Instance::next_tarot(source, ante, soulable)` exists and isn't
-    // wired up yet - real accuracy here is a follow-up.
-    fn roll_random_tarot(&mut self, exclude: &[Tarot]) -> Consumable {
-        self.fast.roll_random_tarot(exclude)
+    // Tags not yet offered at this ante are locked for the draw, the same
+    // as `Instance::init_locks` does for a fresh run.
+    fn draw_ante_tags(&mut self, ante: i32) -> (Tag, Tag) {
+        let gated: Vec<Tag> = Tag::iter().filter(|t| ante < t.min_ante()).collect();
+        for t in &gated {
+            self.instance.lock(t.name());
+        }
+        let tags = (self.instance.next_tag(ante), self.instance.next_tag(ante));
+        for t in &gated {
+            self.instance.unlock(t.name());
+        }
+        tags
     }
 
-    // `Instance::next_planet(source, ante, soulable)` exists and isn't
-    // wired up yet - real accuracy here is a follow-up.
+    fn draw_boss(&mut self, candidates: &[BossBlind]) -> BossBlind {
+        self.instance.next_boss_among(candidates)
+    }
+
+    // The real game picks Orbital's hand off `pseudoseed('orbital')`, out of
+    // the visible hands. It lists them in Lua table order, which can't be
+    // reproduced, so the stream stays in step but the pick may differ.
     fn roll_random_planet(&mut self, planetarium: &Planetarium, exclude: &[Planets]) -> Consumable {
-        self.fast.roll_random_planet(planetarium, exclude)
+        let visible: Vec<Planets> = Planets::iter()
+            .filter(|p| !exclude.contains(p))
+            .filter(|p| !p.is_secret() || planetarium.level(p.hand_rank()).plays > 0)
+            .collect();
+        if visible.is_empty() {
+            return self.roll_random_planet(planetarium, &[]);
+        }
+        Consumable::Planet(*self.instance.pseudorandom_element(&visible, "orbital"))
+    }
+
+    // The real game's `reset_castle_card`/`reset_mail_rank`: picks among
+    // the non-Stone cards, Spades and Aces when there are none.
+    fn roll_discard_targets(&mut self, cards: &[Card], ante: i32) -> (Suit, Value) {
+        let mut valid: Vec<Card> = cards
+            .iter()
+            .filter(|c| c.enhancement != Some(Enhancement::Stone))
+            .copied()
+            .collect();
+        if valid.is_empty() {
+            return (Suit::Spade, Value::Ace);
+        }
+        valid.sort_by_key(|c| c.id);
+        let value = self
+            .instance
+            .pseudorandom_element(&valid, &format!("mail{ante}"))
+            .value;
+        let suit = self
+            .instance
+            .pseudorandom_element(&valid, &format!("cas{ante}"))
+            .suit;
+        (suit, value)
     }
 
     fn roll_discard_selector(&mut self, j: &mut Jokers) {
-        self.fast.roll_discard_selector(j);
+        crate::joker::roll_discard_selector(&mut self.extra_rng, j);
+    }
+
+    // `poll_edition(key, nil, true, true)`: guaranteed, no Negative.
+    fn roll_random_edition(&mut self, key: &str) -> Edition {
+        let poll = self.instance.pseudorandom(key);
+        if poll > 0.85 {
+            Edition::Polychrome
+        } else if poll > 0.5 {
+            Edition::Holographic
+        } else {
+            Edition::Foil
+        }
     }
 
-    fn roll_random_edition(&mut self) -> Edition {
-        self.fast.roll_random_edition()
+    // The Eternal/Perishable poll is drawn for every shop joker, the
+    // Rental one only once Gold Stake enables it.
+    fn roll_stickers(&mut self, stake: Stake, ante: i32) -> Stickers {
+        let mut stickers = Stickers::default();
+        let poll = self.instance.pseudorandom(&format!("etperpoll{ante}"));
+        if stake >= Stake::Black && poll > 0.7 {
+            stickers.eternal = true;
+        } else if stake >= Stake::Orange && poll > 0.4 && poll <= 0.7 {
+            stickers.perishable = true;
+        }
+        if stake >= Stake::Gold && self.instance.pseudorandom(&format!("ssjr{ante}")) > 0.7 {
+            stickers.rental = true;
+        }
+        stickers
     }
 
-    fn roll_random_suit(&mut self) -> Suit {
-        self.fast.roll_random_suit()
+    fn roll_random_suit(&mut self, key: &str) -> Suit {
+        *self.instance.pseudorandom_element(&SUITS, key)
     }
 
-    fn roll_random_value(&mut self) -> Value {
-        self.fast.roll_random_value()
+    fn roll_random_value(&mut self, key: &str) -> Value {
+        *self.instance.pseudorandom_element(&VALUES, key)
     }
 
-    fn pick_random_card(&mut self, available: Vec<Card>) -> Card {
-        self.fast.pick_random_card(available)
+    fn roll_random_seal(&mut self, key: &str) -> Seal {
+        let poll = self.instance.pseudorandom(key);
+        if poll > 0.75 {
+            Seal::Red
+        } else if poll > 0.5 {
+            Seal::Blue
+        } else if poll > 0.25 {
+            Seal::Gold
+        } else {
+            Seal::Purple
+        }
     }
 
-    fn gen_random_card(
-        &mut self,
-        prob_mult: u32,
-        force_enhance: bool,
-        force_values: Option<&[Value]>,
-    ) -> Card {
-        self.fast
-            .gen_random_card(prob_mult, force_enhance, force_values)
+    fn gen_card_front(&mut self, key: &str) -> Card {
+        self.instance.next_card_front(key).into()
+    }
+
+    fn pick_random_card(&mut self, mut available: Vec<Card>, key: &str) -> Card {
+        available.sort_by_key(|c| c.id);
+        *self.instance.pseudorandom_element(&available, key)
+    }
+
+    // Consumables carry no id, so held order stands in for `sort_id`.
+    fn pick_random_consumable(&mut self, available: Vec<Consumable>, key: &str) -> Consumable {
+        *self.instance.pseudorandom_element(&available, key)
+    }
+
+    // Rank (unless there's only one), then suit off `key`, then the
+    // enhancement off `"spe_card"`, never Stone. No edition or seal.
+    fn create_enhanced_card(&mut self, key: &str, _prob_mult: u32, values: &[Value]) -> Card {
+        const ENHANCEMENTS: [Enhancement; 7] = [
+            Enhancement::Bonus,
+            Enhancement::Mult,
+            Enhancement::Wild,
+            Enhancement::Glass,
+            Enhancement::Steel,
+            Enhancement::Gold,
+            Enhancement::Lucky,
+        ];
+        let value = match values {
+            [value] => *value,
+            _ => *self.instance.pseudorandom_element(values, key),
+        };
+        let suit = *self.instance.pseudorandom_element(&SUITS, key);
+        let mut card = Card::new(value, suit);
+        card.enhancement = Some(
+            *self
+                .instance
+                .pseudorandom_element(&ENHANCEMENTS, "spe_card"),
+        );
+        card
     }
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub(crate) enum Backend {
     Fast(FastBackend),
     Real(RealBackend),
 }
 
+impl Backend {
+    /// The real seed streams, `None` under `RngMode::Fast`.
+    pub(crate) fn instance(&self) -> Option<&Instance> {
+        match self {
+            Backend::Fast(_) => None,
+            Backend::Real(b) => Some(&b.instance),
+        }
+    }
+}
+
 impl RngBackend for Backend {
     fn gen_shop_item(
         &mut self,
@@ -543,6 +968,44 @@ impl RngBackend for Backend {
         }
     }
 
+    fn gen_pack_of(
+        &mut self,
+        ante: i32,
+        planetarium: &Planetarium,
+        prob_mult: u32,
+        category: PackCategory,
+        size: PackSize,
+        held_jokers: &[Jokers],
+    ) -> Pack {
+        match self {
+            Backend::Fast(b) => {
+                b.gen_pack_of(ante, planetarium, prob_mult, category, size, held_jokers)
+            }
+            Backend::Real(b) => {
+                b.gen_pack_of(ante, planetarium, prob_mult, category, size, held_jokers)
+            }
+        }
+    }
+
+    fn draw_voucher(
+        &mut self,
+        ante: i32,
+        redeemed: &[Voucher],
+        exclude: &[Voucher],
+    ) -> Option<Voucher> {
+        match self {
+            Backend::Fast(b) => b.draw_voucher(ante, redeemed, exclude),
+            Backend::Real(b) => b.draw_voucher(ante, redeemed, exclude),
+        }
+    }
+
+    fn on_voucher_redeemed(&mut self, voucher: Voucher) {
+        match self {
+            Backend::Fast(b) => b.on_voucher_redeemed(voucher),
+            Backend::Real(b) => b.on_voucher_redeemed(voucher),
+        }
+    }
+
     fn on_joker_bought(&mut self, joker: &Jokers) {
         match self {
             Backend::Fast(b) => b.on_joker_bought(joker),
@@ -557,6 +1020,13 @@ impl RngBackend for Backend {
         }
     }
 
+    fn set_deck(&mut self, deck: DeckVariant) {
+        match self {
+            Backend::Fast(b) => b.set_deck(deck),
+            Backend::Real(b) => b.set_deck(deck),
+        }
+    }
+
     fn set_showman(&mut self, owned: bool) {
         match self {
             Backend::Fast(b) => b.set_showman(owned),
@@ -564,72 +1034,100 @@ impl RngBackend for Backend {
         }
     }
 
-    fn gen_joker(&mut self, ante: i32, prob_mult: u32, exclude: &[Jokers]) -> Jokers {
+    fn pick_random_joker(&mut self, available: Vec<Jokers>, key: &str) -> Jokers {
+        match self {
+            Backend::Fast(b) => b.pick_random_joker(available, key),
+            Backend::Real(b) => b.pick_random_joker(available, key),
+        }
+    }
+
+    fn clone_joker(&mut self, j: Jokers) -> Jokers {
         match self {
-            Backend::Fast(b) => b.gen_joker(ante, prob_mult, exclude),
-            Backend::Real(b) => b.gen_joker(ante, prob_mult, exclude),
+            Backend::Fast(b) => b.clone_joker(j),
+            Backend::Real(b) => b.clone_joker(j),
         }
     }
 
-    fn gen_joker_of_rarity(
+    fn create_joker(
         &mut self,
+        source: &str,
         ante: i32,
         prob_mult: u32,
         exclude: &[Jokers],
-        rarity: Rarity,
+        rarity: Option<Rarity>,
     ) -> Jokers {
         match self {
-            Backend::Fast(b) => b.gen_joker_of_rarity(ante, prob_mult, exclude, rarity),
-            Backend::Real(b) => b.gen_joker_of_rarity(ante, prob_mult, exclude, rarity),
+            Backend::Fast(b) => b.create_joker(source, ante, prob_mult, exclude, rarity),
+            Backend::Real(b) => b.create_joker(source, ante, prob_mult, exclude, rarity),
         }
     }
 
-    fn pick_random_joker(&mut self, available: Vec<Jokers>) -> Jokers {
+    fn create_tarot(&mut self, source: &str, ante: i32, exclude: &[Tarot]) -> Consumable {
         match self {
-            Backend::Fast(b) => b.pick_random_joker(available),
-            Backend::Real(b) => b.pick_random_joker(available),
+            Backend::Fast(b) => b.create_tarot(source, ante, exclude),
+            Backend::Real(b) => b.create_tarot(source, ante, exclude),
         }
     }
 
-    fn clone_joker(&mut self, j: Jokers) -> Jokers {
+    fn create_spectral(&mut self, source: &str, ante: i32) -> Consumable {
         match self {
-            Backend::Fast(b) => b.clone_joker(j),
-            Backend::Real(b) => b.clone_joker(j),
+            Backend::Fast(b) => b.create_spectral(source, ante),
+            Backend::Real(b) => b.create_spectral(source, ante),
         }
     }
 
-    fn shuffle_deck(&mut self, deck: &mut Deck) {
+    fn create_planet(
+        &mut self,
+        source: &str,
+        ante: i32,
+        planetarium: &Planetarium,
+        exclude: &[Planets],
+    ) -> Consumable {
+        match self {
+            Backend::Fast(b) => b.create_planet(source, ante, planetarium, exclude),
+            Backend::Real(b) => b.create_planet(source, ante, planetarium, exclude),
+        }
+    }
+
+    fn shuffle_deck(&mut self, deck: &mut Deck, key: &str) {
         match self {
-            Backend::Fast(b) => b.shuffle_deck(deck),
-            Backend::Real(b) => b.shuffle_deck(deck),
+            Backend::Fast(b) => b.shuffle_deck(deck, key),
+            Backend::Real(b) => b.shuffle_deck(deck, key),
         }
     }
 
-    fn prob_roll(&mut self, numerator: u32, denominator: u32) -> bool {
+    fn shuffle_jokers(&mut self, jokers: &mut [Jokers], key: &str) {
         match self {
-            Backend::Fast(b) => b.prob_roll(numerator, denominator),
-            Backend::Real(b) => b.prob_roll(numerator, denominator),
+            Backend::Fast(b) => b.shuffle_jokers(jokers, key),
+            Backend::Real(b) => b.shuffle_jokers(jokers, key),
         }
     }
 
-    fn draw_ante_tags(&mut self) -> (Tag, Tag) {
+    fn shuffle_cards(&mut self, cards: &mut [Card], key: &str) {
         match self {
-            Backend::Fast(b) => b.draw_ante_tags(),
-            Backend::Real(b) => b.draw_ante_tags(),
+            Backend::Fast(b) => b.shuffle_cards(cards, key),
+            Backend::Real(b) => b.shuffle_cards(cards, key),
         }
     }
 
-    fn draw_boss(&mut self, ante: i32) -> BossBlind {
+    fn prob_roll(&mut self, key: &str, numerator: u32, denominator: u32) -> bool {
         match self {
-            Backend::Fast(b) => b.draw_boss(ante),
-            Backend::Real(b) => b.draw_boss(ante),
+            Backend::Fast(b) => b.prob_roll(key, numerator, denominator),
+            Backend::Real(b) => b.prob_roll(key, numerator, denominator),
         }
     }
 
-    fn roll_random_tarot(&mut self, exclude: &[Tarot]) -> Consumable {
+    fn draw_ante_tags(&mut self, ante: i32) -> (Tag, Tag) {
         match self {
-            Backend::Fast(b) => b.roll_random_tarot(exclude),
-            Backend::Real(b) => b.roll_random_tarot(exclude),
+            Backend::Fast(b) => b.draw_ante_tags(ante),
+            Backend::Real(b) => b.draw_ante_tags(ante),
+        }
+    }
+
+    fn draw_boss(&mut self, candidates: &[BossBlind]) -> BossBlind {
+        match self {
+            Backend::Fast(b) => b.draw_boss(candidates),
+            Backend::Real(b) => b.draw_boss(candidates),
         }
     }
 
@@ -640,49 +1138,79 @@ impl RngBackend for Backend {
         }
     }
 
+    fn roll_discard_targets(&mut self, cards: &[Card], ante: i32) -> (Suit, Value) {
+        match self {
+            Backend::Fast(b) => b.roll_discard_targets(cards, ante),
+            Backend::Real(b) => b.roll_discard_targets(cards, ante),
+        }
+    }
+
     fn roll_discard_selector(&mut self, j: &mut Jokers) {
         match self {
             Backend::Fast(b) => b.roll_discard_selector(j),
             Backend::Real(b) => b.roll_discard_selector(j),
         }
     }
-    fn roll_random_edition(&mut self) -> Edition {
+    fn roll_random_edition(&mut self, key: &str) -> Edition {
         match self {
-            Backend::Fast(b) => b.roll_random_edition(),
-            Backend::Real(b) => b.roll_random_edition(),
+            Backend::Fast(b) => b.roll_random_edition(key),
+            Backend::Real(b) => b.roll_random_edition(key),
         }
     }
 
-    fn roll_random_suit(&mut self) -> Suit {
+    fn roll_stickers(&mut self, stake: Stake, ante: i32) -> Stickers {
         match self {
-            Backend::Fast(b) => b.roll_random_suit(),
-            Backend::Real(b) => b.roll_random_suit(),
+            Backend::Fast(b) => b.roll_stickers(stake, ante),
+            Backend::Real(b) => b.roll_stickers(stake, ante),
         }
     }
 
-    fn roll_random_value(&mut self) -> Value {
+    fn roll_random_suit(&mut self, key: &str) -> Suit {
         match self {
-            Backend::Fast(b) => b.roll_random_value(),
-            Backend::Real(b) => b.roll_random_value(),
+            Backend::Fast(b) => b.roll_random_suit(key),
+            Backend::Real(b) => b.roll_random_suit(key),
         }
     }
 
-    fn pick_random_card(&mut self, available: Vec<Card>) -> Card {
+    fn roll_random_value(&mut self, key: &str) -> Value {
         match self {
-            Backend::Fast(b) => b.pick_random_card(available),
-            Backend::Real(b) => b.pick_random_card(available),
+            Backend::Fast(b) => b.roll_random_value(key),
+            Backend::Real(b) => b.roll_random_value(key),
         }
     }
 
-    fn gen_random_card(
-        &mut self,
-        prob_mult: u32,
-        force_enhance: bool,
-        force_values: Option<&[Value]>,
-    ) -> Card {
+    fn roll_random_seal(&mut self, key: &str) -> Seal {
+        match self {
+            Backend::Fast(b) => b.roll_random_seal(key),
+            Backend::Real(b) => b.roll_random_seal(key),
+        }
+    }
+
+    fn gen_card_front(&mut self, key: &str) -> Card {
+        match self {
+            Backend::Fast(b) => b.gen_card_front(key),
+            Backend::Real(b) => b.gen_card_front(key),
+        }
+    }
+
+    fn pick_random_card(&mut self, available: Vec<Card>, key: &str) -> Card {
         match self {
-            Backend::Fast(b) => b.gen_random_card(prob_mult, force_enhance, force_values),
-            Backend::Real(b) => b.gen_random_card(prob_mult, force_enhance, force_values),
+            Backend::Fast(b) => b.pick_random_card(available, key),
+            Backend::Real(b) => b.pick_random_card(available, key),
+        }
+    }
+
+    fn pick_random_consumable(&mut self, available: Vec<Consumable>, key: &str) -> Consumable {
+        match self {
+            Backend::Fast(b) => b.pick_random_consumable(available, key),
+            Backend::Real(b) => b.pick_random_consumable(available, key),
+        }
+    }
+
+    fn create_enhanced_card(&mut self, key: &str, prob_mult: u32, values: &[Value]) -> Card {
+        match self {
+            Backend::Fast(b) => b.create_enhanced_card(key, prob_mult, values),
+            Backend::Real(b) => b.create_enhanced_card(key, prob_mult, values),
         }
     }
 }
@@ -690,34 +1218,50 @@ impl RngBackend for Backend {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::joker::JokerEffects;
+    use crate::stage::BossPool;
 
     #[test]
-    fn fast_backend_draw_ante_tags_draws_two_valid_tags() {
+    fn fast_backend_draw_ante_tags_respects_min_ante() {
         let mut backend = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
-        let (small, big) = backend.draw_ante_tags();
-        assert!(Tag::iter().any(|t| t == small));
-        assert!(Tag::iter().any(|t| t == big));
+        for _ in 0..100 {
+            let (small, big) = backend.draw_ante_tags(1);
+            assert!(small.min_ante() <= 1, "ante 1 drew {small:?}");
+            assert!(big.min_ante() <= 1, "ante 1 drew {big:?}");
+        }
     }
 
     #[test]
     fn fast_backend_draw_boss_matches_category() {
         let mut backend = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
-        for ante in [1, 7, 8, 9, 16] {
-            let boss = backend.draw_boss(ante);
+        let pool = BossPool::default();
+        for ante in [0, 1, 7, 8, 9, 16] {
+            let boss = backend.draw_boss(&pool.candidates(ante));
             assert_eq!(
                 boss.is_finisher(),
-                ante % 8 == 0,
+                ante >= 2 && ante % 8 == 0,
                 "ante {ante} drew {boss:?}"
             );
         }
     }
 
+    #[test]
+    fn draw_boss_never_draws_a_finisher_at_ante_zero() {
+        let mut fast = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
+        let mut real = RealBackend::new("TESTSEED");
+        let pool = BossPool::default();
+        for _ in 0..50 {
+            assert!(!fast.draw_boss(&pool.candidates(0)).is_finisher());
+            assert!(!real.draw_boss(&pool.candidates(0)).is_finisher());
+        }
+    }
+
     #[test]
     fn fast_backend_draw_boss_respects_min_ante() {
         let mut backend = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
+        let mut pool = BossPool::default();
         for _ in 0..200 {
-            let boss = backend.draw_boss(1);
+            let boss = backend.draw_boss(&pool.candidates(1));
+            pool.record(boss);
             assert!(boss.min_ante() <= 1, "ante 1 drew {boss:?} (min_ante > 1)");
         }
     }
@@ -728,14 +1272,14 @@ mod tests {
         // numerator 0 -> never; numerator == denominator -> always,
         // regardless of what's drawn.
         for _ in 0..20 {
-            assert!(!backend.prob_roll(0, 10));
+            assert!(!backend.prob_roll("test", 0, 10));
         }
         for _ in 0..20 {
-            assert!(backend.prob_roll(10, 10));
+            assert!(backend.prob_roll("test", 10, 10));
         }
         // numerator > denominator gets clamped to denominator (always true),
         // not a `gen_ratio` panic.
-        assert!(backend.prob_roll(50, 10));
+        assert!(backend.prob_roll("test", 50, 10));
     }
 
     #[test]
@@ -743,7 +1287,7 @@ mod tests {
         let mut backend = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
         let mut deck = crate::deck::Deck::default();
         let before = deck.cards();
-        backend.shuffle_deck(&mut deck);
+        backend.shuffle_deck(&mut deck, "nr1");
         let after = deck.cards();
 
         assert_eq!(before.len(), after.len());
@@ -758,53 +1302,175 @@ mod tests {
         );
     }
 
-    // `RealBackend`'s new methods all fall back to `extra_rng` (no real
-    // `Instance` equivalent wired up yet, see each method's doc comment) -
-    // this is a smoke test that the fallback wiring is complete and doesn't
-    // panic, not a claim of real-Balatro accuracy.
+    // Each real-mode draw should be exactly the `Instance` call it stands
+    // for, so a fresh `Instance` on the same seed replays it.
     #[test]
-    fn real_backend_fallback_methods_all_work() {
-        let fast = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
-        let mut backend = RealBackend::new("TESTSEED", fast);
+    fn real_backend_draws_replay_instance_streams() {
+        let mut backend = RealBackend::new("TESTSEED");
+        let mut reference = Instance::new("TESTSEED");
 
         let mut deck = crate::deck::Deck::default();
-        let before = deck.cards();
-        backend.shuffle_deck(&mut deck);
-        assert_ne!(before, deck.cards());
+        let mut expected = deck.cards();
+        reference.shuffle(&mut expected, "nr1");
+        expected.reverse();
+        backend.shuffle_deck(&mut deck, "nr1");
+        assert_eq!(deck.cards(), expected);
 
-        assert!(!backend.prob_roll(0, 10));
-        assert!(backend.prob_roll(10, 10));
+        let roll = reference.pseudorandom("lucky_mult");
+        assert_eq!(backend.prob_roll("lucky_mult", 1, 5), roll < 0.2);
+        assert!(!backend.prob_roll("glass", 0, 10));
+        assert!(backend.prob_roll("glass", 10, 10));
 
-        let (small, big) = backend.draw_ante_tags();
-        assert!(Tag::iter().any(|t| t == small));
-        assert!(Tag::iter().any(|t| t == big));
+        let tags = (reference.next_tag(3), reference.next_tag(3));
+        assert_eq!(backend.draw_ante_tags(3), tags);
 
         let planetarium = Planetarium::new();
-        let _ = backend.roll_random_planet(&planetarium, &[]);
-        let _ = backend.roll_random_tarot(&[]);
+        assert_eq!(
+            backend.create_planet("pri", 1, &planetarium, &[]),
+            reference.next_planet("pri", 1, false)
+        );
+        let visible: Vec<Planets> = Planets::iter().filter(|p| !p.is_secret()).collect();
+        assert_eq!(
+            backend.roll_random_planet(&planetarium, &[]),
+            Consumable::Planet(*reference.pseudorandom_element(&visible, "orbital"))
+        );
+    }
+
+    // Effect draws (bosses, jokers, consumables) replay off the real game's
+    // keys too, with cards and jokers picked from in creation order however
+    // they're handed in.
+    #[test]
+    fn real_backend_effect_draws_replay_instance_streams() {
+        use crate::joker::TheJoker;
+
+        let mut backend = RealBackend::new("TESTSEED");
+        let mut reference = Instance::new("TESTSEED");
 
-        let joker = backend.gen_joker(1, 1, &[]);
-        assert!(joker.is_implemented());
+        let mut cards = crate::deck::Deck::default().cards();
+        cards.sort_by_key(|c| c.id);
+        let reversed_cards: Vec<Card> = cards.iter().rev().copied().collect();
+        assert_eq!(
+            backend.pick_random_card(reversed_cards.clone(), "hook"),
+            *reference.pseudorandom_element(&cards, "hook")
+        );
+        let mut expected = cards.clone();
+        reference.shuffle(&mut expected, "immolate");
+        let mut shuffled = reversed_cards.clone();
+        backend.shuffle_cards(&mut shuffled, "immolate");
+        assert_eq!(shuffled, expected);
+
+        let jokers: Vec<Jokers> = (1..=4)
+            .map(|id| {
+                let mut j = Jokers::TheJoker(TheJoker::default());
+                j.set_instance_id(id);
+                j
+            })
+            .collect();
+        let mut reversed: Vec<Jokers> = jokers.iter().rev().cloned().collect();
+        assert_eq!(
+            backend
+                .pick_random_joker(reversed.clone(), "madness")
+                .instance_id(),
+            reference
+                .pseudorandom_element(&jokers, "madness")
+                .instance_id()
+        );
+        let mut expected: Vec<usize> = jokers.iter().map(|j| j.instance_id()).collect();
+        reference.shuffle(&mut expected, "aajk");
+        backend.shuffle_jokers(&mut reversed, "aajk");
+        let shuffled: Vec<usize> = reversed.iter().map(|j| j.instance_id()).collect();
+        assert_eq!(shuffled, expected);
+
+        let poll = reference.pseudorandom("aura");
+        let edition = backend.roll_random_edition("aura");
+        assert_ne!(edition, Edition::Negative);
+        assert_eq!(edition == Edition::Polychrome, poll > 0.85);
+        assert_eq!(edition == Edition::Foil, poll <= 0.5);
+
+        let poll = reference.pseudorandom("certsl");
+        let seal = backend.roll_random_seal("certsl");
+        assert_eq!(seal == Seal::Red, poll > 0.75);
+        assert_eq!(seal == Seal::Purple, poll <= 0.25);
+
+        assert_eq!(
+            backend.roll_random_suit("sigil"),
+            *reference.pseudorandom_element(&SUITS, "sigil")
+        );
+        assert_eq!(
+            backend.roll_random_value("ouija"),
+            *reference.pseudorandom_element(&VALUES, "ouija")
+        );
+        let front: Card = reference.next_card_front("cert_fr").into();
+        let card = backend.gen_card_front("cert_fr");
+        assert_eq!((card.value, card.suit), (front.value, front.suit));
+
+        let held = [
+            Consumable::Tarot(Tarot::Fool),
+            Consumable::Planet(Planets::Mars),
+        ];
+        assert_eq!(
+            backend.pick_random_consumable(held.to_vec(), "perkeo"),
+            *reference.pseudorandom_element(&held, "perkeo")
+        );
+
+        let eternal = reference.pseudorandom("etperpoll2") > 0.7;
+        let rental = reference.pseudorandom("ssjr2") > 0.7;
+        let stickers = backend.roll_stickers(Stake::Gold, 2);
+        assert_eq!((stickers.eternal, stickers.rental), (eternal, rental));
+
+        let mail = reference.pseudorandom_element(&cards, "mail3").value;
+        let castle = reference.pseudorandom_element(&cards, "cas3").suit;
+        assert_eq!(
+            backend.roll_discard_targets(&reversed_cards, 3),
+            (castle, mail)
+        );
+
+        let faces = [Value::Jack, Value::Queen, Value::King];
+        let value = *reference.pseudorandom_element(&faces, "familiar_create");
+        let suit = *reference.pseudorandom_element(&SUITS, "familiar_create");
+        let card = backend.create_enhanced_card("familiar_create", 1, &faces);
+        assert_eq!((card.value, card.suit), (value, suit));
+        assert!(card.enhancement.is_some_and(|e| e != Enhancement::Stone));
+        assert_eq!((card.edition, card.seal), (Edition::Base, None));
     }
 
     #[test]
-    fn real_backend_draw_boss_matches_category() {
-        let fast = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
-        let mut backend = RealBackend::new("TESTSEED", fast);
-        for ante in [1, 7, 8, 9, 16] {
-            let boss = backend.draw_boss(ante);
-            assert_eq!(
-                boss.is_finisher(),
-                ante % 8 == 0,
-                "ante {ante} drew {boss:?}"
-            );
+    fn real_backend_draw_ante_tags_respects_min_ante() {
+        let mut backend = RealBackend::new("TESTSEED");
+        for _ in 0..100 {
+            let (small, big) = backend.draw_ante_tags(1);
+            assert!(small.min_ante() <= 1, "ante 1 drew {small:?}");
+            assert!(big.min_ante() <= 1, "ante 1 drew {big:?}");
+        }
+    }
+
+    // `explore` runs `next_boss` off `init_locks`/`init_unlocks`; the shared
+    // `BossPool` has to land on the same bosses.
+    #[test]
+    fn real_backend_draw_boss_matches_explore() {
+        let mut backend = RealBackend::new("TESTSEED");
+        let mut pool = BossPool::default();
+        let mut explore = Instance::new("TESTSEED");
+        explore.init_locks(1, false, true);
+        for ante in 1..=16 {
+            explore.init_unlocks(ante, false);
+            let boss = backend.draw_boss(&pool.candidates(ante));
+            pool.record(boss);
+            assert_eq!(boss, explore.next_boss(ante), "ante {ante}");
         }
     }
 
+    #[test]
+    fn real_backend_lock_profile_locks_only_the_given_items() {
+        let mut backend = RealBackend::new("TESTSEED");
+        backend.lock_profile(&["Blueprint".to_string()]);
+        assert!(backend.instance.is_locked("Blueprint"));
+        assert!(!backend.instance.is_locked("Showman"));
+    }
+
     #[test]
     fn real_backend_set_showman_propagates() {
-        let fast = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
-        let mut backend = RealBackend::new("TESTSEED", fast);
+        let mut backend = RealBackend::new("TESTSEED");
         assert!(!backend.instance.params.showman);
         backend.set_showman(true);
         assert!(backend.instance.params.showman);
@@ -815,8 +1481,7 @@ mod tests {
     // Structural check on gen_pack's typed plumbing across many draws.
     #[test]
     fn real_backend_gen_pack_contents_match_category_and_count() {
-        let fast = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
-        let mut backend = RealBackend::new("TESTSEED", fast);
+        let mut backend = RealBackend::new("TESTSEED");
         let planetarium = Planetarium::new();
         let mut seen_categories: std::collections::HashSet<PackCategory> =
             std::collections::HashSet::new();
@@ -872,8 +1537,7 @@ mod tests {
     fn real_backend_seed_joker_with_id_rolls_discard_selector() {
         use crate::joker::{Castle, MailInRebate};
 
-        let fast = FastBackend::new(ChaCha8Rng::seed_from_u64(1));
-        let mut backend = RealBackend::new("TESTSEED", fast);
+        let mut backend = RealBackend::new("TESTSEED");
         let castle = seed_joker_with_id(Jokers::Castle(Castle::default()), &mut backend.extra_rng);
         assert!(
             castle.state().selector.is_some(),
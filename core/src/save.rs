@@ -0,0 +1,2063 @@
+//! Bridges real-game saves and the engine: `Game::from_save_snapshot` turns
+//! a parsed `save.jkr` into a `Game` that picks the run up where it was, and
+//! `Game::to_save_jkr` writes a `Game` back out as one the real game loads.
+
+use crate::card::{Card, Edition, Enhancement, Seal, Suit, Value};
+use crate::config::{Config, RngMode};
+use crate::deck::Deck;
+use crate::error::GameError;
+use crate::game::{default_reroll_cost, Game};
+use crate::joker::{JokerEffects, Jokers, PERISHABLE_ROUNDS};
+use crate::pack::{OpenPackState, Pack, PackCategory, PackContent, PackSize};
+use crate::rng::RngBackend;
+use crate::stage::{blind_display, Blind, BlindExt, BossBlind, BossPool, End, Stage};
+use crate::tag::Tag;
+use balatro_jkr::{LuaKey, LuaValue};
+use balatro_profile::{BlindStatus, SaveSnapshot, SaveState};
+use balatro_types::{Ante, BigNum, Consumable, DeckVariant, HandRank, Planetarium, Stake, Voucher};
+use std::cmp::Ordering;
+use std::fmt;
+use strum::IntoEnumIterator;
+
+/// A part of the save that couldn't be carried over, and what the engine
+/// did instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unmapped {
+    pub field: &'static str,
+    pub note: String,
+}
+
+impl Unmapped {
+    fn new(field: &'static str, note: impl Into<String>) -> Self {
+        Self {
+            field,
+            note: note.into(),
+        }
+    }
+}
+
+impl fmt::Display for Unmapped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.note)
+    }
+}
+
+// The engine's `blind` is the last one started, `None` before an ante's
+// Small Blind; the save names the one up next.
+fn blind_before(on_deck: Blind) -> Option<Blind> {
+    match on_deck {
+        Blind::Small => None,
+        Blind::Big => Some(Blind::Small),
+        Blind::Boss => Some(Blind::Big),
+    }
+}
+
+impl Game {
+    /// Builds a mid-run `Game` from a real save. Deck, stake and seed come
+    /// from the snapshot, everything else from `config`. Whatever the
+    /// snapshot doesn't cover is listed in the returned `Unmapped`s.
+    pub fn from_save_snapshot(snapshot: &SaveSnapshot, config: Config) -> (Game, Vec<Unmapped>) {
+        let mut unmapped = Vec::new();
+        if snapshot.seed.is_none() {
+            unmapped.push(Unmapped::new("seed", "not in the save, using the config's"));
+        }
+        let rng_mode = if snapshot.seed.is_some() {
+            RngMode::Real
+        } else {
+            config.rng_mode
+        };
+        let mut game = Game::new(Config {
+            deck: Some(snapshot.deck),
+            stake: snapshot.stake,
+            seed_str: snapshot.seed.clone().or(config.seed_str.clone()),
+            rng_mode,
+            ..config
+        });
+        if rng_mode == RngMode::Real {
+            unmapped.push(Unmapped::new(
+                "rng",
+                "stream positions aren't in the snapshot, draws restart from the seed",
+            ));
+        }
+
+        // before the ante is set, Hieroglyph and Petroglyph would otherwise
+        // set it back again
+        for &voucher in &snapshot.vouchers {
+            game.redeem_voucher(voucher);
+        }
+
+        game.ante_current = Ante::try_from(snapshot.ante.max(1)).unwrap_or(Ante::One);
+        game.won = snapshot.won;
+        if game.ante_current > game.ante_end {
+            game.config.endless = true;
+        }
+        game.round = snapshot.round;
+        game.money = snapshot.dollars;
+
+        // Only what the save doesn't have is drawn, so the draws that are
+        // made come from the same stream positions as a fresh ante's.
+        game.boss_pool = BossPool::default();
+        for &(boss, times) in &snapshot.bosses_used {
+            for _ in 0..times {
+                game.boss_pool.record(boss);
+            }
+        }
+        if snapshot.bosses_used.is_empty() {
+            unmapped.push(Unmapped::new(
+                "bosses used",
+                "not in the save, every boss counts as unseen",
+            ));
+        }
+        match snapshot.boss {
+            Some(boss) => {
+                if snapshot.bosses_used.is_empty() {
+                    game.boss_pool.record(boss);
+                }
+                game.current_boss = Some(boss);
+            }
+            None => {
+                game.draw_ante_boss();
+                unmapped.push(Unmapped::new("boss", "not in the save, drawn fresh"));
+            }
+        }
+        game.tags = snapshot.tags.clone();
+        if snapshot.blind_tags.is_empty() {
+            game.draw_ante_tags();
+            unmapped.push(Unmapped::new(
+                "tags",
+                "skip tags on offer aren't in the save, drawn fresh",
+            ));
+        }
+        for &(blind, tag) in &snapshot.blind_tags {
+            let tag = match tag {
+                Tag::Orbital(_) => {
+                    unmapped.push(Unmapped::new(
+                        "tags",
+                        "Orbital's hand isn't read from the save, rolled fresh",
+                    ));
+                    game.offer_tag(tag)
+                }
+                _ => tag,
+            };
+            match blind {
+                Blind::Small => game.small_blind_tag = tag,
+                Blind::Big => game.big_blind_tag = tag,
+                Blind::Boss => {}
+            }
+        }
+        match snapshot.voucher {
+            Some(voucher) => game.shop.vouchers = vec![voucher],
+            None => {
+                game.draw_ante_voucher();
+                unmapped.push(Unmapped::new("voucher", "not in the save, drawn fresh"));
+            }
+        }
+
+        for &(rank, level, played) in &snapshot.hand_levels {
+            if rank != rank.scoring_rank() {
+                continue;
+            }
+            for _ in 1..level {
+                game.planetarium.level_up(rank);
+            }
+            for _ in 0..played {
+                game.planetarium.play(rank);
+            }
+        }
+
+        for (joker, edition) in &snapshot.jokers {
+            let mut joker = joker.clone();
+            if let Some(edition) = edition {
+                joker.set_edition(*edition);
+            }
+            joker.set_instance_id(crate::joker::mint_joker_id());
+            game.backend.roll_discard_selector(&mut joker);
+            if !joker.is_implemented() {
+                unmapped.push(Unmapped::new(
+                    "jokers",
+                    format!("{} has no engine effect", joker.name()),
+                ));
+            }
+            // counted on the game, not the joker
+            let counter = joker.state().counter.round() as usize;
+            match joker {
+                Jokers::RideTheBus(_) => game.consecutive_hands_without_face_card = counter,
+                Jokers::Obelisk(_) => game.consecutive_hands_not_most_played_type = counter,
+                Jokers::Yorick(_) => game.total_cards_discarded = counter,
+                _ => {}
+            }
+            game.add_joker(joker);
+        }
+        if game.jokers.iter().any(|j| j.state().selector.is_some()) {
+            unmapped.push(Unmapped::new(
+                "joker state",
+                "selectors aren't in the snapshot, jokers pick them fresh",
+            ));
+        }
+        game.register_jokers();
+        game.consumables.clear();
+        for &(consumable, edition) in &snapshot.consumables {
+            if edition == Some(Edition::Negative) {
+                game.add_negative_consumable(consumable);
+            } else {
+                game.consumables.push(consumable.into());
+            }
+        }
+
+        let pile = |cards: &[balatro_types::Card]| -> Vec<Card> {
+            cards.iter().map(|&c| Card::from(c)).collect()
+        };
+        if snapshot.deck_cards.is_empty() && snapshot.hand.is_empty() && snapshot.discard.is_empty()
+        {
+            unmapped.push(Unmapped::new(
+                "cards",
+                "no playing cards in the save, kept the deck's starting cards",
+            ));
+        } else {
+            // The real game draws from the back of its deck, the engine from
+            // the front.
+            let mut deck_cards = pile(&snapshot.deck_cards);
+            deck_cards.reverse();
+            game.deck = Deck::new();
+            game.deck.extend(deck_cards);
+            game.available.empty();
+            game.available.extend(pile(&snapshot.hand));
+            game.discarded = pile(&snapshot.discard);
+        }
+
+        let on_deck = snapshot.blind.unwrap_or(Blind::Small);
+        game.blind = blind_before(on_deck);
+        game.skipped_this_ante = snapshot
+            .blind_states
+            .iter()
+            .filter(|(_, status)| *status == BlindStatus::Skipped)
+            .map(|&(blind, _)| blind)
+            .collect();
+        match snapshot.state {
+            Some(SaveState::Playing) => {
+                game.blind = Some(on_deck);
+                game.stage = Stage::Blind(on_deck);
+                game.plays = snapshot.hands_left;
+                game.discards_remaining = snapshot.discards_left;
+                game.score = BigNum::new(snapshot.chips);
+            }
+            Some(SaveState::BlindSelect) => game.stage = Stage::PreBlind(),
+            Some(SaveState::RoundEval) => {
+                game.stage = Stage::PostBlind();
+                unmapped.push(Unmapped::new(
+                    "cash out",
+                    "the round's reward isn't in the snapshot, nothing is paid out",
+                ));
+            }
+            Some(SaveState::Shop) => game.resume_shop(snapshot, &mut unmapped),
+            Some(SaveState::PackOpen) => {
+                game.resume_shop(snapshot, &mut unmapped);
+                game.resume_pack(snapshot, &mut unmapped);
+            }
+            Some(SaveState::GameOver) => {
+                game.stage = Stage::End(if game.won {
+                    End::EndlessLose
+                } else {
+                    End::Lose
+                })
+            }
+            None => {
+                game.stage = Stage::PreBlind();
+                unmapped.push(Unmapped::new(
+                    "state",
+                    "not recognised, resumed at blind select",
+                ));
+            }
+        }
+        (game, unmapped)
+    }
+
+    /// Opens the shop, stocked with what the save had for sale.
+    fn resume_shop(&mut self, snapshot: &SaveSnapshot, unmapped: &mut Vec<Unmapped>) {
+        self.open_shop();
+        if snapshot.reroll_cost > 0 {
+            self.reroll_cost = snapshot.reroll_cost;
+        }
+        let stock = &snapshot.shop;
+        if stock.is_empty() {
+            unmapped.push(Unmapped::new(
+                "shop",
+                "contents aren't in the save, rolled fresh",
+            ));
+            return;
+        }
+        self.shop.jokers = stock.jokers.iter().map(|(j, _)| j.clone()).collect();
+        self.shop.consumables = stock.consumables.iter().map(|&(c, _)| c).collect();
+        self.shop.playing_cards = stock.cards.iter().map(|&c| Card::from(c)).collect();
+        self.shop.vouchers = stock.vouchers.clone();
+        self.shop.free_jokers.clear();
+        self.shop.free_consumables.clear();
+        self.shop.free_packs.clear();
+        let ante = self.ante_current.num() as i32;
+        self.shop.packs = stock
+            .packs
+            .iter()
+            .map(|p| {
+                self.backend.gen_pack_of(
+                    ante,
+                    &self.planetarium,
+                    self.prob_mult,
+                    p.category,
+                    p.size,
+                    &self.jokers,
+                )
+            })
+            .collect();
+        if !stock.packs.is_empty() {
+            unmapped.push(Unmapped::new(
+                "shop packs",
+                "contents are only rolled once opened, rolled fresh",
+            ));
+        }
+    }
+
+    /// Reopens the pack the save was picking from, over the shop.
+    fn resume_pack(&mut self, snapshot: &SaveSnapshot, unmapped: &mut Vec<Unmapped>) {
+        let pack = &snapshot.pack;
+        if pack.is_empty() {
+            unmapped.push(Unmapped::new(
+                "open pack",
+                "contents aren't in the save, resumed in the shop",
+            ));
+            return;
+        }
+        let contents: Vec<PackContent> = pack
+            .jokers
+            .iter()
+            .map(|(j, _)| PackContent::Joker(j.clone()))
+            .chain(pack.consumables.iter().map(|(c, _)| match c {
+                Consumable::Tarot(t) => PackContent::Tarot(*t),
+                Consumable::Planet(p) => PackContent::Planet(*p),
+                Consumable::Spectral(s) => PackContent::Spectral(*s),
+            }))
+            .chain(
+                pack.cards
+                    .iter()
+                    .map(|&c| PackContent::PlayingCard(Card::from(c))),
+            )
+            .collect();
+        let picks = snapshot.pack_choices.max(1);
+        let category = pack_category(&contents);
+        // Buffoon packs hold 2 or 4, the rest 3 or 5; only a Mega pack
+        // gives two picks
+        let size = if picks > 1 {
+            PackSize::Mega
+        } else if contents.len() >= 4 {
+            PackSize::Jumbo
+        } else {
+            PackSize::Normal
+        };
+        let description = Pack {
+            category,
+            size,
+            contents: vec![],
+        }
+        .description();
+        self.open_pack = Some(OpenPackState {
+            contents,
+            picks_remaining: picks,
+            description,
+        });
+        self.pack_prev_stage = Some(self.stage);
+        self.stage = Stage::PackOpen();
+    }
+}
+
+// Exporting: the reverse trip, a `Game` written out as the `save.jkr` table
+// the real game loads. `Card:load` copies each card's `ability` table
+// wholesale and the game has no per-center defaults to fall back on, so every
+// card carries its center's config, and jokers their running counters.
+
+/// The game version the exported save claims to come from.
+const SAVE_VERSION: &str = "1.0.1o-FULL";
+
+/// `GAME.hands` keys, in the real game's display `order` (1 first).
+const HAND_NAMES: [(HandRank, &str); 12] = [
+    (HandRank::FlushFive, "Flush Five"),
+    (HandRank::FlushHouse, "Flush House"),
+    (HandRank::FiveOfAKind, "Five of a Kind"),
+    (HandRank::StraightFlush, "Straight Flush"),
+    (HandRank::FourOfAKind, "Four of a Kind"),
+    (HandRank::FullHouse, "Full House"),
+    (HandRank::Flush, "Flush"),
+    (HandRank::Straight, "Straight"),
+    (HandRank::ThreeOfAKind, "Three of a Kind"),
+    (HandRank::TwoPair, "Two Pair"),
+    (HandRank::OnePair, "Pair"),
+    (HandRank::HighCard, "High Card"),
+];
+
+/// The `GAME.hands` key `rank` is scored as.
+fn hand_name(rank: HandRank) -> &'static str {
+    HAND_NAMES
+        .iter()
+        .find(|(r, _)| *r == rank.scoring_rank())
+        .map_or("", |(_, name)| name)
+}
+
+fn table(entries: Vec<(&str, LuaValue)>) -> LuaValue {
+    LuaValue::Table(
+        entries
+            .into_iter()
+            .map(|(k, v)| (LuaKey::Str(k.to_string()), v))
+            .collect(),
+    )
+}
+
+fn list(values: Vec<LuaValue>) -> LuaValue {
+    LuaValue::Table(
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (LuaKey::Num(i as i64 + 1), v))
+            .collect(),
+    )
+}
+
+fn num(n: impl Into<f64>) -> LuaValue {
+    LuaValue::Num(n.into())
+}
+
+fn count(n: usize) -> LuaValue {
+    LuaValue::Num(n as f64)
+}
+
+fn text(s: &str) -> LuaValue {
+    LuaValue::Str(s.to_string())
+}
+
+/// Overwrites `key` in a table built by [`table`], or appends it.
+fn put(value: &mut LuaValue, key: &str, v: LuaValue) {
+    let LuaValue::Table(entries) = value else {
+        return;
+    };
+    match entries
+        .iter_mut()
+        .find(|(k, _)| *k == LuaKey::Str(key.to_string()))
+    {
+        Some((_, old)) => *old = v,
+        None => entries.push((LuaKey::Str(key.to_string()), v)),
+    }
+}
+
+/// The fields every `card.ability` carries, at their unmodified values.
+fn ability(name: &str, set: &str, effect: &str) -> LuaValue {
+    table(vec![
+        ("name", text(name)),
+        ("set", text(set)),
+        ("effect", text(effect)),
+        ("order", count(0)),
+        ("type", text("")),
+        ("bonus", count(0)),
+        ("perma_bonus", count(0)),
+        ("mult", count(0)),
+        ("x_mult", count(1)),
+        ("h_mult", count(0)),
+        ("h_x_mult", count(0)),
+        ("h_dollars", count(0)),
+        ("p_dollars", count(0)),
+        ("t_mult", count(0)),
+        ("t_chips", count(0)),
+        ("h_size", count(0)),
+        ("d_size", count(0)),
+        ("extra_value", count(0)),
+        ("hands_played_at_create", count(0)),
+    ])
+}
+
+/// `extra = { ... }`.
+fn extra(entries: Vec<(&str, LuaValue)>) -> (&'static str, LuaValue) {
+    ("extra", table(entries))
+}
+
+/// A joker's `G.P_CENTERS[key].config`, which `Card:set_ability` spreads
+/// over `ability`. The engine's own numbers for these jokers match; only
+/// the running counters differ from card to card, see [`put_joker_counter`].
+fn joker_config(id: &str) -> Vec<(&'static str, LuaValue)> {
+    let suit_mult = |suit| extra(vec![("s_mult", count(3)), ("suit", text(suit))]);
+    let hand = |key, amount: usize, hand| vec![(key, count(amount)), ("type", text(hand))];
+    match id {
+        "j_joker" => vec![("mult", count(4))],
+        "j_greedy_joker" => vec![suit_mult("Diamonds")],
+        "j_lusty_joker" => vec![suit_mult("Hearts")],
+        "j_wrathful_joker" => vec![suit_mult("Spades")],
+        "j_gluttenous_joker" => vec![suit_mult("Clubs")],
+        "j_jolly" => hand("t_mult", 8, "Pair"),
+        "j_zany" => hand("t_mult", 12, "Three of a Kind"),
+        "j_mad" => hand("t_mult", 10, "Two Pair"),
+        "j_crazy" => hand("t_mult", 12, "Straight"),
+        "j_droll" => hand("t_mult", 10, "Flush"),
+        "j_sly" => hand("t_chips", 50, "Pair"),
+        "j_wily" => hand("t_chips", 100, "Three of a Kind"),
+        "j_clever" => hand("t_chips", 80, "Two Pair"),
+        "j_devious" => hand("t_chips", 100, "Straight"),
+        "j_crafty" => hand("t_chips", 80, "Flush"),
+        "j_duo" => hand("Xmult", 2, "Pair"),
+        "j_trio" => hand("Xmult", 3, "Three of a Kind"),
+        "j_family" => hand("Xmult", 4, "Four of a Kind"),
+        "j_order" => hand("Xmult", 3, "Straight"),
+        "j_tribe" => hand("Xmult", 2, "Flush"),
+        "j_half" => vec![extra(vec![("mult", count(20)), ("size", count(3))])],
+        "j_ceremonial" => vec![("mult", count(0))],
+        "j_mystic_summit" => vec![extra(vec![("mult", count(15)), ("d_remaining", count(0))])],
+        "j_loyalty_card" => vec![extra(vec![
+            ("Xmult", count(4)),
+            ("every", count(5)),
+            ("remaining", text("5 remaining")),
+        ])],
+        "j_misprint" => vec![extra(vec![("max", count(23)), ("min", count(0))])],
+        "j_gros_michel" => vec![extra(vec![("odds", count(6)), ("mult", count(15))])],
+        "j_scholar" => vec![extra(vec![("mult", count(4)), ("chips", count(20))])],
+        "j_runner" => vec![extra(vec![("chips", count(0)), ("chip_mod", count(15))])],
+        "j_ice_cream" => vec![extra(vec![("chips", count(100)), ("chip_mod", count(5))])],
+        "j_constellation" | "j_vampire" => vec![("extra", num(0.1)), ("Xmult", count(1))],
+        "j_hologram" | "j_lucky_cat" => vec![("extra", num(0.25)), ("Xmult", count(1))],
+        "j_obelisk" => vec![("extra", num(0.2)), ("Xmult", count(1))],
+        "j_glass" => vec![("extra", num(0.75)), ("Xmult", count(1))],
+        "j_faceless" => vec![extra(vec![("dollars", count(5)), ("faces", count(3))])],
+        "j_green_joker" => vec![extra(vec![
+            ("hand_add", count(1)),
+            ("discard_sub", count(1)),
+        ])],
+        "j_todo_list" => vec![extra(vec![
+            ("dollars", count(4)),
+            ("poker_hand", text("High Card")),
+        ])],
+        "j_cavendish" => vec![extra(vec![("odds", count(1000)), ("Xmult", count(3))])],
+        "j_card_sharp" => vec![extra(vec![("Xmult", count(3))])],
+        "j_square" => vec![extra(vec![("chips", count(0)), ("chip_mod", count(4))])],
+        "j_seance" => vec![extra(vec![("poker_hand", text("Straight Flush"))])],
+        "j_rocket" => vec![extra(vec![("dollars", count(1)), ("increase", count(2))])],
+        "j_turtle_bean" => vec![extra(vec![("h_size", count(5)), ("h_mod", count(1))])],
+        "j_reserved_parking" => vec![extra(vec![("odds", count(2)), ("dollars", count(1))])],
+        "j_juggler" => vec![("h_size", count(1))],
+        "j_drunkard" => vec![("d_size", count(1))],
+        "j_merry_andy" => vec![("d_size", count(3)), ("h_size", LuaValue::Num(-1.0))],
+        "j_troubadour" => vec![extra(vec![
+            ("h_size", count(2)),
+            ("h_plays", LuaValue::Num(-1.0)),
+        ])],
+        "j_stuntman" => vec![extra(vec![("h_size", count(2)), ("chip_mod", count(250))])],
+        "j_flash" => vec![("extra", count(2)), ("mult", count(0))],
+        "j_popcorn" => vec![("mult", count(20)), ("extra", count(4))],
+        "j_ramen" => vec![("Xmult", count(2)), ("extra", num(0.01))],
+        "j_walkie_talkie" => vec![extra(vec![("chips", count(10)), ("mult", count(4))])],
+        "j_castle" => vec![extra(vec![("chips", count(0)), ("chip_mod", count(3))])],
+        "j_swashbuckler" => vec![("mult", count(1))],
+        "j_bloodstone" => vec![extra(vec![("odds", count(2)), ("Xmult", num(1.5))])],
+        "j_wee" => vec![extra(vec![("chips", count(0)), ("chip_mod", count(8))])],
+        "j_bootstraps" => vec![extra(vec![("mult", count(2)), ("dollars", count(5))])],
+        "j_yorick" => vec![extra(vec![("xmult", count(1)), ("discards", count(23))])],
+        "j_steel_joker" => vec![("extra", num(0.2))],
+        "j_throwback" | "j_campfire" => vec![("extra", num(0.25))],
+        "j_madness" | "j_hit_the_road" => vec![("extra", num(0.5))],
+        "j_baron" | "j_baseball" | "j_ancient" => vec![("extra", num(1.5))],
+        "j_mime" | "j_marble" | "j_dusk" | "j_chaos" | "j_hack" | "j_supernova"
+        | "j_ride_the_bus" | "j_cloud_9" | "j_gift" | "j_to_the_moon" | "j_fortune_teller"
+        | "j_sock_and_buskin" | "j_hanging_chad" | "j_rough_gem" | "j_satellite" | "j_caino" => {
+            vec![("extra", count(1))]
+        }
+        "j_business" | "j_delayed_grat" | "j_blue_joker" | "j_riff_raff" | "j_photograph"
+        | "j_hallucination" | "j_bull" | "j_trousers" | "j_idol" | "j_seeing_double"
+        | "j_invisible" | "j_triboulet" => vec![("extra", count(2))],
+        "j_abstract" | "j_egg" | "j_burglar" | "j_blackboard" | "j_red_card" | "j_trading"
+        | "j_acrobat" | "j_flower_pot" | "j_drivers_license" => vec![("extra", count(3))],
+        "j_8_ball" | "j_even_steven" | "j_space" | "j_vagabond" | "j_erosion" | "j_golden"
+        | "j_ticket" => vec![("extra", count(4))],
+        "j_hiker" | "j_mail" | "j_smiley" => vec![("extra", count(5))],
+        "j_onyx_agate" => vec![("extra", count(7))],
+        "j_fibonacci" | "j_matador" => vec![("extra", count(8))],
+        "j_selzer" => vec![("extra", count(10))],
+        "j_shoot_the_moon" => vec![("extra", count(13))],
+        "j_credit_card" => vec![("extra", count(20))],
+        "j_stone" => vec![("extra", count(25))],
+        "j_banner" | "j_scary_face" => vec![("extra", count(30))],
+        "j_odd_todd" => vec![("extra", count(31))],
+        "j_arrowhead" => vec![("extra", count(50))],
+        _ => vec![],
+    }
+}
+
+/// A tarot or spectral's `G.P_CENTERS[key].config`, which `Card:set_ability`
+/// keeps as `ability.consumeable`.
+fn consumable_config(id: &str) -> Vec<(&'static str, LuaValue)> {
+    let convert = |to, most: usize| vec![("mod_conv", text(to)), ("max_highlighted", count(most))];
+    let suit = |to| vec![("suit_conv", text(to)), ("max_highlighted", count(3))];
+    let seal = |to| vec![("extra", text(to)), ("max_highlighted", count(1))];
+    let destroy = |n: usize| vec![("remove_card", LuaValue::Bool(true)), ("extra", count(n))];
+    match id {
+        "c_magician" => convert("m_lucky", 2),
+        "c_empress" => convert("m_mult", 2),
+        "c_heirophant" => convert("m_bonus", 2),
+        "c_lovers" => convert("m_wild", 1),
+        "c_chariot" => convert("m_steel", 1),
+        "c_justice" => convert("m_glass", 1),
+        "c_devil" => convert("m_gold", 1),
+        "c_tower" => convert("m_stone", 1),
+        "c_strength" => convert("up_rank", 2),
+        "c_death" => vec![
+            ("mod_conv", text("card")),
+            ("max_highlighted", count(2)),
+            ("min_highlighted", count(2)),
+        ],
+        "c_hanged_man" => vec![
+            ("remove_card", LuaValue::Bool(true)),
+            ("max_highlighted", count(2)),
+        ],
+        "c_star" => suit("Diamonds"),
+        "c_moon" => suit("Clubs"),
+        "c_sun" => suit("Hearts"),
+        "c_world" => suit("Spades"),
+        "c_high_priestess" => vec![("planets", count(2))],
+        "c_emperor" => vec![("tarots", count(2))],
+        "c_hermit" => vec![("extra", count(20))],
+        "c_wheel_of_fortune" => vec![("extra", count(4))],
+        "c_temperance" => vec![("extra", count(50))],
+        "c_familiar" => destroy(3),
+        "c_grim" => destroy(2),
+        "c_incantation" => destroy(4),
+        "c_immolate" => vec![
+            ("remove_card", LuaValue::Bool(true)),
+            extra(vec![("destroy", count(5)), ("dollars", count(20))]),
+        ],
+        "c_talisman" => seal("Gold"),
+        "c_deja_vu" => seal("Red"),
+        "c_trance" => seal("Blue"),
+        "c_medium" => seal("Purple"),
+        "c_ankh" | "c_hex" => vec![("extra", count(2))],
+        "c_cryptid" => vec![("extra", count(2)), ("max_highlighted", count(1))],
+        _ => vec![],
+    }
+}
+
+/// Spreads a center `config` over `ability` the way `Card:set_ability`
+/// does: `Xmult` lands in `x_mult`, `extra` is copied whole.
+fn put_config(ab: &mut LuaValue, config: Vec<(&str, LuaValue)>) {
+    for (key, value) in config {
+        match key {
+            "Xmult" => put(ab, "x_mult", value),
+            "mult" | "t_mult" | "t_chips" | "h_size" | "d_size" | "type" | "extra" => {
+                put(ab, key, value)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Overwrites `key` in `ability.extra`.
+fn put_extra(ab: &mut LuaValue, key: &str, v: LuaValue) {
+    let LuaValue::Table(entries) = ab else {
+        return;
+    };
+    if let Some((_, extra)) = entries
+        .iter_mut()
+        .find(|(k, _)| *k == LuaKey::Str("extra".to_string()))
+    {
+        put(extra, key, v);
+    }
+}
+
+/// Writes the joker's running counter where the real game keeps it, the
+/// same fields `SaveSnapshot::from_lua` reads it back from. Ride the Bus,
+/// Obelisk and Yorick count on the `Game` instead, see
+/// [`Game::held_joker_card`].
+fn put_joker_counter(ab: &mut LuaValue, joker: &Jokers, counter: f32) {
+    let counter = counter as f64;
+    match joker {
+        Jokers::SpareTrousers(_)
+        | Jokers::CeremonialDagger(_)
+        | Jokers::FlashCard(_)
+        | Jokers::RedCard(_)
+        | Jokers::RideTheBus(_) => put(ab, "mult", num(counter)),
+        Jokers::Madness(_)
+        | Jokers::Vampire(_)
+        | Jokers::Hologram(_)
+        | Jokers::Constellation(_)
+        | Jokers::LuckyCat(_)
+        | Jokers::GlassJoker(_)
+        | Jokers::Campfire(_) => put(ab, "x_mult", num(1.0 + counter)),
+        Jokers::Obelisk(_) => put(ab, "x_mult", num(1.0 + 0.2 * counter)),
+        Jokers::Canio(_) => put(ab, "caino_xmult", num(1.0 + counter)),
+        Jokers::WeeJoker(_) | Jokers::SquareJoker(_) | Jokers::Runner(_) => {
+            put_extra(ab, "chips", num(counter))
+        }
+        Jokers::Rocket(_) => put_extra(ab, "dollars", num(1.0 + counter)),
+        Jokers::IceCream(_) => put_extra(ab, "chips", num(100.0 - counter)),
+        Jokers::TurtleBean(_) => put_extra(ab, "h_size", num(5.0 - counter)),
+        Jokers::InvisibleJoker(_) => put(ab, "invis_rounds", num(counter)),
+        Jokers::Yorick(_) => {
+            let discards = counter as usize;
+            put_extra(ab, "xmult", count(1 + discards / 23));
+            put(ab, "yorick_discards", count(23 - discards % 23));
+        }
+        _ => {}
+    }
+}
+
+fn edition_table(edition: Edition) -> Option<LuaValue> {
+    Some(match edition {
+        Edition::Base => return None,
+        Edition::Foil => table(vec![
+            ("foil", LuaValue::Bool(true)),
+            ("chips", count(50)),
+            ("type", text("foil")),
+        ]),
+        Edition::Holographic => table(vec![
+            ("holo", LuaValue::Bool(true)),
+            ("mult", count(10)),
+            ("type", text("holo")),
+        ]),
+        Edition::Polychrome => table(vec![
+            ("polychrome", LuaValue::Bool(true)),
+            ("x_mult", num(1.5)),
+            ("type", text("polychrome")),
+        ]),
+        Edition::Negative => table(vec![
+            ("negative", LuaValue::Bool(true)),
+            ("type", text("negative")),
+        ]),
+    })
+}
+
+/// One entry of a card area. `center` is the `G.P_CENTERS` key, `card`
+/// the playing-card code (`"H_A"`) for playing cards.
+struct SavedCard {
+    center: String,
+    card: Option<String>,
+    label: String,
+    ability: LuaValue,
+    base: Option<LuaValue>,
+    edition: Edition,
+    seal: Option<Seal>,
+    cost: usize,
+    sell_cost: usize,
+    facing: &'static str,
+    debuff: bool,
+}
+
+impl SavedCard {
+    fn into_lua(self, sort_id: usize, playing_card: Option<usize>) -> LuaValue {
+        let mut save_fields = vec![("center", text(&self.center))];
+        if let Some(card) = &self.card {
+            save_fields.push(("card", text(card)));
+        }
+        let mut entries = vec![
+            ("save_fields", table(save_fields)),
+            ("sort_id", count(sort_id)),
+            ("label", text(&self.label)),
+            ("ability", self.ability),
+            ("base_cost", count(self.cost)),
+            ("cost", count(self.cost)),
+            ("sell_cost", count(self.sell_cost)),
+            ("extra_cost", count(0)),
+            ("facing", text(self.facing)),
+            ("sprite_facing", text(self.facing)),
+            ("debuff", LuaValue::Bool(self.debuff)),
+            ("pinned", LuaValue::Bool(false)),
+            ("bypass_discovery_center", LuaValue::Bool(true)),
+            ("bypass_discovery_ui", LuaValue::Bool(true)),
+            ("bypass_lock", LuaValue::Bool(true)),
+            ("params", table(vec![])),
+        ];
+        if let Some(base) = self.base {
+            entries.push(("base", base));
+        }
+        if let Some(edition) = edition_table(self.edition) {
+            entries.push(("edition", edition));
+        }
+        if let Some(seal) = self.seal {
+            entries.push(("seal", text(seal.id())));
+        }
+        if let Some(n) = playing_card {
+            entries.push(("playing_card", count(n)));
+        }
+        table(entries)
+    }
+}
+
+/// `(ability.name, ability.effect)` for an enhancement.
+fn enhancement_ability(enhancement: Enhancement) -> (&'static str, &'static str) {
+    match enhancement {
+        Enhancement::Bonus => ("Bonus", "Bonus Card"),
+        Enhancement::Mult => ("Mult", "Mult Card"),
+        Enhancement::Wild => ("Wild Card", "Wild Card"),
+        Enhancement::Glass => ("Glass Card", "Glass Card"),
+        Enhancement::Steel => ("Steel Card", "Steel Card"),
+        Enhancement::Stone => ("Stone Card", "Stone Card"),
+        Enhancement::Gold => ("Gold Card", "Gold Card"),
+        Enhancement::Lucky => ("Lucky Card", "Lucky Card"),
+    }
+}
+
+/// `(base.value, base.id)`: the rank's display name and its 2–14 order.
+fn value_name(value: Value) -> (&'static str, usize) {
+    match value {
+        Value::Two => ("2", 2),
+        Value::Three => ("3", 3),
+        Value::Four => ("4", 4),
+        Value::Five => ("5", 5),
+        Value::Six => ("6", 6),
+        Value::Seven => ("7", 7),
+        Value::Eight => ("8", 8),
+        Value::Nine => ("9", 9),
+        Value::Ten => ("10", 10),
+        Value::Jack => ("Jack", 11),
+        Value::Queen => ("Queen", 12),
+        Value::King => ("King", 13),
+        Value::Ace => ("Ace", 14),
+    }
+}
+
+fn suit_nominal(suit: Suit) -> f64 {
+    match suit {
+        Suit::Spade => 0.04,
+        Suit::Heart => 0.03,
+        Suit::Club => 0.02,
+        Suit::Diamond => 0.01,
+    }
+}
+
+fn playing_card(card: &Card, cost: usize) -> SavedCard {
+    let (center, name, effect) = match card.enhancement {
+        Some(e) => {
+            let (name, effect) = enhancement_ability(e);
+            (e.id(), name, effect)
+        }
+        None => ("c_base", "Default Base", "Base"),
+    };
+    let set = if card.enhancement.is_some() {
+        "Enhanced"
+    } else {
+        "Default"
+    };
+    let mut ab = ability(name, set, effect);
+    match card.enhancement {
+        Some(Enhancement::Bonus) => put(&mut ab, "bonus", count(30)),
+        Some(Enhancement::Stone) => put(&mut ab, "bonus", count(50)),
+        Some(Enhancement::Mult) => put(&mut ab, "mult", count(4)),
+        Some(Enhancement::Glass) => {
+            put(&mut ab, "x_mult", count(2));
+            put(&mut ab, "extra", count(4));
+        }
+        Some(Enhancement::Steel) => put(&mut ab, "h_x_mult", num(1.5)),
+        Some(Enhancement::Gold) => put(&mut ab, "h_dollars", count(3)),
+        Some(Enhancement::Lucky) => {
+            put(&mut ab, "mult", count(20));
+            put(&mut ab, "p_dollars", count(20));
+        }
+        Some(Enhancement::Wild) | None => {}
+    }
+    put(&mut ab, "perma_bonus", count(card.bonus_chips));
+
+    let (value, id) = value_name(card.value);
+    let suit_letter = char::from(card.suit).to_ascii_uppercase();
+    let base = table(vec![
+        ("name", text(&format!("{value} of {}", card.suit))),
+        ("suit", text(&card.suit.to_string())),
+        ("value", text(value)),
+        ("id", count(id)),
+        ("nominal", count(card.chips())),
+        ("suit_nominal", num(suit_nominal(card.suit))),
+        ("face_nominal", num(id.saturating_sub(10) as f64 * 0.1)),
+        ("times_played", count(0)),
+    ]);
+    SavedCard {
+        center: center.to_string(),
+        card: Some(format!("{suit_letter}_{}", char::from(card.value))),
+        label: "Base Card".to_string(),
+        ability: ab,
+        base: Some(base),
+        edition: card.edition,
+        seal: card.seal,
+        cost,
+        sell_cost: (cost / 2).max(1),
+        facing: if card.face_down { "back" } else { "front" },
+        debuff: card.debuffed,
+    }
+}
+
+/// `counter` is the joker's running count, usually `state().counter`.
+fn joker_card(joker: &Jokers, counter: f32, cost: usize) -> SavedCard {
+    let mut ab = ability(joker.name(), "Joker", "");
+    put_config(&mut ab, joker_config(joker.id()));
+    put_joker_counter(&mut ab, joker, counter);
+    let stickers = joker.stickers();
+    let state = joker.state();
+    put(&mut ab, "eternal", LuaValue::Bool(stickers.eternal));
+    put(&mut ab, "perishable", LuaValue::Bool(stickers.perishable));
+    if stickers.perishable {
+        put(
+            &mut ab,
+            "perish_tally",
+            count(PERISHABLE_ROUNDS.saturating_sub(state.perish_rounds)),
+        );
+    }
+    put(&mut ab, "rental", LuaValue::Bool(stickers.rental));
+    put(&mut ab, "extra_value", count(state.extra_value));
+    SavedCard {
+        center: joker.id().to_string(),
+        card: None,
+        label: joker.name().to_string(),
+        ability: ab,
+        base: None,
+        edition: joker.edition(),
+        seal: None,
+        cost,
+        sell_cost: joker.sell_value(),
+        facing: "front",
+        debuff: false,
+    }
+}
+
+fn consumable_card(
+    consumable: &Consumable,
+    cost: usize,
+    sell_cost: usize,
+    negative: bool,
+) -> SavedCard {
+    let set = match consumable {
+        Consumable::Planet(_) => "Planet",
+        Consumable::Tarot(_) => "Tarot",
+        Consumable::Spectral(_) => "Spectral",
+    };
+    let mut ab = ability(consumable.name(), set, "");
+    if let Consumable::Planet(planet) = consumable {
+        let hand = hand_name(planet.hand_rank());
+        put(
+            &mut ab,
+            "consumeable",
+            table(vec![("hand_type", text(hand))]),
+        );
+    } else {
+        let config = consumable_config(consumable.id());
+        put_config(&mut ab, config.clone());
+        put(&mut ab, "consumeable", table(config));
+    }
+    SavedCard {
+        center: consumable.id().to_string(),
+        card: None,
+        label: consumable.name().to_string(),
+        ability: ab,
+        base: None,
+        edition: if negative {
+            Edition::Negative
+        } else {
+            Edition::Base
+        },
+        seal: None,
+        cost,
+        sell_cost,
+        facing: "front",
+        debuff: false,
+    }
+}
+
+fn voucher_card(voucher: &Voucher, cost: usize) -> SavedCard {
+    SavedCard {
+        center: voucher.id().to_string(),
+        card: None,
+        label: voucher.name().to_string(),
+        ability: ability(voucher.name(), "Voucher", ""),
+        base: None,
+        edition: Edition::Base,
+        seal: None,
+        cost,
+        sell_cost: (cost / 2).max(1),
+        facing: "front",
+        debuff: false,
+    }
+}
+
+fn pack_card(pack: &Pack, cost: usize) -> SavedCard {
+    // the trailing number only picks the pack's art
+    let center = format!("p_{}_{}_1", pack.category.id(), pack.size.id());
+    SavedCard {
+        center,
+        card: None,
+        label: pack.name(),
+        ability: ability(&pack.name(), "Booster", ""),
+        base: None,
+        edition: Edition::Base,
+        seal: None,
+        cost,
+        sell_cost: 0,
+        facing: "front",
+        debuff: false,
+    }
+}
+
+/// An open pack only keeps its contents; what they are says what kind of
+/// pack it was.
+fn pack_category(contents: &[PackContent]) -> PackCategory {
+    let any = |f: fn(&PackContent) -> bool| contents.iter().any(f);
+    if any(|c| matches!(c, PackContent::Joker(_))) {
+        PackCategory::Buffoon
+    } else if any(|c| matches!(c, PackContent::PlayingCard(_))) {
+        PackCategory::Standard
+    } else if any(|c| matches!(c, PackContent::Planet(_))) {
+        PackCategory::Celestial
+    } else if any(|c| matches!(c, PackContent::Tarot(_))) {
+        PackCategory::Arcana
+    } else {
+        PackCategory::Spectral
+    }
+}
+
+/// A `cardAreas` entry.
+fn card_area(kind: &str, limit: usize, cards: Vec<LuaValue>) -> LuaValue {
+    let card_count = cards.len();
+    table(vec![
+        ("cards", list(cards)),
+        (
+            "config",
+            table(vec![
+                ("type", text(kind)),
+                ("card_limit", count(limit)),
+                ("temp_limit", count(limit)),
+                ("card_count", count(card_count)),
+                ("highlighted_limit", count(5)),
+            ]),
+        ),
+    ])
+}
+
+/// What `BLIND.debuff` holds for the bosses that debuff by a card property.
+fn boss_debuff(boss: Option<BossBlind>) -> LuaValue {
+    match boss {
+        Some(BossBlind::Club) => table(vec![("suit", text("Clubs"))]),
+        Some(BossBlind::Goad) => table(vec![("suit", text("Spades"))]),
+        Some(BossBlind::Window) => table(vec![("suit", text("Diamonds"))]),
+        Some(BossBlind::Head) => table(vec![("suit", text("Hearts"))]),
+        Some(BossBlind::Plant) => table(vec![("is_face", text("face"))]),
+        Some(BossBlind::Psychic) => table(vec![("h_size_ge", count(5))]),
+        _ => table(vec![]),
+    }
+}
+
+fn blind_key(blind: Blind) -> &'static str {
+    match blind {
+        Blind::Small => "Small",
+        Blind::Big => "Big",
+        Blind::Boss => "Boss",
+    }
+}
+
+impl Game {
+    /// Writes the run out as the table a real `save.jkr` holds, ready for
+    /// `balatro_jkr::encode`. Anything the engine doesn't model (card
+    /// sprites, run statistics) gets the value a fresh run would have.
+    ///
+    /// Every real run has a deck, so a game started without one can't be
+    /// written out: it fails with `GameError::NoDeck`.
+    pub fn to_save_lua(&self) -> Result<LuaValue, GameError> {
+        let deck = self.config.deck.ok_or(GameError::NoDeck)?;
+        Ok(table(vec![
+            ("GAME", self.save_game_table(deck)),
+            ("BACK", self.save_back_table(deck)),
+            ("BLIND", self.save_blind_table()),
+            ("cardAreas", self.save_card_areas()),
+            ("tags", self.save_tags()),
+            ("STATE", count(self.save_state_id() as usize)),
+            ("VERSION", text(SAVE_VERSION)),
+        ]))
+    }
+
+    /// [`Game::to_save_lua`], encoded as `.jkr` bytes.
+    pub fn to_save_jkr(&self) -> Result<Vec<u8>, GameError> {
+        Ok(balatro_jkr::encode(&self.to_save_lua()?))
+    }
+
+    /// The stage the run picks up from: a tarot or spectral being targeted,
+    /// or an open pack, resolves to the stage it was opened from.
+    fn resume_stage(&self) -> &Stage {
+        let mut stage = &self.stage;
+        // at most a pack, then a tarot or spectral out of it
+        for _ in 0..2 {
+            let prev = match stage {
+                Stage::TarotHand(_) => self.tarot_prev_stage.as_ref(),
+                Stage::SpectralHand(_) => self.spectral_prev_stage.as_ref(),
+                Stage::PackOpen() => self.pack_prev_stage.as_ref(),
+                _ => break,
+            };
+            match prev {
+                Some(prev) => stage = prev,
+                None => break,
+            }
+        }
+        stage
+    }
+
+    /// `G.STATE` for the stage. An open pack has a state per kind of pack;
+    /// a tarot or spectral mid-use has none, so it reports the stage it was
+    /// used from.
+    fn save_state_id(&self) -> u8 {
+        if let Some(open) = &self.open_pack {
+            return match pack_category(&open.contents) {
+                PackCategory::Arcana => 9,
+                PackCategory::Celestial => 10,
+                PackCategory::Spectral => 15,
+                PackCategory::Standard => 17,
+                PackCategory::Buffoon => 18,
+            };
+        }
+        match self.resume_stage() {
+            Stage::Blind(_) => 1,
+            Stage::End(_) => 4,
+            Stage::PreBlind() => 7,
+            Stage::PostBlind() => 8,
+            _ => 5,
+        }
+    }
+
+    /// The blind the save's `blind_on_deck` names: the one being played,
+    /// or the next one up.
+    fn blind_on_deck(&self) -> Blind {
+        match (&self.stage, self.blind) {
+            (Stage::Blind(blind), _) => *blind,
+            (_, None) => Blind::Small,
+            (_, Some(blind)) => blind.next(),
+        }
+    }
+
+    fn save_hands_table(&self) -> LuaValue {
+        let fresh = Planetarium::new();
+        let entries = HAND_NAMES
+            .iter()
+            .enumerate()
+            .map(|(i, &(rank, name))| {
+                let base = fresh.level(rank);
+                let mut stepped = fresh.clone();
+                stepped.level_up(rank);
+                let step = stepped.level(rank);
+                let level = self.planetarium.level(rank);
+                let secret = matches!(
+                    rank,
+                    HandRank::FlushFive | HandRank::FlushHouse | HandRank::FiveOfAKind
+                );
+                let played_this_round = self.hand_ranks_played_this_round.contains(&rank);
+                let hand = table(vec![
+                    ("order", count(i + 1)),
+                    ("visible", LuaValue::Bool(!secret || level.plays > 0)),
+                    ("level", count(level.level)),
+                    ("chips", count(level.chips)),
+                    ("mult", count(level.mult)),
+                    ("s_chips", count(base.chips)),
+                    ("s_mult", count(base.mult)),
+                    ("l_chips", count(step.chips - base.chips)),
+                    ("l_mult", count(step.mult - base.mult)),
+                    ("played", count(level.plays)),
+                    ("played_this_round", count(played_this_round as usize)),
+                    ("example", table(vec![])),
+                ]);
+                (LuaKey::Str(name.to_string()), hand)
+            })
+            .collect();
+        LuaValue::Table(entries)
+    }
+
+    fn save_game_table(&self, deck: DeckVariant) -> LuaValue {
+        let stake = self.config.stake;
+        let ante = self.ante_current.num();
+        let on_deck = self.blind_on_deck();
+
+        let mut pseudorandom = vec![];
+        if let Some(seed) = &self.config.seed_str {
+            pseudorandom.push((LuaKey::Str("seed".to_string()), text(seed)));
+            pseudorandom.push((
+                LuaKey::Str("hashed_seed".to_string()),
+                num(balatro_seed::pseudohash(seed)),
+            ));
+        }
+        if let Some(instance) = self.backend.instance() {
+            pseudorandom.extend(
+                instance
+                    .node_states()
+                    .map(|(key, state)| (LuaKey::Str(key.to_string()), num(state))),
+            );
+        }
+
+        let in_blind = self.stage.is_blind();
+        let blind_state = |blind: Blind| {
+            let state = match blind.cmp(&on_deck) {
+                Ordering::Less if self.skipped_this_ante.contains(&blind) => "Skipped",
+                Ordering::Less => "Defeated",
+                Ordering::Equal if in_blind => "Current",
+                Ordering::Equal => "Select",
+                Ordering::Greater => "Upcoming",
+            };
+            (blind_key(blind), text(state))
+        };
+        let blind_states = table(vec![
+            blind_state(Blind::Small),
+            blind_state(Blind::Big),
+            blind_state(Blind::Boss),
+        ]);
+
+        let mut blind_choices = vec![("Small", text("bl_small")), ("Big", text("bl_big"))];
+        if let Some(boss) = self.current_boss {
+            blind_choices.push(("Boss", text(boss.id())));
+        }
+        let round_resets = table(vec![
+            ("ante", count(ante)),
+            ("blind_ante", count(ante)),
+            ("hands", count(self.max_plays())),
+            ("discards", count(self.max_discards())),
+            ("reroll_cost", count(self.base_reroll_cost())),
+            ("blind_states", blind_states.clone()),
+            ("loc_blind_states", blind_states),
+            ("blind_choices", table(blind_choices)),
+            (
+                "boss_rerolled",
+                LuaValue::Bool(self.boss_rerolls_this_ante > 0),
+            ),
+            (
+                "blind_tags",
+                table(vec![
+                    ("Small", text(self.small_blind_tag.id())),
+                    ("Big", text(self.big_blind_tag.id())),
+                ]),
+            ),
+        ]);
+
+        let (hands_left, discards_left) = if in_blind {
+            (self.plays, self.discards_remaining)
+        } else {
+            (self.max_plays(), self.max_discards())
+        };
+        let current_round = table(vec![
+            ("hands_left", count(hands_left)),
+            ("discards_left", count(discards_left)),
+            ("hands_played", count(self.hands_played_this_round)),
+            (
+                "discards_used",
+                count(self.max_discards().saturating_sub(discards_left)),
+            ),
+            ("reroll_cost", count(self.reroll_cost)),
+            ("reroll_cost_increase", count(0)),
+            ("free_rerolls", count(0)),
+            ("jokers_purchased", count(0)),
+            ("dollars", count(0)),
+            ("dollars_to_be_earned", text("")),
+            (
+                "most_played_poker_hand",
+                text(hand_name(self.most_played_hand_rank())),
+            ),
+            ("used_packs", table(vec![])),
+            ("cards_flipped", count(0)),
+            ("round_text", text("Round ")),
+            (
+                "current_hand",
+                table(vec![
+                    ("chips", count(0)),
+                    ("chip_text", text("0")),
+                    ("mult", count(0)),
+                    ("mult_text", text("0")),
+                    ("chip_total", count(0)),
+                    ("chip_total_text", text("")),
+                    ("handname", text("")),
+                    ("hand_level", text("")),
+                ]),
+            ),
+            (
+                "voucher",
+                text(self.shop.vouchers.first().map_or("", |v| v.id())),
+            ),
+        ]);
+
+        let (edition_rate, tarot_rate, planet_rate) = (
+            if self.has_voucher(Voucher::GlowUp) {
+                4.0
+            } else if self.has_voucher(Voucher::Hone) {
+                2.0
+            } else {
+                1.0
+            },
+            if self.has_voucher(Voucher::TarotTycoon) {
+                32.0
+            } else if self.has_voucher(Voucher::TarotMerchant) {
+                9.6
+            } else {
+                4.0
+            },
+            if self.has_voucher(Voucher::PlanetTycoon) {
+                32.0
+            } else if self.has_voucher(Voucher::PlanetMerchant) {
+                9.6
+            } else {
+                4.0
+            },
+        );
+        let playing_card_rate = if self.has_voucher(Voucher::MagicTrick) {
+            4.0
+        } else {
+            0.0
+        };
+        let spectral_rate = if deck == DeckVariant::Ghost { 2.0 } else { 0.0 };
+
+        let mut modifiers = vec![];
+        if stake >= Stake::Red {
+            modifiers.push((
+                "no_blind_reward",
+                table(vec![("Small", LuaValue::Bool(true))]),
+            ));
+        }
+        if stake >= Stake::Green {
+            modifiers.push(("scaling", count(if stake >= Stake::Purple { 3 } else { 2 })));
+        }
+        if stake >= Stake::Black {
+            modifiers.push(("enable_eternals_in_shop", LuaValue::Bool(true)));
+        }
+        if stake >= Stake::Orange {
+            modifiers.push(("enable_perishables_in_shop", LuaValue::Bool(true)));
+        }
+        if stake >= Stake::Gold {
+            modifiers.push(("enable_rentals_in_shop", LuaValue::Bool(true)));
+        }
+
+        let used = |ids: Vec<&str>| {
+            LuaValue::Table(
+                ids.into_iter()
+                    .map(|id| (LuaKey::Str(id.to_string()), LuaValue::Bool(true)))
+                    .collect(),
+            )
+        };
+        let bosses_used = LuaValue::Table(
+            BossBlind::iter()
+                .map(|b| {
+                    (
+                        LuaKey::Str(b.id().to_string()),
+                        count(self.boss_pool.times_drawn(b)),
+                    )
+                })
+                .collect(),
+        );
+        let cards_played = LuaValue::Table(
+            [
+                "2", "3", "4", "5", "6", "7", "8", "9", "10", "Jack", "Queen", "King", "Ace",
+            ]
+            .iter()
+            .map(|rank| {
+                let entry = table(vec![("suits", table(vec![])), ("total", count(0))]);
+                (LuaKey::Str(rank.to_string()), entry)
+            })
+            .collect(),
+        );
+        let round_scores = table(vec![
+            (
+                "furthest_ante",
+                table(vec![("label", text("Ante")), ("amt", count(ante))]),
+            ),
+            (
+                "furthest_round",
+                table(vec![("label", text("Round")), ("amt", count(self.round))]),
+            ),
+            (
+                "hand",
+                table(vec![("label", text("Best Hand")), ("amt", count(0))]),
+            ),
+            (
+                "poker_hand",
+                table(vec![("label", text("Most Played Hand")), ("amt", count(0))]),
+            ),
+            (
+                "new_collection",
+                table(vec![("label", text("New Discoveries")), ("amt", count(0))]),
+            ),
+            (
+                "cards_played",
+                table(vec![("label", text("Cards Played")), ("amt", count(0))]),
+            ),
+            (
+                "cards_discarded",
+                table(vec![("label", text("Cards Discarded")), ("amt", count(0))]),
+            ),
+            (
+                "times_rerolled",
+                table(vec![("label", text("Times Rerolled")), ("amt", count(0))]),
+            ),
+            (
+                "cards_purchased",
+                table(vec![("label", text("Cards Purchased")), ("amt", count(0))]),
+            ),
+        ]);
+
+        let entries = vec![
+            ("won", LuaValue::Bool(self.won)),
+            ("stake", count(stake.id() as usize)),
+            ("selected_back_key", table(vec![("key", text(deck.id()))])),
+            ("round", count(self.round)),
+            ("dollars", num(self.money as f64)),
+            (
+                "chips",
+                num(if in_blind { self.score.to_f64() } else { 0.0 }),
+            ),
+            ("win_ante", count(self.ante_end.num())),
+            ("pseudorandom", LuaValue::Table(pseudorandom)),
+            ("round_resets", round_resets),
+            ("blind_on_deck", text(blind_key(on_deck))),
+            ("current_round", current_round),
+            ("hands", self.save_hands_table()),
+            (
+                "starting_params",
+                table(vec![
+                    ("dollars", count(self.config.money_start)),
+                    ("hands", count(self.config.plays)),
+                    ("discards", count(self.config.discards)),
+                    ("hand_size", count(self.config.available)),
+                    ("reroll_cost", count(default_reroll_cost())),
+                    ("joker_slots", count(self.config.joker_slots)),
+                    ("consumable_slots", count(self.config.consumable_slots)),
+                    (
+                        "ante_scaling",
+                        count(if deck == DeckVariant::Plasma { 2 } else { 1 }),
+                    ),
+                    ("no_faces", LuaValue::Bool(deck == DeckVariant::Abandoned)),
+                    (
+                        "erratic_suits_and_ranks",
+                        LuaValue::Bool(deck == DeckVariant::Erratic),
+                    ),
+                ]),
+            ),
+            ("modifiers", table(modifiers)),
+            (
+                "probabilities",
+                table(vec![("normal", count(self.prob_mult as usize))]),
+            ),
+            ("bosses_used", bosses_used),
+            (
+                "used_vouchers",
+                used(self.vouchers.iter().map(|v| v.id()).collect()),
+            ),
+            (
+                "used_jokers",
+                used(self.jokers.iter().map(|j| j.id()).collect()),
+            ),
+            ("tags", table(vec![])),
+            ("tag_tally", count(self.tags.len())),
+            ("edition_rate", num(edition_rate)),
+            ("joker_rate", count(20)),
+            ("tarot_rate", num(tarot_rate)),
+            ("planet_rate", num(planet_rate)),
+            ("spectral_rate", num(spectral_rate)),
+            ("playing_card_rate", num(playing_card_rate)),
+            ("interest_cap", count(self.config.interest_max * 5)),
+            ("interest_amount", count(1)),
+            ("discount_percent", count(self.shop.discount_percent)),
+            ("bankrupt_at", num(self.money_floor() as f64)),
+            ("shop", table(vec![("joker_max", count(self.shop.slots))])),
+            ("cards_played", cards_played),
+            ("round_scores", round_scores),
+            (
+                "round_bonus",
+                table(vec![("next_hands", count(0)), ("discards", count(0))]),
+            ),
+            ("previous_round", table(vec![("dollars", count(0))])),
+            ("skips", count(self.total_blinds_skipped)),
+            ("hands_played", count(self.total_hands_played)),
+            ("unused_discards", count(self.total_unused_discards)),
+            ("max_jokers", count(self.config.joker_slots)),
+            ("inflation", count(0)),
+            ("base_reroll_cost", count(default_reroll_cost())),
+            ("sort", text("desc")),
+            ("facing_blind", LuaValue::Bool(in_blind)),
+            ("pack_size", count(2)),
+            (
+                "pack_choices",
+                count(self.open_pack.as_ref().map_or(0, |o| o.picks_remaining)),
+            ),
+        ];
+        let mut game = table(entries);
+        if let Some(last) = self.last_consumable_used {
+            put(&mut game, "last_tarot_planet", text(last.id()));
+        }
+        game
+    }
+
+    fn save_back_table(&self, deck: DeckVariant) -> LuaValue {
+        table(vec![
+            ("name", text(deck.name())),
+            ("key", text(deck.id())),
+            ("pos", table(vec![("x", count(0)), ("y", count(0))])),
+        ])
+    }
+
+    fn save_blind_table(&self) -> LuaValue {
+        let Stage::Blind(blind) = self.stage else {
+            return table(vec![
+                ("name", text("")),
+                ("config_blind", text("")),
+                ("chips", count(0)),
+                ("chip_text", text("0")),
+                ("dollars", count(0)),
+                ("mult", count(0)),
+                ("boss", LuaValue::Bool(false)),
+                ("disabled", LuaValue::Bool(false)),
+                ("debuff", table(vec![])),
+            ]);
+        };
+        let boss = (blind == Blind::Boss)
+            .then_some(self.current_boss)
+            .flatten();
+        let (name, key, mult) = match (blind, boss) {
+            (Blind::Boss, Some(boss)) => {
+                let mult = match boss {
+                    BossBlind::Wall => 4.0,
+                    BossBlind::VioletVessel => 6.0,
+                    BossBlind::Needle => 1.0,
+                    _ => 2.0,
+                };
+                (boss.name().to_string(), boss.id(), mult)
+            }
+            (Blind::Big, _) => (blind_display(&blind).to_string(), "bl_big", 1.5),
+            _ => (blind_display(&blind).to_string(), "bl_small", 1.0),
+        };
+        let required = self.required_score();
+        table(vec![
+            ("name", text(&name)),
+            ("config_blind", text(key)),
+            ("chips", num(required.to_f64())),
+            ("chip_text", text(&required.to_string())),
+            ("dollars", count(blind.reward())),
+            ("mult", num(mult)),
+            ("boss", LuaValue::Bool(blind == Blind::Boss)),
+            ("disabled", LuaValue::Bool(self.boss_disabled_by_luchador)),
+            ("hands_sub", count(0)),
+            ("discards_sub", count(0)),
+            ("triggered", LuaValue::Bool(self.boss_triggered_this_hand)),
+            ("debuff", boss_debuff(boss)),
+            ("pos", table(vec![("x", count(0)), ("y", count(0))])),
+        ])
+    }
+
+    fn save_card_areas(&self) -> LuaValue {
+        let mut sort_id = 0;
+        let mut next = |card: SavedCard, playing: Option<usize>| {
+            sort_id += 1;
+            card.into_lua(sort_id, playing)
+        };
+
+        let jokers = self
+            .jokers
+            .iter()
+            .map(|j| next(self.held_joker_card(j), None))
+            .collect();
+        let consumables = self
+            .consumables
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                next(
+                    consumable_card(c, c.cost(), self.consumable_sell_value(i), c.negative),
+                    None,
+                )
+            })
+            .collect();
+
+        // the real game draws from the back of its deck, the engine from the
+        // front
+        let mut playing = 0;
+        let mut pile = |cards: Vec<Card>| -> Vec<LuaValue> {
+            cards
+                .iter()
+                .map(|c| {
+                    playing += 1;
+                    next(playing_card(c, 1), Some(playing))
+                })
+                .collect()
+        };
+        let deck_cards = pile(self.deck.cards().into_iter().rev().collect());
+        let hand = pile(self.available.cards());
+        let discard = pile(self.discarded.clone());
+        let total = playing;
+
+        let mut areas = vec![
+            (
+                "jokers",
+                card_area("joker", self.config.joker_slots, jokers),
+            ),
+            (
+                "consumeables",
+                card_area("joker", self.config.consumable_slots, consumables),
+            ),
+            ("deck", card_area("deck", total, deck_cards)),
+            ("hand", card_area("hand", self.hand_size(), hand)),
+            ("discard", card_area("discard", 500, discard)),
+            ("play", card_area("play", 5, vec![])),
+        ];
+        if *self.resume_stage() == Stage::Shop() {
+            let for_sale: Vec<SavedCard> = self
+                .shop
+                .jokers
+                .iter()
+                .map(|j| joker_card(j, j.state().counter, self.shop.joker_cost(j)))
+                .chain(self.shop.consumables.iter().map(|c| {
+                    consumable_card(c, self.shop.consumable_cost(c), c.sell_value(), false)
+                }))
+                .chain(
+                    self.shop
+                        .playing_cards
+                        .iter()
+                        .map(|c| playing_card(c, self.shop.playing_card_cost(c))),
+                )
+                .collect();
+            let shop_cards = for_sale.into_iter().map(|c| next(c, None)).collect();
+            let packs = self
+                .shop
+                .packs
+                .iter()
+                .map(|p| next(pack_card(p, self.shop.pack_cost(p)), None))
+                .collect();
+            areas.push(("shop_booster", card_area("shop", 2, packs)));
+            let vouchers = self
+                .shop
+                .vouchers
+                .iter()
+                .map(|v| next(voucher_card(v, self.shop.voucher_cost(v)), None))
+                .collect();
+            areas.push((
+                "shop_jokers",
+                card_area("shop", self.shop.slots, shop_cards),
+            ));
+            areas.push(("shop_vouchers", card_area("shop", 1, vouchers)));
+        }
+        if let Some(open) = &self.open_pack {
+            let choices: Vec<SavedCard> = open
+                .contents
+                .iter()
+                .map(|content| match content {
+                    PackContent::Joker(j) => joker_card(j, j.state().counter, 0),
+                    PackContent::PlayingCard(c) => playing_card(c, 0),
+                    PackContent::Tarot(t) => consumable_card(&Consumable::Tarot(*t), 0, 0, false),
+                    PackContent::Planet(p) => consumable_card(&Consumable::Planet(*p), 0, 0, false),
+                    PackContent::Spectral(sp) => {
+                        consumable_card(&Consumable::Spectral(*sp), 0, 0, false)
+                    }
+                })
+                .collect();
+            let choices = choices.into_iter().map(|c| next(c, None)).collect();
+            areas.push(("pack_cards", card_area("consumeable", 5, choices)));
+        }
+        table(areas)
+    }
+
+    /// A held joker. Ride the Bus, Obelisk and Yorick keep their count on
+    /// the `Game`, the real game on the card.
+    fn held_joker_card(&self, joker: &Jokers) -> SavedCard {
+        let counter = match joker {
+            Jokers::RideTheBus(_) => self.consecutive_hands_without_face_card as f32,
+            Jokers::Obelisk(_) => self.consecutive_hands_not_most_played_type as f32,
+            Jokers::Yorick(_) => self.total_cards_discarded as f32,
+            _ => joker.state().counter,
+        };
+        joker_card(joker, counter, joker.cost())
+    }
+
+    /// Pending tags, as `Tag:save` writes them.
+    fn save_tags(&self) -> LuaValue {
+        list(
+            self.tags
+                .iter()
+                .enumerate()
+                .map(|(i, tag)| {
+                    table(vec![
+                        ("key", text(tag.id())),
+                        ("tally", count(i + 1)),
+                        ("ability", table(vec![])),
+                    ])
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::joker::{GreenJoker, Jokers};
+    use balatro_profile::SavedCards;
+    use balatro_types::{Planets, Tag};
+
+    fn snapshot(state: SaveState) -> SaveSnapshot {
+        SaveSnapshot {
+            stake: Stake::Red,
+            deck: DeckVariant::Blue,
+            dollars: 17,
+            seed: Some("TEST".to_string()),
+            state: Some(state),
+            ante: 3,
+            round: 8,
+            blind: Some(Blind::Big),
+            boss: Some(BossBlind::Wall),
+            bosses_used: vec![(BossBlind::Hook, 1), (BossBlind::Wall, 1)],
+            hands_left: 2,
+            discards_left: 1,
+            chips: 450.0,
+            hand_levels: vec![(HandRank::Flush, 3, 4)],
+            jokers: vec![(
+                Jokers::GreenJoker(GreenJoker::default()),
+                Some(Edition::Foil),
+            )],
+            deck_cards: vec![
+                balatro_types::Card::new(Value::Two, Suit::Spade),
+                balatro_types::Card::new(Value::Three, Suit::Spade),
+            ],
+            hand: vec![balatro_types::Card::new(Value::Ace, Suit::Heart)],
+            discard: vec![balatro_types::Card::new(Value::King, Suit::Club)],
+            consumables: vec![
+                (Consumable::Planet(Planets::Venus), None),
+                (Consumable::Planet(Planets::Mars), Some(Edition::Negative)),
+            ],
+            voucher: Some(Voucher::Grabber),
+            won: false,
+            blind_states: vec![],
+            blind_tags: vec![(Blind::Small, Tag::Double), (Blind::Big, Tag::Rare)],
+            vouchers: vec![Voucher::Overstock],
+            tags: vec![Tag::Investment],
+            reroll_cost: 7,
+            shop: SavedCards {
+                jokers: vec![(Jokers::GreenJoker(GreenJoker::default()), None)],
+                vouchers: vec![Voucher::Grabber],
+                packs: vec![balatro_types::Pack {
+                    category: PackCategory::Arcana,
+                    size: PackSize::Jumbo,
+                    contents: vec![],
+                }],
+                ..SavedCards::default()
+            },
+            pack: SavedCards {
+                consumables: vec![
+                    (Consumable::Planet(Planets::Mars), None),
+                    (Consumable::Planet(Planets::Venus), None),
+                    (Consumable::Planet(Planets::Earth), None),
+                ],
+                ..SavedCards::default()
+            },
+            pack_choices: 1,
+        }
+    }
+
+    #[test]
+    fn test_from_save_snapshot_resumes_mid_blind() {
+        let (g, unmapped) =
+            Game::from_save_snapshot(&snapshot(SaveState::Playing), Config::default());
+        assert_eq!(g.config.deck, Some(DeckVariant::Blue));
+        assert_eq!(g.config.stake, Stake::Red);
+        assert_eq!(g.config.seed_str.as_deref(), Some("TEST"));
+        assert_eq!(g.ante_current, Ante::Three);
+        assert_eq!(g.round, 8);
+        assert_eq!(g.money, 17);
+        assert_eq!(g.stage, Stage::Blind(Blind::Big));
+        assert_eq!(g.current_boss, Some(BossBlind::Wall));
+        assert_eq!((g.plays, g.discards_remaining), (2, 1));
+        assert_eq!(g.score, BigNum::new(450.0));
+        assert_eq!(g.planetarium.level(HandRank::Flush).level, 3);
+        assert_eq!(g.planetarium.level(HandRank::Flush).plays, 4);
+
+        assert_eq!(g.jokers.len(), 1);
+        assert_eq!(g.jokers[0].edition(), Edition::Foil);
+        assert_ne!(g.jokers[0].instance_id(), 0);
+        assert_eq!(
+            g.held_consumables(),
+            vec![
+                Consumable::Planet(Planets::Venus),
+                Consumable::Planet(Planets::Mars)
+            ]
+        );
+        assert!(g.is_consumable_negative(1));
+        assert_eq!(
+            g.config.consumable_slots,
+            Config::default().consumable_slots + 1
+        );
+
+        let hand: Vec<Value> = g.available.cards().iter().map(|c| c.value).collect();
+        assert_eq!(hand, vec![Value::Ace]);
+        assert_eq!(g.discarded.len(), 1);
+        // drawn from the back in the real game, the front here
+        assert_eq!(g.deck.cards()[0].value, Value::Three);
+
+        assert!(g.has_voucher(Voucher::Overstock));
+        assert_eq!(g.tags, vec![Tag::Investment]);
+        assert_eq!(
+            (g.small_blind_tag, g.big_blind_tag),
+            (Tag::Double, Tag::Rare)
+        );
+        assert_eq!(g.boss_pool.times_drawn(BossBlind::Hook), 1);
+        assert_eq!(g.boss_pool.times_drawn(BossBlind::Wall), 1);
+        assert_eq!(g.shop.vouchers, vec![Voucher::Grabber]);
+
+        let fields: Vec<&str> = unmapped.iter().map(|u| u.field).collect();
+        // Green Joker has no selector to lose
+        assert!(!fields.contains(&"joker state"));
+        assert!(!fields.contains(&"boss"));
+        assert!(!fields.contains(&"bosses used"));
+        assert!(!fields.contains(&"tags"));
+        assert!(!fields.contains(&"voucher"));
+    }
+
+    #[test]
+    fn test_from_save_snapshot_between_blinds() {
+        let (g, unmapped) =
+            Game::from_save_snapshot(&snapshot(SaveState::BlindSelect), Config::default());
+        assert_eq!(g.stage, Stage::PreBlind());
+        // Big Blind is up next, so the Small Blind was the last one played
+        assert_eq!(g.blind, Some(Blind::Small));
+        assert!(!unmapped.iter().any(|u| u.field == "shop"));
+
+        let (g, unmapped) = Game::from_save_snapshot(&snapshot(SaveState::Shop), Config::default());
+        assert_eq!(g.stage, Stage::Shop());
+        assert_eq!(g.reroll_cost, 7);
+        let shop_jokers: Vec<&str> = g.shop.jokers.iter().map(|j| j.name()).collect();
+        assert_eq!(shop_jokers, vec!["Green Joker"]);
+        assert_eq!(g.shop.vouchers, vec![Voucher::Grabber]);
+        assert_eq!(g.shop.packs.len(), 1);
+        assert_eq!(g.shop.packs[0].category, PackCategory::Arcana);
+        assert!(!g.shop.packs[0].contents.is_empty());
+        assert!(!unmapped.iter().any(|u| u.field == "shop"));
+
+        let (g, _) = Game::from_save_snapshot(&snapshot(SaveState::PackOpen), Config::default());
+        assert_eq!(g.stage, Stage::PackOpen());
+        assert_eq!(g.pack_prev_stage, Some(Stage::Shop()));
+        let open = g.open_pack.as_ref().expect("pack reopened");
+        assert_eq!(open.contents.len(), 3);
+        assert_eq!(open.picks_remaining, 1);
+        assert_eq!(g.save_state_id(), 10);
+    }
+
+    fn exported_game() -> Game {
+        let mut g = Game::new(Config {
+            deck: Some(DeckVariant::Blue),
+            stake: Stake::Red,
+            seed_str: Some("TEST".to_string()),
+            rng_mode: RngMode::Real,
+            ..Config::default()
+        });
+        g.start();
+        let mut joker = Jokers::GreenJoker(GreenJoker::default());
+        joker.set_edition(Edition::Foil);
+        g.jokers.push(joker);
+        g.consumables
+            .push(Consumable::Planet(Planets::Venus).into());
+        g.planetarium.level_up(HandRank::Flush);
+        g.planetarium.play(HandRank::Flush);
+        g.money = 23;
+        g.handle_action(crate::action::Action::SelectBlind(Blind::Small))
+            .expect("can select small blind");
+        // a Hiker-boosted card in hand
+        let hiked = g.available.cards()[0].id;
+        g.mutate_card(hiked, |c| c.bonus_chips = 5);
+        g
+    }
+
+    #[test]
+    fn test_to_save_lua_round_trips_through_snapshot() {
+        let g = exported_game();
+        let bytes = g.to_save_jkr().unwrap();
+        let save = balatro_jkr::decode(&bytes).expect("exported save decodes");
+        assert_eq!(save, g.to_save_lua().unwrap());
+
+        let snapshot = SaveSnapshot::from_lua(&save).expect("exported save parses");
+        assert_eq!(snapshot.stake, Stake::Red);
+        assert_eq!(snapshot.deck, DeckVariant::Blue);
+        assert_eq!(snapshot.dollars, 23);
+        assert_eq!(snapshot.seed.as_deref(), Some("TEST"));
+        assert_eq!(snapshot.state, Some(SaveState::Playing));
+        assert_eq!((snapshot.ante, snapshot.round), (1, g.round));
+        assert_eq!(snapshot.blind, Some(Blind::Small));
+        assert_eq!(snapshot.boss, g.current_boss);
+        let boss = g.current_boss.expect("boss drawn");
+        assert_eq!(snapshot.bosses_used, vec![(boss, 1)]);
+        assert_eq!(snapshot.voucher, g.shop.vouchers.first().copied());
+        assert_eq!(
+            (snapshot.hands_left, snapshot.discards_left),
+            (g.plays, g.discards_remaining)
+        );
+        assert_eq!(snapshot.jokers.len(), 1);
+        assert_eq!(snapshot.jokers[0].0.name(), "Green Joker");
+        assert_eq!(
+            snapshot.consumables,
+            vec![(Consumable::Planet(Planets::Venus), None)]
+        );
+        assert_eq!(snapshot.hand.len(), g.available.cards().len());
+        assert_eq!(snapshot.deck_cards.len(), g.deck.len());
+        assert!(snapshot.hand_levels.contains(&(HandRank::Flush, 2, 1)));
+        assert!(snapshot.hand_levels.contains(&(HandRank::HighCard, 1, 0)));
+        assert_eq!(snapshot.jokers[0].1, Some(Edition::Foil));
+        assert_eq!(snapshot.tags, g.tags);
+        assert_eq!(
+            snapshot.blind_tags,
+            vec![
+                (Blind::Small, g.small_blind_tag),
+                (Blind::Big, g.big_blind_tag)
+            ]
+        );
+        assert!(snapshot.shop.is_empty());
+    }
+
+    #[test]
+    fn test_to_save_lua_round_trips_the_shop() {
+        let (g, _) = Game::from_save_snapshot(&snapshot(SaveState::Shop), Config::default());
+        let exported = SaveSnapshot::from_lua(&g.to_save_lua().unwrap()).unwrap();
+        assert_eq!(exported.state, Some(SaveState::Shop));
+        assert_eq!(exported.vouchers, vec![Voucher::Overstock]);
+        assert_eq!(exported.tags, vec![Tag::Investment]);
+        assert_eq!(exported.reroll_cost, 7);
+        assert_eq!(exported.shop.jokers.len(), 1);
+        assert_eq!(exported.shop.vouchers, vec![Voucher::Grabber]);
+        assert_eq!(exported.shop.packs.len(), 1);
+        assert_eq!(exported.shop.packs[0].size, PackSize::Jumbo);
+
+        let (g, _) = Game::from_save_snapshot(&snapshot(SaveState::PackOpen), Config::default());
+        let exported = SaveSnapshot::from_lua(&g.to_save_lua().unwrap()).unwrap();
+        assert_eq!(exported.state, Some(SaveState::PackOpen));
+        assert_eq!(exported.pack.consumables.len(), 3);
+        assert_eq!(exported.pack_choices, 1);
+    }
+
+    #[test]
+    fn test_to_save_lua_reloads_into_the_same_position() {
+        let g = exported_game();
+        let snapshot = SaveSnapshot::from_lua(&g.to_save_lua().unwrap()).unwrap();
+        let (reloaded, _) = Game::from_save_snapshot(&snapshot, Config::default());
+        assert_eq!(reloaded.stage, g.stage);
+        assert_eq!(reloaded.current_boss, g.current_boss);
+        // card ids are minted fresh on load
+        let faces = |cards: Vec<Card>| -> Vec<(Value, Suit, usize)> {
+            cards
+                .iter()
+                .map(|c| (c.value, c.suit, c.bonus_chips))
+                .collect()
+        };
+        assert_eq!(
+            faces(reloaded.available.cards()),
+            faces(g.available.cards())
+        );
+        assert_eq!(faces(reloaded.deck.cards()), faces(g.deck.cards()));
+    }
+
+    // the value at `path` down nested string-keyed tables
+    fn lookup<'a>(mut v: &'a LuaValue, path: &[&str]) -> Option<&'a LuaValue> {
+        for key in path {
+            let LuaValue::Table(entries) = v else {
+                return None;
+            };
+            v = entries
+                .iter()
+                .find(|(k, _)| *k == LuaKey::Str(key.to_string()))
+                .map(|(_, v)| v)?;
+        }
+        Some(v)
+    }
+
+    #[test]
+    fn test_to_save_lua_round_trips_joker_counters() {
+        use crate::joker::{Campfire, IceCream, Obelisk, RideTheBus};
+        let mut g = exported_game();
+        g.jokers.clear();
+        let mut ice = Jokers::IceCream(IceCream::default());
+        ice.state_mut().counter = 25.0;
+        let mut campfire = Jokers::Campfire(Campfire::default());
+        campfire.state_mut().counter = 0.75;
+        g.jokers.extend([
+            ice,
+            campfire,
+            Jokers::RideTheBus(RideTheBus::default()),
+            Jokers::Obelisk(Obelisk::default()),
+        ]);
+        g.consecutive_hands_without_face_card = 3;
+        g.consecutive_hands_not_most_played_type = 2;
+        g.consumables
+            .push(Consumable::Tarot(crate::tarot::Tarot::Magician).into());
+
+        let save = g.to_save_lua().unwrap();
+        let LuaValue::Table(jokers) = lookup(&save, &["cardAreas", "jokers", "cards"]).unwrap()
+        else {
+            panic!("jokers are a table");
+        };
+        let ability = lookup(&jokers[0].1, &["ability"]).unwrap();
+        // the center's config comes along, with the counter folded in
+        assert_eq!(lookup(ability, &["extra", "chips"]), Some(&num(75.0)));
+        assert_eq!(lookup(ability, &["extra", "chip_mod"]), Some(&count(5)));
+        let LuaValue::Table(consumables) =
+            lookup(&save, &["cardAreas", "consumeables", "cards"]).unwrap()
+        else {
+            panic!("consumables are a table");
+        };
+        let magician = &consumables.last().unwrap().1;
+        assert_eq!(
+            lookup(magician, &["ability", "consumeable", "max_highlighted"]),
+            Some(&count(2))
+        );
+
+        let snapshot = SaveSnapshot::from_lua(&save).unwrap();
+        let (reloaded, _) = Game::from_save_snapshot(&snapshot, Config::default());
+        let counters: Vec<f32> = reloaded.jokers.iter().map(|j| j.state().counter).collect();
+        assert_eq!(counters[..2], [25.0, 0.75]);
+        assert_eq!(reloaded.consecutive_hands_without_face_card, 3);
+        assert_eq!(reloaded.consecutive_hands_not_most_played_type, 2);
+    }
+
+    #[test]
+    fn test_to_save_lua_writes_win_skips_and_most_played_hand() {
+        let mut g = exported_game();
+        g.skipped_this_ante.push(Blind::Small);
+        g.blind = Some(Blind::Small);
+        g.stage = Stage::PreBlind();
+        g.planetarium.play(HandRank::Flush);
+        g.config.endless = true;
+
+        let save = g.to_save_lua().unwrap();
+        // endless alone isn't a win
+        assert_eq!(
+            lookup(&save, &["GAME", "won"]),
+            Some(&LuaValue::Bool(false))
+        );
+        assert_eq!(
+            lookup(&save, &["GAME", "current_round", "most_played_poker_hand"]),
+            Some(&text("Flush"))
+        );
+        let snapshot = SaveSnapshot::from_lua(&save).unwrap();
+        assert_eq!(
+            snapshot.blind_states,
+            vec![
+                (Blind::Small, BlindStatus::Skipped),
+                (Blind::Big, BlindStatus::Select),
+                (Blind::Boss, BlindStatus::Upcoming),
+            ]
+        );
+        assert!(!snapshot.won);
+
+        g.won = true;
+        let snapshot = SaveSnapshot::from_lua(&g.to_save_lua().unwrap()).unwrap();
+        assert!(snapshot.won);
+        let (reloaded, _) = Game::from_save_snapshot(&snapshot, Config::default());
+        assert!(reloaded.won);
+        assert_eq!(reloaded.skipped_this_ante, vec![Blind::Small]);
+    }
+
+    #[test]
+    fn test_to_save_lua_needs_a_deck() {
+        // a deck-less game isn't a Red Deck run: re-importing one as Red
+        // would add a discard every round
+        let g = Game::new(Config::default());
+        assert_eq!(g.config.deck, None);
+        assert!(matches!(g.to_save_lua(), Err(GameError::NoDeck)));
+        assert!(matches!(g.to_save_jkr(), Err(GameError::NoDeck)));
+    }
+
+    #[test]
+    fn test_save_state_id_follows_the_stage() {
+        let mut g = Game::new(Config::default());
+        assert_eq!(g.save_state_id(), 7);
+        g.stage = Stage::Shop();
+        assert_eq!(g.save_state_id(), 5);
+        // a tarot mid-use reports the stage it was used from
+        g.tarot_prev_stage = Some(Stage::PostBlind());
+        g.stage = Stage::TarotHand(crate::tarot::Tarot::Magician);
+        assert_eq!(g.save_state_id(), 8);
+    }
+}
@@ -1,6 +1,9 @@
 use crate::card::Card;
+use crate::consumable::Consumable;
+use crate::deck::DeckVariant;
 use crate::joker::Jokers;
 use crate::rank::HandRank;
+pub use balatro_types::BigNum;
 
 /// What caused a `ScoreStep`'s chips/mult change.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -13,6 +16,10 @@ pub enum ScoreSource {
     StoneKicker(Card),
     HeldCard(Card),
     Joker(Jokers),
+    // held planets under Observatory
+    Consumable(Consumable),
+    // Plasma Deck's balancing
+    Deck(DeckVariant),
 }
 
 /// One step in a scoring pass
@@ -20,20 +27,20 @@ pub enum ScoreSource {
 #[derive(Debug, Clone, PartialEq)]
 pub struct ScoreStep {
     pub source: ScoreSource,
-    pub chips_before: usize,
-    pub chips_after: usize,
-    pub mult_before: usize,
-    pub mult_after: usize,
+    pub chips_before: BigNum,
+    pub chips_after: BigNum,
+    pub mult_before: BigNum,
+    pub mult_after: BigNum,
     pub retrigger: bool,
 }
 
 impl ScoreStep {
-    pub fn delta_chips(&self) -> i64 {
-        self.chips_after as i64 - self.chips_before as i64
+    pub fn delta_chips(&self) -> BigNum {
+        self.chips_after - self.chips_before
     }
 
-    pub fn delta_mult(&self) -> i64 {
-        self.mult_after as i64 - self.mult_before as i64
+    pub fn delta_mult(&self) -> BigNum {
+        self.mult_after - self.mult_before
     }
 
     /// Human-readable summary, e.g. "K♠: +10 Chips" or "Mime (retrigger)".
@@ -44,16 +51,18 @@ impl ScoreStep {
             ScoreSource::StoneKicker(c) => format!("{c} (stone kicker)"),
             ScoreSource::HeldCard(c) => format!("{c} (held)"),
             ScoreSource::Joker(j) => j.name().to_string(),
+            ScoreSource::Consumable(c) => c.name().to_string(),
+            ScoreSource::Deck(d) => d.name().to_string(),
         };
 
         let mut parts = Vec::new();
         let dc = self.delta_chips();
         if dc != 0 {
-            parts.push(format!("{dc:+} Chips"));
+            parts.push(format!("{} Chips", signed(dc)));
         }
         let dm = self.delta_mult();
         if dm != 0 {
-            parts.push(format!("{dm:+} Mult"));
+            parts.push(format!("{} Mult", signed(dm)));
         }
 
         let suffix = if self.retrigger { " (retrigger)" } else { "" };
@@ -65,6 +74,14 @@ impl ScoreStep {
     }
 }
 
+fn signed(n: BigNum) -> String {
+    if n < 0 {
+        n.to_string()
+    } else {
+        format!("+{n}")
+    }
+}
+
 /// Ordered ledger of every `ScoreStep` a `calc_score_traced` call produced.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -78,10 +95,10 @@ mod tests {
     fn test_describe_formats_chips_and_mult() {
         let step = ScoreStep {
             source: ScoreSource::HandLevel(HandRank::TwoPair),
-            chips_before: 0,
-            chips_after: 10,
-            mult_before: 1,
-            mult_after: 3,
+            chips_before: 0.into(),
+            chips_after: 10.into(),
+            mult_before: 1.into(),
+            mult_after: 3.into(),
             retrigger: false,
         };
         assert_eq!(step.describe(), "TwoPair: +10 Chips, +2 Mult");
@@ -94,10 +111,10 @@ mod tests {
         let card = Card::new(crate::card::Value::King, crate::card::Suit::Spade);
         let step = ScoreStep {
             source: ScoreSource::PlayedCard(card),
-            chips_before: 10,
-            chips_after: 20,
-            mult_before: 2,
-            mult_after: 2,
+            chips_before: 10.into(),
+            chips_after: 20.into(),
+            mult_before: 2.into(),
+            mult_after: 2.into(),
             retrigger: true,
         };
         assert_eq!(step.describe(), "K♠: +10 Chips (retrigger)");
@@ -110,10 +127,10 @@ mod tests {
         let card = Card::new(crate::card::Value::Ace, crate::card::Suit::Heart);
         let step = ScoreStep {
             source: ScoreSource::HeldCard(card),
-            chips_before: 5,
-            chips_after: 5,
-            mult_before: 1,
-            mult_after: 1,
+            chips_before: 5.into(),
+            chips_after: 5.into(),
+            mult_before: 1.into(),
+            mult_after: 1.into(),
             retrigger: false,
         };
         assert_eq!(step.describe(), "A♥ (held)");
@@ -125,10 +142,10 @@ mod tests {
         use crate::joker::{Jokers, TheJoker};
         let step = ScoreStep {
             source: ScoreSource::Joker(Jokers::TheJoker(TheJoker::default())),
-            chips_before: 9,
-            chips_after: 9,
-            mult_before: 1,
-            mult_after: 5,
+            chips_before: 9.into(),
+            chips_after: 9.into(),
+            mult_before: 1.into(),
+            mult_after: 5.into(),
             retrigger: false,
         };
         assert_eq!(step.describe(), "Joker: +4 Mult");
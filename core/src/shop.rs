@@ -6,17 +6,43 @@ use crate::joker::{jokers_by_rarity, Jokers, Rarity};
 use crate::pack::{Pack, PackCategory, PackContent, PackSize};
 use crate::planet::{Planetarium, Planets};
 use crate::rng::{Backend, GeneratedItem, RngBackend};
+use crate::spectral::Spectral;
 use crate::tarot::Tarot;
+use crate::voucher::Voucher;
 use rand::distributions::WeightedIndex;
 use rand::prelude::*;
 use strum::IntoEnumIterator;
 
+fn default_shop_slots() -> usize {
+    2
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
 pub struct Shop {
     pub jokers: Vec<Jokers>,
     pub consumables: Vec<Consumable>,
     pub packs: Vec<Pack>,
+    // items a tag (Coupon, Uncommon, Rare, edition tags) made free, cleared
+    // whenever the items themselves are regenerated
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub free_jokers: Vec<Jokers>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub free_consumables: Vec<Consumable>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub free_packs: Vec<Pack>,
+    // ante-scoped voucher offer, redrawn each ante rather than on refresh
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub vouchers: Vec<Voucher>,
+    // playing cards for sale, only ever rolled once Magic Trick is redeemed
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub playing_cards: Vec<Card>,
+    // card slots shared by jokers, consumables and playing cards (Overstock)
+    #[cfg_attr(feature = "serde", serde(default = "default_shop_slots"))]
+    pub slots: usize,
+    // Clearance Sale / Liquidation
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub discount_percent: usize,
 }
 
 impl Shop {
@@ -25,6 +51,68 @@ impl Shop {
             jokers: Vec::new(),
             consumables: Vec::new(),
             packs: Vec::new(),
+            free_jokers: Vec::new(),
+            free_consumables: Vec::new(),
+            free_packs: Vec::new(),
+            vouchers: Vec::new(),
+            playing_cards: Vec::new(),
+            slots: default_shop_slots(),
+            discount_percent: 0,
+        }
+    }
+
+    // Same rounding as the real game, never discounted below $1.
+    fn discounted(&self, cost: usize) -> usize {
+        if self.discount_percent == 0 {
+            return cost;
+        }
+        let pct = 100usize.saturating_sub(self.discount_percent) as f32 / 100.0;
+        (((cost as f32 + 0.5) * pct).floor() as usize).max(1)
+    }
+
+    /// What `joker` costs in this shop, after any tag discounts. Rental
+    /// jokers are a flat $1.
+    pub fn joker_cost(&self, joker: &Jokers) -> usize {
+        if self.free_jokers.contains(joker) {
+            0
+        } else if joker.stickers().rental {
+            1
+        } else {
+            self.discounted(joker.cost())
+        }
+    }
+
+    /// What `consumable` costs in this shop, after any tag discounts.
+    pub fn consumable_cost(&self, consumable: &Consumable) -> usize {
+        if self.free_consumables.contains(consumable) {
+            0
+        } else {
+            self.discounted(consumable.cost())
+        }
+    }
+
+    /// What `voucher` costs in this shop, after any discounts.
+    pub fn voucher_cost(&self, voucher: &Voucher) -> usize {
+        self.discounted(voucher.cost())
+    }
+
+    /// What playing card `card` costs in this shop, after any discounts.
+    pub fn playing_card_cost(&self, card: &Card) -> usize {
+        let edition = match card.edition {
+            Edition::Foil => 2,
+            Edition::Holographic => 3,
+            Edition::Polychrome | Edition::Negative => 5,
+            Edition::Base => 0,
+        };
+        self.discounted(1 + edition)
+    }
+
+    /// What `pack` costs in this shop, after any tag discounts.
+    pub fn pack_cost(&self, pack: &Pack) -> usize {
+        if self.free_packs.contains(pack) {
+            0
+        } else {
+            self.discounted(pack.cost())
         }
     }
 }
@@ -47,6 +135,9 @@ impl Shop {
     ) {
         self.jokers.clear();
         self.consumables.clear();
+        self.playing_cards.clear();
+        self.free_jokers.clear();
+        self.free_consumables.clear();
 
         let mut excl_jokers: Vec<Jokers> = held_jokers.to_vec();
         let mut excl_tarots: Vec<Tarot> = held
@@ -70,7 +161,7 @@ impl Shop {
             })
             .collect();
 
-        for _ in 0..2 {
+        for _ in 0..self.slots {
             match backend.gen_shop_item(
                 ante,
                 planetarium,
@@ -91,6 +182,7 @@ impl Shop {
                     }
                     self.consumables.push(c);
                 }
+                GeneratedItem::PlayingCard(card) => self.playing_cards.push(card),
             }
         }
     }
@@ -113,6 +205,7 @@ impl Shop {
         let exclude = (&p1.category, &p1.size);
         let p2 = backend.gen_pack(ante, planetarium, prob_mult, Some(exclude), held_jokers);
         self.packs = vec![p1, p2];
+        self.free_packs.clear();
     }
 
     pub(crate) fn joker_from_index(&self, i: usize) -> Option<Jokers> {
@@ -127,6 +220,14 @@ impl Shop {
         self.packs.get(i).cloned()
     }
 
+    pub(crate) fn voucher_from_index(&self, i: usize) -> Option<Voucher> {
+        self.vouchers.get(i).copied()
+    }
+
+    pub(crate) fn playing_card_from_index(&self, i: usize) -> Option<Card> {
+        self.playing_cards.get(i).copied()
+    }
+
     pub(crate) fn buy_joker(&mut self, joker: &Jokers) -> Result<Jokers, GameError> {
         let i = self
             .jokers
@@ -157,6 +258,24 @@ impl Shop {
         Ok(self.packs.remove(i))
     }
 
+    pub(crate) fn buy_voucher(&mut self, voucher: &Voucher) -> Result<Voucher, GameError> {
+        let i = self
+            .vouchers
+            .iter()
+            .position(|v| v == voucher)
+            .ok_or(GameError::NoVoucherMatch)?;
+        Ok(self.vouchers.remove(i))
+    }
+
+    pub(crate) fn buy_playing_card(&mut self, card: &Card) -> Result<Card, GameError> {
+        let i = self
+            .playing_cards
+            .iter()
+            .position(|c| c.id == card.id)
+            .ok_or(GameError::NoCardMatch)?;
+        Ok(self.playing_cards.remove(i))
+    }
+
     pub(crate) fn gen_moves_buy_joker(
         &self,
         balance: usize,
@@ -166,11 +285,12 @@ impl Shop {
         }
         let buys = self
             .jokers
-            .clone()
-            .into_iter()
-            .filter(move |j| j.cost() <= balance)
-            .map(Action::BuyJoker);
-        Some(buys)
+            .iter()
+            .filter(|j| self.joker_cost(j) <= balance)
+            .cloned()
+            .map(Action::BuyJoker)
+            .collect::<Vec<_>>();
+        Some(buys.into_iter())
     }
 
     pub(crate) fn gen_moves_buy_consumable(
@@ -184,11 +304,12 @@ impl Shop {
         }
         let buys = self
             .consumables
-            .clone()
-            .into_iter()
-            .filter(move |c| c.cost() <= balance)
-            .map(Action::BuyConsumable);
-        Some(buys)
+            .iter()
+            .filter(|c| self.consumable_cost(c) <= balance)
+            .cloned()
+            .map(Action::BuyConsumable)
+            .collect::<Vec<_>>();
+        Some(buys.into_iter())
     }
 
     pub(crate) fn gen_moves_buy_pack(
@@ -200,18 +321,65 @@ impl Shop {
         }
         let buys = self
             .packs
-            .clone()
-            .into_iter()
-            .filter(move |p| p.cost() <= balance)
-            .map(Action::BuyPack);
-        Some(buys)
+            .iter()
+            .filter(|p| self.pack_cost(p) <= balance)
+            .cloned()
+            .map(Action::BuyPack)
+            .collect::<Vec<_>>();
+        Some(buys.into_iter())
+    }
+
+    pub(crate) fn gen_moves_buy_voucher(
+        &self,
+        balance: usize,
+    ) -> Option<impl Iterator<Item = Action>> {
+        if self.vouchers.is_empty() {
+            return None;
+        }
+        let buys = self
+            .vouchers
+            .iter()
+            .filter(|v| self.voucher_cost(v) <= balance)
+            .copied()
+            .map(Action::BuyVoucher)
+            .collect::<Vec<_>>();
+        Some(buys.into_iter())
     }
+
+    pub(crate) fn gen_moves_buy_playing_card(
+        &self,
+        balance: usize,
+    ) -> Option<impl Iterator<Item = Action>> {
+        if self.playing_cards.is_empty() {
+            return None;
+        }
+        let buys = self
+            .playing_cards
+            .iter()
+            .filter(|c| self.playing_card_cost(c) <= balance)
+            .copied()
+            .map(Action::BuyPlayingCard)
+            .collect::<Vec<_>>();
+        Some(buys.into_iter())
+    }
+}
+
+fn default_edition_rate() -> u32 {
+    1
 }
 
-pub(crate) fn gen_edition(prob_mult: u32, rng: &mut impl Rng, allow_negative: bool) -> Edition {
+// `edition_rate` scales Foil/Holographic/Polychrome only (Hone, Glow Up),
+// never Negative.
+pub(crate) fn gen_edition(
+    prob_mult: u32,
+    edition_rate: u32,
+    rng: &mut impl Rng,
+    allow_negative: bool,
+) -> Edition {
     if allow_negative && rng.gen_ratio(3u32.saturating_mul(prob_mult).min(1000), 1000) {
         return Edition::Negative;
     }
+    let prob_mult = prob_mult.saturating_mul(edition_rate);
     if rng.gen_ratio(3u32.saturating_mul(prob_mult).min(1000), 1000) {
         return Edition::Polychrome;
     }
@@ -226,6 +394,7 @@ pub(crate) fn gen_edition(prob_mult: u32, rng: &mut impl Rng, allow_negative: bo
 
 pub(crate) fn gen_random_playing_card(
     prob_mult: u32,
+    edition_rate: u32,
     rng: &mut impl Rng,
     force_enhance: bool,
     force_values: Option<&[Value]>,
@@ -252,7 +421,7 @@ pub(crate) fn gen_random_playing_card(
         card.enhancement = Some(ENHANCEMENTS[rng.gen_range(0..ENHANCEMENTS.len())]);
     }
 
-    card.edition = gen_edition(prob_mult, rng, false);
+    card.edition = gen_edition(prob_mult, edition_rate, rng, false);
 
     if rng.gen_ratio(1, 10) {
         const SEALS: [Seal; 4] = [Seal::Gold, Seal::Red, Seal::Blue, Seal::Purple];
@@ -264,11 +433,17 @@ pub(crate) fn gen_random_playing_card(
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
-pub(crate) struct JokerGenerator {}
+pub(crate) struct JokerGenerator {
+    // Hone/Glow Up
+    #[cfg_attr(feature = "serde", serde(default = "default_edition_rate"))]
+    pub(crate) edition_rate: u32,
+}
 
 impl JokerGenerator {
     pub(crate) fn new() -> Self {
-        JokerGenerator {}
+        JokerGenerator {
+            edition_rate: default_edition_rate(),
+        }
     }
 
     // Randomly generate rarity of new joker.
@@ -311,7 +486,7 @@ impl JokerGenerator {
         let choices = if choices.is_empty() { all } else { choices };
         let i = rng.gen_range(0..choices.len());
         let mut joker = choices[i].clone();
-        joker.set_edition(gen_edition(prob_mult, rng, true));
+        joker.set_edition(gen_edition(prob_mult, self.edition_rate, rng, true));
         joker.set_instance_id(crate::joker::mint_joker_id());
         crate::joker::roll_discard_selector(rng, &mut joker);
         joker
@@ -389,9 +564,23 @@ impl ConsumableGenerator {
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
-pub(crate) struct PackGenerator {}
+pub(crate) struct PackGenerator {
+    // Hone/Glow Up
+    #[cfg_attr(feature = "serde", serde(default = "default_edition_rate"))]
+    pub(crate) edition_rate: u32,
+    // Spectral cards may appear in Arcana packs
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) omen_globe: bool,
+}
 
 impl PackGenerator {
+    pub(crate) fn new() -> Self {
+        PackGenerator {
+            edition_rate: default_edition_rate(),
+            omen_globe: false,
+        }
+    }
+
     // Weighted random pack selection per spec (real per-mille weights from
     // balatro-seed/src/pools.rs's PACKS table, scaled *100 so every category,
     // including Spectral's 0.07 Mega weight, is an exact integer):
@@ -434,17 +623,30 @@ impl PackGenerator {
         let dist = WeightedIndex::new(&weights).unwrap();
         let idx = dist.sample(rng);
         let (category, size, _) = choices[idx];
+        self.gen_pack_of(*category, *size, planetarium, prob_mult, held_jokers, rng)
+    }
 
+    /// A pack of a fixed category and size, as handed out by the pack tags.
+    pub(crate) fn gen_pack_of(
+        &self,
+        category: PackCategory,
+        size: PackSize,
+        planetarium: &Planetarium,
+        prob_mult: u32,
+        held_jokers: &[Jokers],
+        rng: &mut impl Rng,
+    ) -> Pack {
         // Shared with `RealBackend` (`rng.rs`) so Fast/Real modes can never disagree
         // on pack content counts - Buffoon and Spectral share the smaller 2/4/4
         // shape, everything else is 3/5/5.
-        let count = balatro_seed::pack_card_count(*category, *size) as usize;
+        let count = balatro_seed::pack_card_count(category, size) as usize;
 
-        let contents = self.gen_contents(category, count, planetarium, prob_mult, held_jokers, rng);
+        let contents =
+            self.gen_contents(&category, count, planetarium, prob_mult, held_jokers, rng);
 
         Pack {
-            category: *category,
-            size: *size,
+            category,
+            size,
             contents,
         }
     }
@@ -458,17 +660,42 @@ impl PackGenerator {
         held_jokers: &[Jokers],
         rng: &mut impl Rng,
     ) -> Vec<PackContent> {
-        let joker_gen = JokerGenerator {};
+        let joker_gen = JokerGenerator {
+            edition_rate: self.edition_rate,
+        };
         let consumable_gen = ConsumableGenerator {};
 
+        let mut rare_seen: Vec<Spectral> = vec![];
         match category {
             PackCategory::Arcana => (0..count)
-                .map(|_| PackContent::Tarot(crate::tarot::random_tarot(rng)))
+                .map(|_| {
+                    if self.omen_globe && rng.gen_ratio(1, 5) {
+                        let rare = roll_rare_spectral(
+                            &[Spectral::Soul, Spectral::BlackHole],
+                            &mut rare_seen,
+                            rng,
+                        );
+                        PackContent::Spectral(
+                            rare.unwrap_or_else(|| crate::spectral::random_spectral(rng)),
+                        )
+                    } else if let Some(soul) =
+                        roll_rare_spectral(&[Spectral::Soul], &mut rare_seen, rng)
+                    {
+                        PackContent::Spectral(soul)
+                    } else {
+                        PackContent::Tarot(crate::tarot::random_tarot(rng))
+                    }
+                })
                 .collect(),
             PackCategory::Celestial => {
                 let mut exclude: Vec<Planets> = vec![];
                 (0..count)
                     .map(|_| {
+                        if let Some(hole) =
+                            roll_rare_spectral(&[Spectral::BlackHole], &mut rare_seen, rng)
+                        {
+                            return PackContent::Spectral(hole);
+                        }
                         let planet = consumable_gen.gen_planet(planetarium, &exclude, rng);
                         exclude.push(planet);
                         PackContent::Planet(planet)
@@ -487,16 +714,51 @@ impl PackGenerator {
             }
             PackCategory::Standard => (0..count)
                 .map(|_| {
-                    PackContent::PlayingCard(gen_random_playing_card(prob_mult, rng, false, None))
+                    PackContent::PlayingCard(gen_random_playing_card(
+                        prob_mult,
+                        self.edition_rate,
+                        rng,
+                        false,
+                        None,
+                    ))
                 })
                 .collect(),
             PackCategory::Spectral => (0..count)
-                .map(|_| PackContent::Spectral(crate::spectral::random_spectral(rng)))
+                .map(|_| {
+                    let rare = roll_rare_spectral(
+                        &[Spectral::Soul, Spectral::BlackHole],
+                        &mut rare_seen,
+                        rng,
+                    );
+                    PackContent::Spectral(
+                        rare.unwrap_or_else(|| crate::spectral::random_spectral(rng)),
+                    )
+                })
                 .collect(),
         }
     }
 }
 
+/// The Soul and Black Hole's own 0.3% rolls ahead of a pack card's normal
+/// draw, the same odds as `balatro_seed`'s soulable draws. Each turns up at
+/// most once per pack; if both hit, the last candidate wins.
+fn roll_rare_spectral(
+    candidates: &[Spectral],
+    seen: &mut Vec<Spectral>,
+    rng: &mut impl Rng,
+) -> Option<Spectral> {
+    let mut hit = None;
+    for s in candidates {
+        if !seen.contains(s) && rng.gen::<f64>() > 0.997 {
+            hit = Some(*s);
+        }
+    }
+    if let Some(s) = hit {
+        seen.push(s);
+    }
+    hit
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -552,7 +814,7 @@ mod tests {
         let mut shop = Shop::new();
         shop.consumables = vec![Consumable::Planet(Planets::Mercury)];
         assert_eq!(shop.consumables.len(), 1);
-        let c1 = shop.consumables[0].clone();
+        let c1 = shop.consumables[0];
         shop.buy_consumable(&c1).expect("buy consumable");
         assert_eq!(shop.consumables.len(), 0);
     }
@@ -609,7 +871,7 @@ mod tests {
     #[test]
     fn test_pack_gen_produces_valid_packs() {
         let planetarium = Planetarium::new();
-        let gen = PackGenerator {};
+        let gen = PackGenerator::new();
         for _ in 0..50 {
             let pack = gen.gen_pack(&planetarium, 1, None, &[], &mut rand::thread_rng());
             let expected_count =
@@ -621,7 +883,7 @@ mod tests {
     #[test]
     fn test_pack_gen_can_produce_valid_spectral_packs() {
         let planetarium = Planetarium::new();
-        let gen = PackGenerator {};
+        let gen = PackGenerator::new();
         let mut saw_spectral = false;
         for _ in 0..200 {
             let pack = gen.gen_pack(&planetarium, 1, None, &[], &mut rand::thread_rng());
@@ -632,16 +894,43 @@ mod tests {
             let expected_count =
                 balatro_seed::pack_card_count(pack.category, pack.size) as usize;
             assert_eq!(pack.contents.len(), expected_count);
+            let mut rare = vec![];
             for content in &pack.contents {
                 let PackContent::Spectral(s) = content else {
                     panic!("Spectral pack produced non-Spectral content: {content:?}");
                 };
-                assert!(!s.is_rare(), "{s:?} should never appear in a normal draw");
+                // Soul/Black Hole only through their own roll, once a pack
+                if s.is_rare() {
+                    assert!(!rare.contains(s), "{s:?} twice in one pack");
+                    rare.push(*s);
+                }
             }
         }
         assert!(saw_spectral, "expected at least one Spectral pack in 200 draws");
     }
 
+    #[test]
+    fn test_soul_is_the_only_way_to_a_legendary() {
+        use rand::SeedableRng;
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let planetarium = Planetarium::new();
+        let gen = PackGenerator::new();
+        let mut souls = 0;
+        for _ in 0..2000 {
+            for category in [PackCategory::Arcana, PackCategory::Buffoon] {
+                for content in gen.gen_contents(&category, 5, &planetarium, 1, &[], &mut rng) {
+                    match content {
+                        PackContent::Spectral(Spectral::Soul) => souls += 1,
+                        PackContent::Joker(j) => assert_ne!(j.rarity(), Rarity::Legendary),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        // 0.3% a card, ~30 expected
+        assert!((5..100).contains(&souls), "souls: {souls}");
+    }
+
     #[test]
     fn test_gen_joker_rarity_distribution_roughly_matches_weights() {
         use rand::SeedableRng;
@@ -683,7 +972,7 @@ mod tests {
         // prob_mult saturates every gen_ratio numerator at 1000/1000 (always true);
         // with allow_negative=false this should fall through to Polychrome instead.
         for _ in 0..500 {
-            assert_ne!(gen_edition(400, &mut rng, false), Edition::Negative);
+            assert_ne!(gen_edition(400, 1, &mut rng, false), Edition::Negative);
         }
     }
 
@@ -691,6 +980,6 @@ mod tests {
     fn test_gen_edition_jokers_can_be_negative() {
         use rand::SeedableRng;
         let mut rng = ChaCha8Rng::seed_from_u64(42);
-        assert_eq!(gen_edition(400, &mut rng, true), Edition::Negative);
+        assert_eq!(gen_edition(400, 1, &mut rng, true), Edition::Negative);
     }
 }
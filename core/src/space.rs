@@ -1,7 +1,7 @@
 use crate::action::{Action, MoveDirection, SortBy};
 use crate::config::Config;
 use crate::error::ActionSpaceError;
-use crate::game::Game;
+use crate::game::{Game, SHOP_VOUCHER_SLOTS};
 use crate::stage::{Blind, BlindExt};
 #[cfg(feature = "python")]
 use pyo3::pyclass;
@@ -26,20 +26,27 @@ const PACK_CONTENTS_MAX: usize = 5;
 // 77: next round
 // 78: select blind
 // 79: skip blind
-// 80-81: buy consumable
-// 82-83: use consumable
-// 84: apply tarot
-// 85-89: sell joker
-// 90-91: sell consumable
-// 92-93: buy pack
-// 94-98: pick pack card
-// 99: skip pack
-// 100: sort hand (rank)
-// 101: sort hand (suit)
-// 102: reroll
-// 103: apply spectral
+// 80-83: buy consumable
+// 84-85: use consumable
+// 86: apply tarot
+// 87-91: sell joker
+// 92-93: sell consumable
+// 94-95: buy pack
+// 96-100: pick pack card
+// 101: skip pack
+// 102: sort hand (rank)
+// 103: sort hand (suit)
+// 104: reroll
+// 105: apply spectral
+// 106-107: buy voucher
+// 108-111: buy playing card
+// 112: reroll boss
+// 113-116: move joker (left)
+// 117-120: move joker (right)
+// 121: move consumable (left)
+// 122: move consumable (right)
 //
-// We end up with a vector of length 104 where each index
+// We end up with a vector of length 123 where each index
 // represents a potential action.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass(eq))]
@@ -66,6 +73,13 @@ pub struct ActionSpace {
     pub sort_hand: Vec<usize>,
     pub reroll: Vec<usize>,
     pub apply_spectral: Vec<usize>,
+    pub buy_voucher: Vec<usize>,
+    pub buy_playing_card: Vec<usize>,
+    pub reroll_boss: Vec<usize>,
+    pub move_joker_left: Vec<usize>,
+    pub move_joker_right: Vec<usize>,
+    pub move_consumable_left: Vec<usize>,
+    pub move_consumable_right: Vec<usize>,
 }
 
 impl ActionSpace {
@@ -91,6 +105,13 @@ impl ActionSpace {
             + self.sort_hand.len()
             + self.reroll.len()
             + self.apply_spectral.len()
+            + self.buy_voucher.len()
+            + self.buy_playing_card.len()
+            + self.reroll_boss.len()
+            + self.move_joker_left.len()
+            + self.move_joker_right.len()
+            + self.move_consumable_left.len()
+            + self.move_consumable_right.len()
     }
 
     fn select_card_min(&self) -> usize {
@@ -261,6 +282,62 @@ impl ActionSpace {
         self.apply_spectral_min()
     }
 
+    fn buy_voucher_min(&self) -> usize {
+        self.apply_spectral_min() + self.apply_spectral.len()
+    }
+
+    fn buy_voucher_max(&self) -> usize {
+        self.buy_voucher_min() + self.buy_voucher.len().saturating_sub(1)
+    }
+
+    fn buy_playing_card_min(&self) -> usize {
+        self.buy_voucher_min() + self.buy_voucher.len()
+    }
+
+    fn buy_playing_card_max(&self) -> usize {
+        self.buy_playing_card_min() + self.buy_playing_card.len().saturating_sub(1)
+    }
+
+    fn reroll_boss_min(&self) -> usize {
+        self.buy_playing_card_min() + self.buy_playing_card.len()
+    }
+
+    fn reroll_boss_max(&self) -> usize {
+        self.reroll_boss_min()
+    }
+
+    fn move_joker_left_min(&self) -> usize {
+        self.reroll_boss_min() + self.reroll_boss.len()
+    }
+
+    fn move_joker_left_max(&self) -> usize {
+        self.move_joker_left_min() + self.move_joker_left.len().saturating_sub(1)
+    }
+
+    fn move_joker_right_min(&self) -> usize {
+        self.move_joker_left_min() + self.move_joker_left.len()
+    }
+
+    fn move_joker_right_max(&self) -> usize {
+        self.move_joker_right_min() + self.move_joker_right.len().saturating_sub(1)
+    }
+
+    fn move_consumable_left_min(&self) -> usize {
+        self.move_joker_right_min() + self.move_joker_right.len()
+    }
+
+    fn move_consumable_left_max(&self) -> usize {
+        self.move_consumable_left_min() + self.move_consumable_left.len().saturating_sub(1)
+    }
+
+    fn move_consumable_right_min(&self) -> usize {
+        self.move_consumable_left_min() + self.move_consumable_left.len()
+    }
+
+    fn move_consumable_right_max(&self) -> usize {
+        self.move_consumable_right_min() + self.move_consumable_right.len().saturating_sub(1)
+    }
+
     // Not all actions are always legal, by default all actions
     // are masked out, but provide methods to unmask valid.
     pub(crate) fn unmask_select_card(&mut self, i: usize) -> Result<(), ActionSpaceError> {
@@ -391,6 +468,61 @@ impl ActionSpace {
         self.apply_spectral[0] = 1;
     }
 
+    pub(crate) fn unmask_buy_voucher(&mut self, i: usize) -> Result<(), ActionSpaceError> {
+        if i >= self.buy_voucher.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.buy_voucher[i] = 1;
+        Ok(())
+    }
+
+    pub(crate) fn unmask_buy_playing_card(&mut self, i: usize) -> Result<(), ActionSpaceError> {
+        if i >= self.buy_playing_card.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.buy_playing_card[i] = 1;
+        Ok(())
+    }
+
+    pub(crate) fn unmask_reroll_boss(&mut self) {
+        self.reroll_boss[0] = 1;
+    }
+
+    pub(crate) fn unmask_move_joker_left(&mut self, i: usize) -> Result<(), ActionSpaceError> {
+        if i >= self.move_joker_left.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.move_joker_left[i] = 1;
+        Ok(())
+    }
+
+    pub(crate) fn unmask_move_joker_right(&mut self, i: usize) -> Result<(), ActionSpaceError> {
+        if i >= self.move_joker_right.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.move_joker_right[i] = 1;
+        Ok(())
+    }
+
+    pub(crate) fn unmask_move_consumable_left(&mut self, i: usize) -> Result<(), ActionSpaceError> {
+        if i >= self.move_consumable_left.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.move_consumable_left[i] = 1;
+        Ok(())
+    }
+
+    pub(crate) fn unmask_move_consumable_right(
+        &mut self,
+        i: usize,
+    ) -> Result<(), ActionSpaceError> {
+        if i >= self.move_consumable_right.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.move_consumable_right[i] = 1;
+        Ok(())
+    }
+
     pub fn to_action(&self, index: usize, game: &Game) -> Result<Action, ActionSpaceError> {
         let vec = self.to_vec();
         if let Some(v) = vec.get(index) {
@@ -424,9 +556,7 @@ impl ActionSpace {
             }
             n if (self.play_min()..=self.play_max()).contains(&n) => Ok(Action::Play()),
             n if (self.discard_min()..=self.discard_max()).contains(&n) => Ok(Action::Discard()),
-            n if (self.cash_out_min()..=self.cash_out_max()).contains(&n) => {
-                Ok(Action::CashOut(game.reward))
-            }
+            n if (self.cash_out_min()..=self.cash_out_max()).contains(&n) => Ok(Action::CashOut()),
             n if (self.buy_joker_min()..=self.buy_joker_max()).contains(&n) => {
                 let n_offset = n - self.buy_joker_min();
                 game.shop
@@ -458,8 +588,7 @@ impl ActionSpace {
                 let n_offset = n - self.use_consumable_min();
                 game.consumables
                     .get(n_offset)
-                    .cloned()
-                    .map(Action::UseConsumable)
+                    .map(|c| Action::UseConsumable(c.consumable))
                     .ok_or(ActionSpaceError::InvalidActionConversion)
             }
             n if (self.apply_tarot_min()..=self.apply_tarot_max()).contains(&n) => {
@@ -518,6 +647,56 @@ impl ActionSpace {
             {
                 Ok(Action::ApplySpectral())
             }
+            n if !self.buy_voucher.is_empty()
+                && (self.buy_voucher_min()..=self.buy_voucher_max()).contains(&n) =>
+            {
+                let n_offset = n - self.buy_voucher_min();
+                game.shop
+                    .voucher_from_index(n_offset)
+                    .map(Action::BuyVoucher)
+                    .ok_or(ActionSpaceError::InvalidActionConversion)
+            }
+            n if !self.buy_playing_card.is_empty()
+                && (self.buy_playing_card_min()..=self.buy_playing_card_max()).contains(&n) =>
+            {
+                let n_offset = n - self.buy_playing_card_min();
+                game.shop
+                    .playing_card_from_index(n_offset)
+                    .map(Action::BuyPlayingCard)
+                    .ok_or(ActionSpaceError::InvalidActionConversion)
+            }
+            n if !self.reroll_boss.is_empty()
+                && (self.reroll_boss_min()..=self.reroll_boss_max()).contains(&n) =>
+            {
+                Ok(Action::RerollBoss())
+            }
+            // Index shifted to right (+1), since leftmost joker cannot move left
+            n if !self.move_joker_left.is_empty()
+                && (self.move_joker_left_min()..=self.move_joker_left_max()).contains(&n) =>
+            {
+                let n_offset = n - self.move_joker_left_min() + 1;
+                Ok(Action::MoveJoker(MoveDirection::Left, n_offset))
+            }
+            n if !self.move_joker_right.is_empty()
+                && (self.move_joker_right_min()..=self.move_joker_right_max()).contains(&n) =>
+            {
+                let n_offset = n - self.move_joker_right_min();
+                Ok(Action::MoveJoker(MoveDirection::Right, n_offset))
+            }
+            n if !self.move_consumable_left.is_empty()
+                && (self.move_consumable_left_min()..=self.move_consumable_left_max())
+                    .contains(&n) =>
+            {
+                let n_offset = n - self.move_consumable_left_min() + 1;
+                Ok(Action::MoveConsumable(MoveDirection::Left, n_offset))
+            }
+            n if !self.move_consumable_right.is_empty()
+                && (self.move_consumable_right_min()..=self.move_consumable_right_max())
+                    .contains(&n) =>
+            {
+                let n_offset = n - self.move_consumable_right_min();
+                Ok(Action::MoveConsumable(MoveDirection::Right, n_offset))
+            }
             _ => Err(ActionSpaceError::InvalidActionConversion),
         }
     }
@@ -545,6 +724,13 @@ impl ActionSpace {
             self.sort_hand.clone(),
             self.reroll.clone(),
             self.apply_spectral.clone(),
+            self.buy_voucher.clone(),
+            self.buy_playing_card.clone(),
+            self.reroll_boss.clone(),
+            self.move_joker_left.clone(),
+            self.move_joker_right.clone(),
+            self.move_consumable_left.clone(),
+            self.move_consumable_right.clone(),
         ]
         .concat()
     }
@@ -569,7 +755,8 @@ impl From<Config> for ActionSpace {
             next_round: vec![0; 1],
             select_blind: vec![0; 1],
             skip_blind: vec![0; 1],
-            buy_consumable: vec![0; c.consumable_slots],
+            // Overstock can fill every shop slot with consumables
+            buy_consumable: vec![0; c.store_consumable_slots_max],
             use_consumable: vec![0; c.consumable_slots],
             apply_tarot: vec![0; 1],
             sell_joker: vec![0; c.joker_slots],
@@ -580,6 +767,15 @@ impl From<Config> for ActionSpace {
             sort_hand: vec![0; 2],
             reroll: vec![0; 1],
             apply_spectral: vec![0; 1],
+            buy_voucher: vec![0; SHOP_VOUCHER_SLOTS],
+            buy_playing_card: vec![0; c.store_consumable_slots_max],
+            reroll_boss: vec![0; 1],
+            // every joker/consumable but the leftmost can move left, every
+            // one but the rightmost right
+            move_joker_left: vec![0; c.joker_slots.saturating_sub(1)],
+            move_joker_right: vec![0; c.joker_slots.saturating_sub(1)],
+            move_consumable_left: vec![0; c.consumable_slots.saturating_sub(1)],
+            move_consumable_right: vec![0; c.consumable_slots.saturating_sub(1)],
         }
     }
 }
@@ -609,6 +805,13 @@ impl From<ActionSpace> for Vec<usize> {
             a.sort_hand,
             a.reroll,
             a.apply_spectral,
+            a.buy_voucher,
+            a.buy_playing_card,
+            a.reroll_boss,
+            a.move_joker_left,
+            a.move_joker_right,
+            a.move_consumable_left,
+            a.move_consumable_right,
         ]
         .concat()
     }
@@ -655,12 +858,14 @@ mod tests {
         let a = ActionSpace::from(c.clone());
         // 24 select + 23 move_left + 23 move_right + 1 play + 1 discard
         // + 1 cashout + 4 buy_joker + 1 next_round + 1 select_blind + 1 skip_blind
-        // + 2 buy_consumable + 2 use_consumable + 1 apply_tarot
+        // + 4 buy_consumable + 2 use_consumable + 1 apply_tarot
         // + 5 sell_joker + 2 sell_consumable
         // + 2 buy_pack + 5 pick_pack_card + 1 skip_pack + 2 sort_hand + 1 reroll
-        // + 1 apply_spectral = 104
-        assert_eq!(a.size(), 104);
-        assert_eq!(a.to_vec().len(), 104);
+        // + 1 apply_spectral + 2 buy_voucher + 4 buy_playing_card
+        // + 1 reroll_boss + 4 move_joker_left + 4 move_joker_right
+        // + 1 move_consumable_left + 1 move_consumable_right = 123
+        assert_eq!(a.size(), 123);
+        assert_eq!(a.to_vec().len(), 123);
     }
 
     #[test]
@@ -675,7 +880,7 @@ mod tests {
         assert_eq!(a.to_vec().len(), a.size());
         // to_action on the apply_tarot index must still resolve and not panic
         assert!(a.to_action(a.apply_tarot_min(), &Game::default()).is_err()); // masked, not a panic
-        // Same for the apply_spectral index, appended at the end of the vector.
+                                                                              // Same for the apply_spectral index, appended at the end of the vector.
         assert!(a
             .to_action(a.apply_spectral_min(), &Game::default())
             .is_err()); // masked, not a panic
@@ -734,6 +939,8 @@ mod tests {
     fn test_skip_blind_round_trip() {
         let mut g = Game::default();
         g.start();
+        // a tag that waits for the shop, so it's still held after the skip
+        g.small_blind_tag = crate::tag::Tag::Coupon;
         let space = g.gen_action_space();
         let index = space.skip_blind_min();
         assert_eq!(space.to_vec()[index], 1);
@@ -820,7 +1027,76 @@ mod tests {
         assert_eq!(a.buy_consumable[0], 0);
         a.unmask_buy_consumable(0).unwrap();
         assert_eq!(a.buy_consumable[0], 1);
-        assert!(a.unmask_buy_consumable(2).is_err());
+        a.unmask_buy_consumable(3).unwrap();
+        assert!(a.unmask_buy_consumable(4).is_err());
+    }
+
+    #[test]
+    fn test_buy_voucher_round_trip() {
+        use crate::voucher::Voucher;
+        let mut g = Game {
+            stage: crate::stage::Stage::Shop(),
+            money: 10,
+            ..Default::default()
+        };
+        g.shop.vouchers = vec![Voucher::Blank, Voucher::Hone];
+        let space = g.gen_action_space();
+        let index = space.buy_voucher_min() + 1;
+        assert_eq!(space.to_vec()[index], 1);
+        let action = space.to_action(index, &g).expect("to action");
+        assert_eq!(action, Action::BuyVoucher(Voucher::Hone));
+        g.handle_action(action).unwrap();
+        assert_eq!(g.vouchers, vec![Voucher::Hone]);
+        assert_eq!(g.shop.vouchers, vec![Voucher::Blank]);
+    }
+
+    #[test]
+    fn test_reroll_boss_round_trip() {
+        use crate::voucher::Voucher;
+        let mut g = Game::default();
+        g.start();
+        g.money = 10;
+        let space = g.gen_action_space();
+        assert_eq!(space.to_vec()[space.reroll_boss_min()], 0);
+
+        g.vouchers.push(Voucher::DirectorsCut);
+        let space = g.gen_action_space();
+        let index = space.reroll_boss_min();
+        assert_eq!(index, 112);
+        assert_eq!(space.to_vec()[index], 1);
+        let action = space.to_action(index, &g).expect("to action");
+        assert_eq!(action, Action::RerollBoss());
+        g.handle_action(action).unwrap();
+        assert_eq!(g.money, 0);
+    }
+
+    #[test]
+    fn test_move_joker_round_trip() {
+        use crate::joker::Jokers;
+        use balatro_types::joker::{Acrobat, TheJoker};
+        let mut g = Game {
+            stage: crate::stage::Stage::Shop(),
+            jokers: vec![
+                Jokers::TheJoker(TheJoker::default()),
+                Jokers::Acrobat(Acrobat::default()),
+            ],
+            ..Default::default()
+        };
+        let space = g.gen_action_space();
+        assert_eq!(space.move_joker_left, vec![1, 0, 0, 0]);
+        assert_eq!(space.move_joker_right, vec![1, 0, 0, 0]);
+        assert_eq!(space.to_vec()[space.move_consumable_left_min()], 0);
+
+        let index = space.move_joker_left_min();
+        assert_eq!(index, 113);
+        let action = space.to_action(index, &g).expect("to action");
+        assert_eq!(action, Action::MoveJoker(MoveDirection::Left, 1));
+        g.handle_action(action).unwrap();
+        assert!(matches!(g.jokers[0], Jokers::Acrobat(_)));
+
+        let index = space.move_joker_right_min();
+        let action = space.to_action(index, &g).expect("to action");
+        assert_eq!(action, Action::MoveJoker(MoveDirection::Right, 0));
     }
 
     #[test]
@@ -1,9 +1,9 @@
 use crate::card::Seal;
 use crate::error::GameError;
 use crate::game::Game;
+use crate::modifier::StatModifiers;
 use crate::rng::RngBackend;
 
-use crate::card::Card;
 use balatro_types::Edition;
 use balatro_types::HandRank;
 use balatro_types::Rarity;
@@ -58,7 +58,7 @@ impl SpectralEffect for Spectral {
                     return Err(GameError::InvalidAction);
                 }
                 let card = selected[0];
-                let edition = game.backend.roll_random_edition();
+                let edition = game.backend.roll_random_edition("aura");
                 game.mutate_card(card.id, |c| c.edition = edition);
             }
             Self::Cryptid => {
@@ -67,49 +67,44 @@ impl SpectralEffect for Spectral {
                     return Err(GameError::InvalidAction);
                 }
                 let og = selected[0];
-                let copy_one = Card::new(og.value, og.suit);
-                let copy_two = Card::new(og.value, og.suit);
-                let (id_one, id_two) = (copy_one.id, copy_two.id);
-                game.available.extend(vec![copy_one, copy_two]);
-                let copy_fields = |c: &mut Card| {
-                    c.enhancement = og.enhancement;
-                    c.edition = og.edition;
-                    c.seal = og.seal;
-                };
-                game.mutate_card(id_one, copy_fields);
-                game.mutate_card(id_two, copy_fields);
+                let copies = vec![og.duplicate(), og.duplicate()];
+                game.add_to_hand(copies);
             }
             Self::Familiar => {
-                let card = game.backend.pick_random_card(game.available.cards());
-                game.destroy_card(card.id);
-                let values: [Value; 3] = [Value::King, Value::Queen, Value::Jack];
-                // TODO: does enhanced mean only enhance, no roll for seal/edition?
-                let card_one = game
-                    .backend
-                    .gen_random_card(game.prob_mult, true, Some(&values));
-                let card_two = game
-                    .backend
-                    .gen_random_card(game.prob_mult, true, Some(&values));
-                let card_three = game
+                let card = game
                     .backend
-                    .gen_random_card(game.prob_mult, true, Some(&values));
-                game.available
-                    .extend([card_one, card_two, card_three].to_vec());
+                    .pick_random_card(game.available.cards(), "random_destroy");
+                game.destroy_card(card.id);
+                let values: [Value; 3] = [Value::Jack, Value::Queen, Value::King];
+                let cards = (0..3)
+                    .map(|_| {
+                        game.backend.create_enhanced_card(
+                            "familiar_create",
+                            game.prob_mult,
+                            &values,
+                        )
+                    })
+                    .collect();
+                game.add_to_hand(cards);
             }
             Self::Grim => {
-                let card = game.backend.pick_random_card(game.available.cards());
+                let card = game
+                    .backend
+                    .pick_random_card(game.available.cards(), "random_destroy");
                 game.destroy_card(card.id);
                 let values: [Value; 1] = [Value::Ace];
-                let card_one = game
-                    .backend
-                    .gen_random_card(game.prob_mult, true, Some(&values));
-                let card_two = game
-                    .backend
-                    .gen_random_card(game.prob_mult, true, Some(&values));
-                game.available.extend([card_one, card_two].to_vec());
+                let cards = (0..2)
+                    .map(|_| {
+                        game.backend
+                            .create_enhanced_card("grim_create", game.prob_mult, &values)
+                    })
+                    .collect();
+                game.add_to_hand(cards);
             }
             Self::Incantation => {
-                let card = game.backend.pick_random_card(game.available.cards());
+                let card = game
+                    .backend
+                    .pick_random_card(game.available.cards(), "random_destroy");
                 game.destroy_card(card.id);
                 let values: [Value; 9] = [
                     Value::Two,
@@ -122,41 +117,37 @@ impl SpectralEffect for Spectral {
                     Value::Nine,
                     Value::Ten,
                 ];
-                let card_one = game
-                    .backend
-                    .gen_random_card(game.prob_mult, true, Some(&values));
-                let card_two = game
-                    .backend
-                    .gen_random_card(game.prob_mult, true, Some(&values));
-                let card_three = game
-                    .backend
-                    .gen_random_card(game.prob_mult, true, Some(&values));
-                let card_four = game
-                    .backend
-                    .gen_random_card(game.prob_mult, true, Some(&values));
-                game.available
-                    .extend([card_one, card_two, card_three, card_four].to_vec());
+                let cards = (0..4)
+                    .map(|_| {
+                        game.backend.create_enhanced_card(
+                            "incantation_create",
+                            game.prob_mult,
+                            &values,
+                        )
+                    })
+                    .collect();
+                game.add_to_hand(cards);
             }
             Self::Sigil => {
-                let suit = game.backend.roll_random_suit();
+                let suit = game.backend.roll_random_suit("sigil");
                 for card in game.available.cards() {
                     game.mutate_card(card.id, |c| c.suit = suit);
                 }
             }
             Self::Ouija => {
-                let value = game.backend.roll_random_value();
+                let value = game.backend.roll_random_value("ouija");
                 for card in game.available.cards() {
                     game.mutate_card(card.id, |c| c.value = value);
                 }
-                game.config.available = game.config.available.saturating_sub(1);
+                game.apply_permanent(StatModifiers::hand_size(-1));
             }
             Self::Immolate => {
-                let count_destroy = 5.min(game.available.cards().len());
-                for _ in 0..count_destroy {
-                    let card = game.backend.pick_random_card(game.available.cards());
+                let mut hand = game.available.cards();
+                game.backend.shuffle_cards(&mut hand, "immolate");
+                for card in hand.into_iter().take(5) {
                     game.destroy_card(card.id);
                 }
-                game.money += 20;
+                game.earn(20);
             }
             Self::BlackHole => {
                 // `RoyalFlush` shares its level slot with `StraightFlush`
@@ -167,60 +158,47 @@ impl SpectralEffect for Spectral {
                 }
             }
             Self::Wraith => {
-                if game.jokers.len() < game.config.joker_slots {
-                    let ante = game.ante_current as i32;
-                    let exclude = game.jokers.clone();
-                    let prob_mult = game.prob_mult;
-                    let joker =
-                        game.backend
-                            .gen_joker_of_rarity(ante, prob_mult, &exclude, Rarity::Rare);
-                    game.jokers.push(joker);
-                }
+                game.create_joker("wra", Some(Rarity::Rare));
                 game.money = 0;
             }
-            Self::Soul => {
-                if game.jokers.len() < game.config.joker_slots {
-                    let ante = game.ante_current as i32;
-                    let exclude = game.jokers.clone();
-                    let prob_mult = game.prob_mult;
-                    let joker = game.backend.gen_joker_of_rarity(
-                        ante,
-                        prob_mult,
-                        &exclude,
-                        Rarity::Legendary,
-                    );
-                    game.jokers.push(joker);
-                }
-            }
+            // The only way to a Legendary. "sou" is the real game's source,
+            // so `Real` mode draws the same legendary the seed would.
+            Self::Soul => game.create_joker("sou", Some(Rarity::Legendary)),
+            // Ectoplasm and Hex only pick jokers that have no edition yet
             Self::Ectoplasm => {
-                if !game.jokers.is_empty() {
-                    let picked = game.backend.pick_random_joker(game.jokers.clone());
-                    if let Some(j) = game
-                        .jokers
-                        .iter_mut()
-                        .find(|j| j.instance_id() == picked.instance_id())
-                    {
-                        j.set_edition(Edition::Negative);
-                    }
-                    game.config.available = game.config.available.saturating_sub(1);
+                let editionless = game.editionless_jokers();
+                if !editionless.is_empty() {
+                    let picked = game.backend.pick_random_joker(editionless, "ectoplasm");
+                    game.set_joker_edition(picked.instance_id(), Edition::Negative);
+                    game.apply_permanent(StatModifiers::hand_size(-1));
                 }
             }
             Self::Ankh => {
                 if !game.jokers.is_empty() {
-                    let original = game.backend.pick_random_joker(game.jokers.clone());
+                    let original = game
+                        .backend
+                        .pick_random_joker(game.jokers.clone(), "ankh_choice");
                     let mut clone = game.backend.clone_joker(original.clone());
                     // Strip negative
                     if clone.edition() == Edition::Negative {
                         clone.set_edition(Edition::Base);
                     }
-                    game.jokers = vec![original, clone];
+                    // Eternal jokers survive
+                    game.remove_jokers_where(|j| {
+                        j.instance_id() != original.instance_id() && !j.stickers().eternal
+                    });
+                    game.add_joker(clone);
                 }
             }
             Self::Hex => {
-                if !game.jokers.is_empty() {
-                    let mut original = game.backend.pick_random_joker(game.jokers.clone());
-                    original.set_edition(Edition::Polychrome);
-                    game.jokers = vec![original];
+                let editionless = game.editionless_jokers();
+                if !editionless.is_empty() {
+                    let picked = game.backend.pick_random_joker(editionless, "hex");
+                    // Eternal jokers survive
+                    game.remove_jokers_where(|j| {
+                        j.instance_id() != picked.instance_id() && !j.stickers().eternal
+                    });
+                    game.set_joker_edition(picked.instance_id(), Edition::Polychrome);
                 }
             }
         }
@@ -241,7 +219,7 @@ pub fn random_spectral(rng: &mut impl Rng) -> Spectral {
 mod tests {
     use super::*;
     use crate::card::{Card, Enhancement, Suit, Value};
-    use crate::joker::{jokers_by_rarity, mint_joker_id, Jokers};
+    use crate::joker::{jokers_by_rarity, mint_joker_id, Jokers, Stickers};
     use crate::stage::{Blind, Stage};
     use std::collections::HashSet;
 
@@ -543,6 +521,22 @@ mod tests {
         assert_eq!(g.jokers.last().unwrap().rarity(), Rarity::Legendary);
     }
 
+    #[test]
+    fn test_soul_matches_balatro_seed_in_real_mode() {
+        use crate::config::{Config, RngMode};
+        let config = Config {
+            rng_mode: RngMode::Real,
+            seed_str: Some("TEST".to_string()),
+            ..Config::default()
+        };
+        let mut g = Game::new(config);
+        g.stage = Stage::Blind(Blind::Small);
+        Spectral::Soul.apply(&mut g).unwrap();
+        let expected = balatro_seed::Instance::new("TEST").next_joker("sou", 1);
+        assert_eq!(g.jokers[0].name(), expected.name());
+        assert_eq!(g.jokers[0].rarity(), Rarity::Legendary);
+    }
+
     #[test]
     fn test_soul_noop_when_joker_slots_full() {
         let mut g = game_in_blind();
@@ -555,6 +549,7 @@ mod tests {
     fn test_ectoplasm_negatives_exactly_one_joker_and_shrinks_hand_size() {
         let mut g = game_in_blind();
         let available_before = g.config.available;
+        let slots_before = g.config.joker_slots;
         g.jokers = vec![
             minted_joker(Rarity::Common, 0),
             minted_joker(Rarity::Common, 1),
@@ -568,6 +563,45 @@ mod tests {
             .count();
         assert_eq!(negative_count, 1);
         assert_eq!(g.config.available, available_before - 1);
+        assert_eq!(g.config.joker_slots, slots_before + 1);
+
+        // selling it gives the slot back, no more
+        let idx = g
+            .jokers
+            .iter()
+            .position(|j| j.edition() == Edition::Negative)
+            .unwrap();
+        g.sell_joker(idx).unwrap();
+        assert_eq!(g.config.joker_slots, slots_before);
+    }
+
+    #[test]
+    fn test_ectoplasm_only_picks_editionless_jokers() {
+        let mut g = game_in_blind();
+        let mut foil = minted_joker(Rarity::Common, 0);
+        foil.set_edition(Edition::Foil);
+        let plain = minted_joker(Rarity::Common, 1);
+        let slots = g.config.joker_slots;
+        for _ in 0..20 {
+            g.jokers = vec![foil.clone(), plain.clone()];
+            g.config.joker_slots = slots;
+            g.negative_joker_slots = 0;
+            Spectral::Ectoplasm.apply(&mut g).unwrap();
+            assert_eq!(g.jokers[0].edition(), Edition::Foil);
+            assert_eq!(g.jokers[1].edition(), Edition::Negative);
+        }
+    }
+
+    #[test]
+    fn test_ectoplasm_noop_when_every_joker_has_an_edition() {
+        let mut g = game_in_blind();
+        let available_before = g.config.available;
+        let mut foil = minted_joker(Rarity::Common, 0);
+        foil.set_edition(Edition::Foil);
+        g.jokers = vec![foil];
+        Spectral::Ectoplasm.apply(&mut g).unwrap();
+        assert_eq!(g.jokers[0].edition(), Edition::Foil);
+        assert_eq!(g.config.available, available_before);
     }
 
     #[test]
@@ -655,6 +689,28 @@ mod tests {
         assert_eq!(g.jokers[0].edition(), Edition::Polychrome);
     }
 
+    #[test]
+    fn test_hex_and_ankh_spare_eternal_jokers() {
+        let mut eternal = minted_joker(Rarity::Common, 0);
+        eternal.set_stickers(Stickers {
+            eternal: true,
+            ..Default::default()
+        });
+        for spectral in [Spectral::Hex, Spectral::Ankh] {
+            let mut g = game_in_blind();
+            g.jokers = vec![
+                eternal.clone(),
+                minted_joker(Rarity::Common, 1),
+                minted_joker(Rarity::Common, 2),
+            ];
+            spectral.apply(&mut g).unwrap();
+            assert!(g
+                .jokers
+                .iter()
+                .any(|j| j.instance_id() == eternal.instance_id()));
+        }
+    }
+
     #[test]
     fn test_hex_noop_when_no_jokers() {
         let mut g = game_in_blind();
@@ -1,8 +1,9 @@
 use crate::spectral::Spectral;
 use crate::tarot::Tarot;
-pub use balatro_types::Blind;
+pub use balatro_types::{Blind, BossBlind};
 #[cfg(feature = "python")]
 use pyo3::{pyclass, pymethods};
+use strum::IntoEnumIterator;
 
 /// Engine behavior for `Blind`
 pub trait BlindExt {
@@ -38,6 +39,41 @@ pub fn blind_display(blind: &Blind) -> &'static str {
     }
 }
 
+/// Which bosses are left to draw. Mirrors Balatro's `get_new_boss`: of the
+/// bosses eligible at the ante, only the least-drawn are candidates, so none
+/// repeats until every eligible one has come up.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BossPool {
+    drawn: Vec<BossBlind>,
+}
+
+impl BossPool {
+    /// Bosses the next draw at `ante` picks from, in `BossBlind` order.
+    pub fn candidates(&self, ante: i32) -> Vec<BossBlind> {
+        let eligible: Vec<BossBlind> = BossBlind::iter()
+            .filter(|b| b.is_eligible_at(ante))
+            .collect();
+        let least = eligible
+            .iter()
+            .map(|b| self.times_drawn(*b))
+            .min()
+            .unwrap_or(0);
+        eligible
+            .into_iter()
+            .filter(|b| self.times_drawn(*b) == least)
+            .collect()
+    }
+
+    pub fn times_drawn(&self, boss: BossBlind) -> usize {
+        self.drawn.iter().filter(|d| **d == boss).count()
+    }
+
+    pub fn record(&mut self, boss: BossBlind) {
+        self.drawn.push(boss);
+    }
+}
+
 /// Game ending
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass(eq))]
@@ -45,6 +81,15 @@ pub fn blind_display(blind: &Blind) -> &'static str {
 pub enum End {
     Win,
     Lose,
+    /// Beat the final ante, then lost a blind later in endless mode.
+    EndlessLose,
+}
+
+impl End {
+    /// Whether the run was won, endless runs count once past the final ante.
+    pub fn is_win(&self) -> bool {
+        matches!(self, Self::Win | Self::EndlessLose)
+    }
 }
 
 /// Stages of playing.
@@ -80,12 +125,12 @@ impl Stage {
     pub(crate) fn is_pack_open(&self) -> bool {
         matches!(self, Stage::PackOpen())
     }
-}
 
-#[cfg(feature = "python")]
-#[pymethods]
-impl Stage {
-    fn int(&self) -> usize {
+    /// Largest value `index` returns.
+    pub const MAX_INDEX: usize = 11;
+
+    /// Stable integer id for the stage, what python sees as `Stage.int()`.
+    pub fn index(&self) -> usize {
         match self {
             Self::PreBlind() => 0,
             Self::Blind(blind) => match blind {
@@ -98,6 +143,7 @@ impl Stage {
             Self::End(end) => match end {
                 End::Win => 6,
                 End::Lose => 7,
+                End::EndlessLose => 11,
             },
             Self::TarotHand(_) => 8,
             Self::SpectralHand(_) => 9,
@@ -106,6 +152,14 @@ impl Stage {
     }
 }
 
+#[cfg(feature = "python")]
+#[pymethods]
+impl Stage {
+    fn int(&self) -> usize {
+        self.index()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +185,43 @@ mod tests {
         assert_eq!(blind_display(&Blind::Boss), "Boss Blind");
     }
 
+    #[test]
+    fn test_boss_pool_cycles_without_repeats() {
+        let mut pool = BossPool::default();
+        let mut seen = Vec::new();
+        for _ in 0..8 {
+            let boss = pool.candidates(1)[0];
+            assert!(!seen.contains(&boss), "{boss:?} repeated");
+            seen.push(boss);
+            pool.record(boss);
+        }
+        // Ante 1's eight are used up: the cycle starts over.
+        assert_eq!(pool.candidates(1).len(), 8);
+        // Newly eligible bosses haven't been drawn yet, so they go first.
+        assert!(pool.candidates(2).iter().all(|b| b.min_ante() == 2));
+        assert_eq!(pool.candidates(8).len(), 5);
+    }
+
+    #[test]
+    fn test_stage_max_index_is_the_largest_index() {
+        let stages = [
+            Stage::PreBlind(),
+            Stage::Blind(Blind::Small),
+            Stage::Blind(Blind::Big),
+            Stage::Blind(Blind::Boss),
+            Stage::PostBlind(),
+            Stage::Shop(),
+            Stage::End(End::Win),
+            Stage::End(End::Lose),
+            Stage::End(End::EndlessLose),
+            Stage::TarotHand(Tarot::Fool),
+            Stage::SpectralHand(Spectral::Familiar),
+            Stage::PackOpen(),
+        ];
+        let max = stages.iter().map(|s| s.index()).max();
+        assert_eq!(max, Some(Stage::MAX_INDEX));
+    }
+
     #[test]
     fn test_stage_is_blind_and_is_pack_open() {
         assert!(Stage::Blind(Blind::Small).is_blind());
@@ -1 +1,46 @@
 pub use balatro_types::Tag;
+
+/// When a held tag fires. Tags wait in `Game::tags` until their moment
+/// comes around, or until they're able to take effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TagTrigger {
+    /// As soon as the tag is gained.
+    Immediate,
+    /// On entering the next shop.
+    Shop,
+    /// When the next blind is selected.
+    RoundStart,
+    /// After the next Boss Blind is defeated.
+    BossDefeated,
+    /// When the next tag is gained (Double only).
+    NextTag,
+}
+
+pub(crate) fn tag_trigger(tag: Tag) -> TagTrigger {
+    match tag {
+        Tag::Uncommon
+        | Tag::Rare
+        | Tag::Negative
+        | Tag::Foil
+        | Tag::Holographic
+        | Tag::Polychrome
+        | Tag::Voucher
+        | Tag::Coupon
+        | Tag::D6 => TagTrigger::Shop,
+        Tag::Boss
+        | Tag::Standard
+        | Tag::Charm
+        | Tag::Meteor
+        | Tag::Buffoon
+        | Tag::Ethereal
+        | Tag::Handy
+        | Tag::Garbage
+        | Tag::TopUp
+        | Tag::Speed
+        | Tag::Orbital(_)
+        | Tag::Economy => TagTrigger::Immediate,
+        Tag::Juggle => TagTrigger::RoundStart,
+        Tag::Investment => TagTrigger::BossDefeated,
+        Tag::Double => TagTrigger::NextTag,
+    }
+}
@@ -115,74 +115,38 @@ impl TarotEffect for Tarot {
                 });
             }
             Self::Hermit => {
-                let gain = game.money.min(20);
-                game.money += gain;
+                let gain = game.money.clamp(0, 20) as usize;
+                game.earn(gain);
             }
             Self::Temperance => {
                 let total: usize = game.jokers.iter().map(|j| j.sell_value()).sum();
-                game.money += total.min(50);
+                game.earn(total.min(50));
             }
             Self::WheelOfFortune => {
-                if !game.jokers.is_empty() && game.prob_roll(1, 4) {
-                    let picked = game.backend.pick_random_joker(game.jokers.clone());
-                    let edition = game.backend.roll_random_edition();
-                    if let Some(j) = game
-                        .jokers
-                        .iter_mut()
-                        .find(|j| j.instance_id() == picked.instance_id())
-                    {
-                        j.set_edition(edition);
-                    }
+                let editionless = game.editionless_jokers();
+                if !editionless.is_empty() && game.prob_roll("wheel_of_fortune", 1, 4) {
+                    let picked = game
+                        .backend
+                        .pick_random_joker(editionless, "wheel_of_fortune");
+                    let edition = game.backend.roll_random_edition("wheel_of_fortune");
+                    game.set_joker_edition(picked.instance_id(), edition);
                 }
             }
             Self::HighPriestess => {
-                let slots = game.config.consumable_slots;
                 for _ in 0..2 {
-                    if game.consumables.len() >= slots {
-                        break;
-                    }
-                    let planetarium = game.planetarium.clone();
-                    let planet = game.backend.roll_random_planet(&planetarium, &[]);
-                    game.consumables.push(planet);
+                    game.create_planet("pri");
                 }
             }
             Self::Emperor => {
-                let slots = game.config.consumable_slots;
-                let mut excl: Vec<Tarot> = game
-                    .consumables
-                    .iter()
-                    .filter_map(|c| {
-                        if let crate::consumable::Consumable::Tarot(t) = c {
-                            Some(*t)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
                 for _ in 0..2 {
-                    if game.consumables.len() >= slots {
-                        break;
-                    }
-                    let tarot = game.backend.roll_random_tarot(&excl);
-                    if let crate::consumable::Consumable::Tarot(t) = &tarot {
-                        excl.push(*t);
-                    }
-                    game.consumables.push(tarot);
-                }
-            }
-            Self::Judgement => {
-                if game.jokers.len() < game.config.joker_slots {
-                    let prob_mult = game.prob_mult;
-                    let exclude = game.jokers.clone();
-                    let ante = game.ante_current as i32;
-                    let joker = game.backend.gen_joker(ante, prob_mult, &exclude);
-                    game.jokers.push(joker);
+                    game.create_tarot("emp");
                 }
             }
+            Self::Judgement => game.create_joker("jud", None),
             Self::Fool => {
                 if let Some(last) = game.last_consumable_used {
                     if game.consumables.len() < game.config.consumable_slots {
-                        game.consumables.push(last);
+                        game.consumables.push(last.into());
                     }
                 }
             }
@@ -442,7 +406,13 @@ mod tests {
             g.jokers[0].set_edition(Edition::Base);
         }
 
-        assert!(saw_base, "1-in-4 gate should sometimes not fire in 200 tries");
-        assert!(saw_edition, "1-in-4 gate should sometimes fire in 200 tries");
+        assert!(
+            saw_base,
+            "1-in-4 gate should sometimes not fire in 200 tries"
+        );
+        assert!(
+            saw_edition,
+            "1-in-4 gate should sometimes fire in 200 tries"
+        );
     }
 }
@@ -0,0 +1 @@
+pub use balatro_types::Voucher;
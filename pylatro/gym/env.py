@@ -4,6 +4,17 @@ import pylatro
 from typing import Optional
 import numpy as np
 
+# Upper bound of the score/target observations
+SCORE_MAX = 100_000
+
+
+def _clamp_score(value):
+    # Scores are floats that can overflow to inf (or nan), which int() can't
+    # convert; pin them inside the Discrete space.
+    if value != value:
+        return 0
+    return int(min(max(value, 0), SCORE_MAX - 1))
+
 
 class BalatroEnv(gym.Env):
     def __init__(self):
@@ -22,8 +33,8 @@ class BalatroEnv(gym.Env):
         self.action_space = spaces.Discrete(len(self._game.gen_action_space()))
         self.observation_space = gym.spaces.Dict(
             {
-                "score": gym.spaces.Discrete(100_000),
-                "target": gym.spaces.Discrete(100_000),
+                "score": gym.spaces.Discrete(SCORE_MAX),
+                "target": gym.spaces.Discrete(SCORE_MAX),
                 "stage": gym.spaces.Discrete(config.stage_max + 1),
                 "round": gym.spaces.Discrete(config.ante_end + 1),
                 "plays": gym.spaces.Discrete(config.plays + 1),
@@ -48,7 +59,7 @@ class BalatroEnv(gym.Env):
             "round": state.round,
             "plays": state.plays,
             "discards": state.discards,
-            "money": state.money,
+            "money": max(state.money, 0),
             "deck_len": len(state.deck),
             "selected_len": len(state.selected),
             "available_len": len(state.available),
@@ -72,8 +83,8 @@ class BalatroEnv(gym.Env):
         truncated = terminated
 
         self._last_score = self._score
-        self._score = self._game.state.score
-        self._target_score = self._game.state.required_score
+        self._score = _clamp_score(self._game.state.score)
+        self._target_score = _clamp_score(self._game.state.required_score)
 
         if self._score > self._high_score:
             self._high_score = self._score
@@ -1,10 +1,14 @@
-use balatro_rs::action::Action;
+use balatro_rs::action::{Action, MoveDirection};
 use balatro_rs::card::Card;
 use balatro_rs::config::Config;
+use balatro_rs::consumable::Consumable;
+use balatro_rs::deck::DeckVariant;
 use balatro_rs::error::GameError;
 use balatro_rs::game::Game;
 use balatro_rs::joker::Jokers;
-use balatro_rs::stage::{End, Stage};
+use balatro_rs::stage::Stage;
+use balatro_rs::stake::Stake;
+use balatro_rs::voucher::Voucher;
 use pyo3::prelude::*;
 
 #[pyclass]
@@ -50,7 +54,7 @@ impl GameEngine {
     }
     #[getter]
     fn is_win(&self) -> bool {
-        self.game.result() == Some(End::Win)
+        self.game.result().is_some_and(|end| end.is_win())
     }
 }
 
@@ -99,21 +103,39 @@ impl GameState {
     }
 
     #[getter]
-    fn score(&self) -> usize {
-        self.game.score
+    fn score(&self) -> f64 {
+        self.game.score.to_f64()
     }
     #[getter]
-    fn required_score(&self) -> usize {
-        self.game.required_score()
+    fn required_score(&self) -> f64 {
+        self.game.required_score().to_f64()
+    }
+    /// Pending cash out as (source, dollars) pairs, e.g. ("Interest", 3).
+    #[getter]
+    fn round_reward(&self) -> Vec<(String, usize)> {
+        self.game
+            .round_reward
+            .lines
+            .iter()
+            .map(|l| (l.describe(), l.amount))
+            .collect()
     }
     #[getter]
     fn jokers(&self) -> Vec<Jokers> {
         self.game.jokers.clone()
     }
     #[getter]
-    fn money(&self) -> usize {
+    fn consumables(&self) -> Vec<Consumable> {
+        self.game.held_consumables()
+    }
+    #[getter]
+    fn money(&self) -> i64 {
         self.game.money
     }
+    #[getter]
+    fn vouchers(&self) -> Vec<Voucher> {
+        self.game.vouchers.clone()
+    }
 
     #[getter]
     fn seed(&self) -> u64 {
@@ -138,9 +160,13 @@ fn seed_from_str(s: &str) -> u64 {
 #[pymodule]
 fn pylatro(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Config>()?;
+    m.add_class::<DeckVariant>()?;
+    m.add_class::<Stake>()?;
     m.add_class::<GameEngine>()?;
     m.add_class::<GameState>()?;
     m.add_class::<Stage>()?;
+    m.add_class::<Action>()?;
+    m.add_class::<MoveDirection>()?;
     m.add_function(wrap_pyfunction!(seed_from_str, m)?)?;
     Ok(())
 }
@@ -14,6 +14,8 @@ pub enum WidgetId {
     ShopJoker(usize),
     ShopConsumable(usize),
     ShopPack(usize),
+    ShopVoucher(usize),
+    ShopPlayingCard(usize),
     PackContent(usize),
     SkipPackButton,
     BlindOption(usize),
@@ -64,6 +66,8 @@ pub enum ShopSlot {
     Joker(usize),
     Consumable(usize),
     Pack(usize),
+    Voucher(usize),
+    PlayingCard(usize),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1,7 +1,7 @@
 use crate::app::{AppState, DeckTab, FocusZone, Overlay, RunInfoTab, ShopSlot};
-use balatro_rs::action::{Action, SortBy};
+use balatro_rs::action::{Action, MoveDirection, SortBy};
 use balatro_rs::stage::Stage;
-use crossterm::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 
 pub fn handle_key(app: &mut AppState, key: KeyEvent) {
     if key.code == KeyCode::Char('c')
@@ -55,18 +55,14 @@ fn handle_key_overlay(app: &mut AppState, key: KeyEvent, overlay: Overlay) {
             }
         }
         Overlay::Consumable(idx) => match key.code {
-            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => {
-                if app.overlay_cursor > 0 {
-                    app.overlay_cursor -= 1;
-                }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab if app.overlay_cursor > 0 => {
+                app.overlay_cursor -= 1;
             }
-            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
-                if app.overlay_cursor < 1 {
-                    app.overlay_cursor += 1;
-                }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab if app.overlay_cursor < 1 => {
+                app.overlay_cursor += 1;
             }
             KeyCode::Char('u') => {
-                if let Some(c) = app.game.consumables.get(idx).cloned() {
+                if let Some(c) = app.game.consumables.get(idx).map(|h| h.consumable) {
                     let prev = app.game.stage;
                     if app.game.handle_action(Action::UseConsumable(c)).is_ok() {
                         app.close_overlay();
@@ -78,7 +74,7 @@ fn handle_key_overlay(app: &mut AppState, key: KeyEvent, overlay: Overlay) {
             }
             KeyCode::Enter => match app.overlay_cursor {
                 0 => {
-                    if let Some(c) = app.game.consumables.get(idx).cloned() {
+                    if let Some(c) = app.game.consumables.get(idx).map(|h| h.consumable) {
                         let prev = app.game.stage;
                         if app.game.handle_action(Action::UseConsumable(c)).is_ok() {
                             app.close_overlay();
@@ -88,10 +84,8 @@ fn handle_key_overlay(app: &mut AppState, key: KeyEvent, overlay: Overlay) {
                         }
                     }
                 }
-                1 => {
-                    if app.game.handle_action(Action::SellConsumable(idx)).is_ok() {
-                        app.close_overlay();
-                    }
+                1 if app.game.handle_action(Action::SellConsumable(idx)).is_ok() => {
+                    app.close_overlay();
                 }
                 _ => {}
             },
@@ -99,15 +93,11 @@ fn handle_key_overlay(app: &mut AppState, key: KeyEvent, overlay: Overlay) {
             _ => {}
         },
         Overlay::Joker(idx) => match key.code {
-            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => {
-                if app.overlay_cursor > 0 {
-                    app.overlay_cursor -= 1;
-                }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab if app.overlay_cursor > 0 => {
+                app.overlay_cursor -= 1;
             }
-            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
-                if app.overlay_cursor < 1 {
-                    app.overlay_cursor += 1;
-                }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab if app.overlay_cursor < 1 => {
+                app.overlay_cursor += 1;
             }
             KeyCode::Enter => match app.overlay_cursor {
                 0 => {
@@ -124,15 +114,11 @@ fn handle_key_overlay(app: &mut AppState, key: KeyEvent, overlay: Overlay) {
             _ => {}
         },
         Overlay::ShopBuy(slot) => match key.code {
-            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => {
-                if app.overlay_cursor > 0 {
-                    app.overlay_cursor -= 1;
-                }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab if app.overlay_cursor > 0 => {
+                app.overlay_cursor -= 1;
             }
-            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
-                if app.overlay_cursor < 1 {
-                    app.overlay_cursor += 1;
-                }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab if app.overlay_cursor < 1 => {
+                app.overlay_cursor += 1;
             }
             KeyCode::Enter => match app.overlay_cursor {
                 0 => {
@@ -161,15 +147,11 @@ fn handle_key_overlay(app: &mut AppState, key: KeyEvent, overlay: Overlay) {
             _ => {}
         },
         Overlay::PackPick(idx) => match key.code {
-            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => {
-                if app.overlay_cursor > 0 {
-                    app.overlay_cursor -= 1;
-                }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab if app.overlay_cursor > 0 => {
+                app.overlay_cursor -= 1;
             }
-            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
-                if app.overlay_cursor < 1 {
-                    app.overlay_cursor += 1;
-                }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab if app.overlay_cursor < 1 => {
+                app.overlay_cursor += 1;
             }
             KeyCode::Enter => match app.overlay_cursor {
                 0 => {
@@ -202,7 +184,9 @@ fn execute_pack_pick(app: &mut AppState, idx: usize) -> bool {
     else {
         return false;
     };
-    app.game.handle_action(Action::PickPackCard(content)).is_ok()
+    app.game
+        .handle_action(Action::PickPackCard(content))
+        .is_ok()
 }
 
 /// Attempts the shop purchase for `slot`, returning whether it succeeded
@@ -222,6 +206,14 @@ fn execute_shop_buy(app: &mut AppState, slot: ShopSlot) -> bool {
             Some(pack) => app.game.handle_action(Action::BuyPack(pack)).is_ok(),
             None => false,
         },
+        ShopSlot::Voucher(idx) => match app.game.shop.vouchers.get(idx).copied() {
+            Some(v) => app.game.handle_action(Action::BuyVoucher(v)).is_ok(),
+            None => false,
+        },
+        ShopSlot::PlayingCard(idx) => match app.game.shop.playing_cards.get(idx).copied() {
+            Some(card) => app.game.handle_action(Action::BuyPlayingCard(card)).is_ok(),
+            None => false,
+        },
     }
 }
 
@@ -374,15 +366,11 @@ fn handle_key_blind(app: &mut AppState, key: KeyEvent) {
 fn handle_key_blind_cards(app: &mut AppState, key: KeyEvent) {
     let card_count = app.game.available.cards().len();
     match key.code {
-        KeyCode::Left => {
-            if app.cursor > 0 {
-                app.cursor -= 1;
-            }
+        KeyCode::Left if app.cursor > 0 => {
+            app.cursor -= 1;
         }
-        KeyCode::Right => {
-            if app.cursor + 1 < card_count {
-                app.cursor += 1;
-            }
+        KeyCode::Right if app.cursor + 1 < card_count => {
+            app.cursor += 1;
         }
         KeyCode::Enter | KeyCode::Char(' ') => toggle_card(app),
         _ => {}
@@ -391,15 +379,11 @@ fn handle_key_blind_cards(app: &mut AppState, key: KeyEvent) {
 
 fn handle_key_blind_buttons(app: &mut AppState, key: KeyEvent) {
     match key.code {
-        KeyCode::Left => {
-            if app.cursor > 0 {
-                app.cursor -= 1;
-            }
+        KeyCode::Left if app.cursor > 0 => {
+            app.cursor -= 1;
         }
-        KeyCode::Right => {
-            if app.cursor < 3 {
-                app.cursor += 1;
-            }
+        KeyCode::Right if app.cursor < 3 => {
+            app.cursor += 1;
         }
         KeyCode::Enter => match app.cursor {
             0 => {
@@ -420,47 +404,74 @@ fn handle_key_blind_buttons(app: &mut AppState, key: KeyEvent) {
     }
 }
 
+// Shift+Left/Right drags the item under the cursor along its strip
+fn strip_move(key: KeyEvent) -> Option<MoveDirection> {
+    if !key.modifiers.contains(KeyModifiers::SHIFT) {
+        return None;
+    }
+    match key.code {
+        KeyCode::Left => Some(MoveDirection::Left),
+        KeyCode::Right => Some(MoveDirection::Right),
+        _ => None,
+    }
+}
+
+fn follow_move(app: &mut AppState, dir: MoveDirection) {
+    match dir {
+        MoveDirection::Left => app.cursor -= 1,
+        MoveDirection::Right => app.cursor += 1,
+    }
+}
+
 fn handle_key_joker_strip(app: &mut AppState, key: KeyEvent) {
+    if let Some(dir) = strip_move(key) {
+        if app
+            .game
+            .handle_action(Action::MoveJoker(dir.clone(), app.cursor))
+            .is_ok()
+        {
+            follow_move(app, dir);
+        }
+        return;
+    }
     let joker_count = app.game.jokers.len();
     match key.code {
-        KeyCode::Left => {
-            if app.cursor > 0 {
-                app.cursor -= 1;
-            }
+        KeyCode::Left if app.cursor > 0 => {
+            app.cursor -= 1;
         }
-        KeyCode::Right => {
-            if joker_count > 0 && app.cursor + 1 < joker_count {
-                app.cursor += 1;
-            }
+        KeyCode::Right if joker_count > 0 && app.cursor + 1 < joker_count => {
+            app.cursor += 1;
         }
-        KeyCode::Enter => {
-            if app.cursor < joker_count {
-                app.overlay = Some(Overlay::Joker(app.cursor));
-                app.overlay_cursor = 1;
-            }
+        KeyCode::Enter if app.cursor < joker_count => {
+            app.overlay = Some(Overlay::Joker(app.cursor));
+            app.overlay_cursor = 1;
         }
         _ => {}
     }
 }
 
 fn handle_key_consumable_strip(app: &mut AppState, key: KeyEvent) {
+    if let Some(dir) = strip_move(key) {
+        if app
+            .game
+            .handle_action(Action::MoveConsumable(dir.clone(), app.cursor))
+            .is_ok()
+        {
+            follow_move(app, dir);
+        }
+        return;
+    }
     let count = app.game.consumables.len();
     match key.code {
-        KeyCode::Left => {
-            if app.cursor > 0 {
-                app.cursor -= 1;
-            }
+        KeyCode::Left if app.cursor > 0 => {
+            app.cursor -= 1;
         }
-        KeyCode::Right => {
-            if count > 0 && app.cursor + 1 < count {
-                app.cursor += 1;
-            }
+        KeyCode::Right if count > 0 && app.cursor + 1 < count => {
+            app.cursor += 1;
         }
-        KeyCode::Enter => {
-            if app.cursor < count {
-                app.overlay = Some(Overlay::Consumable(app.cursor));
-                app.overlay_cursor = 0;
-            }
+        KeyCode::Enter if app.cursor < count => {
+            app.overlay = Some(Overlay::Consumable(app.cursor));
+            app.overlay_cursor = 0;
         }
         _ => {}
     }
@@ -468,11 +479,8 @@ fn handle_key_consumable_strip(app: &mut AppState, key: KeyEvent) {
 
 fn handle_key_postblind(app: &mut AppState, key: KeyEvent) {
     match &app.focus {
-        FocusZone::CashOutButton => {
-            if key.code == KeyCode::Enter {
-                let reward = compute_cashout(app);
-                let _ = app.game.handle_action(Action::CashOut(reward));
-            }
+        FocusZone::CashOutButton if key.code == KeyCode::Enter => {
+            let _ = app.game.handle_action(Action::CashOut());
         }
         FocusZone::JokerStrip => handle_key_joker_strip(app, key),
         FocusZone::ConsumableStrip => handle_key_consumable_strip(app, key),
@@ -480,23 +488,15 @@ fn handle_key_postblind(app: &mut AppState, key: KeyEvent) {
     }
 }
 
-pub fn compute_cashout(app: &AppState) -> usize {
-    app.game.reward
-}
-
 fn handle_key_shop(app: &mut AppState, key: KeyEvent) {
     match &app.focus {
         FocusZone::ShopJokers => handle_key_shop_jokers(app, key),
         FocusZone::ShopPacks => handle_key_shop_packs(app, key),
-        FocusZone::ShopReroll => {
-            if key.code == KeyCode::Enter || key.code == KeyCode::Char(' ') {
-                let _ = app.game.handle_action(Action::Reroll());
-            }
+        FocusZone::ShopReroll if (key.code == KeyCode::Enter || key.code == KeyCode::Char(' ')) => {
+            let _ = app.game.handle_action(Action::Reroll());
         }
-        FocusZone::ShopNextRound => {
-            if key.code == KeyCode::Enter {
-                let _ = app.game.handle_action(Action::NextRound());
-            }
+        FocusZone::ShopNextRound if key.code == KeyCode::Enter => {
+            let _ = app.game.handle_action(Action::NextRound());
         }
         FocusZone::JokerStrip => handle_key_joker_strip(app, key),
         FocusZone::ConsumableStrip => handle_key_consumable_strip(app, key),
@@ -509,23 +509,22 @@ fn handle_key_shop(app: &mut AppState, key: KeyEvent) {
 }
 
 fn handle_key_shop_packs(app: &mut AppState, key: KeyEvent) {
-    let count = app.game.shop.packs.len();
+    let pack_count = app.game.shop.packs.len();
+    let count = pack_count + app.game.shop.vouchers.len();
     match key.code {
-        KeyCode::Left => {
-            if app.cursor > 0 {
-                app.cursor -= 1;
-            }
+        KeyCode::Left if app.cursor > 0 => {
+            app.cursor -= 1;
         }
-        KeyCode::Right => {
-            if count > 0 && app.cursor + 1 < count {
-                app.cursor += 1;
-            }
+        KeyCode::Right if count > 0 && app.cursor + 1 < count => {
+            app.cursor += 1;
         }
-        KeyCode::Enter => {
-            if app.cursor < count {
-                app.overlay = Some(Overlay::ShopBuy(ShopSlot::Pack(app.cursor)));
-                app.overlay_cursor = 0;
-            }
+        KeyCode::Enter if app.cursor < pack_count => {
+            app.overlay = Some(Overlay::ShopBuy(ShopSlot::Pack(app.cursor)));
+            app.overlay_cursor = 0;
+        }
+        KeyCode::Enter if app.cursor < count => {
+            app.overlay = Some(Overlay::ShopBuy(ShopSlot::Voucher(app.cursor - pack_count)));
+            app.overlay_cursor = 0;
         }
         _ => {}
     }
@@ -554,21 +553,15 @@ fn handle_key_pack_contents(app: &mut AppState, key: KeyEvent) {
         .map(|s| s.contents.len())
         .unwrap_or(0);
     match key.code {
-        KeyCode::Left => {
-            if app.cursor > 0 {
-                app.cursor -= 1;
-            }
+        KeyCode::Left if app.cursor > 0 => {
+            app.cursor -= 1;
         }
-        KeyCode::Right => {
-            if count > 0 && app.cursor + 1 < count {
-                app.cursor += 1;
-            }
+        KeyCode::Right if count > 0 && app.cursor + 1 < count => {
+            app.cursor += 1;
         }
-        KeyCode::Enter | KeyCode::Char(' ') => {
-            if app.cursor < count {
-                app.overlay = Some(Overlay::PackPick(app.cursor));
-                app.overlay_cursor = 0;
-            }
+        KeyCode::Enter | KeyCode::Char(' ') if app.cursor < count => {
+            app.overlay = Some(Overlay::PackPick(app.cursor));
+            app.overlay_cursor = 0;
         }
         _ => {}
     }
@@ -577,27 +570,29 @@ fn handle_key_pack_contents(app: &mut AppState, key: KeyEvent) {
 fn handle_key_shop_jokers(app: &mut AppState, key: KeyEvent) {
     let joker_count = app.game.shop.jokers.len();
     let consumable_count = app.game.shop.consumables.len();
-    let count = joker_count + consumable_count;
+    let card_start = joker_count + consumable_count;
+    let count = card_start + app.game.shop.playing_cards.len();
     match key.code {
-        KeyCode::Left => {
-            if app.cursor > 0 {
-                app.cursor -= 1;
-            }
+        KeyCode::Left if app.cursor > 0 => {
+            app.cursor -= 1;
         }
-        KeyCode::Right => {
-            if count > 0 && app.cursor + 1 < count {
-                app.cursor += 1;
-            }
+        KeyCode::Right if count > 0 && app.cursor + 1 < count => {
+            app.cursor += 1;
         }
         KeyCode::Enter => {
             if app.cursor < joker_count {
                 app.overlay = Some(Overlay::ShopBuy(ShopSlot::Joker(app.cursor)));
                 app.overlay_cursor = 0;
-            } else if app.cursor < count {
+            } else if app.cursor < card_start {
                 app.overlay = Some(Overlay::ShopBuy(ShopSlot::Consumable(
                     app.cursor - joker_count,
                 )));
                 app.overlay_cursor = 0;
+            } else if app.cursor < count {
+                app.overlay = Some(Overlay::ShopBuy(ShopSlot::PlayingCard(
+                    app.cursor - card_start,
+                )));
+                app.overlay_cursor = 0;
             }
         }
         _ => {}
@@ -620,15 +615,11 @@ fn handle_key_tarot(app: &mut AppState, key: KeyEvent) {
 fn handle_key_tarot_cards(app: &mut AppState, key: KeyEvent) {
     let card_count = app.game.available.cards().len();
     match key.code {
-        KeyCode::Left => {
-            if app.cursor > 0 {
-                app.cursor -= 1;
-            }
+        KeyCode::Left if app.cursor > 0 => {
+            app.cursor -= 1;
         }
-        KeyCode::Right => {
-            if app.cursor + 1 < card_count {
-                app.cursor += 1;
-            }
+        KeyCode::Right if app.cursor + 1 < card_count => {
+            app.cursor += 1;
         }
         KeyCode::Enter | KeyCode::Char(' ') => toggle_card(app),
         _ => {}
@@ -678,18 +669,20 @@ fn open_inspect(app: &mut AppState) {
     match &app.focus {
         FocusZone::Cards | FocusZone::TarotCards => {
             let cards = app.game.available.cards();
-            if let Some(card) = cards.get(app.cursor) {
+            // face-down cards stay hidden
+            if let Some(card) = cards.get(app.cursor).filter(|c| !c.face_down) {
                 app.overlay = Some(Overlay::Inspect(InspectTarget::Card(*card)));
             }
         }
         FocusZone::JokerStrip => {
-            if let Some(joker) = app.game.jokers.get(app.cursor) {
+            let hidden = app.game.jokers_face_down();
+            if let Some(joker) = app.game.jokers.get(app.cursor).filter(|_| !hidden) {
                 app.overlay = Some(Overlay::Inspect(InspectTarget::Joker(joker.clone())));
             }
         }
         FocusZone::ConsumableStrip => {
             if let Some(c) = app.game.consumables.get(app.cursor) {
-                app.overlay = Some(Overlay::Inspect(InspectTarget::Consumable(*c)));
+                app.overlay = Some(Overlay::Inspect(InspectTarget::Consumable(c.consumable)));
             }
         }
         FocusZone::ShopJokers => {
@@ -698,10 +691,12 @@ fn open_inspect(app: &mut AppState) {
                 if let Some(joker) = app.game.shop.jokers.get(app.cursor) {
                     app.overlay = Some(Overlay::Inspect(InspectTarget::Joker(joker.clone())));
                 }
+            } else if let Some(c) = app.game.shop.consumables.get(app.cursor - joker_count) {
+                app.overlay = Some(Overlay::Inspect(InspectTarget::Consumable(*c)));
             } else {
-                let ci = app.cursor - joker_count;
-                if let Some(c) = app.game.shop.consumables.get(ci) {
-                    app.overlay = Some(Overlay::Inspect(InspectTarget::Consumable(*c)));
+                let pi = app.cursor - joker_count - app.game.shop.consumables.len();
+                if let Some(card) = app.game.shop.playing_cards.get(pi) {
+                    app.overlay = Some(Overlay::Inspect(InspectTarget::Card(*card)));
                 }
             }
         }
@@ -830,6 +825,22 @@ fn dispatch_mouse_click(app: &mut AppState, id: crate::app::WidgetId) {
                 app.overlay_cursor = 0;
             }
         }
+        ShopVoucher(idx) => {
+            app.focus = FocusZone::ShopPacks;
+            app.cursor = app.game.shop.packs.len() + idx;
+            if idx < app.game.shop.vouchers.len() {
+                app.overlay = Some(Overlay::ShopBuy(ShopSlot::Voucher(idx)));
+                app.overlay_cursor = 0;
+            }
+        }
+        ShopPlayingCard(idx) => {
+            app.focus = FocusZone::ShopJokers;
+            app.cursor = app.game.shop.jokers.len() + app.game.shop.consumables.len() + idx;
+            if idx < app.game.shop.playing_cards.len() {
+                app.overlay = Some(Overlay::ShopBuy(ShopSlot::PlayingCard(idx)));
+                app.overlay_cursor = 0;
+            }
+        }
         PackContent(idx) => {
             app.focus = FocusZone::PackContents;
             app.cursor = idx;
@@ -871,8 +882,7 @@ fn dispatch_mouse_click(app: &mut AppState, id: crate::app::WidgetId) {
             if matches!(app.game.stage, Stage::End(_)) {
                 app.should_quit = true;
             } else {
-                let reward = compute_cashout(app);
-                let _ = app.game.handle_action(Action::CashOut(reward));
+                let _ = app.game.handle_action(Action::CashOut());
             }
         }
         NextRoundButton => {
@@ -888,7 +898,7 @@ fn dispatch_mouse_click(app: &mut AppState, id: crate::app::WidgetId) {
         SpectralButton(_) => {}
         OverlayButton(0) => match app.overlay.clone() {
             Some(crate::app::Overlay::Consumable(idx)) => {
-                if let Some(c) = app.game.consumables.get(idx).cloned() {
+                if let Some(c) = app.game.consumables.get(idx).map(|h| h.consumable) {
                     let prev = app.game.stage;
                     if app.game.handle_action(Action::UseConsumable(c)).is_ok() {
                         app.close_overlay();
@@ -4,7 +4,7 @@ mod ui;
 
 use anyhow::Result;
 use app::AppState;
-use balatro_rs::{config::Config, game::Game};
+use balatro_rs::{config::Config, deck::DeckVariant, game::Game, stake::Stake};
 use clap::Parser;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event},
@@ -21,6 +21,15 @@ struct Args {
     load: Option<String>,
     #[arg(long, value_name = "SEED")]
     seed: Option<String>,
+    /// Starting deck, e.g. `plasma` or `b_plasma`
+    #[arg(long, value_name = "DECK")]
+    deck: Option<String>,
+    /// Stake, by save-file number (1 = White .. 8 = Gold)
+    #[arg(long, value_name = "STAKE")]
+    stake: Option<u8>,
+    /// Keep playing past the final ante until a blind is lost
+    #[arg(long)]
+    endless: bool,
 }
 
 fn main() -> Result<()> {
@@ -40,6 +49,21 @@ fn main() -> Result<()> {
                     Err(_) => config.seed_str = Some(s),
                 },
             }
+            if let Some(d) = args.deck {
+                let id = d.to_lowercase();
+                let id = id.strip_prefix("b_").unwrap_or(&id);
+                match DeckVariant::from_id(&format!("b_{id}")) {
+                    Some(deck) => config.deck = Some(deck),
+                    None => anyhow::bail!("unknown deck: {d}"),
+                }
+            }
+            if let Some(n) = args.stake {
+                match Stake::from_id(n) {
+                    Some(stake) => config.stake = stake,
+                    None => anyhow::bail!("unknown stake: {n}"),
+                }
+            }
+            config.endless = args.endless;
             let mut g = Game::new(config);
             g.start();
             g
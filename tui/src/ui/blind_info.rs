@@ -1,15 +1,16 @@
 use balatro_rs::game::Game;
+use balatro_rs::score::BigNum;
 use balatro_rs::stage::Blind;
 
 /// The score a given blind will require - unlike `Game::required_score()`,
 /// doesn't depend on `self.blind` already being set to this blind, so it
 /// can be computed for all three (including the as-yet-unselected Boss) at
 /// any point in the run, not just while it's the active blind.
-pub fn required_score(game: &Game, blind: Blind) -> usize {
-    let base = game.ante_current.base();
+pub fn required_score(game: &Game, blind: Blind) -> BigNum {
+    let base = game.blind_base();
     match blind {
         Blind::Small => base,
-        Blind::Big => (base as f32 * 1.5) as usize,
+        Blind::Big => base * 1.5,
         Blind::Boss => game.boss_required_score(),
     }
 }
@@ -100,7 +100,7 @@ fn card_inner_text(card: Card, debuffed: bool) -> Text<'static> {
     // Interior: 8 wide, 5 tall.
     // Row 0: seal indicator (top-right) or blank
     // Row 1: rank + suit centered
-    // Row 2: blank
+    // Row 2: permanent bonus chips (Hiker) or blank
     // Row 3: enhancement indicator (bottom-left) or blank
     // Row 4: blank
 
@@ -134,6 +134,20 @@ fn card_inner_text(card: Card, debuffed: bool) -> Text<'static> {
         ])
     };
 
+    let row2 = if card.bonus_chips > 0 {
+        Line::from(vec![
+            Span::raw(" "),
+            Span::styled(
+                format!("+{}", card.bonus_chips),
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])
+    } else {
+        Line::from("")
+    };
+
     let row3 = match card.enhancement {
         Some(enh) if !is_stone => {
             let (label, color) = enhancement_indicator(enh);
@@ -161,7 +175,7 @@ fn card_inner_text(card: Card, debuffed: bool) -> Text<'static> {
         Line::from("")
     };
 
-    let content = vec![row0, row1, Line::from(""), row3, row4];
+    let content = vec![row0, row1, row2, row3, row4];
     // Center the fixed 5-row content within however tall the interior actually
     // is, rather than hardcoding a row count that goes stale if CARD_H changes.
     let pad = (CARD_H as usize).saturating_sub(2).saturating_sub(content.len());
@@ -175,6 +189,17 @@ fn card_inner_text(card: Card, debuffed: bool) -> Text<'static> {
     Text::from(lines)
 }
 
+// A face-down card shows only its back, filling the interior.
+fn card_back_text() -> Text<'static> {
+    let rows = (CARD_H as usize).saturating_sub(2);
+    let back = Style::default().fg(Color::DarkGray);
+    Text::from(
+        std::iter::repeat_with(|| Line::from(Span::styled("▒".repeat(CARD_W as usize - 2), back)))
+            .take(rows)
+            .collect::<Vec<_>>(),
+    )
+}
+
 fn card_block(card: Card, is_cursor: bool, debuffed: bool) -> Block<'static> {
     let border_type = if is_cursor {
         BorderType::Double
@@ -286,9 +311,13 @@ pub fn render_hand(
         };
 
         let is_cursor = i == cursor_idx;
-        let debuffed = app.game.is_card_debuffed(card);
+        let debuffed = card.debuffed;
         let block = card_block(*card, is_cursor, debuffed);
-        let text = card_inner_text(*card, debuffed);
+        let text = if card.face_down {
+            card_back_text()
+        } else {
+            card_inner_text(*card, debuffed)
+        };
         let para = Paragraph::new(text).block(block);
         f.render_widget(para, card_rect);
 
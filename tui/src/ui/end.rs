@@ -17,6 +17,7 @@ pub fn render(f: &mut Frame, app: &mut AppState, area: Rect) {
     let (color, msg) = match end {
         End::Win => (Color::Green, "You Win!"),
         End::Lose => (Color::Red, "Game Over"),
+        End::EndlessLose => (Color::Yellow, "Game Over (after winning)"),
     };
 
     let block = Block::default()
@@ -59,16 +59,21 @@ fn render_jokers(f: &mut Frame, app: &mut AppState, area: Rect) {
 
         if let Some(joker) = jokers.get(i) {
             let is_cursor = focused && app.cursor == i;
-            let name = joker.name();
-            let (line1, line2) = cards::wrap_two_lines(&name, inner_w);
-            let text_style = Style::default()
-                .fg(Color::Magenta)
-                .add_modifier(Modifier::BOLD);
+            // Amber Acorn hides every joker, debuffed ones are greyed out
+            let (name, color) = if app.game.jokers_face_down() {
+                ("?", Color::DarkGray)
+            } else if app.game.is_joker_debuffed(joker) {
+                (joker.name(), Color::DarkGray)
+            } else {
+                (joker.name(), Color::Magenta)
+            };
+            let (line1, line2) = cards::wrap_two_lines(name, inner_w);
+            let text_style = Style::default().fg(color).add_modifier(Modifier::BOLD);
             let lines = vec![
                 Line::from(Span::styled(line1, text_style)),
                 Line::from(Span::styled(line2, text_style)),
             ];
-            cards::render_item_box(f, slot_rect, is_cursor, Color::Magenta, None, lines, None);
+            cards::render_item_box(f, slot_rect, is_cursor, color, None, lines, None);
             app.widget_rects.insert(WidgetId::JokerSlot(i), slot_rect);
         } else {
             let block = Block::default()
@@ -117,7 +122,7 @@ fn render_consumables(f: &mut Frame, app: &mut AppState, area: Rect) {
             let is_cursor = focused && app.cursor == i;
             let color = super::consumable_type_color(c);
             let name = c.name();
-            let (line1, line2) = cards::wrap_two_lines(&name, inner_w);
+            let (line1, line2) = cards::wrap_two_lines(name, inner_w);
             let text_style = Style::default().fg(color).add_modifier(Modifier::BOLD);
             let lines = vec![
                 Line::from(Span::styled(line1, text_style)),
@@ -16,7 +16,7 @@ pub fn render(f: &mut Frame, app: &mut AppState, area: Rect, idx: usize) {
     let rect = centered_rect(w, h, area);
     f.render_widget(Clear, rect);
 
-    let Some(c) = app.game.consumables.get(idx).cloned() else {
+    let Some(c) = app.game.consumables.get(idx).map(|h| h.consumable) else {
         app.overlay = None;
         return;
     };
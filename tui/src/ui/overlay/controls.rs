@@ -10,7 +10,7 @@ use ratatui::{
 
 pub fn render(f: &mut Frame, app: &mut AppState, area: Rect) {
     let w: u16 = 58;
-    let h: u16 = 22;
+    let h: u16 = 23;
     let rect = centered_rect(w, h, area);
     f.render_widget(Clear, rect);
 
@@ -36,6 +36,7 @@ pub fn render(f: &mut Frame, app: &mut AppState, area: Rect) {
         bind("i", "Inspect item"),
         bind("Tab / Shift+Tab", "Next / prev zone"),
         bind("←/→", "Move cursor"),
+        bind("Shift+←/→", "Reorder joker / consumable"),
         bind("Enter / Space", "Select / confirm"),
         bind("Esc / Enter", "Close overlay"),
         Line::from(""),
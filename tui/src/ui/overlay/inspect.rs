@@ -1,10 +1,12 @@
 use crate::app::{AppState, InspectTarget, WidgetId};
 use crate::ui::cards::{edition_color, rank_str, suit_char, suit_color};
 use crate::ui::overlay::centered_rect;
-use crate::ui::wrap;
+use crate::ui::{hand_rank_name, wrap};
 use balatro_rs::consumable::Consumable;
 use balatro_rs::joker::Jokers;
 use balatro_rs::pack::{Pack, PackCategory};
+use balatro_rs::tag::Tag;
+use balatro_rs::voucher::Voucher;
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -135,6 +137,13 @@ pub(crate) fn card_lines(card: &balatro_rs::card::Card) -> Vec<Line<'static>> {
                 Style::default().fg(Color::Cyan),
             ),
         ]),
+        Line::from(vec![
+            Span::raw("  Bonus:       "),
+            Span::styled(
+                format!("+{} chips", card.bonus_chips),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
         Line::from(""),
     ]
 }
@@ -172,6 +181,33 @@ pub(crate) fn pack_lines(pack: &Pack, w: u16) -> Vec<Line<'static>> {
     lines
 }
 
+pub(crate) fn voucher_lines(voucher: &Voucher, w: u16) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("  Cost:  "),
+            Span::styled(
+                format!("${}", voucher.cost()),
+                Style::default().fg(Color::Yellow),
+            ),
+        ]),
+    ];
+    if let Some(base) = voucher.requires() {
+        lines.push(Line::from(vec![
+            Span::raw("  Needs: "),
+            Span::styled(base.name().to_string(), Style::default().fg(Color::Cyan)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    for word_line in wrap(voucher.description(), w as usize - 4) {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", word_line),
+            Style::default().fg(Color::White),
+        )));
+    }
+    lines
+}
+
 pub fn render(f: &mut Frame, app: &mut AppState, area: Rect, target: InspectTarget) {
     let w: u16 = 44;
     let h: u16 = 18;
@@ -212,6 +248,12 @@ pub fn render(f: &mut Frame, app: &mut AppState, area: Rect, target: InspectTarg
                     Style::default().fg(Color::White),
                 )));
             }
+            if let Tag::Orbital(rank) = tag {
+                lines.push(Line::from(Span::styled(
+                    format!("  Hand: {}", hand_rank_name(rank)),
+                    Style::default().fg(Color::Cyan),
+                )));
+            }
             lines.push(Line::from(""));
             (title, lines)
         }
@@ -8,6 +8,39 @@ use ratatui::{
     Frame,
 };
 
+fn render_vouchers(f: &mut Frame, app: &AppState, area: Rect) {
+    if app.game.vouchers.is_empty() {
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                "  No vouchers redeemed",
+                Style::default().fg(Color::DarkGray),
+            )),
+            area,
+        );
+        return;
+    }
+    let lines: Vec<Line> = app
+        .game
+        .vouchers
+        .iter()
+        .map(|v| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:<16}", v.name()),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    v.description().to_string(),
+                    Style::default().fg(Color::White),
+                ),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), area);
+}
+
 pub fn render(f: &mut Frame, app: &mut AppState, area: Rect) {
     let w: u16 = 72;
     let h: u16 = 22;
@@ -91,15 +124,7 @@ pub fn render(f: &mut Frame, app: &mut AppState, area: Rect) {
     match app.run_info_tab {
         RunInfoTab::Blinds => blinds::render_body(f, app, chunks[2]),
         RunInfoTab::PokerHands => poker_hands::render_body(f, app, chunks[2]),
-        RunInfoTab::Vouchers => {
-            f.render_widget(
-                Paragraph::new(Span::styled(
-                    "  Coming soon",
-                    Style::default().fg(Color::DarkGray),
-                )),
-                chunks[2],
-            );
-        }
+        RunInfoTab::Vouchers => render_vouchers(f, app, chunks[2]),
     }
 
     let seed_label = app
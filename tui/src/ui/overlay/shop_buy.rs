@@ -1,6 +1,8 @@
 use crate::app::{AppState, ShopSlot, WidgetId};
 use crate::ui::overlay::centered_rect;
-use crate::ui::overlay::inspect::{consumable_lines, joker_lines, pack_lines};
+use crate::ui::overlay::inspect::{
+    card_lines, consumable_lines, joker_lines, pack_lines, voucher_lines,
+};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -20,7 +22,7 @@ pub fn render(f: &mut Frame, app: &mut AppState, area: Rect, slot: ShopSlot) {
         return;
     };
 
-    let can_afford = app.game.money >= cost;
+    let can_afford = app.game.spendable() >= cost;
     let buy_selected = app.overlay_cursor == 0;
     // A dim, un-highlighted "Buy" that's still technically clickable but
     // silently does nothing reads as broken — spell out *why* instead, so
@@ -48,7 +50,7 @@ pub fn render(f: &mut Frame, app: &mut AppState, area: Rect, slot: ShopSlot) {
     let buy_text = if can_afford {
         format!("  [ Buy (${}) ]", cost)
     } else {
-        format!("  [ Need ${} more ]", cost.saturating_sub(app.game.money))
+        format!("  [ Need ${} more ]", cost.saturating_sub(app.game.spendable()))
     };
     let gap = "   ";
     let cancel_text = "[ Cancel ]";
@@ -106,7 +108,7 @@ fn item_info(app: &AppState, slot: ShopSlot, w: u16) -> Option<(String, usize, V
             let joker = app.game.shop.jokers.get(idx)?;
             Some((
                 format!(" {} ", joker.name()),
-                joker.cost(),
+                app.game.shop.joker_cost(joker),
                 joker_lines(joker, w),
             ))
         }
@@ -114,13 +116,33 @@ fn item_info(app: &AppState, slot: ShopSlot, w: u16) -> Option<(String, usize, V
             let c = app.game.shop.consumables.get(idx)?;
             Some((
                 format!(" {} ", c.name()),
-                c.cost(),
+                app.game.shop.consumable_cost(c),
                 consumable_lines(c, w),
             ))
         }
         ShopSlot::Pack(idx) => {
             let pack = app.game.shop.packs.get(idx)?;
-            Some((format!(" {} ", pack.name()), pack.cost(), pack_lines(pack, w)))
+            Some((
+                format!(" {} ", pack.name()),
+                app.game.shop.pack_cost(pack),
+                pack_lines(pack, w),
+            ))
+        }
+        ShopSlot::Voucher(idx) => {
+            let voucher = app.game.shop.vouchers.get(idx)?;
+            Some((
+                format!(" {} ", voucher.name()),
+                app.game.shop.voucher_cost(voucher),
+                voucher_lines(voucher, w),
+            ))
+        }
+        ShopSlot::PlayingCard(idx) => {
+            let card = app.game.shop.playing_cards.get(idx)?;
+            Some((
+                format!(" {} ", card),
+                app.game.shop.playing_card_cost(card),
+                card_lines(card),
+            ))
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::app::{AppState, FocusZone, WidgetId};
 use crate::ui::{joker_strip, sidebar};
-use balatro_rs::stage::BlindExt;
+use balatro_rs::reward::{RewardLine, RewardSource};
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
@@ -39,8 +39,11 @@ fn render_main(f: &mut Frame, app: &mut AppState, area: Rect) {
         height: inner.height.saturating_sub(joker_strip::STRIP_H),
     };
 
+    let reward = &app.game.round_reward;
+    let total = reward.total();
+
     let panel_w: u16 = 50;
-    let panel_h: u16 = 11;
+    let panel_h: u16 = reward.lines.len() as u16 + 7;
     let btn_w: u16 = 20;
     let btn_h: u16 = 3;
     let gap: u16 = 1;
@@ -54,13 +57,6 @@ fn render_main(f: &mut Frame, app: &mut AppState, area: Rect) {
         height: panel_h,
     };
 
-    // game.reward = blind_base + interest + hand_bonus (computed by engine on blind clear)
-    let total = app.game.reward;
-    let blind_base = app.game.blind.map(|b| b.reward()).unwrap_or(0);
-    let hands_bonus = app.game.plays * app.game.config.money_per_hand;
-    let interest = ((app.game.money as f32 * app.game.config.interest_rate) as usize)
-        .min(app.game.config.interest_max);
-
     let block = Block::default()
         .title(Span::styled(
             format!(" Cash Out: ${} ", total),
@@ -74,61 +70,31 @@ fn render_main(f: &mut Frame, app: &mut AppState, area: Rect) {
     let sep = "─".repeat(panel_w as usize - 2);
 
     let score_label = format!("  Round score: ◆ {}", app.game.last_score);
-    let blind_label = "  Beat the blind".to_string();
-    let hands_label = format!(
-        "  {} hand(s) remaining x ${}",
-        app.game.plays, app.game.config.money_per_hand
-    );
-    let interest_per = (1.0 / app.game.config.interest_rate).round() as usize;
-    let interest_label = format!(
-        "  Interest ($1 per ${}, max {})",
-        interest_per, app.game.config.interest_max
-    );
 
-    let lines = vec![
+    let mut lines = vec![
         Line::from(""),
         Line::from(vec![Span::styled(
-            score_label.clone(),
+            score_label,
             Style::default().fg(Color::LightBlue),
         )]),
         Line::from(""),
-        Line::from(vec![
-            Span::styled(blind_label.clone(), Style::default().fg(Color::White)),
-            Span::raw(format!(
-                "{:>width$}",
-                format!("${}", blind_base),
-                width = (panel_w as usize).saturating_sub(blind_label.len() + 2)
-            )),
-        ]),
-        Line::from(vec![
-            Span::styled(hands_label.clone(), Style::default().fg(Color::White)),
-            Span::styled(
-                format!(
-                    "{:>width$}",
-                    format!("${}", hands_bonus),
-                    width = (panel_w as usize).saturating_sub(hands_label.len() + 2)
-                ),
-                Style::default().fg(Color::Yellow),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled(interest_label.clone(), Style::default().fg(Color::White)),
+    ];
+    // one row per cash out source, in the order the engine paid them
+    for line in &reward.lines {
+        let label = format!("  {}", reward_label(app, line));
+        lines.push(Line::from(vec![
+            Span::styled(label.clone(), Style::default().fg(Color::White)),
             Span::styled(
                 format!(
                     "{:>width$}",
-                    format!("${}", interest),
-                    width = (panel_w as usize).saturating_sub(interest_label.len() + 2)
+                    format!("${}", line.amount),
+                    width = (panel_w as usize).saturating_sub(label.chars().count() + 2)
                 ),
                 Style::default().fg(Color::Yellow),
             ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Joker bonuses", Style::default().fg(Color::DarkGray)),
-            Span::styled(
-                format!("{:>width$}", "(coming soon)", width = panel_w as usize - 17),
-                Style::default().fg(Color::DarkGray),
-            ),
-        ]),
+        ]));
+    }
+    lines.extend([
         Line::from(Span::styled(sep, Style::default().fg(Color::DarkGray))),
         Line::from(vec![
             Span::styled(
@@ -148,7 +114,7 @@ fn render_main(f: &mut Frame, app: &mut AppState, area: Rect) {
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
-    ];
+    ]);
 
     let para = Paragraph::new(Text::from(lines)).block(block);
     f.render_widget(para, panel_rect);
@@ -181,3 +147,23 @@ fn render_main(f: &mut Frame, app: &mut AppState, area: Rect) {
     f.render_widget(btn_para, btn_rect);
     app.widget_rects.insert(WidgetId::CashOutButton, btn_rect);
 }
+
+fn reward_label(app: &AppState, line: &RewardLine) -> String {
+    let config = &app.game.config;
+    match line.source {
+        RewardSource::Blind(_) => "Beat the blind".to_string(),
+        RewardSource::Hands => format!(
+            "{} hand(s) remaining x ${}",
+            app.game.plays,
+            line.amount / app.game.plays.max(1)
+        ),
+        RewardSource::Interest => {
+            let interest_per = (1.0 / config.interest_rate).round() as usize;
+            format!(
+                "Interest ($1 per ${}, max {})",
+                interest_per, config.interest_max
+            )
+        }
+        _ => line.describe(),
+    }
+}
@@ -90,8 +90,9 @@ fn render_jokers_for_sale(f: &mut Frame, app: &mut AppState, area: Rect) {
             break;
         }
         let is_cursor = focused && app.cursor == i;
-        let can_afford = app.game.money >= joker.cost();
-        render_price_tag(f, x, area.y, joker.cost(), can_afford);
+        let cost = app.game.shop.joker_cost(joker);
+        let can_afford = app.game.spendable() >= cost;
+        render_price_tag(f, x, area.y, cost, can_afford);
 
         let item_rect = Rect {
             x,
@@ -127,8 +128,9 @@ fn render_jokers_for_sale(f: &mut Frame, app: &mut AppState, area: Rect) {
             break;
         }
         let is_cursor = focused && app.cursor == slot;
-        let can_afford = app.game.money >= consumable.cost();
-        render_price_tag(f, x, area.y, consumable.cost(), can_afford);
+        let cost = app.game.shop.consumable_cost(consumable);
+        let can_afford = app.game.spendable() >= cost;
+        render_price_tag(f, x, area.y, cost, can_afford);
         let fg = super::consumable_type_color(consumable);
 
         let item_rect = Rect {
@@ -151,6 +153,29 @@ fn render_jokers_for_sale(f: &mut Frame, app: &mut AppState, area: Rect) {
         app.widget_rects
             .insert(WidgetId::ShopConsumable(ci), item_rect);
     }
+
+    // Magic Trick puts playing cards on the shelf after the consumables
+    let card_start = joker_count + consumables.len();
+    let playing_cards = app.game.shop.playing_cards.clone();
+    for (pi, card) in playing_cards.iter().enumerate() {
+        let slot = card_start + pi;
+        let x = area.x + 1 + slot as u16 * SLOT_W;
+        if x + CARD_W > area.x + area.width {
+            break;
+        }
+        let is_cursor = focused && app.cursor == slot;
+        let cost = app.game.shop.playing_card_cost(card);
+        render_price_tag(f, x, area.y, cost, app.game.spendable() >= cost);
+        let item_rect = Rect {
+            x,
+            y: area.y + 1,
+            width: CARD_W,
+            height: CARD_H,
+        };
+        cards::render_card(f, *card, item_rect, is_cursor, false);
+        app.widget_rects
+            .insert(WidgetId::ShopPlayingCard(pi), item_rect);
+    }
 }
 
 fn render_packs_for_sale(f: &mut Frame, app: &mut AppState, area: Rect) {
@@ -164,8 +189,9 @@ fn render_packs_for_sale(f: &mut Frame, app: &mut AppState, area: Rect) {
             break;
         }
         let is_cursor = focused && app.cursor == i;
-        let can_afford = app.game.money >= pack.cost();
-        render_price_tag(f, x, area.y, pack.cost(), can_afford);
+        let cost = app.game.shop.pack_cost(pack);
+        let can_afford = app.game.spendable() >= cost;
+        render_price_tag(f, x, area.y, cost, can_afford);
         let category_color = pack_category_color(&pack.category);
 
         let item_rect = Rect {
@@ -189,11 +215,45 @@ fn render_packs_for_sale(f: &mut Frame, app: &mut AppState, area: Rect) {
         cards::render_item_box(f, item_rect, is_cursor, border_color, None, lines, None);
         app.widget_rects.insert(WidgetId::ShopPack(i), item_rect);
     }
+
+    // this ante's voucher offer sits after the packs
+    let vouchers = app.game.shop.vouchers.clone();
+    for (vi, voucher) in vouchers.iter().enumerate() {
+        let slot = packs.len() + vi;
+        let x = area.x + 1 + slot as u16 * SLOT_W;
+        if x + CARD_W > area.x + area.width {
+            break;
+        }
+        let is_cursor = focused && app.cursor == slot;
+        let cost = app.game.shop.voucher_cost(voucher);
+        let can_afford = app.game.spendable() >= cost;
+        render_price_tag(f, x, area.y, cost, can_afford);
+
+        let item_rect = Rect {
+            x,
+            y: area.y + 1,
+            width: CARD_W,
+            height: CARD_H,
+        };
+
+        let border_color = if can_afford { Color::Red } else { Color::DarkGray };
+        let (line1, line2) = cards::wrap_two_lines(voucher.name(), inner_w);
+        let text_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+        let lines = vec![
+            Line::from(Span::styled(line1, text_style)),
+            Line::from(Span::styled(line2, text_style)),
+        ];
+        let footer = Line::from(Span::styled("Voucher", Style::default().fg(Color::DarkGray)));
+
+        cards::render_item_box(f, item_rect, is_cursor, border_color, None, lines, Some(footer));
+        app.widget_rects
+            .insert(WidgetId::ShopVoucher(vi), item_rect);
+    }
 }
 
 fn render_reroll(f: &mut Frame, app: &mut AppState, area: Rect) {
     let cost = app.game.reroll_cost;
-    let can_afford = app.game.money >= cost;
+    let can_afford = app.game.spendable() >= cost;
     let focused = app.focus == FocusZone::ShopReroll;
 
     let btn_w: u16 = 24;
@@ -166,9 +166,7 @@ fn render_stats(f: &mut Frame, app: &AppState, inner: Rect) {
     let eval = if let Stage::Blind(_) = &game.stage {
         let selected = game.available.selected();
         if !selected.is_empty() {
-            balatro_rs::hand::SelectHand::new(selected.clone())
-                .best_hand()
-                .ok()
+            game.best_hand(selected.clone()).ok()
         } else {
             None
         }
@@ -234,11 +232,7 @@ fn render_stats(f: &mut Frame, app: &AppState, inner: Rect) {
     lines.push(Line::from(vec![
         label("Ante  "),
         value(
-            format!(
-                "{}/{}",
-                ante_num(game.ante_current),
-                ante_num(game.ante_end)
-            ),
+            format!("{}/{}", game.ante_current.num(), game.ante_end.num()),
             Color::White,
         ),
     ]));
@@ -287,21 +281,6 @@ fn render_sidebar_button(f: &mut Frame, rect: Rect, label_text: &str) {
     f.render_widget(para, rect);
 }
 
-fn ante_num(ante: balatro_rs::ante::Ante) -> usize {
-    use balatro_rs::ante::Ante;
-    match ante {
-        Ante::Zero => 0,
-        Ante::One => 1,
-        Ante::Two => 2,
-        Ante::Three => 3,
-        Ante::Four => 4,
-        Ante::Five => 5,
-        Ante::Six => 6,
-        Ante::Seven => 7,
-        Ante::Eight => 8,
-    }
-}
-
 pub fn split_sidebar_main(area: Rect) -> (Rect, Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)